@@ -1,6 +1,6 @@
 use crate::{
     bitmap::{
-        utils::{BitmapIter, ZipValidity},
+        utils::{BitmapIter, TrueIdxIter, ZipValidity},
         Bitmap, MutableBitmap,
     },
     datatypes::{DataType, PhysicalType},
@@ -92,6 +92,34 @@ impl BooleanArray {
         Self::try_new(data_type, values, validity).unwrap()
     }
 
+    /// Creates a new [`BooleanArray`] from `length` bit-packed values, stored in `values` in
+    /// LSB order as specified by Arrow.
+    /// This function is `O(1)` over the values (except for the count of its unset bits), as
+    /// `values` is re-used without being unpacked.
+    /// # Errors
+    /// This function errors iff:
+    /// * `length > values.len() * 8`
+    /// * the validity is not `None` and its length is different from `length`
+    /// * The `data_type`'s [`PhysicalType`] is not equal to [`PhysicalType::Boolean`].
+    pub fn try_from_packed(
+        data_type: DataType,
+        values: Vec<u8>,
+        length: usize,
+        validity: Option<Bitmap>,
+    ) -> Result<Self, Error> {
+        Self::try_new(data_type, Bitmap::try_new(values, length)?, validity)
+    }
+
+    /// Alias to `Self::try_from_packed().unwrap()`
+    pub fn from_packed(
+        data_type: DataType,
+        values: Vec<u8>,
+        length: usize,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_from_packed(data_type, values, length, validity).unwrap()
+    }
+
     /// Returns an iterator over the optional values of this [`BooleanArray`].
     #[inline]
     pub fn iter(&self) -> ZipValidity<bool, BitmapIter, BitmapIter> {
@@ -104,6 +132,14 @@ impl BooleanArray {
         self.values().iter()
     }
 
+    /// Returns an iterator of `(index, value)` over the non-null slots of this [`BooleanArray`].
+    #[inline]
+    pub fn non_null_values_iter(&self) -> impl TrustedLen<Item = (usize, bool)> + '_ {
+        TrueIdxIter::new(self.len(), self.validity())
+            // Safety: the indices are bounded by the length of the array
+            .map(move |i| (i, unsafe { self.values().get_bit_unchecked(i) }))
+    }
+
     /// Returns the length of this array
     #[inline]
     pub fn len(&self) -> usize {
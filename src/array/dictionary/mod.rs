@@ -3,8 +3,8 @@ use std::hint::unreachable_unchecked;
 
 use crate::{
     bitmap::{
-        utils::{BitmapIter, ZipValidity},
-        Bitmap,
+        utils::{BitmapIter, SlicesIterator, ZipValidity},
+        Bitmap, MutableBitmap,
     },
     datatypes::{DataType, IntegerType},
     error::Error,
@@ -27,6 +27,7 @@ use crate::array::dictionary::typed_iterator::{DictValue, DictionaryValuesIterTy
 pub use iterator::*;
 pub use mutable::*;
 
+use super::{growable::make_growable, ord::build_compare};
 use super::{new_empty_array, primitive::PrimitiveArray, Array};
 use super::{new_null_array, specification::check_indexes};
 
@@ -390,6 +391,88 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         new_scalar(self.values.as_ref(), index)
     }
 
+    /// Returns this [`DictionaryArray`] without the values that no key references (e.g. after
+    /// slicing or filtering it), with its keys re-mapped to the remaining values.
+    ///
+    /// The remaining values keep their relative order, so that an ordered dictionary remains
+    /// ordered. When all values are referenced, this function returns a (cheap) clone.
+    #[must_use]
+    pub fn compact(&self) -> Self {
+        self.compact_keeping(&[])
+    }
+
+    /// Returns this [`DictionaryArray`] compacted as in [`Self::compact`], but keeping the
+    /// minimum and maximum non-null values of its dictionary even when no key references them,
+    /// so that the range of the dictionary (e.g. as used by statistics) is preserved.
+    /// # Errors
+    /// This function errors iff the type of the values has no natural order.
+    pub fn compact_preserving_min_max(&self) -> Result<Self, Error> {
+        let values = self.values.as_ref();
+        let compare = build_compare(values, values)?;
+        let mut min_max: Option<(usize, usize)> = None;
+        for row in (0..values.len()).filter(|row| values.is_valid(*row)) {
+            min_max = Some(match min_max {
+                None => (row, row),
+                Some((min, max)) => (
+                    if compare(row, min).is_lt() { row } else { min },
+                    if compare(row, max).is_gt() { row } else { max },
+                ),
+            });
+        }
+        let keep = min_max.map(|(min, max)| vec![min, max]).unwrap_or_default();
+        Ok(self.compact_keeping(&keep))
+    }
+
+    /// Compacts this [`DictionaryArray`], keeping the referenced values and the values `keep`.
+    fn compact_keeping(&self, keep: &[usize]) -> Self {
+        let mut used = MutableBitmap::from_len_zeroed(self.values.len());
+        self.keys_iter()
+            .flatten()
+            .chain(keep.iter().copied())
+            .for_each(|key| used.set(key, true));
+        if used.unset_bits() == used.len() && !self.is_empty() && !used.is_empty() {
+            // null slots still require a valid key
+            used.set(0, true);
+        }
+        let used: Bitmap = used.into();
+        if used.unset_bits() == 0 {
+            return self.clone();
+        }
+
+        // the position of every value in the compacted values
+        let positions = used
+            .iter()
+            .scan(0, |position, is_used| {
+                let current = *position;
+                *position += is_used as usize;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+        let mut growable = make_growable(
+            &[self.values.as_ref()],
+            false,
+            used.len() - used.unset_bits(),
+        );
+        for (start, length) in SlicesIterator::new(&used) {
+            growable.extend(0, start, length);
+        }
+        let values = growable.as_box();
+
+        let keys = self
+            .keys_iter()
+            .map(|key| key.map(|key| positions[key]).unwrap_or_default())
+            // a compacted key is never larger than its original key, and so it fits in `K`
+            .map(|key| K::try_from(key).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let keys = PrimitiveArray::new(
+            K::PRIMITIVE.into(),
+            keys.into(),
+            self.keys.validity().cloned(),
+        );
+        // safety: every key is the position of a compacted value
+        unsafe { Self::try_new_unchecked(self.data_type.clone(), keys, values) }.unwrap()
+    }
+
     pub(crate) fn try_get_child(data_type: &DataType) -> Result<&DataType, Error> {
         Ok(match data_type.to_logical_type() {
             DataType::Dictionary(_, values, _) => values.as_ref(),
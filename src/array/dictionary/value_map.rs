@@ -165,6 +165,22 @@ impl<K: DictionaryKey, M: MutableArray> ValueMap<K, M> {
 
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
+        if self.map.capacity() > self.map.len() {
+            // all items are distinct, so they can be re-inserted with their stored hash
+            let mut map = HashMap::<Hashed<K>, _, _>::with_capacity_and_hasher(
+                self.map.len(),
+                BuildHasherDefault::<PassthroughHasher>::default(),
+            );
+            for (item, _) in self.map.drain() {
+                if let RawEntryMut::Vacant(entry) =
+                    map.raw_entry_mut().from_hash(item.hash, |_| false)
+                {
+                    // NB: don't use .insert() here!
+                    entry.insert_hashed_nocheck(item.hash, item, ());
+                }
+            }
+            self.map = map;
+        }
     }
 }
 
@@ -0,0 +1,174 @@
+//! Contains the trait [`AsArray`], offering typed downcasts of `dyn Array`.
+use crate::offset::Offset;
+use crate::types::NativeType;
+
+use super::*;
+
+macro_rules! downcast_fns {
+    ($as:ident, $expect:ident, $ty:ty, $name:literal) => {
+        #[doc = concat!("Downcasts this array to a [`", $name, "`], returning `None` if it is of a different type.")]
+        #[inline]
+        fn $as(&self) -> Option<&$ty> {
+            self.as_array().as_any().downcast_ref()
+        }
+
+        #[doc = concat!("Downcasts this array to a [`", $name, "`].")]
+        /// # Panics
+        /// This function panics iff the array is of a different type.
+        #[inline]
+        fn $expect(&self) -> &$ty {
+            let array = self.as_array();
+            self.$as().unwrap_or_else(|| {
+                panic!(
+                    concat!("expected a ", $name, " but got an array of type {:?}"),
+                    array.data_type()
+                )
+            })
+        }
+    };
+}
+
+/// Trait offering typed downcasts of [`Array`] trait objects, as an ergonomic alternative to
+/// `array.as_any().downcast_ref::<...>()`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, AsArray, Int32Array};
+///
+/// let array: Box<dyn Array> = Int32Array::from_slice([1, 2]).boxed();
+/// assert_eq!(array.as_primitive::<i32>().unwrap().value(1), 2);
+/// assert!(array.as_utf8::<i32>().is_none());
+/// ```
+pub trait AsArray {
+    /// Returns this as a `&dyn Array`.
+    fn as_array(&self) -> &dyn Array;
+
+    /// Downcasts this array to a [`PrimitiveArray<T>`], returning `None` if it is of a different type.
+    #[inline]
+    fn as_primitive<T: NativeType>(&self) -> Option<&PrimitiveArray<T>> {
+        self.as_array().as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`PrimitiveArray<T>`].
+    /// # Panics
+    /// This function panics iff the array is of a different type.
+    #[inline]
+    fn expect_primitive<T: NativeType>(&self) -> &PrimitiveArray<T> {
+        let array = self.as_array();
+        self.as_primitive().unwrap_or_else(|| {
+            panic!(
+                "expected a PrimitiveArray<{}> but got an array of type {:?}",
+                std::any::type_name::<T>(),
+                array.data_type()
+            )
+        })
+    }
+
+    /// Downcasts this array to a [`Utf8Array<O>`], returning `None` if it is of a different type.
+    #[inline]
+    fn as_utf8<O: Offset>(&self) -> Option<&Utf8Array<O>> {
+        self.as_array().as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`Utf8Array<O>`].
+    /// # Panics
+    /// This function panics iff the array is of a different type.
+    #[inline]
+    fn expect_utf8<O: Offset>(&self) -> &Utf8Array<O> {
+        let array = self.as_array();
+        self.as_utf8().unwrap_or_else(|| {
+            panic!(
+                "expected a Utf8Array<{}> but got an array of type {:?}",
+                std::any::type_name::<O>(),
+                array.data_type()
+            )
+        })
+    }
+
+    /// Downcasts this array to a [`BinaryArray<O>`], returning `None` if it is of a different type.
+    #[inline]
+    fn as_binary<O: Offset>(&self) -> Option<&BinaryArray<O>> {
+        self.as_array().as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`BinaryArray<O>`].
+    /// # Panics
+    /// This function panics iff the array is of a different type.
+    #[inline]
+    fn expect_binary<O: Offset>(&self) -> &BinaryArray<O> {
+        let array = self.as_array();
+        self.as_binary().unwrap_or_else(|| {
+            panic!(
+                "expected a BinaryArray<{}> but got an array of type {:?}",
+                std::any::type_name::<O>(),
+                array.data_type()
+            )
+        })
+    }
+
+    /// Downcasts this array to a [`ListArray<O>`], returning `None` if it is of a different type.
+    #[inline]
+    fn as_list<O: Offset>(&self) -> Option<&ListArray<O>> {
+        self.as_array().as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`ListArray<O>`].
+    /// # Panics
+    /// This function panics iff the array is of a different type.
+    #[inline]
+    fn expect_list<O: Offset>(&self) -> &ListArray<O> {
+        let array = self.as_array();
+        self.as_list().unwrap_or_else(|| {
+            panic!(
+                "expected a ListArray<{}> but got an array of type {:?}",
+                std::any::type_name::<O>(),
+                array.data_type()
+            )
+        })
+    }
+
+    /// Downcasts this array to a [`DictionaryArray<K>`], returning `None` if it is of a different type.
+    #[inline]
+    fn as_dictionary<K: DictionaryKey>(&self) -> Option<&DictionaryArray<K>> {
+        self.as_array().as_any().downcast_ref()
+    }
+
+    /// Downcasts this array to a [`DictionaryArray<K>`].
+    /// # Panics
+    /// This function panics iff the array is of a different type.
+    #[inline]
+    fn expect_dictionary<K: DictionaryKey>(&self) -> &DictionaryArray<K> {
+        let array = self.as_array();
+        self.as_dictionary().unwrap_or_else(|| {
+            panic!(
+                "expected a DictionaryArray<{}> but got an array of type {:?}",
+                std::any::type_name::<K>(),
+                array.data_type()
+            )
+        })
+    }
+
+    downcast_fns!(as_null, expect_null, NullArray, "NullArray");
+    downcast_fns!(as_boolean, expect_boolean, BooleanArray, "BooleanArray");
+    downcast_fns!(
+        as_fixed_size_binary,
+        expect_fixed_size_binary,
+        FixedSizeBinaryArray,
+        "FixedSizeBinaryArray"
+    );
+    downcast_fns!(
+        as_fixed_size_list,
+        expect_fixed_size_list,
+        FixedSizeListArray,
+        "FixedSizeListArray"
+    );
+    downcast_fns!(as_struct, expect_struct, StructArray, "StructArray");
+    downcast_fns!(as_map, expect_map, MapArray, "MapArray");
+    downcast_fns!(as_union, expect_union, UnionArray, "UnionArray");
+}
+
+impl AsArray for dyn Array {
+    #[inline]
+    fn as_array(&self) -> &dyn Array {
+        self
+    }
+}
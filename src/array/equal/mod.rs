@@ -200,6 +200,8 @@ impl PartialEq<&dyn Array> for MapArray {
 /// Two arrays are logically equal if and only if:
 /// * their data types are equal
 /// * each of their items are equal
+///
+/// For floating point arrays, `NaN`s are considered equal to each other.
 pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
     if lhs.data_type() != rhs.data_type() {
         return false;
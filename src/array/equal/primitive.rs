@@ -1,5 +1,38 @@
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    types::{f16, NativeType, PrimitiveType},
+};
+
+/// Equality of two values where all `NaN`s are equal to each other. Other floats keep their
+/// IEEE 754 equality (so that `-0.0 == 0.0`).
+#[inline]
+fn nan_eq<T: NativeType>(lhs: &T, rhs: &T) -> bool {
+    match T::PRIMITIVE {
+        PrimitiveType::Float16 => {
+            let (lhs, rhs): (f16, f16) = (bytemuck::cast(*lhs), bytemuck::cast(*rhs));
+            lhs == rhs || (lhs.is_nan() && rhs.is_nan())
+        }
+        PrimitiveType::Float32 => {
+            let (lhs, rhs): (f32, f32) = (bytemuck::cast(*lhs), bytemuck::cast(*rhs));
+            lhs == rhs || (lhs.is_nan() && rhs.is_nan())
+        }
+        PrimitiveType::Float64 => {
+            let (lhs, rhs): (f64, f64) = (bytemuck::cast(*lhs), bytemuck::cast(*rhs));
+            lhs == rhs || (lhs.is_nan() && rhs.is_nan())
+        }
+        _ => lhs == rhs,
+    }
+}
 
 pub(super) fn equal<T: NativeType>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    lhs.data_type() == rhs.data_type()
+        && lhs.len() == rhs.len()
+        && lhs
+            .iter()
+            .zip(rhs.iter())
+            .all(|(lhs, rhs)| match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => nan_eq(lhs, rhs),
+                (None, None) => true,
+                _ => false,
+            })
 }
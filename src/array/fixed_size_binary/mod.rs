@@ -1,5 +1,7 @@
 use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::Error};
 
+use either::Either;
+
 use super::Array;
 
 #[cfg(feature = "arrow")]
@@ -119,6 +121,44 @@ impl FixedSizeBinaryArray {
     impl_sliced!();
     impl_mut_validity!();
     impl_into_array!();
+
+    /// Try to convert this [`FixedSizeBinaryArray`] to a [`MutableFixedSizeBinaryArray`] via
+    /// copy-on-write semantics.
+    ///
+    /// This function returns a [`MutableFixedSizeBinaryArray`] iff both values and validity
+    /// have not been cloned / are unique references to their underlying vectors.
+    #[must_use]
+    pub fn into_mut(self) -> Either<Self, MutableFixedSizeBinaryArray> {
+        use Either::*;
+
+        if let Some(bitmap) = self.validity {
+            match bitmap.into_mut() {
+                Left(bitmap) => Left(Self::new(self.data_type, self.values, Some(bitmap))),
+                Right(mutable_bitmap) => match self.values.into_mut() {
+                    Left(values) => Left(Self::new(
+                        self.data_type,
+                        values,
+                        Some(mutable_bitmap.into()),
+                    )),
+                    Right(values) => Right(
+                        MutableFixedSizeBinaryArray::try_new(
+                            self.data_type,
+                            values,
+                            Some(mutable_bitmap),
+                        )
+                        .unwrap(),
+                    ),
+                },
+            }
+        } else {
+            match self.values.into_mut() {
+                Left(values) => Left(Self::new(self.data_type, values, None)),
+                Right(values) => Right(
+                    MutableFixedSizeBinaryArray::try_new(self.data_type, values, None).unwrap(),
+                ),
+            }
+        }
+    }
 }
 
 // accessors
@@ -26,7 +26,20 @@ impl<'a, O: Offset> GrowableBinary<'a, O> {
     /// Creates a new [`GrowableBinary`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a BinaryArray<O>>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a BinaryArray<O>>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_capacities(arrays, use_validity, capacity, 0)
+    }
+
+    /// Creates a new [`GrowableBinary`] bound to `arrays` with a pre-allocated `capacity` of rows
+    /// and `values_capacity` of bytes.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
+        arrays: Vec<&'a BinaryArray<O>>,
+        mut use_validity: bool,
+        capacity: usize,
+        values_capacity: usize,
+    ) -> Self {
         let data_type = arrays[0].data_type().clone();
 
         // if any of the arrays has nulls, insertions from any array requires setting bits
@@ -43,7 +56,7 @@ impl<'a, O: Offset> GrowableBinary<'a, O> {
         Self {
             arrays,
             data_type,
-            values: Vec::with_capacity(0),
+            values: Vec::with_capacity(values_capacity),
             offsets: Offsets::with_capacity(capacity),
             validity: MutableBitmap::with_capacity(capacity),
             extend_null_bits,
@@ -42,7 +42,7 @@ impl<'a> GrowableFixedSizeBinary<'a> {
         let size = FixedSizeBinaryArray::get_size(arrays[0].data_type());
         Self {
             arrays,
-            values: Vec::with_capacity(0),
+            values: Vec::with_capacity(capacity * size),
             validity: MutableBitmap::with_capacity(capacity),
             extend_null_bits,
             size,
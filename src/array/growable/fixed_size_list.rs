@@ -7,9 +7,9 @@ use crate::{
 };
 
 use super::{
-    make_growable,
+    make_growable_with_capacities,
     utils::{build_extend_null_bits, ExtendNullBits},
-    Growable,
+    Capacities, Growable,
 };
 
 /// Concrete [`Growable`] for the [`FixedSizeListArray`].
@@ -25,10 +25,19 @@ impl<'a> GrowableFixedSizeList<'a> {
     /// Creates a new [`GrowableFixedSizeList`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(
+    pub fn new(arrays: Vec<&'a FixedSizeListArray>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_capacities(arrays, use_validity, capacity, Capacities::Array(0))
+    }
+
+    /// Creates a new [`GrowableFixedSizeList`] bound to `arrays` with a pre-allocated `capacity`
+    /// of rows and `values` capacities of its items.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
         arrays: Vec<&'a FixedSizeListArray>,
         mut use_validity: bool,
         capacity: usize,
+        values: Capacities,
     ) -> Self {
         assert!(!arrays.is_empty());
 
@@ -54,7 +63,7 @@ impl<'a> GrowableFixedSizeList<'a> {
             .iter()
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
-        let values = make_growable(&inner, use_validity, 0);
+        let values = make_growable_with_capacities(&inner, use_validity, values);
 
         Self {
             arrays,
@@ -7,9 +7,9 @@ use crate::{
 };
 
 use super::{
-    make_growable,
+    make_growable_with_capacities,
     utils::{build_extend_null_bits, ExtendNullBits},
-    Growable,
+    Capacities, Growable,
 };
 
 fn extend_offset_values<O: Offset>(
@@ -45,7 +45,20 @@ impl<'a, O: Offset> GrowableList<'a, O> {
     /// Creates a new [`GrowableList`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a ListArray<O>>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a ListArray<O>>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_capacities(arrays, use_validity, capacity, Capacities::Array(0))
+    }
+
+    /// Creates a new [`GrowableList`] bound to `arrays` with a pre-allocated `capacity` of rows
+    /// and `values` capacities of its items.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
+        arrays: Vec<&'a ListArray<O>>,
+        mut use_validity: bool,
+        capacity: usize,
+        values: Capacities,
+    ) -> Self {
         // if any of the arrays has nulls, insertions from any array requires setting bits
         // as there is at least one array with nulls.
         if !use_validity & arrays.iter().any(|array| array.null_count() > 0) {
@@ -61,7 +74,7 @@ impl<'a, O: Offset> GrowableList<'a, O> {
             .iter()
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
-        let values = make_growable(&inner, use_validity, 0);
+        let values = make_growable_with_capacities(&inner, use_validity, values);
 
         Self {
             arrays,
@@ -7,9 +7,9 @@ use crate::{
 };
 
 use super::{
-    make_growable,
+    make_growable_with_capacities,
     utils::{build_extend_null_bits, ExtendNullBits},
-    Growable,
+    Capacities, Growable,
 };
 
 fn extend_offset_values(growable: &mut GrowableMap<'_>, index: usize, start: usize, len: usize) {
@@ -40,7 +40,22 @@ impl<'a> GrowableMap<'a> {
     /// Creates a new [`GrowableMap`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a MapArray>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a MapArray>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_capacities(arrays, use_validity, capacity, Capacities::Array(0))
+    }
+
+    /// Creates a new [`GrowableMap`] bound to `arrays` with a pre-allocated `capacity` of rows
+    /// and `values` capacities of its entries.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
+        arrays: Vec<&'a MapArray>,
+        mut use_validity: bool,
+        capacity: usize,
+        values: Capacities,
+    ) -> Self {
+        assert!(!arrays.is_empty());
+
         // if any of the arrays has nulls, insertions from any array requires setting bits
         // as there is at least one array with nulls.
         if !use_validity & arrays.iter().any(|array| array.null_count() > 0) {
@@ -56,7 +71,7 @@ impl<'a> GrowableMap<'a> {
             .iter()
             .map(|array| array.field().as_ref())
             .collect::<Vec<_>>();
-        let values = make_growable(&inner, use_validity, 0);
+        let values = make_growable_with_capacities(&inner, use_validity, values);
 
         Self {
             arrays,
@@ -59,6 +59,33 @@ pub trait Growable<'a> {
     fn as_box(&mut self) -> Box<dyn Array>;
 }
 
+/// The capacities to pre-allocate in a [`Growable`] and in the growables of its children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Capacities {
+    /// The number of rows
+    Array(usize),
+    /// The number of rows and the number of bytes of the values, of binary and string arrays
+    #[cfg(feature = "compute_concatenate")]
+    Binary(usize, usize),
+    /// The number of rows and the capacities of the values, of list and map arrays
+    #[cfg(feature = "compute_concatenate")]
+    List(usize, Box<Capacities>),
+    /// The number of rows and the capacities of every field, of struct arrays
+    #[cfg(feature = "compute_concatenate")]
+    Struct(usize, Vec<Capacities>),
+}
+
+impl Capacities {
+    /// The number of rows
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Array(length) => *length,
+            #[cfg(feature = "compute_concatenate")]
+            Self::Binary(length, _) | Self::List(length, _) | Self::Struct(length, _) => *length,
+        }
+    }
+}
+
 macro_rules! dyn_growable {
     ($ty:ty, $arrays:expr, $use_validity:expr, $capacity:expr) => {{
         let arrays = $arrays
@@ -146,3 +173,85 @@ pub fn make_growable<'a>(
         }
     }
 }
+
+/// Creates a new [`Growable`] from an arbitrary number of [`Array`]s, pre-allocating
+/// `capacities`. Capacities that do not apply to the type of `arrays` fall back to its
+/// number of rows.
+/// # Panics
+/// This function panics iff
+/// * the arrays do not have the same [`DataType`].
+/// * `arrays.is_empty()`.
+pub(crate) fn make_growable_with_capacities<'a>(
+    arrays: &[&'a dyn Array],
+    use_validity: bool,
+    capacities: Capacities,
+) -> Box<dyn Growable<'a> + 'a> {
+    #[cfg(feature = "compute_concatenate")]
+    macro_rules! downcast {
+        () => {
+            arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref().unwrap())
+                .collect::<Vec<_>>()
+        };
+    }
+    #[cfg(feature = "compute_concatenate")]
+    use PhysicalType::*;
+    match (arrays[0].data_type().to_physical_type(), capacities) {
+        #[cfg(feature = "compute_concatenate")]
+        (Utf8, Capacities::Binary(length, values)) => Box::new(
+            utf8::GrowableUtf8::<i32>::with_capacities(downcast!(), use_validity, length, values),
+        ),
+        #[cfg(feature = "compute_concatenate")]
+        (LargeUtf8, Capacities::Binary(length, values)) => Box::new(
+            utf8::GrowableUtf8::<i64>::with_capacities(downcast!(), use_validity, length, values),
+        ),
+        #[cfg(feature = "compute_concatenate")]
+        (Binary, Capacities::Binary(length, values)) => {
+            Box::new(binary::GrowableBinary::<i32>::with_capacities(
+                downcast!(),
+                use_validity,
+                length,
+                values,
+            ))
+        }
+        #[cfg(feature = "compute_concatenate")]
+        (LargeBinary, Capacities::Binary(length, values)) => {
+            Box::new(binary::GrowableBinary::<i64>::with_capacities(
+                downcast!(),
+                use_validity,
+                length,
+                values,
+            ))
+        }
+        #[cfg(feature = "compute_concatenate")]
+        (List, Capacities::List(length, values)) => Box::new(
+            list::GrowableList::<i32>::with_capacities(downcast!(), use_validity, length, *values),
+        ),
+        #[cfg(feature = "compute_concatenate")]
+        (LargeList, Capacities::List(length, values)) => Box::new(
+            list::GrowableList::<i64>::with_capacities(downcast!(), use_validity, length, *values),
+        ),
+        #[cfg(feature = "compute_concatenate")]
+        (FixedSizeList, Capacities::List(length, values)) => {
+            Box::new(fixed_size_list::GrowableFixedSizeList::with_capacities(
+                downcast!(),
+                use_validity,
+                length,
+                *values,
+            ))
+        }
+        #[cfg(feature = "compute_concatenate")]
+        (Map, Capacities::List(length, values)) => Box::new(map::GrowableMap::with_capacities(
+            downcast!(),
+            use_validity,
+            length,
+            *values,
+        )),
+        #[cfg(feature = "compute_concatenate")]
+        (Struct, Capacities::Struct(length, fields)) => Box::new(
+            structure::GrowableStruct::with_capacities(downcast!(), use_validity, length, fields),
+        ),
+        (_, capacities) => make_growable(arrays, use_validity, capacities.len()),
+    }
+}
@@ -6,9 +6,9 @@ use crate::{
 };
 
 use super::{
-    make_growable,
+    make_growable_with_capacities,
     utils::{build_extend_null_bits, ExtendNullBits},
-    Growable,
+    Capacities, Growable,
 };
 
 /// Concrete [`Growable`] for the [`StructArray`].
@@ -23,7 +23,22 @@ impl<'a> GrowableStruct<'a> {
     /// Creates a new [`GrowableStruct`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a StructArray>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a StructArray>, use_validity: bool, capacity: usize) -> Self {
+        assert!(!arrays.is_empty());
+        let fields = vec![Capacities::Array(capacity); arrays[0].values().len()];
+        Self::with_capacities(arrays, use_validity, capacity, fields)
+    }
+
+    /// Creates a new [`GrowableStruct`] bound to `arrays` with a pre-allocated `capacity` of rows
+    /// and `fields` capacities of each of its fields.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
+        arrays: Vec<&'a StructArray>,
+        mut use_validity: bool,
+        capacity: usize,
+        fields: Vec<Capacities>,
+    ) -> Self {
         assert!(!arrays.is_empty());
 
         // if any of the arrays has nulls, insertions from any array requires setting bits
@@ -43,15 +58,17 @@ impl<'a> GrowableStruct<'a> {
             .collect::<Vec<_>>();
 
         // ([field1, field2], [field3, field4]) -> ([field1, field3], [field2, field3])
-        let values = (0..arrays[0].values().len())
-            .map(|i| {
-                make_growable(
+        let values = fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, capacities)| {
+                make_growable_with_capacities(
                     &arrays
                         .iter()
                         .map(|x| x.values()[i].as_ref())
                         .collect::<Vec<_>>(),
                     use_validity,
-                    capacity,
+                    capacities,
                 )
             })
             .collect::<Vec<Box<dyn Growable>>>();
@@ -31,7 +31,8 @@ impl<'a> GrowableUnion<'a> {
                         .iter()
                         .map(|x| x.fields()[i].as_ref())
                         .collect::<Vec<_>>(),
-                    false,
+                    // nulls are pushed to the fields by `extend_validity`
+                    true,
                     capacity,
                 )
             })
@@ -91,7 +92,26 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
         }
     }
 
-    fn extend_validity(&mut self, _additional: usize) {}
+    /// Extends with null slots of the first declared field, as a union has no validity of its own.
+    fn extend_validity(&mut self, additional: usize) {
+        // the first declared field is at index 0, whichever its type id
+        let (type_id, _, _) = self.arrays[0]
+            .fields_by_type_id()
+            .next()
+            .expect("a union with null slots has at least one field");
+        self.types
+            .extend(std::iter::repeat(type_id).take(additional));
+        if let Some(offsets) = self.offsets.as_mut() {
+            let field = &mut self.fields[0];
+            let start = field.len() as i32;
+            offsets.extend(start..start + additional as i32);
+            field.extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
 
     #[inline]
     fn len(&self) -> usize {
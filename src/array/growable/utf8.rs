@@ -24,7 +24,20 @@ impl<'a, O: Offset> GrowableUtf8<'a, O> {
     /// Creates a new [`GrowableUtf8`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a Utf8Array<O>>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a Utf8Array<O>>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_capacities(arrays, use_validity, capacity, 0)
+    }
+
+    /// Creates a new [`GrowableUtf8`] bound to `arrays` with a pre-allocated `capacity` of rows
+    /// and `values_capacity` of bytes.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub(crate) fn with_capacities(
+        arrays: Vec<&'a Utf8Array<O>>,
+        mut use_validity: bool,
+        capacity: usize,
+        values_capacity: usize,
+    ) -> Self {
         // if any of the arrays has nulls, insertions from any array requires setting bits
         // as there is at least one array with nulls.
         if arrays.iter().any(|array| array.null_count() > 0) {
@@ -38,7 +51,7 @@ impl<'a, O: Offset> GrowableUtf8<'a, O> {
 
         Self {
             arrays: arrays.to_vec(),
-            values: Vec::with_capacity(0),
+            values: Vec::with_capacity(values_capacity),
             offsets: Offsets::with_capacity(capacity),
             validity: MutableBitmap::with_capacity(capacity),
             extend_null_bits,
@@ -142,6 +142,14 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
 
     /// Clone a `&dyn Array` to an owned `Box<dyn Array>`.
     fn to_boxed(&self) -> Box<dyn Array>;
+
+    /// Validates all invariants of this [`Array`] and of its children. See [`validate`] for details.
+    /// # Errors
+    /// This function errors iff any of the invariants of the array is not upheld.
+    fn validate(&self) -> crate::error::Result<()> {
+        // `Self` may be unsized here, so `self` is validated through an (`O(1)`) boxed clone
+        validate(self.to_boxed().as_ref())
+    }
 }
 
 dyn_clone::clone_trait_object!(Array);
@@ -661,6 +669,11 @@ macro_rules! impl_common_array {
         fn to_boxed(&self) -> Box<dyn Array> {
             Box::new(self.clone())
         }
+
+        #[inline]
+        fn validate(&self) -> crate::error::Result<()> {
+            crate::array::validate(self)
+        }
     };
 }
 
@@ -717,12 +730,14 @@ mod struct_;
 mod union;
 mod utf8;
 
+mod downcast;
 mod equal;
 mod ffi;
 mod fmt;
 #[doc(hidden)]
 pub mod indexable;
 mod iterator;
+mod validate;
 
 pub mod growable;
 pub mod ord;
@@ -730,8 +745,10 @@ pub mod ord;
 pub(crate) use iterator::ArrayAccessor;
 pub use iterator::ArrayValuesIter;
 
+pub use downcast::AsArray;
 pub use equal::equal;
 pub use fmt::{get_display, get_value_display};
+pub use validate::validate;
 
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray, MutableBinaryValuesArray};
 pub use boolean::{BooleanArray, MutableBooleanArray};
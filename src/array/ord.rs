@@ -2,10 +2,14 @@
 
 use std::cmp::Ordering;
 
+use crate::bitmap::Bitmap;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::offset::Offset;
-use crate::{array::*, types::NativeType};
+use crate::{
+    array::*,
+    types::{f16, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -38,6 +42,18 @@ pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
     left.cmp(&right)
 }
 
+/// implements comparison using IEEE 754 total ordering for f16
+#[inline]
+pub fn total_cmp_f16(l: &f16, r: &f16) -> std::cmp::Ordering {
+    let mut left = l.to_bits() as i16;
+    let mut right = r.to_bits() as i16;
+
+    left ^= (((left >> 15) as u16) >> 1) as i16;
+    right ^= (((right >> 15) as u16) >> 1) as i16;
+
+    left.cmp(&right)
+}
+
 /// Total order of all native types whose Rust implementation
 /// that support total order.
 #[inline]
@@ -76,6 +92,20 @@ fn compare_boolean(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
+fn compare_f16(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| total_cmp_f16(&left.value(i), &right.value(j)))
+}
+
 fn compare_f32(left: &dyn Array, right: &dyn Array) -> DynComparator {
     let left = left
         .as_any()
@@ -149,6 +179,90 @@ where
     }))
 }
 
+/// Returns a comparator that orders nulls before any value.
+fn compare_nullable(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let comparator = build_compare(left, right)?;
+    let (left_validity, right_validity) = match (left.validity(), right.validity()) {
+        (None, None) => return Ok(comparator),
+        (lhs, rhs) => (lhs.cloned(), rhs.cloned()),
+    };
+
+    let is_valid = |validity: &Option<Bitmap>, i: usize| match validity {
+        Some(validity) => validity.get_bit(i),
+        None => true,
+    };
+    Ok(Box::new(move |i, j| {
+        match (is_valid(&left_validity, i), is_valid(&right_validity, j)) {
+            (true, true) => (comparator)(i, j),
+            (lhs, rhs) => lhs.cmp(&rhs),
+        }
+    }))
+}
+
+/// Compares the items of `left` in `left_range` with the items of `right` in `right_range`
+/// lexicographically.
+#[inline]
+fn compare_ranges(
+    comparator: &DynComparator,
+    left_range: std::ops::Range<usize>,
+    right_range: std::ops::Range<usize>,
+) -> Ordering {
+    let lengths = left_range.len().cmp(&right_range.len());
+    left_range
+        .zip(right_range)
+        .map(|(i, j)| (comparator)(i, j))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(lengths)
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+
+    let left = left.offsets().clone();
+    let right = right.offsets().clone();
+    Ok(Box::new(move |i, j| {
+        let (left_start, left_end) = left.start_end(i);
+        let (right_start, right_end) = right.start_end(j);
+        compare_ranges(&comparator, left_start..left_end, right_start..right_end)
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let comparator = compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+
+    let size = left.size();
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            &comparator,
+            i * size..(i + 1) * size,
+            j * size..(j + 1) * size,
+        )
+    }))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values())
+        .map(|(left, right)| compare_nullable(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|comparator| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 macro_rules! dyn_dict {
     ($key:ty, $lhs:expr, $rhs:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
@@ -174,6 +288,8 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// Lists and fixed size lists are compared lexicographically by their items, and structs
+/// lexicographically by their fields. Null items and fields are ordered before any value.
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
 // This is a factory of comparisons.
@@ -211,6 +327,7 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Float16, Float16) => compare_f16(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
@@ -218,6 +335,15 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
+        (Extension(_, _, _), Extension(_, _, _)) => {
+            let left = crate::compute::extension::storage(left).unwrap();
+            let right = crate::compute::extension::storage(right).unwrap();
+            build_compare(left.as_ref(), right.as_ref())?
+        }
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
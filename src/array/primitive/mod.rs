@@ -1,6 +1,6 @@
 use crate::{
     bitmap::{
-        utils::{BitmapIter, ZipValidity},
+        utils::{BitmapIter, TrueIdxIter, ZipValidity},
         Bitmap,
     },
     buffer::Buffer,
@@ -154,6 +154,18 @@ impl<T: NativeType> PrimitiveArray<T> {
         self.values().iter()
     }
 
+    /// Returns an iterator of `(index, value)` over the non-null slots of this array.
+    ///
+    /// The validity is traversed in chunks of 64 bits, which makes this iterator
+    /// efficient on sparse arrays.
+    #[inline]
+    pub fn non_null_values_iter(&self) -> impl TrustedLen<Item = (usize, T)> + '_ {
+        let values = self.values().as_slice();
+        TrueIdxIter::new(self.len(), self.validity())
+            // Safety: the indices are bounded by the length of the array
+            .map(move |i| (i, *unsafe { values.get_unchecked(i) }))
+    }
+
     /// Returns the length of this array
     #[inline]
     pub fn len(&self) -> usize {
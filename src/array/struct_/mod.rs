@@ -226,6 +226,61 @@ impl StructArray {
     }
 }
 
+// projections
+impl StructArray {
+    /// Returns a new [`StructArray`] with the fields at `indices`, in that order.
+    /// The child arrays and the validity are shared with `self` (no copy).
+    /// # Errors
+    /// This function errors iff `indices` is empty or any of its indices is out of bounds.
+    /// # Implementation
+    /// This operation is `O(I)` where `I` is the number of indices.
+    pub fn project(&self, indices: &[usize]) -> Result<Self, Error> {
+        let (fields, values): (Vec<_>, Vec<_>) = indices
+            .iter()
+            .map(|&index| {
+                self.fields()
+                    .get(index)
+                    .zip(self.values.get(index))
+                    .map(|(field, value)| (field.clone(), value.clone()))
+                    .ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "The index {index} is out of bounds of a StructArray with {} fields",
+                            self.values.len()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .unzip();
+
+        Self::try_new(DataType::Struct(fields), values, self.validity.clone())
+    }
+
+    /// Returns a new [`StructArray`] with the fields named `names`, in that order.
+    /// The child arrays and the validity are shared with `self` (no copy).
+    /// # Errors
+    /// This function errors iff `names` is empty or any of its names is not a field of `self`.
+    /// # Implementation
+    /// This operation is `O(N * F)` where `N` is the number of names and `F` the number of fields.
+    pub fn reorder_by_names<N: AsRef<str>>(&self, names: &[N]) -> Result<Self, Error> {
+        let indices = names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                self.fields()
+                    .iter()
+                    .position(|field| field.name == name)
+                    .ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "The StructArray has no field named \"{name}\""
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.project(&indices)
+    }
+}
+
 impl StructArray {
     /// Returns the fields the `DataType::Struct`.
     pub(crate) fn try_get_fields(data_type: &DataType) -> Result<&[Field], Error> {
@@ -280,6 +280,47 @@ impl UnionArray {
         &self.types
     }
 
+    /// The offset of this array in the fields of a sparse union.
+    pub(crate) fn fields_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the position in [`Self::fields`] of the field selected by `type_id`,
+    /// or `None` if no field is selected by `type_id`.
+    /// When the [`DataType::Union`] has no ids, the `type_id` of a field is its position.
+    pub fn field_index_by_type_id(&self, type_id: i8) -> Option<usize> {
+        match Self::get_all(&self.data_type).1 {
+            Some(ids) => ids.iter().position(|&id| id == type_id as i32),
+            None => {
+                (type_id >= 0 && (type_id as usize) < self.fields.len()).then_some(type_id as usize)
+            }
+        }
+    }
+
+    /// Returns the [`Field`] and corresponding child array selected by `type_id`,
+    /// or `None` if no field is selected by `type_id`.
+    pub fn field_by_type_id(&self, type_id: i8) -> Option<(&Field, &dyn Array)> {
+        self.field_index_by_type_id(type_id).map(|index| {
+            (
+                &Self::get_fields(&self.data_type)[index],
+                self.fields[index].as_ref(),
+            )
+        })
+    }
+
+    /// Returns an iterator of the `(type_id, field, child array)` of this [`UnionArray`].
+    pub fn fields_by_type_id(&self) -> impl Iterator<Item = (i8, &Field, &dyn Array)> {
+        let (fields, ids, _) = Self::get_all(&self.data_type);
+        fields
+            .iter()
+            .zip(self.fields.iter())
+            .enumerate()
+            .map(move |(index, (field, array))| {
+                let type_id = ids.map(|ids| ids[index] as i8).unwrap_or(index as i8);
+                (type_id, field, array.as_ref())
+            })
+    }
+
     #[inline]
     unsafe fn field_slot_unchecked(&self, index: usize) -> usize {
         self.offsets()
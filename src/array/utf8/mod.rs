@@ -1,6 +1,6 @@
 use crate::{
     bitmap::{
-        utils::{BitmapIter, ZipValidity},
+        utils::{BitmapIter, TrueIdxIter, ZipValidity},
         Bitmap,
     },
     buffer::Buffer,
@@ -14,7 +14,7 @@ use either::Either;
 
 use super::{
     specification::{try_check_offsets_bounds, try_check_utf8},
-    Array, GenericBinaryArray,
+    Array, BinaryArray, GenericBinaryArray,
 };
 
 #[cfg(feature = "arrow")]
@@ -143,6 +143,13 @@ impl<O: Offset> Utf8Array<O> {
         Utf8ValuesIter::new(self)
     }
 
+    /// Returns an iterator of `(index, &str)` over the non-null slots of this array.
+    pub fn non_null_values_iter(&self) -> impl TrustedLen<Item = (usize, &str)> + '_ {
+        TrueIdxIter::new(self.len(), self.validity())
+            // Safety: the indices are bounded by the length of the array
+            .map(move |i| (i, unsafe { self.value_unchecked(i) }))
+    }
+
     /// Returns the length of this array
     #[inline]
     pub fn len(&self) -> usize {
@@ -252,6 +259,28 @@ impl<O: Offset> Utf8Array<O> {
         (data_type, offsets, values, validity)
     }
 
+    /// Returns a [`Utf8Array`] from a [`BinaryArray`], re-using its offsets, values and validity.
+    /// # Errors
+    /// This function errors iff the values of `array` are not valid utf8.
+    /// # Implementation
+    /// This function is `O(N)` - checking utf8 is `O(N)` - but does not copy any buffer.
+    pub fn try_from_binary(array: BinaryArray<O>) -> Result<Self> {
+        let (_, offsets, values, validity) = array.into_inner();
+        Self::try_new(Self::default_data_type(), offsets, values, validity)
+    }
+
+    /// Returns a [`BinaryArray`] sharing the offsets, values and validity of this array.
+    /// # Implementation
+    /// This function is `O(1)`.
+    pub fn to_binary(&self) -> BinaryArray<O> {
+        BinaryArray::<O>::new(
+            BinaryArray::<O>::default_data_type(),
+            self.offsets.clone(),
+            self.values.clone(),
+            self.validity.clone(),
+        )
+    }
+
     /// Try to convert this `Utf8Array` to a `MutableUtf8Array`
     #[must_use]
     pub fn into_mut(self) -> Either<Self, MutableUtf8Array<O>> {
@@ -0,0 +1,156 @@
+use crate::{
+    bitmap::Bitmap,
+    datatypes::PhysicalType,
+    error::{Error, Result},
+    offset::{try_check_offsets, Offset, OffsetsBuffer},
+};
+
+use super::{
+    specification::{check_indexes, try_check_offsets_bounds, try_check_utf8},
+    *,
+};
+
+/// Validates all invariants of `array` and, recursively, of its children.
+///
+/// Some constructors, such as [`Utf8Array::new_unchecked`] or
+/// [`DictionaryArray::try_new_unchecked`], skip expensive checks so that trusted producers
+/// (e.g. FFI or memory mapped IPC) avoid paying for them. This function can be used to audit
+/// arrays built this way. It verifies that:
+/// * offsets are monotonically increasing and within the bounds of their values
+/// * utf8 values are valid utf8
+/// * validities and children have the length expected by their parent
+/// * dictionary keys and union type ids and offsets are within bounds
+/// # Implementation
+/// This operation is `O(N)` over the total number of values of the array and its children.
+pub fn validate(array: &dyn Array) -> Result<()> {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => Ok(()),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            validate_validity(array.validity(), array.len())
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            validate_validity(array.validity(), array.len())
+        }),
+        Binary => validate_binary::<i32>(array),
+        LargeBinary => validate_binary::<i64>(array),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            if array.values().len() % array.size() != 0 {
+                return Err(Error::oos(
+                    "FixedSizeBinaryArray's values must be a multiple of its size",
+                ));
+            }
+            validate_validity(array.validity(), array.len())
+        }
+        Utf8 => validate_utf8::<i32>(array),
+        LargeUtf8 => validate_utf8::<i64>(array),
+        List => validate_list::<i32>(array),
+        LargeList => validate_list::<i64>(array),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            if array.values().len() != array.len() * array.size() {
+                return Err(Error::oos(
+                    "FixedSizeListArray's values must have the length of the array times its size",
+                ));
+            }
+            validate_validity(array.validity(), array.len())?;
+            validate(array.values().as_ref())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            validate_validity(array.validity(), array.len())?;
+            array.values().iter().try_for_each(|child| {
+                if child.len() != array.len() {
+                    return Err(Error::oos(
+                        "StructArray's children must have the length of the array",
+                    ));
+                }
+                validate(child.as_ref())
+            })
+        }
+        Union => validate_union(array.as_any().downcast_ref::<UnionArray>().unwrap()),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            validate_offsets(array.offsets(), array.field().len())?;
+            validate_validity(array.validity(), array.len())?;
+            validate(array.field().as_ref())
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            validate(array.keys())?;
+            if array.keys().null_count() != array.keys().len() {
+                check_indexes(array.keys().values(), array.values().len())?;
+            }
+            validate(array.values().as_ref())
+        }),
+    }
+}
+
+fn validate_validity(validity: Option<&Bitmap>, len: usize) -> Result<()> {
+    match validity {
+        Some(validity) if validity.len() != len => Err(Error::oos(
+            "validity mask length must match the number of values",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_offsets<O: Offset>(offsets: &OffsetsBuffer<O>, values_len: usize) -> Result<()> {
+    try_check_offsets(offsets.as_slice())?;
+    try_check_offsets_bounds(offsets, values_len)
+}
+
+fn validate_binary<O: Offset>(array: &dyn Array) -> Result<()> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    validate_offsets(array.offsets(), array.values().len())?;
+    validate_validity(array.validity(), array.len())
+}
+
+fn validate_utf8<O: Offset>(array: &dyn Array) -> Result<()> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    validate_offsets(array.offsets(), array.values().len())?;
+    try_check_utf8(array.offsets(), array.values())?;
+    validate_validity(array.validity(), array.len())
+}
+
+fn validate_list<O: Offset>(array: &dyn Array) -> Result<()> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    validate_offsets(array.offsets(), array.values().len())?;
+    validate_validity(array.validity(), array.len())?;
+    validate(array.values().as_ref())
+}
+
+fn validate_union(array: &UnionArray) -> Result<()> {
+    if let Some(offsets) = array.offsets() {
+        if offsets.len() != array.len() {
+            return Err(Error::oos(
+                "UnionArray's offsets must have the length of its types",
+            ));
+        }
+    }
+    for (i, type_id) in array.types().iter().enumerate() {
+        let valid = *type_id >= 0 && array.field_index_by_type_id(*type_id).is_some();
+        if !valid {
+            return Err(Error::oos(format!(
+                "UnionArray's type id {type_id} does not correspond to any of its fields"
+            )));
+        }
+        // Safety: `i < len` and we just checked that the type id is valid
+        let (field, slot) = unsafe { array.index_unchecked(i) };
+        if slot >= array.fields()[field].len() {
+            return Err(Error::oos(format!(
+                "UnionArray's slot {i} points to the position {slot} of its field {field}, which is out of bounds"
+            )));
+        }
+    }
+    array
+        .fields()
+        .iter()
+        .try_for_each(|field| validate(field.as_ref()))
+}
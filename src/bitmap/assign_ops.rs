@@ -112,6 +112,7 @@ fn or_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
         lhs.clear();
         lhs.extend_constant(rhs.len(), true);
     } else if rhs.unset_bits() == rhs.len() {
+        assert_eq!(lhs.len(), rhs.len());
         // bitmap remains
     } else {
         binary_assign(lhs, rhs, |x: T, y| x | y)
@@ -139,9 +140,9 @@ impl<'a> std::ops::BitOr<&'a Bitmap> for MutableBitmap {
 /// Compute bitwise `&` between `lhs` and `rhs`, assigning it to `lhs`
 fn and_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     if rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
         // bitmap remains
-    }
-    if rhs.unset_bits() == rhs.len() {
+    } else if rhs.unset_bits() == rhs.len() {
         assert_eq!(lhs.len(), rhs.len());
         lhs.clear();
         lhs.extend_constant(rhs.len(), false);
@@ -189,3 +190,29 @@ impl<'a> std::ops::BitXor<&'a Bitmap> for MutableBitmap {
         self
     }
 }
+
+impl MutableBitmap {
+    /// Assigns `self & rhs` to `self`, in chunks of 64 bits and without allocating.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn and_assign(&mut self, rhs: &Bitmap) {
+        and_assign::<u64>(self, rhs)
+    }
+
+    /// Assigns `self | rhs` to `self`, in chunks of 64 bits and without allocating.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn or_assign(&mut self, rhs: &Bitmap) {
+        or_assign::<u64>(self, rhs)
+    }
+
+    /// Assigns `self ^ rhs` to `self`, in chunks of 64 bits and without allocating.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn xor_assign(&mut self, rhs: &Bitmap) {
+        xor_assign::<u64>(self, rhs)
+    }
+}
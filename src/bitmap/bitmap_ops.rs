@@ -1,5 +1,6 @@
 use crate::bitmap::MutableBitmap;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::sync::Arc;
 
 use crate::trusted_len::TrustedLen;
 
@@ -122,7 +123,7 @@ where
     Bitmap::from_u8_vec(buffer, length)
 }
 
-fn unary_impl<F, I>(iter: I, op: F, length: usize) -> Bitmap
+fn unary_impl<F, I>(iter: I, op: F) -> Vec<u8>
 where
     I: BitChunkIterExact<u64>,
     F: Fn(u64) -> u64,
@@ -131,26 +132,31 @@ where
 
     let iterator = iter.map(op).chain(std::iter::once(rem));
 
-    let buffer = chunk_iter_to_vec(iterator);
-
-    Bitmap::from_u8_vec(buffer, length)
+    chunk_iter_to_vec(iterator)
 }
 
-/// Apply a bitwise operation `op` to one input and return the result as a [`Bitmap`].
-pub fn unary<F>(lhs: &Bitmap, op: F) -> Bitmap
+fn unary_buffer<F>(lhs: &Bitmap, op: F) -> Vec<u8>
 where
     F: Fn(u64) -> u64,
 {
     let (slice, offset, length) = lhs.as_slice();
     if offset == 0 {
         let iter = BitChunksExact::<u64>::new(slice, length);
-        unary_impl(iter, op, lhs.len())
+        unary_impl(iter, op)
     } else {
         let iter = lhs.chunks::<u64>();
-        unary_impl(iter, op, lhs.len())
+        unary_impl(iter, op)
     }
 }
 
+/// Apply a bitwise operation `op` to one input and return the result as a [`Bitmap`].
+pub fn unary<F>(lhs: &Bitmap, op: F) -> Bitmap
+where
+    F: Fn(u64) -> u64,
+{
+    Bitmap::from_u8_vec(unary_buffer(lhs, op), lhs.len())
+}
+
 // create a new [`Bitmap`] semantically equal to ``bitmap`` but with an offset equal to ``offset``
 pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     let length = bitmap.len();
@@ -232,6 +238,46 @@ fn eq(lhs: &Bitmap, rhs: &Bitmap) -> bool {
     lhs_remainder.zip(rhs_remainder).all(|(x, y)| x == y)
 }
 
+/// Returns an iterator over the pairs of 64-bit chunks of `lhs` and `rhs`, whose bits
+/// beyond the length of the bitmaps are unset.
+fn zip_chunks<'a>(lhs: &'a Bitmap, rhs: &'a Bitmap) -> impl Iterator<Item = (u64, u64)> + 'a {
+    assert_eq!(lhs.len(), rhs.len());
+    let lhs_chunks = lhs.chunks::<u64>();
+    let rhs_chunks = rhs.chunks::<u64>();
+    let mask = (1u64 << lhs_chunks.remainder_len()) - 1;
+    let remainder = (lhs_chunks.remainder() & mask, rhs_chunks.remainder() & mask);
+    lhs_chunks.zip(rhs_chunks).chain(std::iter::once(remainder))
+}
+
+impl Bitmap {
+    /// Returns whether any bit is set in both `self` and `other`.
+    /// This returns as soon as such a bit is found.
+    /// # Panics
+    /// This function panics iff `self.len() != other.len()`.
+    pub fn intersects_with(&self, other: &Self) -> bool {
+        zip_chunks(self, other).any(|(lhs, rhs)| lhs & rhs != 0)
+    }
+
+    /// Returns whether every bit set in `self` is also set in `other`.
+    /// This returns as soon as a bit set only in `self` is found.
+    /// # Panics
+    /// This function panics iff `self.len() != other.len()`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        zip_chunks(self, other).all(|(lhs, rhs)| lhs & !rhs == 0)
+    }
+
+    /// Returns the number of bits set in both `self` and `other`.
+    /// This is equivalent to, but faster than, counting the set bits of `self & other`, since it
+    /// does not allocate.
+    /// # Panics
+    /// This function panics iff `self.len() != other.len()`.
+    pub fn num_intersections_with(&self, other: &Self) -> usize {
+        zip_chunks(self, other)
+            .map(|(lhs, rhs)| (lhs & rhs).count_ones() as usize)
+            .sum()
+    }
+}
+
 impl PartialEq for Bitmap {
     fn eq(&self, other: &Self) -> bool {
         eq(self, other)
@@ -266,6 +312,10 @@ impl Not for &Bitmap {
     type Output = Bitmap;
 
     fn not(self) -> Bitmap {
-        unary(self, |a| !a)
+        let buffer = unary_buffer(self, |a| !a);
+        // the negation of a bitmap has as many unset bits as the bitmap has set bits
+        let unset_bits = self.len() - self.unset_bits();
+        // Safety: `buffer` has `self.len()` bits whose count of unset bits is `unset_bits`
+        unsafe { Bitmap::from_inner_unchecked(Arc::new(buffer.into()), 0, self.len(), unset_bits) }
     }
 }
@@ -6,7 +6,10 @@ use crate::{buffer::Bytes, error::Error, trusted_len::TrustedLen};
 
 use super::{
     chunk_iter_to_vec,
-    utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
+    utils::{
+        count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter,
+        SlicesIterator,
+    },
     IntoIter, MutableBitmap,
 };
 
@@ -98,6 +101,28 @@ impl Bitmap {
         })
     }
 
+    /// Initializes a new [`Bitmap`] from a 64-byte aligned region of bytes and a length,
+    /// without copying it.
+    /// # Errors
+    /// This function errors iff `length > bytes.len() * 8`
+    #[cfg(feature = "aligned_alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aligned_alloc")))]
+    pub fn try_from_aligned<A: crate::buffer::BufferAllocator>(
+        bytes: crate::buffer::AlignedVec<u8, A>,
+        length: usize,
+    ) -> Result<Self, Error> {
+        check(&bytes, 0, length)?;
+        let unset_bits = count_zeros(&bytes, 0, length);
+        let (bytes, offset, _) = crate::buffer::Buffer::from(bytes).into_inner();
+        debug_assert_eq!(offset, 0);
+        Ok(Self {
+            length,
+            offset: 0,
+            bytes,
+            unset_bits,
+        })
+    }
+
     /// Returns the length of the [`Bitmap`].
     #[inline]
     pub fn len(&self) -> usize {
@@ -115,6 +140,20 @@ impl Bitmap {
         BitmapIter::new(&self.bytes, self.offset, self.length)
     }
 
+    /// Returns an iterator of `(start, len)` over the runs of consecutive set bits of this bitmap.
+    ///
+    /// This is useful to copy values in bulk, e.g. via `extend_from_slice`, instead of one by one.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from([true, true, false, true]);
+    /// assert_eq!(bitmap.runs().collect::<Vec<_>>(), vec![(0, 2), (3, 1)]);
+    /// ```
+    pub fn runs(&self) -> SlicesIterator<'_> {
+        SlicesIterator::new(self)
+    }
+
     /// Returns an iterator over bits in bit chunks [`BitChunk`].
     ///
     /// This iterator is useful to operate over multiple bits via e.g. bitwise.
@@ -238,12 +277,32 @@ impl Bitmap {
         self.bytes.deref().as_ptr()
     }
 
+    /// Returns the number of bytes of the region backing this [`Bitmap`],
+    /// including the ones not visible due to slicing.
+    pub(crate) fn data_len(&self) -> usize {
+        self.bytes.len()
+    }
+
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
     pub(crate) fn offset(&self) -> usize {
         self.offset
     }
 
+    /// Returns the [`Bitmap`] spanning `self` and `other` without copying, iff `other` starts
+    /// where `self` ends in the same region, e.g. when both are adjacent slices of a [`Bitmap`].
+    #[cfg(feature = "compute_concatenate")]
+    pub(crate) fn try_join(&self, other: &Self) -> Option<Self> {
+        (Arc::ptr_eq(&self.bytes, &other.bytes) && self.offset + self.length == other.offset).then(
+            || Self {
+                bytes: self.bytes.clone(),
+                offset: self.offset,
+                length: self.length + other.length,
+                unset_bits: self.unset_bits + other.unset_bits,
+            },
+        )
+    }
+
     /// Converts this [`Bitmap`] to [`MutableBitmap`], returning itself if the conversion
     /// is not possible
     ///
@@ -257,7 +316,9 @@ impl Bitmap {
             Arc::get_mut(&mut self.bytes).and_then(|b| b.get_vec()),
         ) {
             (0, Some(v)) => {
-                let data = std::mem::take(v);
+                let mut data = std::mem::take(v);
+                // drop the bytes beyond the length, which `MutableBitmap` does not expect
+                data.truncate(self.length.saturating_add(7) / 8);
                 Either::Right(MutableBitmap::from_vec(data, self.length))
             }
             _ => Either::Left(self),
@@ -276,7 +337,8 @@ impl Bitmap {
                     let vec = chunk_iter_to_vec(chunks.chain(std::iter::once(remainder)));
                     MutableBitmap::from_vec(vec, data.length)
                 } else {
-                    MutableBitmap::from_vec(data.bytes.as_ref().to_vec(), data.length)
+                    let bytes = &data.bytes[..data.length.saturating_add(7) / 8];
+                    MutableBitmap::from_vec(bytes.to_vec(), data.length)
                 }
             }
             Either::Right(data) => data,
@@ -397,10 +459,20 @@ impl Bitmap {
 
 impl<P: AsRef<[bool]>> From<P> for Bitmap {
     fn from(slice: P) -> Self {
-        Self::from_trusted_len_iter(slice.as_ref().iter().copied())
+        Self::from_bool_slice(slice.as_ref())
     }
 }
 
+/// Unpacks the bits of `byte` into 8 booleans, with the least significant bit first.
+#[inline]
+fn unpack_byte(byte: u8) -> [bool; 8] {
+    // broadcast the byte to every lane and keep the bit `i` in the lane `i`
+    let lanes = (byte as u64).wrapping_mul(0x0101010101010101) & 0x8040201008040201;
+    // set the highest bit of every non-zero lane; lanes are at most 0x80 so this never carries
+    let lanes = (lanes.wrapping_add(0x7f7f7f7f7f7f7f7f) >> 7) & 0x0101010101010101;
+    lanes.to_le_bytes().map(|lane| lane != 0)
+}
+
 impl FromIterator<bool> for Bitmap {
     fn from_iter<I>(iter: I) -> Self
     where
@@ -411,6 +483,41 @@ impl FromIterator<bool> for Bitmap {
 }
 
 impl Bitmap {
+    /// Creates a new [`Bitmap`] from a slice of booleans.
+    /// # Implementation
+    /// This packs 8 booleans at a time, which is faster than [`Self::from_trusted_len_iter`].
+    #[inline]
+    pub fn from_bool_slice(slice: &[bool]) -> Self {
+        MutableBitmap::from_bool_slice(slice).into()
+    }
+
+    /// Creates a new [`Bitmap`] with the first `length` bits of `chunks`, where the
+    /// least significant bit of each chunk is its first bit.
+    /// # Panics
+    /// This function panics iff `chunks` contains less than `length` bits.
+    pub fn from_u64_chunks<I: IntoIterator<Item = u64>>(chunks: I, length: usize) -> Self {
+        let mut bitmap = MutableBitmap::with_capacity(length);
+        bitmap.extend_from_chunks(chunks, length);
+        bitmap.into()
+    }
+
+    /// Returns the values of this [`Bitmap`] as a [`Vec<bool>`].
+    /// # Implementation
+    /// This unpacks 8 bits at a time, which is faster than collecting [`Self::iter`].
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        let mut result = Vec::with_capacity(self.length + 64);
+
+        let chunks = self.chunks::<u64>();
+        let remainder = chunks.remainder();
+        chunks
+            .chain(std::iter::once(remainder))
+            .flat_map(|chunk| chunk.to_le_bytes())
+            .for_each(|byte| result.extend_from_slice(&unpack_byte(byte)));
+
+        result.truncate(self.length);
+        result
+    }
+
     /// Creates a new [`Bitmap`] from an iterator of booleans.
     /// # Safety
     /// The iterator must report an accurate length.
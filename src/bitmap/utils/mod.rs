@@ -4,6 +4,7 @@ mod chunks_exact_mut;
 mod fmt;
 mod iterator;
 mod slice_iterator;
+mod true_idx;
 mod zip_validity;
 
 use std::convert::TryInto;
@@ -14,6 +15,7 @@ pub use chunks_exact_mut::BitChunksExactMut;
 pub use fmt::fmt;
 pub use iterator::BitmapIter;
 pub use slice_iterator::SlicesIterator;
+pub use true_idx::TrueIdxIter;
 pub use zip_validity::{ZipValidity, ZipValidityIter};
 
 const BIT_MASK: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
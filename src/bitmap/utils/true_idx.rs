@@ -0,0 +1,81 @@
+use crate::{bitmap::Bitmap, trusted_len::TrustedLen, types::BitChunkOnes};
+
+use super::BitChunks;
+
+/// An [`Iterator`] over the indices of the set bits of a [`Bitmap`].
+///
+/// The bitmap is consumed in chunks of 64 bits, and the set bits of each chunk are found
+/// with `trailing_zeros`, so that unset regions are skipped without branching on every bit.
+/// When no bitmap is provided, all indices in `0..len` are returned.
+pub struct TrueIdxIter<'a> {
+    chunks: Option<BitChunks<'a, u64>>,
+    remainder: Option<u64>,
+    current: BitChunkOnes<u64>,
+    base: usize,
+    next_base: usize,
+    len: usize,
+    remaining: usize,
+}
+
+impl<'a> TrueIdxIter<'a> {
+    /// Creates a new [`TrueIdxIter`].
+    /// # Panics
+    /// This function panics iff `validity` is not `None` and its length differs from `len`.
+    pub fn new(len: usize, validity: Option<&'a Bitmap>) -> Self {
+        let (chunks, remainder, remaining) = if let Some(bitmap) = validity {
+            assert_eq!(len, bitmap.len());
+            let chunks = bitmap.chunks::<u64>();
+            // mask out bits beyond the bitmap that may be part of the remainder
+            let remainder = chunks.remainder() & ((1u64 << chunks.remainder_len()) - 1);
+            (Some(chunks), Some(remainder), len - bitmap.unset_bits())
+        } else {
+            (None, None, len)
+        };
+        Self {
+            chunks,
+            remainder,
+            current: BitChunkOnes::new(0),
+            base: 0,
+            next_base: 0,
+            len,
+            remaining,
+        }
+    }
+}
+
+impl<'a> Iterator for TrueIdxIter<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let chunks = match self.chunks.as_mut() {
+            Some(chunks) => chunks,
+            None => {
+                let index = self.len - self.remaining;
+                self.remaining -= 1;
+                return Some(index);
+            }
+        };
+        loop {
+            if let Some(index) = self.current.next() {
+                self.remaining -= 1;
+                return Some(self.base + index);
+            }
+            let chunk = chunks.next().or_else(|| self.remainder.take())?;
+            self.current = BitChunkOnes::new(chunk);
+            self.base = self.next_base;
+            self.next_base += 64;
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+// Safety: the number of set bits is known upfront
+unsafe impl<'a> TrustedLen for TrueIdxIter<'a> {}
@@ -0,0 +1,307 @@
+use std::alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+use crate::types::NativeType;
+
+use super::Buffer;
+
+/// The alignment, in bytes, of the allocations of [`AlignedVec`]: the size of a cache line and
+/// of an AVX-512 register.
+pub const ALIGNMENT: usize = 64;
+
+/// An allocator of the regions of [`AlignedVec`], e.g. an arena or a NUMA-pinned allocator.
+///
+/// Every [`Layout`] it receives is aligned to [`ALIGNMENT`] bytes and has a non-zero size.
+/// # Safety
+/// Implementors must return either a null pointer or a region that fits `layout` and that is
+/// not used elsewhere until it is deallocated.
+pub unsafe trait BufferAllocator: Send + Sync + 'static {
+    /// Allocates a region that fits `layout`, or returns a null pointer.
+    fn allocate(&self, layout: Layout) -> *mut u8;
+
+    /// Deallocates the region at `ptr`.
+    /// # Safety
+    /// `ptr` must have been returned by this allocator for `layout`.
+    unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout);
+
+    /// Grows the region at `ptr` to `new_size` bytes, keeping its contents, or returns a null
+    /// pointer. By default, it allocates a new region, copies the contents and deallocates `ptr`.
+    /// # Safety
+    /// `ptr` must have been returned by this allocator for `layout`, and `new_size` must be
+    /// larger than `layout.size()`.
+    unsafe fn reallocate(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = self.allocate(new_layout);
+        if !new_ptr.is_null() {
+            new_ptr.copy_from_nonoverlapping(ptr, layout.size());
+            self.deallocate(ptr, layout);
+        }
+        new_ptr
+    }
+}
+
+/// The default [`BufferAllocator`], that allocates from the global allocator.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalAllocator;
+
+// Safety: the global allocator returns null or a region that fits `layout`
+unsafe impl BufferAllocator for GlobalAllocator {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> *mut u8 {
+        // Safety: `layout` has a non-zero size
+        unsafe { alloc(layout) }
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout) {
+        dealloc(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn reallocate(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        realloc(ptr, layout, new_size)
+    }
+}
+
+/// A growable, contiguous container of [`NativeType`]s, like a [`Vec`], whose allocation is
+/// always aligned to [`ALIGNMENT`] bytes, so that SIMD kernels can rely on aligned loads.
+///
+/// Its regions are allocated by a [`BufferAllocator`], the global allocator by default
+/// (see [`AlignedVec::with_allocator`]).
+///
+/// It is converted into a [`Buffer`] (and, for bytes, into a [`crate::bitmap::Bitmap`])
+/// without copying, and the alignment is kept for as long as the [`Buffer`] is not sliced.
+/// # Example
+/// ```
+/// use arrow2::buffer::{AlignedVec, Buffer, ALIGNMENT};
+///
+/// let mut values = AlignedVec::<i32>::with_capacity(3);
+/// values.extend_from_slice(&[1, 2]);
+/// values.push(3);
+///
+/// let buffer: Buffer<i32> = values.into();
+/// assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+/// assert_eq!(buffer.as_slice().as_ptr().align_offset(ALIGNMENT), 0);
+/// ```
+pub struct AlignedVec<T: NativeType, A: BufferAllocator = GlobalAllocator> {
+    ptr: NonNull<T>,
+    length: usize,
+    capacity: usize,
+    allocator: A,
+}
+
+// Safety: `AlignedVec` owns its allocation, like `Vec`, and `NativeType: Send + Sync`
+unsafe impl<T: NativeType, A: BufferAllocator> Send for AlignedVec<T, A> {}
+unsafe impl<T: NativeType, A: BufferAllocator> Sync for AlignedVec<T, A> {}
+
+impl<T: NativeType> AlignedVec<T> {
+    /// Returns a new empty [`AlignedVec`]. It does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_allocator(GlobalAllocator)
+    }
+
+    /// Returns a new empty [`AlignedVec`] with room for at least `capacity` items.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_allocator(capacity, GlobalAllocator)
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> AlignedVec<T, A> {
+    /// Returns a new empty [`AlignedVec`] whose regions are allocated by `allocator`.
+    /// It does not allocate.
+    #[inline]
+    pub fn with_allocator(allocator: A) -> Self {
+        Self {
+            // an empty region is never read, but its pointer is still aligned
+            ptr: NonNull::new(ALIGNMENT as *mut T).unwrap(),
+            length: 0,
+            capacity: 0,
+            allocator,
+        }
+    }
+
+    /// Returns a new empty [`AlignedVec`] with room for at least `capacity` items, whose
+    /// regions are allocated by `allocator`.
+    #[inline]
+    pub fn with_capacity_and_allocator(capacity: usize, allocator: A) -> Self {
+        let mut values = Self::with_allocator(allocator);
+        values.reserve(capacity);
+        values
+    }
+
+    /// Returns the allocator of the [`AlignedVec`].
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
+    /// Returns the number of items the [`AlignedVec`] can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserves room for at least `additional` more items.
+    /// # Panics
+    /// This function panics iff the new capacity overflows `isize::MAX` bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .length
+            .checked_add(additional)
+            .expect("capacity overflow");
+        if required <= self.capacity {
+            return;
+        }
+        let capacity = required.max(self.capacity * 2);
+        let layout = Self::layout(capacity);
+        let ptr = if self.capacity == 0 {
+            self.allocator.allocate(layout)
+        } else {
+            // Safety: the region was allocated with the layout of the current capacity, that
+            // is smaller than the new one
+            unsafe {
+                self.allocator.reallocate(
+                    self.ptr.as_ptr() as *mut u8,
+                    Self::layout(self.capacity),
+                    layout.size(),
+                )
+            }
+        };
+        let Some(ptr) = NonNull::new(ptr as *mut T) else {
+            handle_alloc_error(layout)
+        };
+        self.ptr = ptr;
+        self.capacity = capacity;
+    }
+
+    /// Appends `value` to the end of the [`AlignedVec`].
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.length == self.capacity {
+            self.reserve(1);
+        }
+        // Safety: the region has room for `length + 1` items
+        unsafe { self.ptr.as_ptr().add(self.length).write(value) };
+        self.length += 1;
+    }
+
+    /// Appends all items of `values` to the end of the [`AlignedVec`].
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.reserve(values.len());
+        // Safety: the region has room for `length + values.len()` items and does not overlap
+        // `values`, that is borrowed
+        unsafe {
+            self.ptr
+                .as_ptr()
+                .add(self.length)
+                .copy_from_nonoverlapping(values.as_ptr(), values.len())
+        };
+        self.length += values.len();
+    }
+
+    /// Resizes the [`AlignedVec`] to `length` items, filling new slots with `value`.
+    pub fn resize(&mut self, length: usize, value: T) {
+        if length <= self.length {
+            self.length = length;
+        } else {
+            self.reserve(length - self.length);
+            (self.length..length).for_each(|_| self.push(value));
+        }
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        // `ALIGNMENT` is a power of two larger than the alignment of every `NativeType`
+        std::mem::size_of::<T>()
+            .checked_mul(capacity)
+            .and_then(|size| Layout::from_size_align(size, ALIGNMENT).ok())
+            .expect("capacity overflow")
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> Drop for AlignedVec<T, A> {
+    fn drop(&mut self) {
+        if self.capacity > 0 {
+            // Safety: the region was allocated with the layout of the current capacity
+            unsafe {
+                self.allocator
+                    .deallocate(self.ptr.as_ptr() as *mut u8, Self::layout(self.capacity))
+            }
+        }
+    }
+}
+
+impl<T: NativeType> Default for AlignedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> Deref for AlignedVec<T, A> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        // Safety: the first `length` items are initialized
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.length) }
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> DerefMut for AlignedVec<T, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        // Safety: the first `length` items are initialized
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.length) }
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> std::fmt::Debug for AlignedVec<T, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator + Clone> Clone for AlignedVec<T, A> {
+    fn clone(&self) -> Self {
+        let mut values = Self::with_capacity_and_allocator(self.length, self.allocator.clone());
+        values.extend_from_slice(self);
+        values
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> Extend<T> for AlignedVec<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|value| self.push(value))
+    }
+}
+
+impl<T: NativeType> FromIterator<T> for AlignedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values = Self::new();
+        values.extend(iter);
+        values
+    }
+}
+
+impl<T: NativeType> From<&[T]> for AlignedVec<T> {
+    fn from(values: &[T]) -> Self {
+        let mut aligned = Self::with_capacity(values.len());
+        aligned.extend_from_slice(values);
+        aligned
+    }
+}
+
+impl<T: NativeType, A: BufferAllocator> From<AlignedVec<T, A>> for Buffer<T> {
+    #[inline]
+    fn from(values: AlignedVec<T, A>) -> Self {
+        let (ptr, length) = (values.ptr.as_ptr() as *const T, values.length);
+        // Safety: the heap region does not move with `values`, that owns it and is not
+        // mutated once owned by the buffer
+        unsafe { Buffer::from_owner(values, ptr, length) }
+    }
+}
@@ -2,8 +2,10 @@ use std::{iter::FromIterator, ops::Deref, sync::Arc, usize};
 
 use either::Either;
 
-use super::Bytes;
+use crate::error::Error;
+
 use super::IntoIter;
+use super::{Bytes, BytesAllocator};
 
 /// [`Buffer`] is a contiguous memory region that can be shared across
 /// thread boundaries.
@@ -84,6 +86,26 @@ impl<T> Buffer<T> {
         }
     }
 
+    /// Creates a [`Buffer`] from a region of `length` items starting at `ptr` that is owned
+    /// by `owner`, e.g. a `bytes::Bytes` or memory allocated by a foreign allocator.
+    ///
+    /// The region is not copied. `owner` is dropped (and is thus expected to deallocate
+    /// the region) once the returned [`Buffer`] and all its clones and slices are dropped.
+    /// # Safety
+    /// The caller must ensure that, for as long as `owner` is alive, `ptr` is non-null,
+    /// aligned to `T` and valid for reads of `length` items of `T` that are not mutated.
+    pub unsafe fn from_owner<O: Send + Sync + 'static>(
+        owner: O,
+        ptr: *const T,
+        length: usize,
+    ) -> Self {
+        if length == 0 {
+            return Self::new();
+        }
+        let owner = BytesAllocator::Owner(std::panic::AssertUnwindSafe(Box::new(owner)));
+        Self::from_bytes(Bytes::from_foreign(ptr, length, owner))
+    }
+
     /// Returns the number of bytes in the buffer
     #[inline]
     pub fn len(&self) -> usize {
@@ -179,12 +201,32 @@ impl<T> Buffer<T> {
         self.data.deref().as_ptr()
     }
 
+    /// Returns the number of elements of the region backing this buffer,
+    /// including the ones not visible due to slicing.
+    #[inline]
+    pub(crate) fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
     /// Returns the offset of this buffer.
     #[inline]
     pub fn offset(&self) -> usize {
         self.offset
     }
 
+    /// Returns the [`Buffer`] spanning `self` and `other` without copying, iff `other` starts
+    /// where `self` ends in the same region, e.g. when both are adjacent slices of a [`Buffer`].
+    #[cfg(feature = "compute_concatenate")]
+    pub(crate) fn try_join(&self, other: &Self) -> Option<Self> {
+        (Arc::ptr_eq(&self.data, &other.data) && self.offset + self.length == other.offset).then(
+            || Self {
+                data: self.data.clone(),
+                offset: self.offset,
+                length: self.length + other.length,
+            },
+        )
+    }
+
     /// # Safety
     /// The caller must ensure that the buffer was properly initialized up to `len`.
     #[inline]
@@ -196,9 +238,14 @@ impl<T> Buffer<T> {
     ///
     /// This operation returns [`Either::Right`] iff this [`Buffer`]:
     /// * has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
+    /// * has not been sliced
     /// * has not been imported from the c data interface (FFI)
     #[inline]
     pub fn into_mut(mut self) -> Either<Self, Vec<T>> {
+        // the vector would contain values that are not visible to this buffer
+        if self.is_sliced() {
+            return Either::Left(self);
+        }
         match Arc::get_mut(&mut self.data)
             .and_then(|b| b.get_vec())
             .map(std::mem::take)
@@ -208,6 +255,21 @@ impl<T> Buffer<T> {
         }
     }
 
+    /// Converts this [`Buffer`] into a [`Vec`], cloning its values if required (clone-on-write).
+    ///
+    /// The values are only cloned when [`Self::into_mut`] returns [`Either::Left`],
+    /// e.g. when the buffer is shared or sliced.
+    #[inline]
+    pub fn make_mut(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        match self.into_mut() {
+            Either::Left(buffer) => buffer.as_slice().to_vec(),
+            Either::Right(vec) => vec,
+        }
+    }
+
     /// Returns a mutable reference to its underlying `Vec`, if possible.
     /// Note that only `[self.offset(), self.offset() + self.len()[` in this vector is visible
     /// by this buffer.
@@ -270,6 +332,44 @@ impl<T> Buffer<T> {
     }
 }
 
+impl<T: crate::types::NativeType> Buffer<T> {
+    /// Creates a [`Buffer`] backed by `region` without copying it, e.g. a memory mapped file.
+    ///
+    /// `region` is kept alive (via its ref count) for as long as the returned [`Buffer`],
+    /// or any of its clones or slices, is alive.
+    /// # Errors
+    /// This function errors iff the region is not aligned to `T` or its length is not
+    /// a multiple of the size of `T`.
+    pub fn try_from_region<R: AsRef<[u8]> + Send + Sync + 'static>(
+        region: Arc<R>,
+    ) -> Result<Self, Error> {
+        let bytes = (*region).as_ref();
+        if bytes.is_empty() {
+            return Ok(Self::new());
+        }
+        let alignment = std::mem::align_of::<T>();
+        if bytes.as_ptr().align_offset(alignment) != 0 {
+            return Err(Error::oos(format!(
+                "the region must be aligned to {alignment} bytes to be a buffer of {:?}",
+                T::PRIMITIVE
+            )));
+        }
+        let size = std::mem::size_of::<T>();
+        if bytes.len() % size != 0 {
+            return Err(Error::oos(format!(
+                "the length of the region must be a multiple of {size} bytes to be a buffer of {:?}",
+                T::PRIMITIVE
+            )));
+        }
+        // both the alignment and the length were checked above
+        let values: &[T] = bytemuck::cast_slice(bytes);
+        let (ptr, length) = (values.as_ptr(), values.len());
+
+        // Safety: the region is valid for `length` items of `T` for as long as it is alive
+        Ok(unsafe { Self::from_owner(region, ptr, length) })
+    }
+}
+
 impl<T> From<Vec<T>> for Buffer<T> {
     #[inline]
     fn from(p: Vec<T>) -> Self {
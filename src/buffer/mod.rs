@@ -1,13 +1,20 @@
 //! Contains [`Buffer`], an immutable container for all Arrow physical types (e.g. i32, f64).
 
+#[cfg(feature = "aligned_alloc")]
+mod aligned;
 mod immutable;
 mod iterator;
 
 use crate::ffi::InternalArrowArray;
 use std::ops::Deref;
 
+// the variants are never read: they only keep the owner of a region alive until it is dropped
+#[allow(dead_code)]
 pub(crate) enum BytesAllocator {
     InternalArrowArray(InternalArrowArray),
+    // an opaque owner of the region, e.g. a memory mapped file. It is never accessed, only
+    // dropped, so no panic can observe it in a broken state.
+    Owner(std::panic::AssertUnwindSafe<Box<dyn std::any::Any + Send + Sync>>),
 
     #[cfg(feature = "arrow")]
     Arrow(arrow_buffer::Buffer),
@@ -93,4 +100,7 @@ pub(crate) fn to_bytes<T: crate::types::NativeType>(value: arrow_buffer::Buffer)
 
 pub(super) use iterator::IntoIter;
 
+#[cfg(feature = "aligned_alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "aligned_alloc")))]
+pub use aligned::{AlignedVec, BufferAllocator, GlobalAllocator, ALIGNMENT};
 pub use immutable::Buffer;
@@ -0,0 +1,199 @@
+use crate::array::Array;
+use crate::compute::hash::{hash_with, HashFunction};
+use crate::error::{Error, Result};
+
+/// Version of the serialization format of [`HyperLogLog::to_bytes`].
+const FORMAT_VERSION: u8 = 1;
+
+/// The seed of the hashes of the values inserted into a [`HyperLogLog`].
+const SEED: u64 = 0;
+
+/// A HyperLogLog sketch, that estimates the number of distinct values inserted into it.
+///
+/// Sketches of the same precision are mergeable, so that the number of distinct values of a
+/// column split across partitions or nodes can be estimated by building one sketch per
+/// partition, exchanging them via [`HyperLogLog::to_bytes`] and [`HyperLogLog::from_bytes`],
+/// and [merging](HyperLogLog::merge) them.
+///
+/// Values are hashed with [`HashFunction::XxHash64`] and a fixed seed, whose hashes are stable
+/// across processes and platforms, so sketches built by different binaries can be merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// The smallest supported precision
+    pub const MIN_PRECISION: u8 = 4;
+    /// The largest supported precision
+    pub const MAX_PRECISION: u8 = 18;
+    /// The precision of [`HyperLogLog::default`], whose standard error is about 1.6%.
+    pub const DEFAULT_PRECISION: u8 = 12;
+
+    /// Returns a new empty [`HyperLogLog`] with `2^precision` registers, whose standard error
+    /// is about `1.04 / sqrt(2^precision)`.
+    /// # Errors
+    /// This function errors iff `precision` is not between [`HyperLogLog::MIN_PRECISION`] and
+    /// [`HyperLogLog::MAX_PRECISION`].
+    pub fn try_new(precision: u8) -> Result<Self> {
+        if !(Self::MIN_PRECISION..=Self::MAX_PRECISION).contains(&precision) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The precision of a HyperLogLog must be between {} and {}, but it is {precision}",
+                Self::MIN_PRECISION,
+                Self::MAX_PRECISION,
+            )));
+        }
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Returns the precision of this [`HyperLogLog`].
+    #[inline]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Inserts a value into this [`HyperLogLog`] by its 64-bit hash.
+    #[inline]
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the remaining bits followed by a sentinel bit, so that the rank is bounded
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Inserts the non-null values of `array` into this [`HyperLogLog`].
+    /// # Errors
+    /// This function errors iff the array's type is not supported by [`hash_with`].
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        let hashes = hash_with(array, HashFunction::XxHash64, SEED)?;
+        if hashes.null_count() == 0 {
+            hashes.values().iter().for_each(|x| self.insert_hash(*x));
+        } else {
+            hashes.iter().flatten().for_each(|x| self.insert_hash(*x));
+        }
+        Ok(())
+    }
+
+    /// Merges `other` into this [`HyperLogLog`], so that it estimates the number of distinct
+    /// values inserted into either sketch.
+    /// # Errors
+    /// This function errors iff the sketches have different precisions.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(Error::InvalidArgumentError(format!(
+                "Only HyperLogLogs of the same precision can be merged, but they have precisions {} and {}",
+                self.precision, other.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(lhs, rhs)| *lhs = (*lhs).max(*rhs));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values inserted into this [`HyperLogLog`].
+    pub fn finalize(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|rank| 2f64.powi(-(*rank as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|rank| **rank == 0).count();
+        // linear counting is more accurate for small cardinalities. No correction is needed
+        // for large cardinalities since hashes have 64 bits.
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+
+    /// Serializes this [`HyperLogLog`], see [`HyperLogLog::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.registers.len());
+        bytes.push(FORMAT_VERSION);
+        bytes.push(self.precision);
+        bytes.extend_from_slice(&self.registers);
+        bytes
+    }
+
+    /// Deserializes a [`HyperLogLog`] serialized by [`HyperLogLog::to_bytes`].
+    /// # Errors
+    /// This function errors iff `bytes` is not a serialized [`HyperLogLog`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (version, precision, registers) = match bytes {
+            [version, precision, registers @ ..] => (*version, *precision, registers),
+            _ => return Err(Error::oos("A serialized HyperLogLog has at least 2 bytes")),
+        };
+        if version != FORMAT_VERSION {
+            return Err(Error::oos(format!(
+                "Unknown HyperLogLog serialization version {version}"
+            )));
+        }
+        let mut sketch = Self::try_new(precision).map_err(|e| Error::oos(e.to_string()))?;
+        if registers.len() != sketch.registers.len() {
+            return Err(Error::oos(format!(
+                "A serialized HyperLogLog of precision {precision} has {} registers, but it has {}",
+                sketch.registers.len(),
+                registers.len()
+            )));
+        }
+        let max_rank = 64 - precision + 1;
+        if registers.iter().any(|rank| *rank > max_rank) {
+            return Err(Error::oos(format!(
+                "The registers of a HyperLogLog of precision {precision} are at most {max_rank}"
+            )));
+        }
+        sketch.registers.copy_from_slice(registers);
+        Ok(sketch)
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::try_new(Self::DEFAULT_PRECISION).unwrap()
+    }
+}
+
+/// Returns a [`HyperLogLog`] of `precision` with the non-null values of `array`.
+/// # Errors
+/// This function errors iff the precision is not supported (see [`HyperLogLog::try_new`])
+/// or the array's type is not supported by [`hash_with`].
+pub fn approx_distinct_sketch(array: &dyn Array, precision: u8) -> Result<HyperLogLog> {
+    let mut sketch = HyperLogLog::try_new(precision)?;
+    sketch.update(array)?;
+    Ok(sketch)
+}
+
+/// Returns the estimated number of distinct non-null values of `array`, with a standard error
+/// of about 1.6%, see [`HyperLogLog`].
+/// # Errors
+/// This function errors iff the array's type is not supported by [`hash_with`].
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::approx_distinct;
+///
+/// let array = Int32Array::from([Some(1), Some(2), None, Some(1)]);
+/// assert_eq!(approx_distinct(&array).unwrap(), 2);
+/// ```
+pub fn approx_distinct(array: &dyn Array) -> Result<u64> {
+    approx_distinct_sketch(array, HyperLogLog::DEFAULT_PRECISION).map(|sketch| sketch.finalize())
+}
@@ -0,0 +1,191 @@
+use num_traits::PrimInt;
+
+use crate::array::{Array, BooleanArray, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, IntegerType, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::scalar::*;
+use crate::types::NativeType;
+
+/// Folds the non-null values of `array` with `op`, starting with `identity`, so that null
+/// slots can be replaced by `identity` and all values folded without branching.
+#[inline]
+fn fold_primitive<T, F>(array: &PrimitiveArray<T>, identity: T, op: F) -> Option<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let null_count = array.null_count();
+    if null_count == array.len() {
+        return None;
+    }
+    let result = match array.validity().filter(|_| null_count > 0) {
+        None => array.values().iter().fold(identity, |acc, x| op(acc, *x)),
+        Some(validity) => array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .fold(identity, |acc, (x, is_valid)| {
+                op(acc, if is_valid { *x } else { identity })
+            }),
+    };
+    Some(result)
+}
+
+/// Returns the bitwise AND of all non-null values of `array`, or `None` if it has no
+/// non-null values.
+pub fn bit_and_primitive<T: NativeType + PrimInt>(array: &PrimitiveArray<T>) -> Option<T> {
+    fold_primitive(array, !T::zero(), |acc, x| acc & x)
+}
+
+/// Returns the bitwise OR of all non-null values of `array`, or `None` if it has no non-null
+/// values.
+pub fn bit_or_primitive<T: NativeType + PrimInt>(array: &PrimitiveArray<T>) -> Option<T> {
+    fold_primitive(array, T::zero(), |acc, x| acc | x)
+}
+
+/// Returns the bitwise XOR of all non-null values of `array`, or `None` if it has no
+/// non-null values.
+pub fn bit_xor_primitive<T: NativeType + PrimInt>(array: &PrimitiveArray<T>) -> Option<T> {
+    fold_primitive(array, T::zero(), |acc, x| acc ^ x)
+}
+
+/// Returns the integer type of arrays of `data_type`, if any.
+fn integer_type(data_type: &DataType) -> Option<IntegerType> {
+    let PhysicalType::Primitive(primitive) = data_type.to_physical_type() else {
+        return None;
+    };
+    Some(match primitive {
+        PrimitiveType::Int8 => IntegerType::Int8,
+        PrimitiveType::Int16 => IntegerType::Int16,
+        PrimitiveType::Int32 => IntegerType::Int32,
+        PrimitiveType::Int64 => IntegerType::Int64,
+        PrimitiveType::UInt8 => IntegerType::UInt8,
+        PrimitiveType::UInt16 => IntegerType::UInt16,
+        PrimitiveType::UInt32 => IntegerType::UInt32,
+        PrimitiveType::UInt64 => IntegerType::UInt64,
+        _ => return None,
+    })
+}
+
+/// Whether [`bit_and`], [`bit_or`] and [`bit_xor`] support `data_type`
+pub fn can_bit_aggregate(data_type: &DataType) -> bool {
+    integer_type(data_type).is_some()
+}
+
+fn check_integer_type(name: &str, array: &dyn Array) -> Result<IntegerType> {
+    integer_type(array.data_type()).ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The `{name}` operator does not support type `{:?}`",
+            array.data_type(),
+        ))
+    })
+}
+
+/// Returns the bitwise AND of all non-null values of `array` as a [`Scalar`] of its type, see
+/// [`bit_and_primitive`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_bit_aggregate`]).
+pub fn bit_and(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    let integer = check_integer_type("bit_and", array)?;
+    Ok(match_integer_type!(integer, |$T| {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        Box::new(PrimitiveScalar::new(array.data_type().clone(), bit_and_primitive(array)))
+    }))
+}
+
+/// Returns the bitwise OR of all non-null values of `array` as a [`Scalar`] of its type, see
+/// [`bit_or_primitive`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_bit_aggregate`]).
+pub fn bit_or(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    let integer = check_integer_type("bit_or", array)?;
+    Ok(match_integer_type!(integer, |$T| {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        Box::new(PrimitiveScalar::new(array.data_type().clone(), bit_or_primitive(array)))
+    }))
+}
+
+/// Returns the bitwise XOR of all non-null values of `array` as a [`Scalar`] of its type, see
+/// [`bit_xor_primitive`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_bit_aggregate`]).
+pub fn bit_xor(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    let integer = check_integer_type("bit_xor", array)?;
+    Ok(match_integer_type!(integer, |$T| {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        Box::new(PrimitiveScalar::new(array.data_type().clone(), bit_xor_primitive(array)))
+    }))
+}
+
+/// Returns whether any 64-bit word of `op(values, validity)` has a set bit.
+#[inline]
+fn any_word(values: &Bitmap, validity: &Bitmap, op: impl Fn(u64, u64) -> u64) -> bool {
+    let mut values = values.chunks::<u64>();
+    let mut validity = validity.chunks::<u64>();
+    if values
+        .by_ref()
+        .zip(validity.by_ref())
+        .any(|(values, validity)| op(values, validity) != 0)
+    {
+        return true;
+    }
+    // the remainder may contain bits past the end of the bitmaps
+    let mask = match values.remainder_len() {
+        0 => 0,
+        length => u64::MAX >> (64 - length),
+    };
+    op(values.remainder(), validity.remainder()) & mask != 0
+}
+
+/// Returns whether any non-null value of `array` is `true`, or `None` if it has no
+/// non-null values, as SQL's `BOOL_OR` does.
+///
+/// Contrarily to [`crate::compute::boolean::any`], nulls are checked 64 values at a time.
+/// # Examples
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::any;
+///
+/// assert_eq!(any(&BooleanArray::from([Some(false), None])), Some(false));
+/// assert_eq!(any(&BooleanArray::from([None, None])), None);
+/// ```
+pub fn any(array: &BooleanArray) -> Option<bool> {
+    let null_count = array.null_count();
+    if null_count == array.len() {
+        None
+    } else if null_count == 0 {
+        Some(array.values().unset_bits() < array.len())
+    } else {
+        let validity = array.validity().unwrap();
+        Some(any_word(array.values(), validity, |values, validity| {
+            values & validity
+        }))
+    }
+}
+
+/// Returns whether all non-null values of `array` are `true`, or `None` if it has no
+/// non-null values, as SQL's `BOOL_AND` does.
+///
+/// Contrarily to [`crate::compute::boolean::all`], nulls are checked 64 values at a time.
+/// # Examples
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::all;
+///
+/// assert_eq!(all(&BooleanArray::from([Some(true), None])), Some(true));
+/// assert_eq!(all(&BooleanArray::from([None, None])), None);
+/// ```
+pub fn all(array: &BooleanArray) -> Option<bool> {
+    let null_count = array.null_count();
+    if null_count == array.len() {
+        None
+    } else if null_count == 0 {
+        Some(array.values().unset_bits() == 0)
+    } else {
+        let validity = array.validity().unwrap();
+        Some(!any_word(array.values(), validity, |values, validity| {
+            !values & validity
+        }))
+    }
+}
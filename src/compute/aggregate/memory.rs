@@ -1,5 +1,8 @@
+use std::collections::HashSet;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
 
 fn validity_size(validity: Option<&Bitmap>) -> usize {
@@ -116,3 +119,139 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         }
     }
 }
+
+/// Start addresses of the regions already accounted for by [`get_heap_size`].
+type Seen = HashSet<usize>;
+
+fn buffer_heap_size<T>(buffer: &Buffer<T>, seen: &mut Seen) -> usize {
+    if seen.insert(buffer.as_ptr() as usize) {
+        buffer.data_len() * std::mem::size_of::<T>()
+    } else {
+        0
+    }
+}
+
+fn bitmap_heap_size(bitmap: &Bitmap, seen: &mut Seen) -> usize {
+    if seen.insert(bitmap.as_ptr() as usize) {
+        bitmap.data_len()
+    } else {
+        0
+    }
+}
+
+fn validity_heap_size(validity: Option<&Bitmap>, seen: &mut Seen) -> usize {
+    validity.map(|b| bitmap_heap_size(b, seen)).unwrap_or(0)
+}
+
+macro_rules! dyn_binary_heap {
+    ($array:expr, $ty:ty, $seen:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        buffer_heap_size(array.offsets().buffer(), $seen)
+            + buffer_heap_size(array.values(), $seen)
+            + validity_heap_size(array.validity(), $seen)
+    }};
+}
+
+fn heap_size(array: &dyn Array, seen: &mut Seen) -> usize {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            bitmap_heap_size(array.values(), seen) + validity_heap_size(array.validity(), seen)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap();
+            buffer_heap_size(array.values(), seen) + validity_heap_size(array.validity(), seen)
+        }),
+        Binary => dyn_binary_heap!(array, BinaryArray<i32>, seen),
+        LargeBinary => dyn_binary_heap!(array, BinaryArray<i64>, seen),
+        Utf8 => dyn_binary_heap!(array, Utf8Array<i32>, seen),
+        LargeUtf8 => dyn_binary_heap!(array, Utf8Array<i64>, seen),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            buffer_heap_size(array.values(), seen) + validity_heap_size(array.validity(), seen)
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            buffer_heap_size(array.offsets().buffer(), seen)
+                + heap_size(array.values().as_ref(), seen)
+                + validity_heap_size(array.validity(), seen)
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            buffer_heap_size(array.offsets().buffer(), seen)
+                + heap_size(array.values().as_ref(), seen)
+                + validity_heap_size(array.validity(), seen)
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            heap_size(array.values().as_ref(), seen) + validity_heap_size(array.validity(), seen)
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| heap_size(x.as_ref(), seen))
+                .sum::<usize>()
+                + validity_heap_size(array.validity(), seen)
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let types = buffer_heap_size(array.types(), seen);
+            let offsets = array
+                .offsets()
+                .map(|x| buffer_heap_size(x, seen))
+                .unwrap_or_default();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|x| heap_size(x.as_ref(), seen))
+                .sum::<usize>();
+            types + offsets + fields
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            heap_size(array.keys(), seen) + heap_size(array.values().as_ref(), seen)
+        }),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            buffer_heap_size(array.offsets().buffer(), seen)
+                + heap_size(array.field().as_ref(), seen)
+                + validity_heap_size(array.validity(), seen)
+        }
+    }
+}
+
+/// Returns the number of bytes allocated on the heap by the buffers backing `array`.
+/// # Implementation
+/// Contrarily to [`estimated_bytes_size`], this function reports the allocated size:
+/// * a sliced array reports the size of the whole region backing each of its buffers
+/// * a buffer shared between multiple (nested) arrays, e.g. the values of a [`DictionaryArray`],
+///   is only counted once.
+///
+/// FFI buffers are included in this count.
+pub fn get_heap_size(array: &dyn Array) -> usize {
+    heap_size(array, &mut Seen::new())
+}
+
+/// Returns the number of bytes allocated on the heap by the buffers backing `arrays`,
+/// counting buffers shared between them only once.
+/// This is useful to account the memory of a set of columns, e.g. a [`Chunk`](crate::chunk::Chunk).
+pub fn get_heap_size_many(arrays: &[&dyn Array]) -> usize {
+    let mut seen = Seen::new();
+    arrays
+        .iter()
+        .map(|array| heap_size(*array, &mut seen))
+        .sum()
+}
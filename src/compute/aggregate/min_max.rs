@@ -1,15 +1,17 @@
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::utils::f16_to_f32;
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::offset::Offset;
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
 };
 use multiversion::multiversion;
+use std::cmp::Ordering;
 
 /// Trait describing a type describing multiple lanes with an order relationship
 /// consistent with the same order of `T`.
@@ -204,52 +206,85 @@ where
     })
 }
 
+/// Returns the minimum value in an array of `f16`, computed in `f32`, according to the natural
+/// order.
+/// Any NaN value is considered to be greater than any other non-null value
+pub fn min_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    min_primitive(&f16_to_f32(array)).map(f16::from_f32)
+}
+
+/// Returns the maximum value in an array of `f16`, computed in `f32`, according to the natural
+/// order.
+/// Any NaN value is considered to be greater than any other non-null value
+pub fn max_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    max_primitive(&f16_to_f32(array)).map(f16::from_f32)
+}
+
+/// Returns the first 8 bytes of `value` as a big-endian integer, padded with zeros, so that
+/// `prefix(a) < prefix(b)` implies `a < b` and `prefix(a) > prefix(b)` implies `a > b`.
+#[inline]
+fn prefix(value: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    let length = value.len().min(8);
+    bytes[..length].copy_from_slice(&value[..length]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Returns the value of `values` that is the furthest in the direction of `wanted`, i.e. the
+/// maximum for [`Ordering::Greater`] and the minimum for [`Ordering::Less`].
+///
+/// Values are first compared by their prefix of 8 bytes, the one of the current best value being cached,
+/// and only compared byte by byte (`memcmp`) when their prefixes are equal.
+#[inline]
+fn min_max_bytes<'a, T, I>(mut values: I, wanted: Ordering) -> Option<&'a T>
+where
+    T: AsRef<[u8]> + ?Sized + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let mut best = values.next()?;
+    let mut best_prefix = prefix(best.as_ref());
+    for value in values {
+        let value_prefix = prefix(value.as_ref());
+        let ordering = value_prefix
+            .cmp(&best_prefix)
+            .then_with(|| value.as_ref().cmp(best.as_ref()));
+        if ordering == wanted {
+            best = value;
+            best_prefix = value_prefix;
+        }
+    }
+    Some(best)
+}
+
 /// Helper to compute min/max of [`BinaryArray`] and [`Utf8Array`]
 macro_rules! min_max_binary_utf8 {
-    ($array: expr, $cmp: expr) => {
-        if $array.null_count() == $array.len() {
-            None
-        } else if $array.validity().is_some() {
-            $array
-                .iter()
-                .reduce(|v1, v2| match (v1, v2) {
-                    (None, v2) => v2,
-                    (v1, None) => v1,
-                    (Some(v1), Some(v2)) => {
-                        if $cmp(v1, v2) {
-                            Some(v2)
-                        } else {
-                            Some(v1)
-                        }
-                    }
-                })
-                .unwrap_or(None)
+    ($array: expr, $wanted: expr) => {
+        if $array.null_count() == 0 {
+            min_max_bytes($array.values_iter(), $wanted)
         } else {
-            $array
-                .values_iter()
-                .reduce(|v1, v2| if $cmp(v1, v2) { v2 } else { v1 })
+            min_max_bytes($array.iter().flatten(), $wanted)
         }
     };
 }
 
 /// Returns the maximum value in the binary array, according to the natural order.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary_utf8!(array, |a, b| a < b)
+    min_max_binary_utf8!(array, Ordering::Greater)
 }
 
 /// Returns the minimum value in the binary array, according to the natural order.
 pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary_utf8!(array, |a, b| a > b)
+    min_max_binary_utf8!(array, Ordering::Less)
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_binary_utf8!(array, |a, b| a < b)
+    min_max_binary_utf8!(array, Ordering::Greater)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_binary_utf8!(array, |a, b| a > b)
+    min_max_binary_utf8!(array, Ordering::Less)
 }
 
 /// Returns the minimum value in the boolean array.
@@ -345,6 +380,11 @@ macro_rules! with_match_primitive_type {(
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::<f16>::new(data_type, max_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -356,7 +396,7 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
             dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, max_binary)
         }
         PhysicalType::LargeBinary => {
-            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, min_binary)
+            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, max_binary)
         }
         _ => {
             return Err(Error::InvalidArgumentError(format!(
@@ -373,6 +413,11 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::<f16>::new(data_type, min_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -388,7 +433,7 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
         _ => {
             return Err(Error::InvalidArgumentError(format!(
-                "The `max` operator does not support type `{:?}`",
+                "The `min` operator does not support type `{:?}`",
                 array.data_type(),
             )))
         }
@@ -402,7 +447,16 @@ pub fn can_min(data_type: &DataType) -> bool {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         use PhysicalType::*;
@@ -9,6 +9,26 @@ mod min_max;
 #[cfg(feature = "compute_aggregate")]
 pub use min_max::*;
 
+#[cfg(feature = "compute_aggregate")]
+mod variance;
+#[cfg(feature = "compute_aggregate")]
+pub use variance::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod quantile;
+#[cfg(feature = "compute_aggregate")]
+pub use quantile::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod bitwise;
+#[cfg(feature = "compute_aggregate")]
+pub use bitwise::*;
+
+#[cfg(all(feature = "compute_aggregate", feature = "compute_hash"))]
+mod approx_distinct;
+#[cfg(all(feature = "compute_aggregate", feature = "compute_hash"))]
+pub use approx_distinct::*;
+
 mod memory;
 pub use memory::*;
 #[cfg(feature = "compute_aggregate")]
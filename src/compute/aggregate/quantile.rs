@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::scalar::*;
+use crate::types::NativeType;
+
+use super::can_var;
+
+/// How a quantile is computed when its position falls between two values `i < j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QuantileInterpolation {
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the position
+    #[default]
+    Linear,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `i` or `j`, whichever is nearest; `j` when both are equally near
+    Nearest,
+    /// `(i + j) / 2`
+    Midpoint,
+}
+
+impl QuantileInterpolation {
+    /// Whether the quantiles are values of the array, i.e. [`QuantileInterpolation::Lower`],
+    /// [`QuantileInterpolation::Higher`] and [`QuantileInterpolation::Nearest`].
+    #[inline]
+    pub fn is_discrete(&self) -> bool {
+        matches!(self, Self::Lower | Self::Higher | Self::Nearest)
+    }
+}
+
+fn check_quantile(q: f64) -> Result<()> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(Error::InvalidArgumentError(format!(
+            "A quantile must be between 0 and 1, but it is {q}"
+        )));
+    }
+    Ok(())
+}
+
+/// A total order in which `NaN`s are equal to each other and greater than all other values.
+#[inline]
+fn nan_last<T: PartialOrd>(lhs: &T, rhs: &T) -> Ordering {
+    // only `NaN`s are incomparable, and `x != x` iff `x` is `NaN`
+    #[allow(clippy::eq_op)]
+    lhs.partial_cmp(rhs)
+        .unwrap_or_else(|| (lhs != lhs).cmp(&(rhs != rhs)))
+}
+
+/// The values `i <= j` around the position of a quantile and the fractional part of the
+/// position.
+type Neighbors<T> = (T, T, f64);
+
+/// Returns the [`Neighbors`] of the quantiles `qs` of `values`, selecting the ranks each
+/// quantile needs in increasing order so that each selection only partitions the values above
+/// the previous rank.
+fn select_quantiles<T: NativeType + PartialOrd>(
+    values: &mut [T],
+    qs: &[f64],
+) -> Option<Vec<Neighbors<T>>> {
+    if values.is_empty() {
+        return None;
+    }
+    let last = (values.len() - 1) as f64;
+    let positions = qs.iter().map(|q| q * last).collect::<Vec<_>>();
+
+    let mut ranks = positions
+        .iter()
+        .flat_map(|position| [position.floor() as usize, position.ceil() as usize])
+        .collect::<Vec<_>>();
+    ranks.sort_unstable();
+    ranks.dedup();
+
+    let mut start = 0;
+    for rank in ranks {
+        values[start..].select_nth_unstable_by(rank - start, nan_last);
+        start = rank + 1;
+    }
+
+    let result = positions
+        .into_iter()
+        .map(|position| {
+            let lower = values[position.floor() as usize];
+            let higher = values[position.ceil() as usize];
+            (lower, higher, position.fract())
+        })
+        .collect();
+    Some(result)
+}
+
+/// Returns the value of a quantile of a [discrete](QuantileInterpolation::is_discrete)
+/// interpolation.
+#[inline]
+fn discrete<T>((lower, higher, fraction): Neighbors<T>, interpolation: QuantileInterpolation) -> T {
+    match interpolation {
+        QuantileInterpolation::Nearest if fraction < 0.5 => lower,
+        QuantileInterpolation::Nearest | QuantileInterpolation::Higher => higher,
+        _ => lower,
+    }
+}
+
+/// Returns the value of a quantile as `f64`, interpolating only when its position falls
+/// between two different values.
+#[inline]
+fn continuous<T: PartialEq + AsPrimitive<f64>>(
+    neighbors: Neighbors<T>,
+    interpolation: QuantileInterpolation,
+) -> f64 {
+    let (lower, higher, fraction) = neighbors;
+    if interpolation.is_discrete() {
+        return discrete(neighbors, interpolation).as_();
+    }
+    if fraction == 0.0 || lower == higher {
+        return lower.as_();
+    }
+    let (lower, higher) = (lower.as_(), higher.as_());
+    match interpolation {
+        QuantileInterpolation::Midpoint => lower + (higher - lower) / 2.0,
+        _ => lower + (higher - lower) * fraction,
+    }
+}
+
+fn non_null_values<T: NativeType>(array: &PrimitiveArray<T>) -> Vec<T> {
+    if array.null_count() == 0 {
+        array.values().to_vec()
+    } else {
+        array.iter().flatten().copied().collect()
+    }
+}
+
+/// Returns the quantiles `qs` of the non-null values of `array` as `f64`, or `None` if it has
+/// no non-null values.
+///
+/// The quantiles are found by selection (quickselect) over the values of `T`, which shares the
+/// partitioning work between quantiles instead of sorting the values; only the values around
+/// each quantile are converted to `f64`. `NaN`s are greater than all other values.
+/// # Errors
+/// This function errors iff a quantile is not between 0 and 1.
+pub fn quantiles_primitive<T: NativeType + PartialOrd + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    qs: &[f64],
+    interpolation: QuantileInterpolation,
+) -> Result<Option<Vec<f64>>> {
+    qs.iter().copied().try_for_each(check_quantile)?;
+    Ok(
+        select_quantiles(&mut non_null_values(array), qs).map(|neighbors| {
+            neighbors
+                .into_iter()
+                .map(|neighbors| continuous(neighbors, interpolation))
+                .collect()
+        }),
+    )
+}
+
+/// Returns the quantile `q` of the non-null values of `array`, see [`quantiles_primitive`].
+/// # Errors
+/// This function errors iff `q` is not between 0 and 1.
+pub fn quantile_primitive<T: NativeType + PartialOrd + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>> {
+    Ok(quantiles_primitive(array, &[q], interpolation)?.map(|x| x[0]))
+}
+
+/// Returns the median of the non-null values of `array`, i.e. its quantile `0.5` with
+/// [`QuantileInterpolation::Linear`].
+pub fn median_primitive<T: NativeType + PartialOrd + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+) -> Option<f64> {
+    quantile_primitive(array, 0.5, QuantileInterpolation::Linear).unwrap()
+}
+
+/// Whether [`median`], [`quantile`] and [`quantiles`] support `data_type`
+pub fn can_quantile(data_type: &DataType) -> bool {
+    can_var(data_type)
+}
+
+// `can_quantile` restricts the arrays reaching this to the types below
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => unreachable!(),
+    }
+})}
+
+fn dyn_quantiles(
+    name: &str,
+    array: &dyn Array,
+    qs: &[f64],
+    interpolation: QuantileInterpolation,
+) -> Result<Box<dyn Array>> {
+    qs.iter().copied().try_for_each(check_quantile)?;
+    if !can_quantile(array.data_type()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The `{name}` operator does not support type `{:?}`",
+            array.data_type(),
+        )));
+    }
+    let PhysicalType::Primitive(primitive) = array.data_type().to_physical_type() else {
+        unreachable!()
+    };
+    Ok(with_match_primitive_type!(primitive, |$T| {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        let neighbors = select_quantiles(&mut non_null_values(array), qs);
+        if interpolation.is_discrete() {
+            let data_type = array.data_type().clone();
+            match neighbors {
+                Some(neighbors) => PrimitiveArray::from_vec(
+                    neighbors
+                        .into_iter()
+                        .map(|neighbors| discrete(neighbors, interpolation))
+                        .collect(),
+                )
+                .to(data_type)
+                .boxed(),
+                None => PrimitiveArray::<$T>::new_null(data_type, qs.len()).boxed(),
+            }
+        } else {
+            match neighbors {
+                Some(neighbors) => PrimitiveArray::from_vec(
+                    neighbors
+                        .into_iter()
+                        .map(|neighbors| continuous(neighbors, interpolation))
+                        .collect(),
+                )
+                .boxed(),
+                None => PrimitiveArray::<f64>::new_null(DataType::Float64, qs.len()).boxed(),
+            }
+        }
+    }))
+}
+
+/// Returns the quantiles `qs` of `array`, with nulls if `array` has no non-null values, see
+/// [`quantiles_primitive`].
+///
+/// With a [discrete](QuantileInterpolation::is_discrete) interpolation the quantiles are values
+/// of `array` and are returned exactly, with its type; otherwise they are of type
+/// [`DataType::Float64`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_quantile`]) or a quantile is not
+/// between 0 and 1.
+pub fn quantiles(
+    array: &dyn Array,
+    qs: &[f64],
+    interpolation: QuantileInterpolation,
+) -> Result<Box<dyn Array>> {
+    dyn_quantiles("quantiles", array, qs, interpolation)
+}
+
+/// Returns the quantile `q` of `array` as a [`Scalar`], of the type described in [`quantiles`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_quantile`]) or `q` is not between 0
+/// and 1.
+pub fn quantile(
+    array: &dyn Array,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Box<dyn Scalar>> {
+    let quantiles = dyn_quantiles("quantile", array, &[q], interpolation)?;
+    Ok(new_scalar(quantiles.as_ref(), 0))
+}
+
+/// Returns the median of `array` as a [`Scalar`] of type [`DataType::Float64`], see
+/// [`median_primitive`].
+/// # Error
+/// Errors iff the operation is not supported (see [`can_quantile`]).
+pub fn median(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    let quantiles = dyn_quantiles("median", array, &[0.5], QuantileInterpolation::Linear)?;
+    Ok(new_scalar(quantiles.as_ref(), 0))
+}
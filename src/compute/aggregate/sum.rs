@@ -3,11 +3,12 @@ use std::ops::Add;
 use multiversion::multiversion;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::utils::f16_to_f32;
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, i256, NativeType};
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
@@ -103,13 +104,29 @@ where
     }
 }
 
+/// Returns the sum of all elements in an array of `f16`, accumulated in `f32` and rounded to
+/// the nearest `f16`.
+/// Returns `None` if all elements are null.
+pub fn sum_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    sum_primitive(&f16_to_f32(array)).map(f16::from_f32)
+}
+
 /// Whether [`sum`] supports `data_type`
 pub fn can_sum(data_type: &DataType) -> bool {
     if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         false
@@ -142,10 +159,18 @@ macro_rules! with_match_primitive_type {(
 
 /// Returns the sum of all elements in `array` as a [`Scalar`] of the same physical
 /// and logical types as `array`.
+///
+/// The sum of a [`DataType::Decimal`] array wraps on overflow; use [`sum_decimal`] to widen
+/// its precision instead.
 /// # Error
 /// Errors iff the operation is not supported.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, sum_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -159,3 +184,118 @@ pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
     })
 }
+
+/// Maximum precision of [`DataType::Decimal`].
+const MAX_DECIMAL_PRECISION: usize = 38;
+/// Maximum precision of [`DataType::Decimal256`].
+const MAX_DECIMAL256_PRECISION: usize = 76;
+/// Number of digits the precision of a decimal sum is widened by.
+const SUM_DECIMAL_EXTRA_PRECISION: usize = 10;
+
+/// Options of [`sum_decimal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DecimalSumOptions {
+    /// Whether sums whose precision exceeds 38 are [`DataType::Decimal256`] instead of
+    /// [`DataType::Decimal`] of precision 38.
+    pub widen: bool,
+    /// Whether a sum that does not fit the precision of the result is null instead of an error.
+    pub null_on_overflow: bool,
+}
+
+/// Returns the [`DataType`] of the sum of a [`DataType::Decimal`] `(p, s)`: the precision is
+/// widened by 10 digits, to at most 38, or 76 as a [`DataType::Decimal256`] if `widen`, and
+/// the scale is kept.
+/// # Errors
+/// This function errors iff `data_type` is not a [`DataType::Decimal`].
+pub fn sum_decimal_type(data_type: &DataType, widen: bool) -> Result<DataType> {
+    let (precision, scale) = match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) => (*precision, *scale),
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "`sum_decimal` only supports Decimal arrays, not `{data_type:?}`"
+            )))
+        }
+    };
+    let precision = precision + SUM_DECIMAL_EXTRA_PRECISION;
+    Ok(if precision <= MAX_DECIMAL_PRECISION {
+        DataType::Decimal(precision, scale)
+    } else if widen {
+        DataType::Decimal256(precision.min(MAX_DECIMAL256_PRECISION), scale)
+    } else {
+        DataType::Decimal(MAX_DECIMAL_PRECISION, scale)
+    })
+}
+
+/// Returns the sum of the values of a decimal `array`, as a [`Scalar`] of type
+/// [`sum_decimal_type`]. Contrarily to [`sum`], the values are summed without overflowing
+/// (on 256 bits) and the sum is checked against the precision of the result.
+///
+/// The scalar is null when all elements are null.
+/// # Errors
+/// This function errors iff the array is not a [`DataType::Decimal`], or the sum does not fit
+/// the result's precision and `options.null_on_overflow` is `false`.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::{sum_decimal, DecimalSumOptions};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let max = 10i128.pow(38) - 1;
+/// let array = PrimitiveArray::from_slice([max, max]).to(DataType::Decimal(38, 2));
+///
+/// let options = DecimalSumOptions { widen: true, ..Default::default() };
+/// let result = sum_decimal(&array, options).unwrap();
+/// assert_eq!(result.data_type(), &DataType::Decimal256(48, 2));
+///
+/// assert!(sum_decimal(&array, DecimalSumOptions::default()).is_err());
+/// ```
+pub fn sum_decimal(
+    array: &PrimitiveArray<i128>,
+    options: DecimalSumOptions,
+) -> Result<Box<dyn Scalar>> {
+    let data_type = sum_decimal_type(array.data_type(), options.widen)?;
+    let precision = match data_type {
+        DataType::Decimal(precision, _) | DataType::Decimal256(precision, _) => precision,
+        _ => unreachable!(),
+    };
+
+    let sum = if array.null_count() == array.len() {
+        None
+    } else {
+        // sum on 128 bits and carry into 256 bits only when it would overflow
+        let mut wide = i256::ZERO;
+        let mut narrow = 0i128;
+        let mut add = |x: i128| match narrow.checked_add(x) {
+            Some(sum) => narrow = sum,
+            None => {
+                wide += i256::from(narrow);
+                narrow = x;
+            }
+        };
+        if array.null_count() == 0 {
+            array.values().iter().copied().for_each(&mut add);
+        } else {
+            array.iter().flatten().copied().for_each(&mut add);
+        }
+        let sum = wide + i256::from(narrow);
+
+        let max = i256::checked_pow10(precision as u32).unwrap() - i256::ONE;
+        if sum <= max && sum >= -max {
+            Some(sum)
+        } else if options.null_on_overflow {
+            None
+        } else {
+            return Err(Error::Overflow);
+        }
+    };
+
+    Ok(match data_type {
+        DataType::Decimal(_, _) => Box::new(PrimitiveScalar::new(
+            data_type,
+            // the sum fits the precision, and thus `i128`
+            sum.map(|sum| sum.to_i128().unwrap()),
+        )),
+        _ => Box::new(PrimitiveScalar::new(data_type, sum)),
+    })
+}
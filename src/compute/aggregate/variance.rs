@@ -0,0 +1,186 @@
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::scalar::*;
+use crate::types::NativeType;
+
+/// Number of values whose moments are computed at once before they are merged into the
+/// running moments. Each chunk is small enough to stay in cache, so that it can be traversed
+/// twice (for its mean and for its squared deviations) while the array is traversed once.
+const CHUNK_SIZE: usize = 256;
+
+/// The count, mean and sum of squared deviations from the mean of a set of values.
+#[derive(Debug, Clone, Copy, Default)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    #[inline]
+    fn of_chunk(values: &[f64]) -> Self {
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let m2 = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+        Self { count, mean, m2 }
+    }
+
+    /// Merges the moments of two disjoint sets of values (Chan et al.).
+    #[inline]
+    fn merge(self, other: Self) -> Self {
+        if self.count == 0.0 {
+            return other;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        Self {
+            count,
+            mean: self.mean + delta * other.count / count,
+            m2: self.m2 + other.m2 + delta * delta * self.count * other.count / count,
+        }
+    }
+}
+
+fn moments<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Moments {
+    let mut buffer = [0.0f64; CHUNK_SIZE];
+    let mut moments = Moments::default();
+
+    match array
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+    {
+        None => {
+            for chunk in array.values().chunks(CHUNK_SIZE) {
+                let buffer = &mut buffer[..chunk.len()];
+                buffer
+                    .iter_mut()
+                    .zip(chunk)
+                    .for_each(|(dst, x)| *dst = x.as_());
+                moments = moments.merge(Moments::of_chunk(buffer));
+            }
+        }
+        Some(validity) => {
+            let mut length = 0;
+            for (x, is_valid) in array.values().iter().zip(validity.iter()) {
+                if is_valid {
+                    buffer[length] = x.as_();
+                    length += 1;
+                    if length == CHUNK_SIZE {
+                        moments = moments.merge(Moments::of_chunk(&buffer));
+                        length = 0;
+                    }
+                }
+            }
+            if length > 0 {
+                moments = moments.merge(Moments::of_chunk(&buffer[..length]));
+            }
+        }
+    }
+    moments
+}
+
+/// Returns the sample variance of the values of `array`, i.e. with `n - 1` degrees of freedom.
+///
+/// Returns `None` if the array has less than two non-null values. The variance is computed in
+/// a single pass with a numerically stable algorithm, by merging the moments of chunks of
+/// values.
+pub fn var_primitive<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Option<f64> {
+    let moments = moments(array);
+    (moments.count > 1.0).then(|| moments.m2 / (moments.count - 1.0))
+}
+
+/// Returns the population variance of the values of `array`, i.e. with `n` degrees of freedom.
+///
+/// Returns `None` if the array is empty or only contains null values, see [`var_primitive`].
+pub fn var_pop_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+) -> Option<f64> {
+    let moments = moments(array);
+    (moments.count > 0.0).then(|| moments.m2 / moments.count)
+}
+
+/// Returns the sample standard deviation of the values of `array`, see [`var_primitive`].
+pub fn stddev_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+) -> Option<f64> {
+    var_primitive(array).map(f64::sqrt)
+}
+
+/// Returns the population standard deviation of the values of `array`, see
+/// [`var_pop_primitive`].
+pub fn stddev_pop_primitive<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+) -> Option<f64> {
+    var_pop_primitive(array).map(f64::sqrt)
+}
+
+/// Whether [`var`], [`var_pop`], [`stddev`] and [`stddev_pop`] support `data_type`
+pub fn can_var(data_type: &DataType) -> bool {
+    if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
+        use PrimitiveType::*;
+        matches!(
+            primitive,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+        ) && !matches!(data_type.to_logical_type(), DataType::Decimal(_, _))
+    } else {
+        false
+    }
+}
+
+// dispatches the primitive types of `can_var`
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => unreachable!(),
+    }
+})}
+
+macro_rules! dyn_variance {
+    ($name:ident, $op:ident, $description:literal) => {
+        #[doc = concat!("Returns the ", $description, " of all elements in `array` as a")]
+        #[doc = concat!("[`Scalar`] of type [`DataType::Float64`], see [`", stringify!($op), "`].")]
+        /// # Error
+        /// Errors iff the operation is not supported (see [`can_var`]).
+        pub fn $name(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+            if !can_var(array.data_type()) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The `{}` operator does not support type `{:?}`",
+                    stringify!($name),
+                    array.data_type(),
+                )));
+            }
+            let value = match array.data_type().to_physical_type() {
+                PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                    $op::<$T>(array.as_any().downcast_ref().unwrap())
+                }),
+                _ => unreachable!(),
+            };
+            Ok(Box::new(PrimitiveScalar::new(DataType::Float64, value)))
+        }
+    };
+}
+
+dyn_variance!(var, var_primitive, "sample variance");
+dyn_variance!(var_pop, var_pop_primitive, "population variance");
+dyn_variance!(stddev, stddev_primitive, "sample standard deviation");
+dyn_variance!(
+    stddev_pop,
+    stddev_pop_primitive,
+    "population standard deviation"
+);
@@ -1,14 +1,19 @@
 //! Definition of basic div operations with primitive arrays
 use std::ops::Div;
 
-use num_traits::{CheckedDiv, NumCast};
+use num_traits::{CheckedDiv, NumCast, One};
 
 use crate::datatypes::PrimitiveType;
 use crate::{
     array::{Array, PrimitiveArray},
+    bitmap::Bitmap,
     compute::{
-        arithmetics::{ArrayCheckedDiv, ArrayDiv},
-        arity::{binary, binary_checked, unary, unary_checked},
+        arithmetics::{
+            ArrayCheckedDiv, ArrayDiv, ArrayOverflowingDiv, ArraySaturatingDiv, ArrayWrappingDiv,
+        },
+        arity::{
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+        },
         utils::check_same_len,
     },
 };
@@ -16,7 +21,7 @@ use strength_reduce::{
     StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU8,
 };
 
-use super::NativeArithmetics;
+use super::{one_on_nulls, NativeArithmetics, NativeIntegerArithmetics};
 
 /// Divides two primitive arrays with the same type.
 /// Panics if the divisor is zero of one pair of values overflows.
@@ -73,6 +78,83 @@ where
     binary_checked(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Wrapping division of two primitive arrays. If the result from the division
+/// overflows (i.e. `MIN / -1`), it wraps around at the boundary of the type.
+/// Panics if a divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_div;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(5i8)]);
+/// let result = wrapping_div(&a, &b);
+/// let expected = Int8Array::from(&[Some(-128i8), Some(2i8)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn wrapping_div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    let op = move |a: T, b: T| a.wrapping_div(b);
+
+    binary(lhs, &one_on_nulls(lhs, rhs), lhs.data_type().clone(), op)
+}
+
+/// Saturating division of two primitive arrays. If the result from the division
+/// overflows (i.e. `MIN / -1`), the result is the saturated value.
+/// Panics if a divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::saturating_div;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(5i8)]);
+/// let result = saturating_div(&a, &b);
+/// let expected = Int8Array::from(&[Some(127i8), Some(2i8)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn saturating_div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    let op = move |a: T, b: T| a.saturating_div(b);
+
+    binary(lhs, &one_on_nulls(lhs, rhs), lhs.data_type().clone(), op)
+}
+
+/// Overflowing division of two primitive arrays. If the result from the division
+/// overflows (i.e. `MIN / -1`), the result for the operation will be an array with
+/// wrapped values and a bitmap indicating the overflowing elements.
+/// Panics if a divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_div;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(5i8)]);
+/// let (result, overflow) = overflowing_div(&a, &b);
+/// let expected = Int8Array::from(&[Some(-128i8), Some(2i8)]);
+/// assert_eq!(result, expected);
+/// assert!(overflow.get_bit(0));
+/// ```
+pub fn overflowing_div<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeIntegerArithmetics + One,
+{
+    let op = move |a: T, b: T| a.overflowing_div(b);
+
+    binary_with_bitmap(lhs, &one_on_nulls(lhs, rhs), lhs.data_type().clone(), op)
+}
+
 // Implementation of ArrayDiv trait for PrimitiveArrays
 impl<T> ArrayDiv<PrimitiveArray<T>> for PrimitiveArray<T>
 where
@@ -188,6 +270,74 @@ where
     unary_checked(lhs, op, lhs.data_type().clone())
 }
 
+/// Wrapping division of a primitive array of type T by a scalar T. If the result
+/// overflows (i.e. `MIN / -1`), it wraps around at the boundary of the type.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_div_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), None]);
+/// let result = wrapping_div_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(-128i8), None]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn wrapping_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a.wrapping_div(rhs), lhs.data_type().clone())
+}
+
+/// Saturating division of a primitive array of type T by a scalar T. If the result
+/// overflows (i.e. `MIN / -1`), the result is the saturated value.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::saturating_div_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), None]);
+/// let result = saturating_div_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(127i8), None]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn saturating_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a.saturating_div(rhs), lhs.data_type().clone())
+}
+
+/// Overflowing division of a primitive array of type T by a scalar T. If the result
+/// overflows (i.e. `MIN / -1`), the result for the operation will be an array with
+/// wrapped values and a bitmap indicating the overflowing elements.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_div_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let (result, overflow) = overflowing_div_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(-128i8), Some(-10i8)]);
+/// assert_eq!(result, expected);
+/// assert!(overflow.get_bit(0));
+/// ```
+pub fn overflowing_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeIntegerArithmetics,
+{
+    let rhs = *rhs;
+    unary_with_bitmap(lhs, |a| a.overflowing_div(rhs), lhs.data_type().clone())
+}
+
 // Implementation of ArrayDiv trait for PrimitiveArrays with a scalar
 impl<T> ArrayDiv<T> for PrimitiveArray<T>
 where
@@ -207,3 +357,57 @@ where
         checked_div_scalar(self, rhs)
     }
 }
+
+impl<T> ArrayWrappingDiv<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    fn wrapping_div(&self, rhs: &PrimitiveArray<T>) -> Self {
+        wrapping_div(self, rhs)
+    }
+}
+
+impl<T> ArrayWrappingDiv<T> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    fn wrapping_div(&self, rhs: &T) -> Self {
+        wrapping_div_scalar(self, rhs)
+    }
+}
+
+impl<T> ArraySaturatingDiv<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    fn saturating_div(&self, rhs: &PrimitiveArray<T>) -> Self {
+        saturating_div(self, rhs)
+    }
+}
+
+impl<T> ArraySaturatingDiv<T> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    fn saturating_div(&self, rhs: &T) -> Self {
+        saturating_div_scalar(self, rhs)
+    }
+}
+
+impl<T> ArrayOverflowingDiv<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    fn overflowing_div(&self, rhs: &PrimitiveArray<T>) -> (Self, Bitmap) {
+        overflowing_div(self, rhs)
+    }
+}
+
+impl<T> ArrayOverflowingDiv<T> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    fn overflowing_div(&self, rhs: &T) -> (Self, Bitmap) {
+        overflowing_div_scalar(self, rhs)
+    }
+}
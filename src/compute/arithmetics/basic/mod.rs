@@ -5,6 +5,7 @@
 //! * `checked_*` that turns overflowings to `None`
 //! * `overflowing_*` returning a [`Bitmap`](crate::bitmap::Bitmap) with items that overflow.
 //! * `saturating_*` that saturates the result.
+//! * `wrapping_*` that wraps the result around the boundary of the type.
 mod add;
 pub use add::*;
 mod div;
@@ -15,6 +16,8 @@ mod pow;
 pub use pow::*;
 mod rem;
 pub use rem::*;
+mod sign;
+pub use sign::*;
 mod sub;
 pub use sub::*;
 
@@ -22,9 +25,13 @@ use std::ops::Neg;
 
 use num_traits::{CheckedNeg, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    types::{f16, NativeType},
+};
 
 use super::super::arity::{unary, unary_checked};
+use super::super::utils::combine_validities;
 
 /// Trait describing a [`NativeType`] whose semantics of arithmetic in Arrow equals
 /// the semantics in Rust.
@@ -39,9 +46,85 @@ impl NativeArithmetics for i8 {}
 impl NativeArithmetics for i16 {}
 impl NativeArithmetics for i32 {}
 impl NativeArithmetics for i64 {}
+impl NativeArithmetics for f16 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
 
+/// Trait describing the integer [`NativeArithmetics`], whose division and remainder have
+/// variants that do not panic when they overflow (i.e. `MIN / -1`).
+/// Like Rust's, these variants panic if the divisor is zero.
+pub trait NativeIntegerArithmetics: NativeArithmetics {
+    /// Wrapping division, i.e. `MIN / -1 == MIN`
+    fn wrapping_div(self, rhs: Self) -> Self;
+    /// Saturating division, i.e. `MIN / -1 == MAX`
+    fn saturating_div(self, rhs: Self) -> Self;
+    /// Wrapping division and whether it overflowed
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+    /// Wrapping remainder, i.e. `MIN % -1 == 0`
+    fn wrapping_rem(self, rhs: Self) -> Self;
+    /// Wrapping remainder and whether it overflowed
+    fn overflowing_rem(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! native_integer_arithmetics {
+    ($type:ty) => {
+        impl NativeIntegerArithmetics for $type {
+            #[inline]
+            fn wrapping_div(self, rhs: Self) -> Self {
+                <$type>::wrapping_div(self, rhs)
+            }
+            #[inline]
+            fn saturating_div(self, rhs: Self) -> Self {
+                <$type>::saturating_div(self, rhs)
+            }
+            #[inline]
+            fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_div(self, rhs)
+            }
+            #[inline]
+            fn wrapping_rem(self, rhs: Self) -> Self {
+                <$type>::wrapping_rem(self, rhs)
+            }
+            #[inline]
+            fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_rem(self, rhs)
+            }
+        }
+    };
+}
+
+native_integer_arithmetics!(u8);
+native_integer_arithmetics!(u16);
+native_integer_arithmetics!(u32);
+native_integer_arithmetics!(u64);
+native_integer_arithmetics!(i8);
+native_integer_arithmetics!(i16);
+native_integer_arithmetics!(i32);
+native_integer_arithmetics!(i64);
+
+/// Returns `rhs` where the values of slots that are null in `lhs` or `rhs` are one, so that
+/// dividing by it does not panic on slots whose result is null.
+fn one_on_nulls<T: NativeType + num_traits::One>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    let validity = match combine_validities(lhs.validity(), rhs.validity()) {
+        Some(validity) if lhs.len() == rhs.len() => validity,
+        _ => return rhs.clone(),
+    };
+    let values = rhs
+        .values()
+        .iter()
+        .zip(validity.iter())
+        .map(|(x, is_valid)| if is_valid { *x } else { T::one() })
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        rhs.data_type().clone(),
+        values.into(),
+        rhs.validity().cloned(),
+    )
+}
+
 /// Negates values from array.
 ///
 /// # Examples
@@ -1,20 +1,23 @@
 use std::ops::Rem;
 
-use num_traits::{CheckedRem, NumCast};
+use num_traits::{CheckedRem, NumCast, One};
 
 use crate::datatypes::PrimitiveType;
 use crate::{
     array::{Array, PrimitiveArray},
+    bitmap::Bitmap,
     compute::{
-        arithmetics::{ArrayCheckedRem, ArrayRem},
-        arity::{binary, binary_checked, unary, unary_checked},
+        arithmetics::{ArrayCheckedRem, ArrayOverflowingRem, ArrayRem, ArrayWrappingRem},
+        arity::{
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+        },
     },
 };
 use strength_reduce::{
     StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU8,
 };
 
-use super::NativeArithmetics;
+use super::{one_on_nulls, NativeArithmetics, NativeIntegerArithmetics};
 
 /// Remainder of two primitive arrays with the same type.
 /// Panics if the divisor is zero of one pair of values overflows.
@@ -61,6 +64,59 @@ where
     binary_checked(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Wrapping remainder of two primitive arrays. If the remainder overflows
+/// (i.e. `MIN % -1`), the result is zero, which is also its mathematical value.
+/// Panics if a divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_rem;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(7i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(5i8)]);
+/// let result = wrapping_rem(&a, &b);
+/// let expected = Int8Array::from(&[Some(0i8), Some(2i8)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn wrapping_rem<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    let op = move |a: T, b: T| a.wrapping_rem(b);
+
+    binary(lhs, &one_on_nulls(lhs, rhs), lhs.data_type().clone(), op)
+}
+
+/// Overflowing remainder of two primitive arrays. If the remainder overflows
+/// (i.e. `MIN % -1`), the result for the operation will be an array with
+/// wrapped values and a bitmap indicating the overflowing elements.
+/// Panics if a divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_rem;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(7i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(5i8)]);
+/// let (result, overflow) = overflowing_rem(&a, &b);
+/// let expected = Int8Array::from(&[Some(0i8), Some(2i8)]);
+/// assert_eq!(result, expected);
+/// assert!(overflow.get_bit(0));
+/// ```
+pub fn overflowing_rem<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeIntegerArithmetics + One,
+{
+    let op = move |a: T, b: T| a.overflowing_rem(b);
+
+    binary_with_bitmap(lhs, &one_on_nulls(lhs, rhs), lhs.data_type().clone(), op)
+}
+
 impl<T> ArrayRem<PrimitiveArray<T>> for PrimitiveArray<T>
 where
     T: NativeArithmetics + Rem<Output = T>,
@@ -182,6 +238,52 @@ where
     unary_checked(lhs, op, lhs.data_type().clone())
 }
 
+/// Wrapping remainder of a primitive array of type T by a scalar T. If the remainder
+/// overflows (i.e. `MIN % -1`), the result is zero, which is also its mathematical value.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_rem_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), None]);
+/// let result = wrapping_rem_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(0i8), None]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn wrapping_rem_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a.wrapping_rem(rhs), lhs.data_type().clone())
+}
+
+/// Overflowing remainder of a primitive array of type T by a scalar T. If the remainder
+/// overflows (i.e. `MIN % -1`), the result for the operation will be an array with
+/// wrapped values and a bitmap indicating the overflowing elements.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_rem_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(7i8)]);
+/// let (result, overflow) = overflowing_rem_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(0i8), Some(0i8)]);
+/// assert_eq!(result, expected);
+/// assert!(overflow.get_bit(0));
+/// ```
+pub fn overflowing_rem_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeIntegerArithmetics,
+{
+    let rhs = *rhs;
+    unary_with_bitmap(lhs, |a| a.overflowing_rem(rhs), lhs.data_type().clone())
+}
+
 impl<T> ArrayRem<T> for PrimitiveArray<T>
 where
     T: NativeArithmetics + Rem<Output = T> + NumCast,
@@ -199,3 +301,39 @@ where
         checked_rem_scalar(self, rhs)
     }
 }
+
+impl<T> ArrayWrappingRem<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    fn wrapping_rem(&self, rhs: &PrimitiveArray<T>) -> Self {
+        wrapping_rem(self, rhs)
+    }
+}
+
+impl<T> ArrayWrappingRem<T> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    fn wrapping_rem(&self, rhs: &T) -> Self {
+        wrapping_rem_scalar(self, rhs)
+    }
+}
+
+impl<T> ArrayOverflowingRem<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics + One,
+{
+    fn overflowing_rem(&self, rhs: &PrimitiveArray<T>) -> (Self, Bitmap) {
+        overflowing_rem(self, rhs)
+    }
+}
+
+impl<T> ArrayOverflowingRem<T> for PrimitiveArray<T>
+where
+    T: NativeIntegerArithmetics,
+{
+    fn overflowing_rem(&self, rhs: &T) -> (Self, Bitmap) {
+        overflowing_rem_scalar(self, rhs)
+    }
+}
@@ -0,0 +1,147 @@
+//! Definition of the absolute value and the sign of primitive arrays
+use crate::{
+    array::PrimitiveArray,
+    compute::arity::{unary, unary_checked},
+};
+
+use super::NativeArithmetics;
+
+/// Trait describing the signed [`NativeArithmetics`], i.e. the signed integers and the floats,
+/// whose absolute value and sign are defined.
+pub trait NativeSigned: NativeArithmetics {
+    /// Absolute value, panicking on overflow, i.e. `MIN.abs()`
+    fn abs(self) -> Self;
+    /// Checked absolute value, `None` on overflow, i.e. `MIN.abs()`
+    fn checked_abs(self) -> Option<Self>;
+    /// Wrapping absolute value, i.e. `MIN.abs() == MIN`
+    fn wrapping_abs(self) -> Self;
+    /// The sign of the number: `-1`, `0` or `1`. `NaN` is its own sign.
+    fn signum(self) -> Self;
+}
+
+macro_rules! native_signed_integer {
+    ($type:ty) => {
+        impl NativeSigned for $type {
+            #[inline]
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+            #[inline]
+            fn checked_abs(self) -> Option<Self> {
+                <$type>::checked_abs(self)
+            }
+            #[inline]
+            fn wrapping_abs(self) -> Self {
+                <$type>::wrapping_abs(self)
+            }
+            #[inline]
+            fn signum(self) -> Self {
+                <$type>::signum(self)
+            }
+        }
+    };
+}
+
+native_signed_integer!(i8);
+native_signed_integer!(i16);
+native_signed_integer!(i32);
+native_signed_integer!(i64);
+
+macro_rules! native_signed_float {
+    ($type:ty) => {
+        impl NativeSigned for $type {
+            #[inline]
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+            #[inline]
+            fn checked_abs(self) -> Option<Self> {
+                Some(<$type>::abs(self))
+            }
+            #[inline]
+            fn wrapping_abs(self) -> Self {
+                <$type>::abs(self)
+            }
+            #[inline]
+            fn signum(self) -> Self {
+                // unlike Rust's, the sign of (negative) zero is zero, as in SQL
+                if self == 0.0 {
+                    0.0
+                } else {
+                    <$type>::signum(self)
+                }
+            }
+        }
+    };
+}
+
+native_signed_float!(f32);
+native_signed_float!(f64);
+
+/// Returns the absolute value of every value of an array. Panics if one of the values
+/// overflows, i.e. is the minimum of a signed integer type.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::abs;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(-1), None, Some(2)]);
+/// let actual = abs(&a);
+/// let expected = Int32Array::from(&[Some(1), None, Some(2)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn abs<T: NativeSigned>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    unary(array, |a| a.abs(), array.data_type().clone())
+}
+
+/// Checked absolute value of every value of an array. If a value overflows, i.e. is the minimum
+/// of a signed integer type, the validity for that index is changed to null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_abs;
+/// use arrow2::array::Int64Array;
+///
+/// let a = Int64Array::from(&[Some(-1), None, Some(i64::MIN)]);
+/// let actual = checked_abs(&a);
+/// let expected = Int64Array::from(&[Some(1), None, None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn checked_abs<T: NativeSigned>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    unary_checked(array, |a| a.checked_abs(), array.data_type().clone())
+}
+
+/// Wrapping absolute value of every value of an array, where the minimum of a signed integer
+/// type wraps around to itself.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_abs;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-1), None, Some(i8::MIN)]);
+/// let actual = wrapping_abs(&a);
+/// let expected = Int8Array::from(&[Some(1), None, Some(i8::MIN)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn wrapping_abs<T: NativeSigned>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    unary(array, |a| a.wrapping_abs(), array.data_type().clone())
+}
+
+/// Returns the sign of every value of an array: `-1` for negative values, `0` for zeros and `1`
+/// for positive values. The sign of a float `NaN` is `NaN`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::signum;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(-2.5), None, Some(0.0), Some(3.0)]);
+/// let actual = signum(&a);
+/// let expected = Float64Array::from(&[Some(-1.0), None, Some(0.0), Some(1.0)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn signum<T: NativeSigned>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    unary(array, |a| a.signum(), array.data_type().clone())
+}
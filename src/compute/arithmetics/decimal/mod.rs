@@ -9,6 +9,10 @@ mod div;
 pub use div::*;
 mod mul;
 pub use mul::*;
+mod sign;
+pub use sign::*;
+mod sql;
+pub use sql::*;
 mod sub;
 pub use sub::*;
 
@@ -0,0 +1,152 @@
+//! Defines the negation, absolute value and sign kernels for [`PrimitiveArray`] representing
+//! decimals.
+use crate::{
+    array::{Array, PrimitiveArray},
+    compute::arity::{unary, unary_checked},
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::max_value;
+
+fn precision(data_type: &DataType) -> usize {
+    if let DataType::Decimal(precision, _) = data_type.to_logical_type() {
+        *precision
+    } else {
+        unreachable!()
+    }
+}
+
+/// Applies `op` to every valid value of `array`, erroring if a result does not fit the
+/// precision of the array.
+fn try_unary<F>(array: &PrimitiveArray<i128>, op: F) -> Result<PrimitiveArray<i128>>
+where
+    F: Fn(i128) -> Option<i128>,
+{
+    let max = max_value(precision(array.data_type()));
+    let result = unary_checked(
+        array,
+        |a| op(a).filter(|res| res.abs() <= max),
+        array.data_type().clone(),
+    );
+    if result.null_count() != array.null_count() {
+        return Err(Error::Overflow);
+    }
+    Ok(result)
+}
+
+/// Negates a decimal [`PrimitiveArray`].
+/// # Error
+/// Errors with [`Error::Overflow`] iff a value does not fit the precision of the array, so
+/// that its negation overflows (e.g. `i128::MIN`).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::negate;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(150i128), None, Some(-2i128)]).to(DataType::Decimal(5, 2));
+///
+/// let result = negate(&a).unwrap();
+/// let expected = PrimitiveArray::from([Some(-150i128), None, Some(2i128)]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn negate(array: &PrimitiveArray<i128>) -> Result<PrimitiveArray<i128>> {
+    try_unary(array, |a| a.checked_neg())
+}
+
+/// Checked negation of a decimal [`PrimitiveArray`]. If a value does not fit the precision of
+/// the array, so that its negation overflows, the validity for that index is changed to None.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::checked_negate;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(150i128), None, Some(i128::MIN)]).to(DataType::Decimal(38, 0));
+///
+/// let result = checked_negate(&a);
+/// let expected = PrimitiveArray::from([Some(-150i128), None, None]).to(DataType::Decimal(38, 0));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_negate(array: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let max = max_value(precision(array.data_type()));
+    unary_checked(
+        array,
+        |a| a.checked_neg().filter(|res| res.abs() <= max),
+        array.data_type().clone(),
+    )
+}
+
+/// Returns the absolute value of a decimal [`PrimitiveArray`].
+/// # Error
+/// Errors with [`Error::Overflow`] iff a value does not fit the precision of the array, so
+/// that its absolute value overflows (e.g. `i128::MIN`).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::abs;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(150i128), None, Some(-2i128)]).to(DataType::Decimal(5, 2));
+///
+/// let result = abs(&a).unwrap();
+/// let expected = PrimitiveArray::from([Some(150i128), None, Some(2i128)]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn abs(array: &PrimitiveArray<i128>) -> Result<PrimitiveArray<i128>> {
+    try_unary(array, |a| a.checked_abs())
+}
+
+/// Checked absolute value of a decimal [`PrimitiveArray`]. If a value does not fit the
+/// precision of the array, so that its absolute value overflows, the validity for that index
+/// is changed to None.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::checked_abs;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(-150i128), None, Some(i128::MIN)]).to(DataType::Decimal(38, 0));
+///
+/// let result = checked_abs(&a);
+/// let expected = PrimitiveArray::from([Some(150i128), None, None]).to(DataType::Decimal(38, 0));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_abs(array: &PrimitiveArray<i128>) -> PrimitiveArray<i128> {
+    let max = max_value(precision(array.data_type()));
+    unary_checked(
+        array,
+        |a| a.checked_abs().filter(|res| *res <= max),
+        array.data_type().clone(),
+    )
+}
+
+/// Returns the sign of every value of a decimal [`PrimitiveArray`] as an
+/// [`Int8`](DataType::Int8) array of `-1`, `0` and `1`, since `1` is not representable in
+/// decimals whose precision equals their scale.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::signum;
+/// use arrow2::array::{Int8Array, PrimitiveArray};
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(-150i128), None, Some(0i128), Some(1i128)]).to(DataType::Decimal(5, 2));
+///
+/// let result = signum(&a);
+/// let expected = Int8Array::from([Some(-1), None, Some(0), Some(1)]);
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn signum(array: &PrimitiveArray<i128>) -> PrimitiveArray<i8> {
+    unary(array, |a| a.signum() as i8, DataType::Int8)
+}
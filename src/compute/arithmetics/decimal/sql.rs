@@ -0,0 +1,295 @@
+//! Defines decimal arithmetic kernels whose result precision and scale follow SQL semantics.
+//!
+//! Contrarily to the other decimal kernels, these accept operands of different precision and
+//! scale, derive the precision and scale of the result from them (see [`sql_result_type`]),
+//! perform the operation on 256 bits so that intermediate results never overflow, and check
+//! every result against the precision of the result.
+use crate::{
+    array::{Array, PrimitiveArray},
+    bitmap::MutableBitmap,
+    compute::utils::{check_same_len, combine_validities},
+    datatypes::DataType,
+    error::{Error, Result},
+    types::i256,
+};
+
+/// Maximum precision of [`DataType::Decimal`].
+const MAX_PRECISION: usize = 38;
+/// Maximum precision of [`DataType::Decimal256`].
+const MAX_PRECISION_256: usize = 76;
+/// Minimum scale kept when the precision of a result has to be reduced.
+const MIN_ADJUSTED_SCALE: usize = 6;
+
+/// An arithmetic operator between decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalOperator {
+    /// Addition
+    Add,
+    /// Subtraction
+    Sub,
+    /// Multiplication
+    Mul,
+    /// Division
+    Div,
+}
+
+/// Options of the SQL decimal kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SqlDecimalOptions {
+    /// Whether results whose precision exceeds 38 are [`DataType::Decimal256`] instead of
+    /// [`DataType::Decimal`] with a reduced scale.
+    pub widen: bool,
+    /// Whether results that do not fit the result's precision, as well as divisions by zero,
+    /// are null instead of an error.
+    pub null_on_overflow: bool,
+}
+
+/// Returns the [`DataType`] of `lhs op rhs` for two [`DataType::Decimal`], following SQL rules:
+/// * `+` and `-`: scale `max(s1, s2)` and precision `max(p1 - s1, p2 - s2) + scale + 1`
+/// * `*`: scale `s1 + s2` and precision `p1 + p2 + 1`
+/// * `/`: scale `max(6, s1 + p2 + 1)` and precision `p1 - s1 + s2 + scale`
+///
+/// When the precision exceeds 38, the result is a [`DataType::Decimal256`] if `widen` and the
+/// precision is at most 76. Otherwise the precision is capped and the scale reduced by the
+/// same amount, so that the integral digits are kept, but never below `min(scale, 6)`.
+/// # Errors
+/// This function errors iff either type is not a [`DataType::Decimal`].
+pub fn sql_result_type(
+    op: DecimalOperator,
+    lhs: &DataType,
+    rhs: &DataType,
+    widen: bool,
+) -> Result<DataType> {
+    let (lhs_p, lhs_s) = decimal_parameters(lhs)?;
+    let (rhs_p, rhs_s) = decimal_parameters(rhs)?;
+
+    let (precision, scale) = match op {
+        DecimalOperator::Add | DecimalOperator::Sub => {
+            let scale = lhs_s.max(rhs_s);
+            let integral = (lhs_p - lhs_s).max(rhs_p - rhs_s);
+            (integral + scale + 1, scale)
+        }
+        DecimalOperator::Mul => (lhs_p + rhs_p + 1, lhs_s + rhs_s),
+        DecimalOperator::Div => {
+            let scale = MIN_ADJUSTED_SCALE.max(lhs_s + rhs_p + 1);
+            (lhs_p - lhs_s + rhs_s + scale, scale)
+        }
+    };
+
+    if precision <= MAX_PRECISION {
+        return Ok(DataType::Decimal(precision, scale));
+    }
+    let max_precision = if widen {
+        MAX_PRECISION_256
+    } else {
+        MAX_PRECISION
+    };
+    let (precision, scale) = if precision <= max_precision {
+        (precision, scale)
+    } else {
+        let integral = precision - scale;
+        let min_scale = scale.min(MIN_ADJUSTED_SCALE);
+        let scale = max_precision.saturating_sub(integral).max(min_scale);
+        (max_precision, scale)
+    };
+    Ok(if widen {
+        DataType::Decimal256(precision, scale)
+    } else {
+        DataType::Decimal(precision, scale)
+    })
+}
+
+/// Adds two decimal [`PrimitiveArray`]s of any precision and scale. The result's type is
+/// given by [`sql_result_type`].
+/// # Errors
+/// This function errors iff the arrays have different lengths, are not decimals, or a result
+/// does not fit the result's precision and `options.null_on_overflow` is `false`.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::{sql_add, SqlDecimalOptions};
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(1_25i128), None]).to(DataType::Decimal(5, 2));
+/// let b = PrimitiveArray::from([Some(1_125i128), Some(1i128)]).to(DataType::Decimal(4, 3));
+/// let result = sql_add(&a, &b, SqlDecimalOptions::default()).unwrap();
+/// let expected = PrimitiveArray::from([Some(2_375i128), None]).to(DataType::Decimal(7, 3));
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn sql_add(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    options: SqlDecimalOptions,
+) -> Result<Box<dyn Array>> {
+    sql_arithmetic(lhs, rhs, DecimalOperator::Add, options)
+}
+
+/// Subtracts two decimal [`PrimitiveArray`]s of any precision and scale. The result's type is
+/// given by [`sql_result_type`].
+/// # Errors
+/// This function errors under the same conditions as [`sql_add`].
+pub fn sql_sub(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    options: SqlDecimalOptions,
+) -> Result<Box<dyn Array>> {
+    sql_arithmetic(lhs, rhs, DecimalOperator::Sub, options)
+}
+
+/// Multiplies two decimal [`PrimitiveArray`]s of any precision and scale. The result's type is
+/// given by [`sql_result_type`]; when its scale was reduced, results are rounded half away
+/// from zero.
+/// # Errors
+/// This function errors under the same conditions as [`sql_add`].
+pub fn sql_mul(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    options: SqlDecimalOptions,
+) -> Result<Box<dyn Array>> {
+    sql_arithmetic(lhs, rhs, DecimalOperator::Mul, options)
+}
+
+/// Divides two decimal [`PrimitiveArray`]s of any precision and scale. The result's type is
+/// given by [`sql_result_type`] and results are rounded half away from zero.
+/// # Errors
+/// This function errors under the same conditions as [`sql_add`], and iff a divisor is zero
+/// and `options.null_on_overflow` is `false`.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::{sql_div, SqlDecimalOptions};
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(2_00i128)]).to(DataType::Decimal(5, 2));
+/// let b = PrimitiveArray::from([Some(3i128)]).to(DataType::Decimal(3, 0));
+/// let result = sql_div(&a, &b, SqlDecimalOptions::default()).unwrap();
+/// let expected = PrimitiveArray::from([Some(0_666667i128)]).to(DataType::Decimal(9, 6));
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn sql_div(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    options: SqlDecimalOptions,
+) -> Result<Box<dyn Array>> {
+    sql_arithmetic(lhs, rhs, DecimalOperator::Div, options)
+}
+
+/// Applies `op` to two decimal [`PrimitiveArray`]s of any precision and scale, see
+/// [`sql_result_type`].
+/// # Errors
+/// This function errors under the same conditions as [`sql_add`] and [`sql_div`].
+pub fn sql_arithmetic(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    op: DecimalOperator,
+    options: SqlDecimalOptions,
+) -> Result<Box<dyn Array>> {
+    check_same_len(lhs, rhs)?;
+    let data_type = sql_result_type(op, lhs.data_type(), rhs.data_type(), options.widen)?;
+
+    let (_, lhs_s) = decimal_parameters(lhs.data_type())?;
+    let (_, rhs_s) = decimal_parameters(rhs.data_type())?;
+    let (precision, scale) = match data_type {
+        DataType::Decimal(precision, scale) | DataType::Decimal256(precision, scale) => {
+            (precision, scale)
+        }
+        _ => unreachable!(),
+    };
+    let max = pow10(precision).unwrap() - i256::ONE;
+
+    let kernel = move |a: i128, b: i128| -> Option<i256> {
+        let (a, b) = (i256::from(a), i256::from(b));
+        match op {
+            DecimalOperator::Add | DecimalOperator::Sub => {
+                let exact_scale = lhs_s.max(rhs_s);
+                let a = a * pow10(exact_scale - lhs_s)?;
+                let b = b * pow10(exact_scale - rhs_s)?;
+                let result = if op == DecimalOperator::Add {
+                    a + b
+                } else {
+                    a - b
+                };
+                div_round(result, pow10(exact_scale - scale)?)
+            }
+            DecimalOperator::Mul => div_round(a * b, pow10(lhs_s + rhs_s - scale)?),
+            DecimalOperator::Div => {
+                // a / 10^s1 / (b / 10^s2) = (a * 10^(s + s2 - s1) / b) / 10^s
+                let (a, b) = if scale + rhs_s >= lhs_s {
+                    (a.checked_mul(pow10(scale + rhs_s - lhs_s)?)?, b)
+                } else {
+                    (a, b.checked_mul(pow10(lhs_s - scale - rhs_s)?)?)
+                };
+                div_round(a, b)
+            }
+        }
+        .filter(|x| *x <= max && *x >= -max)
+    };
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let mut result_validity = MutableBitmap::with_capacity(lhs.len());
+    let mut values = Vec::with_capacity(lhs.len());
+    for (index, (a, b)) in lhs.values().iter().zip(rhs.values().iter()).enumerate() {
+        let is_valid = validity
+            .as_ref()
+            .map(|validity| validity.get_bit(index))
+            .unwrap_or(true);
+        let value = if is_valid { kernel(*a, *b) } else { None };
+        if is_valid && value.is_none() && !options.null_on_overflow {
+            return Err(Error::InvalidArgumentError(format!(
+                "The result of {a} {op:?} {b} at position {index} does not fit in {data_type:?}",
+            )));
+        }
+        result_validity.push(value.is_some());
+        values.push(value.unwrap_or_default());
+    }
+    let validity = if result_validity.unset_bits() > 0 {
+        Some(result_validity.into())
+    } else {
+        None
+    };
+
+    Ok(match data_type {
+        DataType::Decimal(_, _) => {
+            // every value fits the precision of the result, and thus `i128`
+            let values = values
+                .into_iter()
+                .map(|x| x.to_i128().unwrap())
+                .collect::<Vec<_>>();
+            PrimitiveArray::new(data_type, values.into(), validity).boxed()
+        }
+        _ => PrimitiveArray::new(data_type, values.into(), validity).boxed(),
+    })
+}
+
+fn decimal_parameters(data_type: &DataType) -> Result<(usize, usize)> {
+    match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) if scale <= precision => Ok((*precision, *scale)),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "SQL decimal arithmetic is only supported for valid Decimal types, not {data_type:?}"
+        ))),
+    }
+}
+
+/// Returns `10^exponent`, or `None` if it overflows 256 bits.
+#[inline]
+fn pow10(exponent: usize) -> Option<i256> {
+    i256::checked_pow10(exponent as u32)
+}
+
+/// Returns `value / divisor` rounded half away from zero, or `None` if `divisor` is zero or
+/// the division overflows.
+#[inline]
+fn div_round(value: i256, divisor: i256) -> Option<i256> {
+    let quotient = value.checked_div(divisor)?;
+    let remainder = (value % divisor).checked_abs()?;
+    // whether the remainder is at least half the divisor, without overflowing
+    if remainder >= divisor.checked_abs()? - remainder {
+        if value.is_negative() == divisor.is_negative() {
+            Some(quotient + i256::ONE)
+        } else {
+            Some(quotient - i256::ONE)
+        }
+    } else {
+        Some(quotient)
+    }
+}
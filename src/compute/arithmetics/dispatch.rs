@@ -0,0 +1,166 @@
+//! Type-erased arithmetics of the dynamically typed functions, enabled by the feature
+//! `compute_dyn_dispatch`.
+//!
+//! Every primitive type has a single [`Arithmetic`], whose functions evaluate any [`Operator`]
+//! at runtime, instead of one monomorphized kernel per operator and type. This trades the
+//! specialized kernels of [`super::basic`] (e.g. the strength reduction of divisions by a
+//! scalar) for a much smaller binary and faster compilation.
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use num_traits::Zero;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::utils::{check_same_len, combine_validities};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::scalar::{PrimitiveScalar, Scalar};
+
+use super::basic::NativeArithmetics;
+use super::Operator;
+
+/// The type-erased arithmetics of arrays of a primitive type.
+struct Arithmetic {
+    array: fn(&dyn Array, &dyn Array, Operator) -> Box<dyn Array>,
+    scalar: fn(&dyn Array, &dyn Scalar, Operator) -> Box<dyn Array>,
+}
+
+trait Native:
+    NativeArithmetics
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Zero
+{
+}
+
+impl<T> Native for T where
+    T: NativeArithmetics
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + Zero
+{
+}
+
+/// Returns the function evaluating `op` on two values, so that it is matched once per array
+/// instead of once per value.
+fn function<T: Native>(op: Operator) -> fn(T, T) -> T {
+    match op {
+        Operator::Add => <T as Add>::add,
+        Operator::Sub => <T as Sub>::sub,
+        Operator::Mul => <T as Mul>::mul,
+        Operator::Div => <T as Div>::div,
+        Operator::Rem => <T as Rem>::rem,
+    }
+}
+
+fn primitive<T: Native>(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Box<dyn Array> {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    check_same_len(lhs, rhs).unwrap();
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let divides = matches!(op, Operator::Div | Operator::Rem);
+    let op = function::<T>(op);
+    let values = match validity.as_ref() {
+        // null slots are not divided, since their divisor may be zero
+        Some(validity) if divides && validity.unset_bits() > 0 => lhs
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .zip(validity.iter())
+            .map(
+                |((lhs, rhs), is_valid)| {
+                    if is_valid {
+                        op(*lhs, *rhs)
+                    } else {
+                        T::zero()
+                    }
+                },
+            )
+            .collect::<Vec<_>>(),
+        _ => lhs
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .map(|(lhs, rhs)| op(*lhs, *rhs))
+            .collect::<Vec<_>>(),
+    };
+    PrimitiveArray::new(lhs.data_type().clone(), values.into(), validity).boxed()
+}
+
+fn primitive_scalar<T: Native>(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Box<dyn Array> {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    let rhs = match rhs.value() {
+        Some(rhs) => *rhs,
+        None => return PrimitiveArray::<T>::new_null(lhs.data_type().clone(), lhs.len()).boxed(),
+    };
+    let op = function::<T>(op);
+    let values = lhs
+        .values()
+        .iter()
+        .map(|lhs| op(*lhs, rhs))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        lhs.data_type().clone(),
+        values.into(),
+        lhs.validity().cloned(),
+    )
+    .boxed()
+}
+
+macro_rules! arithmetic {
+    ($type:ty) => {
+        &Arithmetic {
+            array: primitive::<$type>,
+            scalar: primitive_scalar::<$type>,
+        }
+    };
+}
+
+/// Returns the [`Arithmetic`] of a primitive type.
+/// # Panics
+/// Panics iff `data_type` is not an integer or `f32` or `f64`, which the callers exclude.
+fn arithmetic(data_type: &DataType) -> &'static Arithmetic {
+    use PrimitiveType::*;
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive_type) => match primitive_type {
+            Int8 => arithmetic!(i8),
+            Int16 => arithmetic!(i16),
+            Int32 => arithmetic!(i32),
+            Int64 => arithmetic!(i64),
+            UInt8 => arithmetic!(u8),
+            UInt16 => arithmetic!(u16),
+            UInt32 => arithmetic!(u32),
+            UInt64 => arithmetic!(u64),
+            Float32 => arithmetic!(f32),
+            Float64 => arithmetic!(f64),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+macro_rules! arithmetic_op {
+    ($op:ident, $name:ident $(, $name_scalar:ident)?) => {
+        pub(super) fn $name(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+            (arithmetic(lhs.data_type()).array)(lhs, rhs, Operator::$op)
+        }
+
+        $(
+        pub(super) fn $name_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+            (arithmetic(lhs.data_type()).scalar)(lhs, rhs, Operator::$op)
+        }
+        )?
+    };
+}
+
+arithmetic_op!(Add, add, add_scalar);
+arithmetic_op!(Sub, sub, sub_scalar);
+arithmetic_op!(Mul, mul, mul_scalar);
+arithmetic_op!(Div, div, div_scalar);
+arithmetic_op!(Rem, rem);
@@ -11,20 +11,31 @@
 //! * overflowing: returns an extra [`Bitmap`] denoting whether the operation overflowed.
 //! * adaptive: for [`Decimal`](crate::datatypes::DataType::Decimal) only,
 //!   adjusts the precision and scale to make the resulting value fit.
+//!
+//! With the feature `compute_dyn_dispatch`, the dynamically typed functions (e.g. [`add`]) of
+//! numeric types do not use the kernels of [`basic`], but a single type-erased function per
+//! primitive type, which reduces compile time and binary size at the expense of performance.
 #[forbid(unsafe_code)]
 pub mod basic;
 #[cfg(feature = "compute_arithmetics_decimal")]
 pub mod decimal;
+#[cfg(feature = "compute_dyn_dispatch")]
+mod dispatch;
 pub mod time;
 
+#[cfg(not(feature = "compute_dyn_dispatch"))]
+use crate::types::NativeType;
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
-    datatypes::{DataType, IntervalUnit, TimeUnit},
+    compute::utils::{f16_to_f32, f32_to_f16},
+    datatypes::{DataType, TimeUnit},
+    error::{Error, Result},
     scalar::{PrimitiveScalar, Scalar},
-    types::NativeType,
+    types::f16,
 };
 
+#[cfg(not(feature = "compute_dyn_dispatch"))]
 fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> PrimitiveArray<T>>(
     lhs: &dyn Array,
     rhs: &dyn Array,
@@ -35,6 +46,50 @@ fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> Pr
     op(lhs, rhs).boxed()
 }
 
+// `f16` is computed in `f32`, whose arithmetic is native to the CPU, and cast back.
+fn f16_binary_dyn<F>(lhs: &dyn Array, rhs: &dyn Array, op: F) -> Box<dyn Array>
+where
+    F: Fn(&PrimitiveArray<f32>, &PrimitiveArray<f32>) -> PrimitiveArray<f32>,
+{
+    let lhs = f16_to_f32(lhs.as_any().downcast_ref().unwrap());
+    let rhs = f16_to_f32(rhs.as_any().downcast_ref().unwrap());
+    f32_to_f16(&op(&lhs, &rhs)).boxed()
+}
+
+// Applies an operation on arrays (or an array and a scalar) of an integer type or of `f32` or
+// `f64`, with the kernels of `basic` called through `$binary`
+#[cfg(not(feature = "compute_dyn_dispatch"))]
+macro_rules! primitive_arith {
+    ($lhs:expr, $rhs:expr, $op:tt, $binary:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        match lhs.data_type() {
+            Int8 => $binary::<i8, _>(lhs, rhs, basic::$op),
+            Int16 => $binary::<i16, _>(lhs, rhs, basic::$op),
+            Int32 => $binary::<i32, _>(lhs, rhs, basic::$op),
+            Int64 | Duration(_) => $binary::<i64, _>(lhs, rhs, basic::$op),
+            UInt8 => $binary::<u8, _>(lhs, rhs, basic::$op),
+            UInt16 => $binary::<u16, _>(lhs, rhs, basic::$op),
+            UInt32 => $binary::<u32, _>(lhs, rhs, basic::$op),
+            UInt64 => $binary::<u64, _>(lhs, rhs, basic::$op),
+            Float32 => $binary::<f32, _>(lhs, rhs, basic::$op),
+            Float64 => $binary::<f64, _>(lhs, rhs, basic::$op),
+            // the callers only apply operations on the types above
+            _ => unreachable!(),
+        }
+    }};
+}
+
+// Applies an operation on arrays (or an array and a scalar) of an integer type or of `f32` or
+// `f64`, with the type-erased arithmetics of `dispatch`
+#[cfg(feature = "compute_dyn_dispatch")]
+macro_rules! primitive_arith {
+    ($lhs:expr, $rhs:expr, $op:tt, $binary:ident) => {
+        dispatch::$op($lhs, $rhs)
+    };
+}
+
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
@@ -43,18 +98,18 @@ macro_rules! arith {
         let rhs = $rhs;
         use DataType::*;
         match (lhs.data_type(), rhs.data_type()) {
-            (Int8, Int8) => binary_dyn::<i8, _>(lhs, rhs, basic::$op),
-            (Int16, Int16) => binary_dyn::<i16, _>(lhs, rhs, basic::$op),
-            (Int32, Int32) => binary_dyn::<i32, _>(lhs, rhs, basic::$op),
-            (Int64, Int64) | (Duration(_), Duration(_)) => {
-                binary_dyn::<i64, _>(lhs, rhs, basic::$op)
-            }
-            (UInt8, UInt8) => binary_dyn::<u8, _>(lhs, rhs, basic::$op),
-            (UInt16, UInt16) => binary_dyn::<u16, _>(lhs, rhs, basic::$op),
-            (UInt32, UInt32) => binary_dyn::<u32, _>(lhs, rhs, basic::$op),
-            (UInt64, UInt64) => binary_dyn::<u64, _>(lhs, rhs, basic::$op),
-            (Float32, Float32) => binary_dyn::<f32, _>(lhs, rhs, basic::$op),
-            (Float64, Float64) => binary_dyn::<f64, _>(lhs, rhs, basic::$op),
+            (Int8, Int8)
+            | (Int16, Int16)
+            | (Int32, Int32)
+            | (Int64, Int64)
+            | (Duration(_), Duration(_))
+            | (UInt8, UInt8)
+            | (UInt16, UInt16)
+            | (UInt32, UInt32)
+            | (UInt64, UInt64)
+            | (Float32, Float32)
+            | (Float64, Float64) => primitive_arith!(lhs, rhs, $op, binary_dyn),
+            (Float16, Float16) => f16_binary_dyn(lhs, rhs, basic::$op),
             $ (
             (Decimal(_, _), Decimal(_, _)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
@@ -80,14 +135,12 @@ macro_rules! arith {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
-                let lhs = lhs.as_any().downcast_ref().unwrap();
-                let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            (Timestamp(_, _), Interval(_)) | (Date32, Interval(_)) | (Date64, Interval(_)) => {
+                time::$op_interval(lhs, rhs).unwrap()
             }
             )?
             $ (
-            (Timestamp(_, None), Timestamp(_, None)) => {
+            (Timestamp(_, lhs_tz), Timestamp(_, rhs_tz)) if lhs_tz.is_some() == rhs_tz.is_some() => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
@@ -102,6 +155,7 @@ macro_rules! arith {
     }};
 }
 
+#[cfg(not(feature = "compute_dyn_dispatch"))]
 fn binary_scalar<T: NativeType, F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveArray<T>>(
     lhs: &PrimitiveArray<T>,
     rhs: &PrimitiveScalar<T>,
@@ -115,6 +169,7 @@ fn binary_scalar<T: NativeType, F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveArray<
     op(lhs, &rhs)
 }
 
+#[cfg(not(feature = "compute_dyn_dispatch"))]
 fn binary_scalar_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveArray<T>>(
     lhs: &dyn Array,
     rhs: &dyn Scalar,
@@ -125,6 +180,18 @@ fn binary_scalar_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &T) -> PrimitiveAr
     binary_scalar(lhs, rhs, op).boxed()
 }
 
+fn f16_binary_scalar_dyn<F>(lhs: &dyn Array, rhs: &dyn Scalar, op: F) -> Box<dyn Array>
+where
+    F: Fn(&PrimitiveArray<f32>, &f32) -> PrimitiveArray<f32>,
+{
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<f16>>().unwrap();
+    match rhs.value() {
+        Some(rhs) => f32_to_f16(&op(&f16_to_f32(lhs), &rhs.to_f32())).boxed(),
+        None => PrimitiveArray::<f16>::new_null(lhs.data_type().clone(), lhs.len()).boxed(),
+    }
+}
+
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith_scalar {
@@ -133,18 +200,18 @@ macro_rules! arith_scalar {
         let rhs = $rhs;
         use DataType::*;
         match (lhs.data_type(), rhs.data_type()) {
-            (Int8, Int8) => binary_scalar_dyn::<i8, _>(lhs, rhs, basic::$op),
-            (Int16, Int16) => binary_scalar_dyn::<i16, _>(lhs, rhs, basic::$op),
-            (Int32, Int32) => binary_scalar_dyn::<i32, _>(lhs, rhs, basic::$op),
-            (Int64, Int64) | (Duration(_), Duration(_)) => {
-                binary_scalar_dyn::<i64, _>(lhs, rhs, basic::$op)
-            }
-            (UInt8, UInt8) => binary_scalar_dyn::<u8, _>(lhs, rhs, basic::$op),
-            (UInt16, UInt16) => binary_scalar_dyn::<u16, _>(lhs, rhs, basic::$op),
-            (UInt32, UInt32) => binary_scalar_dyn::<u32, _>(lhs, rhs, basic::$op),
-            (UInt64, UInt64) => binary_scalar_dyn::<u64, _>(lhs, rhs, basic::$op),
-            (Float32, Float32) => binary_scalar_dyn::<f32, _>(lhs, rhs, basic::$op),
-            (Float64, Float64) => binary_scalar_dyn::<f64, _>(lhs, rhs, basic::$op),
+            (Int8, Int8)
+            | (Int16, Int16)
+            | (Int32, Int32)
+            | (Int64, Int64)
+            | (Duration(_), Duration(_))
+            | (UInt8, UInt8)
+            | (UInt16, UInt16)
+            | (UInt32, UInt32)
+            | (UInt64, UInt64)
+            | (Float32, Float32)
+            | (Float64, Float64) => primitive_arith!(lhs, rhs, $op, binary_scalar_dyn),
+            (Float16, Float16) => f16_binary_scalar_dyn(lhs, rhs, basic::$op),
             $ (
             (Decimal(_, _), Decimal(_, _)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
@@ -170,14 +237,12 @@ macro_rules! arith_scalar {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
-                let lhs = lhs.as_any().downcast_ref().unwrap();
-                let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_interval(lhs, rhs).unwrap().boxed()
+            (Timestamp(_, _), Interval(_)) | (Date32, Interval(_)) | (Date64, Interval(_)) => {
+                time::$op_interval(lhs, rhs).unwrap()
             }
             )?
             $ (
-            (Timestamp(_, None), Timestamp(_, None)) => {
+            (Timestamp(_, lhs_tz), Timestamp(_, rhs_tz)) if lhs_tz.is_some() == rhs_tz.is_some() => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_timestamp(lhs, rhs).unwrap().boxed()
@@ -204,7 +269,7 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         rhs,
         add,
         duration = add_duration,
-        interval = add_interval
+        interval = add_interval_dyn
     )
 }
 
@@ -220,7 +285,7 @@ pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         rhs,
         add_scalar,
         duration = add_duration_scalar,
-        interval = add_interval_scalar
+        interval = add_interval_scalar_dyn
     )
 }
 
@@ -239,6 +304,7 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
             | (Date32, Duration(_))
@@ -248,7 +314,9 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
-            | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Timestamp(_, _), Interval(_))
+            | (Date32, Interval(_))
+            | (Date64, Interval(_))
     )
 }
 
@@ -265,6 +333,7 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         sub,
         decimal = sub,
         duration = subtract_duration,
+        interval = sub_interval_dyn,
         timestamp = subtract_timestamps
     )
 }
@@ -281,6 +350,7 @@ pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         rhs,
         sub_scalar,
         duration = sub_duration_scalar,
+        interval = sub_interval_scalar_dyn,
         timestamp = sub_timestamps_scalar
     )
 }
@@ -300,6 +370,7 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
             | (Date32, Duration(_))
@@ -309,7 +380,11 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
+            | (Timestamp(_, _), Interval(_))
+            | (Date32, Interval(_))
+            | (Date64, Interval(_))
             | (Timestamp(_, None), Timestamp(_, None))
+            | (Timestamp(_, Some(_)), Timestamp(_, Some(_)))
     )
 }
 
@@ -345,6 +420,7 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Decimal(_, _), Decimal(_, _))
     )
 }
@@ -395,15 +471,233 @@ pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
     )
 }
 
+/// An arithmetic operator of [`arithmetic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// Addition, see [`add`]
+    Add,
+    /// Subtraction, see [`sub`]
+    Sub,
+    /// Multiplication, see [`mul`]
+    Mul,
+    /// Division, see [`div`]
+    Div,
+    /// Remainder, see [`rem`]
+    Rem,
+}
+
+/// The signedness and the number of bits of an integer type.
+fn integer(data_type: &DataType) -> Option<(bool, usize)> {
+    use DataType::*;
+    Some(match data_type {
+        Int8 => (true, 8),
+        Int16 => (true, 16),
+        Int32 => (true, 32),
+        Int64 => (true, 64),
+        UInt8 => (false, 8),
+        UInt16 => (false, 16),
+        UInt32 => (false, 32),
+        UInt64 => (false, 64),
+        _ => return None,
+    })
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    integer(data_type).is_some()
+        || matches!(
+            data_type,
+            DataType::Float16 | DataType::Float32 | DataType::Float64 | DataType::Decimal(_, _)
+        )
+}
+
+fn integer_type(signed: bool, bits: usize) -> DataType {
+    use DataType::*;
+    match (signed, bits) {
+        (true, 8) => Int8,
+        (true, 16) => Int16,
+        (true, 32) => Int32,
+        (true, 64) => Int64,
+        (false, 8) => UInt8,
+        (false, 16) => UInt16,
+        (false, 32) => UInt32,
+        _ => UInt64,
+    }
+}
+
+/// The [`DataType::Decimal`] that holds every value of an integer type.
+fn integer_decimal((signed, bits): (bool, usize)) -> DataType {
+    let precision = match (signed, bits) {
+        (_, 8) => 3,
+        (_, 16) => 5,
+        (_, 32) => 10,
+        (true, _) => 19,
+        (false, _) => 20,
+    };
+    DataType::Decimal(precision, 0)
+}
+
+/// Returns the types that `lhs` and `rhs` are cast to by [`arithmetic`], following Arrow and
+/// SQL coercion rules:
+/// * integers of the same signedness are widened to the widest of them;
+/// * a signed and an unsigned integer are widened to a signed integer that holds both, or to
+///   `Decimal(20, 0)` for `UInt64` and a signed integer;
+/// * an integer and a float are coerced to the float, or to `Float64` when `Float32` does not
+///   hold every value of the integer, where `Float16` is coerced as `Float32`;
+/// * decimals of different precision and scale are kept (see [`decimal::sql_result_type`]),
+///   and an integer and a decimal are coerced to decimals, the integer with scale 0;
+/// * a decimal and a float are coerced to `Float64`;
+/// * other types, e.g. temporal types, are kept, since the kernels of [`add`] and [`sub`]
+///   already accept e.g. a [`DataType::Timestamp`] and a [`DataType::Duration`].
+/// # Errors
+/// This function errors iff `op` is not supported for `lhs` and `rhs` after coercion.
+pub fn coerce_types(lhs: &DataType, op: Operator, rhs: &DataType) -> Result<(DataType, DataType)> {
+    use DataType::*;
+    let (lhs, rhs) = match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => (lhs.clone(), rhs.clone()),
+        (Float64, other) | (other, Float64) if is_numeric(other) => (Float64, Float64),
+        (Float32 | Float16, other) | (other, Float32 | Float16) if is_numeric(other) => {
+            let float = match integer(other) {
+                Some((_, bits)) if bits <= 16 => Float32,
+                None if matches!(other, Float32 | Float16) => Float32,
+                _ => Float64,
+            };
+            (float.clone(), float)
+        }
+        (Decimal(_, _), Decimal(_, _)) => (lhs.clone(), rhs.clone()),
+        (Decimal(_, _), other) if integer(other).is_some() => {
+            (lhs.clone(), integer_decimal(integer(other).unwrap()))
+        }
+        (other, Decimal(_, _)) if integer(other).is_some() => {
+            (integer_decimal(integer(other).unwrap()), rhs.clone())
+        }
+        _ => match (integer(lhs), integer(rhs)) {
+            (Some((lhs_signed, lhs_bits)), Some((rhs_signed, rhs_bits))) => {
+                let data_type = if lhs_signed == rhs_signed {
+                    integer_type(lhs_signed, lhs_bits.max(rhs_bits))
+                } else {
+                    let (signed_bits, unsigned_bits) = if lhs_signed {
+                        (lhs_bits, rhs_bits)
+                    } else {
+                        (rhs_bits, lhs_bits)
+                    };
+                    if signed_bits > unsigned_bits {
+                        integer_type(true, signed_bits)
+                    } else if unsigned_bits < 64 {
+                        integer_type(true, unsigned_bits * 2)
+                    } else {
+                        Decimal(20, 0)
+                    }
+                };
+                (data_type.clone(), data_type)
+            }
+            _ => (lhs.clone(), rhs.clone()),
+        },
+    };
+
+    let supported = match (&lhs, op, &rhs) {
+        (Decimal(_, _), Operator::Rem, Decimal(_, _)) => false,
+        (Decimal(_, _), _, Decimal(_, _)) => true,
+        (_, Operator::Add, _) => can_add(&lhs, &rhs),
+        (_, Operator::Sub, _) => can_sub(&lhs, &rhs),
+        (_, Operator::Mul, _) => can_mul(&lhs, &rhs),
+        (_, Operator::Div, _) => can_div(&lhs, &rhs),
+        (_, Operator::Rem, _) => can_rem(&lhs, &rhs),
+    };
+    if supported {
+        Ok((lhs, rhs))
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "Arithmetic {op:?} is not supported between {lhs:?} and {rhs:?}"
+        )))
+    }
+}
+
+/// Applies the arithmetic operator `op` to two [`Array`]s of possibly different types.
+///
+/// The arrays are first cast to common types (see [`coerce_types`]), e.g. `Int32 + Int64` is
+/// `Int64` and `Int32 + Float64` is `Float64`, and then dispatched to the typed kernels of
+/// [`add`], [`sub`], [`mul`], [`div`] and [`rem`]. Decimals are computed with
+/// [`decimal::sql_arithmetic`], whose result's precision and scale follow SQL rules. The sum
+/// of a [`DataType::Duration`] and a temporal type is commutative.
+/// # Errors
+/// This function errors iff the types are incompatible for `op`, the arrays have different
+/// lengths, or a decimal result does not fit its precision.
+/// # Panics
+/// This function panics under the same conditions as the typed kernels, e.g. when an integer
+/// operation overflows.
+/// # Examples
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array, Int64Array};
+/// use arrow2::compute::arithmetics::{arithmetic, Operator};
+///
+/// let a = Int32Array::from([Some(1), None, Some(3)]);
+/// let b = Int64Array::from_slice([10, 20, 30]);
+/// let result = arithmetic(&a, Operator::Add, &b).unwrap();
+/// assert_eq!(Int64Array::from([Some(11), None, Some(33)]), result.as_ref());
+///
+/// let c = Float64Array::from_slice([0.5, 0.5, 0.5]);
+/// let result = arithmetic(&a, Operator::Mul, &c).unwrap();
+/// assert_eq!(Float64Array::from([Some(0.5), None, Some(1.5)]), result.as_ref());
+/// ```
+#[cfg(feature = "compute_cast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
+pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    use crate::compute::{
+        cast::{cast, CastOptions},
+        utils::check_same_len,
+    };
+
+    check_same_len(lhs, rhs)?;
+    if op == Operator::Add && matches!(lhs.data_type(), DataType::Duration(_)) {
+        if let DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _) = rhs.data_type()
+        {
+            return arithmetic(rhs, op, lhs);
+        }
+    }
+
+    let (lhs_type, rhs_type) = coerce_types(lhs.data_type(), op, rhs.data_type())?;
+    let lhs = cast(lhs, &lhs_type, CastOptions::default())?;
+    let rhs = cast(rhs, &rhs_type, CastOptions::default())?;
+    let (lhs, rhs) = (lhs.as_ref(), rhs.as_ref());
+
+    if let (DataType::Decimal(_, _), DataType::Decimal(_, _)) = (&lhs_type, &rhs_type) {
+        let op = match op {
+            Operator::Add => decimal::DecimalOperator::Add,
+            Operator::Sub => decimal::DecimalOperator::Sub,
+            Operator::Mul => decimal::DecimalOperator::Mul,
+            Operator::Div => decimal::DecimalOperator::Div,
+            Operator::Rem => unreachable!(),
+        };
+        return decimal::sql_arithmetic(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+            op,
+            Default::default(),
+        );
+    }
+    Ok(match op {
+        Operator::Add => add(lhs, rhs),
+        Operator::Sub => sub(lhs, rhs),
+        Operator::Mul => mul(lhs, rhs),
+        Operator::Div => div(lhs, rhs),
+        Operator::Rem => rem(lhs, rhs),
+    })
+}
+
 macro_rules! with_match_negatable {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns, i256};
+    use crate::types::{days_ms, months_days_ns, f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -413,7 +707,8 @@ macro_rules! with_match_negatable {(
         Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
-        UInt8 | UInt16 | UInt32 | UInt64 | Float16 => todo!(),
+        UInt8 | UInt16 | UInt32 | UInt64 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -463,6 +758,7 @@ pub fn can_neg(data_type: &DataType) -> bool {
             | Primitive(Int64)
             | Primitive(Float64)
             | Primitive(Float32)
+            | Primitive(Float16)
             | Primitive(DaysMs)
             | Primitive(MonthDayNano)
     )
@@ -570,6 +866,24 @@ pub trait ArrayCheckedDiv<Rhs>: Sized {
     fn checked_div(&self, rhs: &Rhs) -> Self;
 }
 
+/// Defines wrapping division operation for primitive arrays
+pub trait ArrayWrappingDiv<Rhs>: Sized {
+    /// wrapping division
+    fn wrapping_div(&self, rhs: &Rhs) -> Self;
+}
+
+/// Defines saturating division operation for primitive arrays
+pub trait ArraySaturatingDiv<Rhs>: Sized {
+    /// saturating division
+    fn saturating_div(&self, rhs: &Rhs) -> Self;
+}
+
+/// Defines Overflowing division operation for primitive arrays
+pub trait ArrayOverflowingDiv<Rhs>: Sized {
+    /// overflowing division
+    fn overflowing_div(&self, rhs: &Rhs) -> (Self, Bitmap);
+}
+
 /// Defines basic reminder operation for primitive arrays
 pub trait ArrayRem<Rhs>: Sized {
     /// remainder
@@ -581,3 +895,15 @@ pub trait ArrayCheckedRem<Rhs>: Sized {
     /// checked remainder
     fn checked_rem(&self, rhs: &Rhs) -> Self;
 }
+
+/// Defines wrapping reminder operation for primitive arrays
+pub trait ArrayWrappingRem<Rhs>: Sized {
+    /// wrapping remainder
+    fn wrapping_rem(&self, rhs: &Rhs) -> Self;
+}
+
+/// Defines Overflowing reminder operation for primitive arrays
+pub trait ArrayOverflowingRem<Rhs>: Sized {
+    /// overflowing remainder
+    fn overflowing_rem(&self, rhs: &Rhs) -> (Self, Bitmap);
+}
@@ -8,19 +8,22 @@
 //! representing the difference between two Unix timestamp is acceptable, but
 //! would yield a value that is possibly a few seconds off from the true
 //! elapsed time.
+//!
+//! Intervals, on the other hand, are added following calendar semantics: their months and days
+//! are added to the (wall clock) date, see [`temporal_conversions::add_interval`].
 
 use std::ops::{Add, Sub};
 
 use num_traits::AsPrimitive;
 
 use crate::{
-    array::PrimitiveArray,
+    array::{Array, PrimitiveArray},
     compute::arity::{binary, unary},
-    datatypes::{DataType, TimeUnit},
+    datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{Error, Result},
     scalar::{PrimitiveScalar, Scalar},
     temporal_conversions,
-    types::{months_days_ns, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 /// Creates the scale required to add or subtract a Duration to a time array
@@ -226,6 +229,9 @@ where
 /// Duration. The timeunit enum is used to scale correctly both arrays;
 /// subtracting seconds with seconds, or milliseconds with milliseconds.
 ///
+/// Either both or none of the timestamps must have a timezone. Timestamps with a timezone
+/// are instants, and so their difference is the elapsed time between them.
+///
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::time::subtract_timestamps;
@@ -261,9 +267,11 @@ pub fn subtract_timestamps(
     // Both timestamps have a Timeunit enum in its data type.
     // This enum is used to adjust the scale between the timestamps.
     match (lhs.data_type(), rhs.data_type()) {
-        // Naive timestamp comparison. It doesn't take into account timezones
-        // from the Timestamp timeunit.
-        (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) => {
+        // Timestamps with a timezone represent instants since the epoch in UTC, and
+        // so their difference does not depend on their timezones.
+        (DataType::Timestamp(timeunit_a, tz_a), DataType::Timestamp(timeunit_b, tz_b))
+            if tz_a.is_some() == tz_b.is_some() =>
+        {
             // Closure for the binary operation. The closure contains the scale
             // required to calculate the difference between the timestamps.
             let scale = temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b);
@@ -278,24 +286,27 @@ pub fn subtract_timestamps(
 }
 
 /// Calculates the difference between two timestamps as [`DataType::Duration`] with the same time scale.
+/// Either both or none of the timestamps must have a timezone.
 pub fn sub_timestamps_scalar(
     lhs: &PrimitiveArray<i64>,
     rhs: &PrimitiveScalar<i64>,
 ) -> Result<PrimitiveArray<i64>> {
-    let (scale, timeunit_a) =
-        if let (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) =
-            (lhs.data_type(), rhs.data_type())
+    let (scale, timeunit_a) = match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Timestamp(timeunit_a, tz_a), DataType::Timestamp(timeunit_b, tz_b))
+            if tz_a.is_some() == tz_b.is_some() =>
         {
             (
                 temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b),
                 timeunit_a,
             )
-        } else {
+        }
+        _ => {
             return Err(Error::InvalidArgumentError(
-                "sub_timestamps_scalar requires both arguments to be timestamps without timezone"
+                "sub_timestamps_scalar requires both arguments to be timestamps with or without timezone"
                     .to_string(),
-            ));
-        };
+            ))
+        }
+    };
 
     let rhs = if let Some(value) = *rhs.value() {
         value
@@ -432,3 +443,173 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+/// Converts an interval array of any [`IntervalUnit`] to [`IntervalUnit::MonthDayNano`].
+/// # Errors
+/// This function errors iff `interval` is not of type [`DataType::Interval`].
+pub fn interval_to_months_days_ns(interval: &dyn Array) -> Result<PrimitiveArray<months_days_ns>> {
+    let to_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    match interval.data_type().to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            let interval = interval.as_any().downcast_ref().unwrap();
+            Ok(unary(
+                interval,
+                |months: i32| months_days_ns::new(months, 0, 0),
+                to_type,
+            ))
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let interval = interval.as_any().downcast_ref().unwrap();
+            Ok(unary(
+                interval,
+                |x: days_ms| months_days_ns::new(0, x.days(), x.milliseconds() as i64 * 1_000_000),
+                to_type,
+            ))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(interval
+            .as_any()
+            .downcast_ref::<PrimitiveArray<months_days_ns>>()
+            .unwrap()
+            .clone()),
+        other => Err(Error::InvalidArgumentError(format!(
+            "Expected an interval, got {other:?}"
+        ))),
+    }
+}
+
+/// Adds an interval to a [`DataType::Date32`] following calendar semantics, i.e. the day of the
+/// month is clamped to the last day of the resulting month. The nanoseconds of the interval
+/// are added before truncating the result to a date.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::add_interval_date32;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::months_days_ns;
+///
+/// // 2021-01-31
+/// let date = PrimitiveArray::from([Some(18658i32), None]).to(DataType::Date32);
+/// let interval = PrimitiveArray::from_slice([months_days_ns::new(1, 0, 0); 2]);
+/// let result = add_interval_date32(&date, &interval);
+/// // 2021-02-28
+/// let expected = PrimitiveArray::from([Some(18686i32), None]).to(DataType::Date32);
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_interval_date32(
+    date: &PrimitiveArray<i32>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<i32> {
+    let op = |days: i32, interval| {
+        let seconds = days as i64 * temporal_conversions::SECONDS_IN_DAY;
+        let seconds = temporal_conversions::add_naive_interval(seconds, TimeUnit::Second, interval);
+        seconds.div_euclid(temporal_conversions::SECONDS_IN_DAY) as i32
+    };
+    binary(date, interval, date.data_type().clone(), op)
+}
+
+/// Adds an interval to a [`DataType::Date64`] following calendar semantics, i.e. the day of the
+/// month is clamped to the last day of the resulting month.
+pub fn add_interval_date64(
+    date: &PrimitiveArray<i64>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<i64> {
+    let op = |milliseconds, interval| {
+        temporal_conversions::add_naive_interval(milliseconds, TimeUnit::Millisecond, interval)
+    };
+    binary(date, interval, date.data_type().clone(), op)
+}
+
+/// Adds an interval of any [`IntervalUnit`] to a [`DataType::Timestamp`], [`DataType::Date32`]
+/// or [`DataType::Date64`], see [`add_interval`], [`add_interval_date32`] and
+/// [`add_interval_date64`].
+/// # Errors
+/// This function errors iff the types are not supported or the timezone cannot be parsed.
+pub fn add_interval_dyn(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    let interval = interval_to_months_days_ns(interval)?;
+    match time.data_type().to_logical_type() {
+        DataType::Timestamp(_, _) => {
+            add_interval(time.as_any().downcast_ref().unwrap(), &interval).map(|x| x.boxed())
+        }
+        DataType::Date32 => {
+            Ok(add_interval_date32(time.as_any().downcast_ref().unwrap(), &interval).boxed())
+        }
+        DataType::Date64 => {
+            Ok(add_interval_date64(time.as_any().downcast_ref().unwrap(), &interval).boxed())
+        }
+        other => Err(Error::InvalidArgumentError(format!(
+            "Adding an interval is not supported for {other:?}"
+        ))),
+    }
+}
+
+/// Subtracts an interval of any [`IntervalUnit`] from a [`DataType::Timestamp`],
+/// [`DataType::Date32`] or [`DataType::Date64`], i.e. adds its negation, see [`add_interval_dyn`].
+/// # Errors
+/// This function errors under the same conditions as [`add_interval_dyn`], or iff the negation
+/// of an interval overflows, i.e. one of its components is the minimum of its type.
+pub fn sub_interval_dyn(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    let interval = interval_to_months_days_ns(interval)?;
+    let negate = |x: months_days_ns| {
+        Some(months_days_ns::new(
+            x.months().checked_neg()?,
+            x.days().checked_neg()?,
+            x.ns().checked_neg()?,
+        ))
+    };
+    let interval = interval
+        .iter()
+        .map(|x| x.map(|x| negate(*x).ok_or(Error::Overflow)).transpose())
+        .collect::<Result<PrimitiveArray<months_days_ns>>>()?;
+    add_interval_dyn(time, &interval)
+}
+
+/// Adds an interval scalar of any [`IntervalUnit`] to a [`DataType::Timestamp`],
+/// [`DataType::Date32`] or [`DataType::Date64`], see [`add_interval_dyn`].
+/// # Errors
+/// This function errors under the same conditions as [`add_interval_dyn`].
+pub fn add_interval_scalar_dyn(time: &dyn Array, interval: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if let (DataType::Timestamp(_, _), DataType::Interval(IntervalUnit::MonthDayNano)) =
+        (time.data_type(), interval.data_type())
+    {
+        let time = time.as_any().downcast_ref().unwrap();
+        let interval = interval.as_any().downcast_ref().unwrap();
+        return add_interval_scalar(time, interval).map(|x| x.boxed());
+    }
+    add_interval_dyn(time, broadcast_interval(interval, time.len())?.as_ref())
+}
+
+/// Subtracts an interval scalar of any [`IntervalUnit`] from a [`DataType::Timestamp`],
+/// [`DataType::Date32`] or [`DataType::Date64`], see [`sub_interval_dyn`].
+/// # Errors
+/// This function errors under the same conditions as [`add_interval_dyn`].
+pub fn sub_interval_scalar_dyn(time: &dyn Array, interval: &dyn Scalar) -> Result<Box<dyn Array>> {
+    sub_interval_dyn(time, broadcast_interval(interval, time.len())?.as_ref())
+}
+
+/// Returns an interval array of length `length` whose values are `interval`.
+fn broadcast_interval(interval: &dyn Scalar, length: usize) -> Result<Box<dyn Array>> {
+    fn broadcast<T: NativeType>(interval: &dyn Scalar, length: usize) -> Box<dyn Array> {
+        let interval = interval
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<T>>()
+            .unwrap();
+        let data_type = interval.data_type().clone();
+        match interval.value() {
+            Some(value) => PrimitiveArray::new(data_type, vec![*value; length].into(), None),
+            None => PrimitiveArray::<T>::new_null(data_type, length),
+        }
+        .boxed()
+    }
+
+    match interval.data_type().to_logical_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => Ok(broadcast::<i32>(interval, length)),
+        DataType::Interval(IntervalUnit::DayTime) => Ok(broadcast::<days_ms>(interval, length)),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            Ok(broadcast::<months_days_ns>(interval, length))
+        }
+        other => Err(Error::InvalidArgumentError(format!(
+            "Expected an interval, got {other:?}"
+        ))),
+    }
+}
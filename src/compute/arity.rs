@@ -122,10 +122,11 @@ where
     // creation of the values with the iterator. If an error was found during
     // the iteration, then the validity is changed to None to mark the value
     // as Null
-    let bitmap: Bitmap = mut_bitmap.into();
-    let validity = combine_validities(array.validity(), Some(&bitmap));
+    if let Some(validity) = array.validity() {
+        mut_bitmap.and_assign(validity);
+    }
 
-    PrimitiveArray::<O>::new(data_type, values, validity)
+    PrimitiveArray::<O>::new(data_type, values, Some(mut_bitmap.into()))
 }
 
 /// Applies a binary operations to two primitive arrays. This is the fastest
@@ -268,14 +269,16 @@ where
         .collect::<Vec<_>>()
         .into();
 
-    let bitmap: Bitmap = mut_bitmap.into();
-    let validity = combine_validities(lhs.validity(), rhs.validity());
-
     // The validity has to be checked against the bitmap created during the
     // creation of the values with the iterator. If an error was found during
     // the iteration, then the validity is changed to None to mark the value
     // as Null
-    let validity = combine_validities(validity.as_ref(), Some(&bitmap));
+    if let Some(validity) = lhs.validity() {
+        mut_bitmap.and_assign(validity);
+    }
+    if let Some(validity) = rhs.validity() {
+        mut_bitmap.and_assign(validity);
+    }
 
-    PrimitiveArray::<T>::new(data_type, values, validity)
+    PrimitiveArray::<T>::new(data_type, values, Some(mut_bitmap.into()))
 }
@@ -1,6 +1,9 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`], [`not`], [`shift_left`] and
+//! [`shift_right`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use num_traits::{AsPrimitive, WrappingShl, WrappingShr};
+
 use crate::array::PrimitiveArray;
 use crate::compute::arity::{binary, unary};
 use crate::types::NativeType;
@@ -73,3 +76,49 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Shifts the values of `lhs` left by the values of `rhs`. Like [`i32::wrapping_shl`], the shift
+/// is taken modulo the number of bits of `T`, and negative shifts wrap around
+/// (e.g. `-1i32` shifts by 31).
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shl(b.as_())
+    })
+}
+
+/// Shifts the values of `lhs` right by the values of `rhs`. The shift is arithmetic
+/// (sign-extending) for signed integers and logical for unsigned integers, and is taken modulo
+/// the number of bits of `T`, see [`shift_left`].
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shr(b.as_())
+    })
+}
+
+/// Shifts the values of a [`PrimitiveArray`] left by a scalar, see [`shift_left`].
+pub fn shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shl(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the values of a [`PrimitiveArray`] right by a scalar, see [`shift_right`].
+pub fn shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shr(rhs), lhs.data_type().clone())
+}
@@ -6,6 +6,12 @@ use crate::scalar::BooleanScalar;
 
 use super::utils::combine_validities;
 
+/// The operators `and` and `or` of SQL's three-valued logic, where e.g. `true OR null` is `true`
+/// and `false AND null` is `false`, implemented in [`boolean_kleene`](super::boolean_kleene).
+#[cfg(feature = "compute_boolean_kleene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_boolean_kleene")))]
+pub use super::boolean_kleene::{and as and_kleene, or as or_kleene};
+
 fn assert_lengths(lhs: &BooleanArray, rhs: &BooleanArray) {
     assert_eq!(
         lhs.len(),
@@ -35,6 +41,7 @@ where
 }
 
 /// Performs `&&` operation on two [`BooleanArray`], combining the validities.
+/// See `and_kleene` for the three-valued logic of SQL.
 /// # Panics
 /// This function panics iff the arrays have different lengths.
 /// # Examples
@@ -77,6 +84,7 @@ pub fn and(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
 }
 
 /// Performs `||` operation on two [`BooleanArray`], combining the validities.
+/// See `or_kleene` for the three-valued logic of SQL.
 /// # Panics
 /// This function panics iff the arrays have different lengths.
 /// # Examples
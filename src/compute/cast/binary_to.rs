@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::offset::{Offset, Offsets};
 use crate::{array::*, datatypes::DataType, types::NativeType};
 
@@ -83,6 +83,32 @@ where
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
 }
 
+/// Casts a [`BinaryArray`] to a [`PrimitiveArray`].
+/// # Errors
+/// This function errors iff any non-null value cannot be parsed into `T`
+pub fn try_binary_to_primitive<O: Offset, T>(
+    from: &BinaryArray<O>,
+    to: &DataType,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + lexical_core::FromLexical,
+{
+    let iter = from.iter().map(|x| {
+        x.map(|x| {
+            lexical_core::parse::<T>(x).map_err(|_| {
+                Error::InvalidArgumentError(format!(
+                    "{:?} cannot be parsed into {to:?}",
+                    String::from_utf8_lossy(x)
+                ))
+            })
+        })
+        .transpose()
+    });
+
+    let array: PrimitiveArray<T> = MutablePrimitiveArray::try_from_trusted_len_iter(iter)?.into();
+    Ok(array.to(to.clone()))
+}
+
 pub(super) fn binary_to_primitive_dyn<O: Offset, T>(
     from: &dyn Array,
     to: &DataType,
@@ -94,6 +120,8 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     if options.partial {
         Ok(Box::new(partial_binary_to_primitive::<O, T>(from, to)))
+    } else if options.strict {
+        try_binary_to_primitive::<O, T>(from, to).map(|x| x.boxed())
     } else {
         Ok(Box::new(binary_to_primitive::<O, T>(from, to)))
     }
@@ -1,34 +1,113 @@
-use num_traits::{AsPrimitive, Float, NumCast};
+use num_traits::{AsPrimitive, Bounded, Float, NumCast};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 use crate::{array::*, datatypes::DataType};
 
+use super::{CastOptions, RoundingMode};
+
+/// The result of casting a single value
+pub(super) enum Casted<T> {
+    /// The value was casted
+    Value(T),
+    /// The value overflowed the target type; contains the saturated value
+    Overflow(T),
+    /// The value has no representation in the target type (e.g. `NaN`)
+    Invalid,
+}
+
+/// Returns the [`PrimitiveArray`] of `to_type` from `values`, where overflowing values are
+/// saturated, error or are `None`, depending on `options`.
+pub(super) fn collect_casted<T, I>(
+    values: I,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    I: TrustedLen<Item = Option<Casted<T>>>,
+{
+    let values = values.map(|x| match x {
+        None => Ok(None),
+        Some(Casted::Value(x)) => Ok(Some(x)),
+        Some(Casted::Overflow(x)) if options.saturate => Ok(Some(x)),
+        Some(_) if options.strict => Err(Error::InvalidArgumentError(format!(
+            "a value cannot be represented in {to_type:?}"
+        ))),
+        Some(_) => Ok(None),
+    });
+    let array: PrimitiveArray<T> = MutablePrimitiveArray::try_from_trusted_len_iter(values)?.into();
+    Ok(array.to(to_type.clone()))
+}
+
+/// Returns `x / divisor` rounded according to `rounding`. `divisor` must be positive.
 #[inline]
-fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
-    from: &PrimitiveArray<i128>,
-    op: F,
-    to_precision: usize,
-    to_scale: usize,
-) -> PrimitiveArray<i128> {
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+pub(crate) fn div_round(x: i128, divisor: i128, rounding: RoundingMode) -> i128 {
+    let quotient = x / divisor;
+    let remainder = x % divisor;
+    if remainder == 0 {
+        return quotient;
+    }
+    // the remainder has the sign of `x`, so rounding away from zero adds its sign
+    let away = quotient + x.signum();
+    let twice = remainder.unsigned_abs() * 2;
+    match rounding {
+        RoundingMode::TowardZero => quotient,
+        RoundingMode::Floor => quotient.min(away),
+        RoundingMode::Ceil => quotient.max(away),
+        RoundingMode::HalfAwayFromZero if twice >= divisor as u128 => away,
+        RoundingMode::HalfEven if twice > divisor as u128 => away,
+        RoundingMode::HalfEven if twice == divisor as u128 && quotient % 2 != 0 => away,
+        _ => quotient,
+    }
+}
 
-    let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            op(*x).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
+/// Returns `x` rounded to an integer according to `rounding`.
+#[inline]
+pub(crate) fn round_float<T: Float>(x: T, rounding: RoundingMode) -> T {
+    match rounding {
+        RoundingMode::TowardZero => x.trunc(),
+        RoundingMode::Floor => x.floor(),
+        RoundingMode::Ceil => x.ceil(),
+        RoundingMode::HalfAwayFromZero => x.round(),
+        RoundingMode::HalfEven => {
+            let rounded = x.round();
+            if (x - x.trunc()).abs() == T::from(0.5).unwrap() {
+                // a tie: `rounded` is away from zero, so its neighbour toward zero is even
+                let half = rounded / T::from(2.0).unwrap();
+                if half == half.trunc() {
+                    rounded
                 } else {
-                    Some(x)
+                    rounded - x.signum()
                 }
-            })
-        })
-    });
-    PrimitiveArray::<i128>::from_trusted_len_iter(values)
-        .to(DataType::Decimal(to_precision, to_scale))
+            } else {
+                rounded
+            }
+        }
+    }
+}
+
+/// Returns the bounds of the values of a decimal with precision `precision`, i.e.
+/// `±(10^precision - 1)`
+#[inline]
+pub(super) fn decimal_bounds(precision: usize) -> (i128, i128) {
+    let max_for_precision = 10_i128
+        .checked_pow(precision as u32)
+        .map_or(i128::MAX, |x| x - 1);
+    (-max_for_precision, max_for_precision)
+}
+
+/// Returns `x` as a [`Casted`] value of a decimal whose values are within `bounds`
+#[inline]
+pub(super) fn to_decimal_bounds(x: i128, (min, max): (i128, i128)) -> Casted<i128> {
+    if x > max {
+        Casted::Overflow(max)
+    } else if x < min {
+        Casted::Overflow(min)
+    } else {
+        Casted::Value(x)
+    }
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
@@ -37,36 +116,52 @@ pub fn decimal_to_decimal(
     to_precision: usize,
     to_scale: usize,
 ) -> PrimitiveArray<i128> {
+    // the default options never error
+    decimal_to_decimal_with_options(from, to_precision, to_scale, CastOptions::default()).unwrap()
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values, rounded according to
+/// `options.rounding` when the scale decreases.
+/// # Errors
+/// This function errors iff `options.strict` and a value overflows `to_precision`
+pub fn decimal_to_decimal_with_options(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>> {
     let (from_precision, from_scale) =
         if let DataType::Decimal(p, s) = from.data_type().to_logical_type() {
             (*p, *s)
         } else {
             panic!("internal error: i128 is always a decimal")
         };
+    let to_type = DataType::Decimal(to_precision, to_scale);
 
     if to_scale == from_scale && to_precision >= from_precision {
         // fast path
-        return from.clone().to(DataType::Decimal(to_precision, to_scale));
+        return Ok(from.clone().to(to_type));
     }
     // todo: other fast paths include increasing scale and precision by so that
     // a number will never overflow (validity is preserved)
 
+    let bounds = decimal_bounds(to_precision);
     if from_scale > to_scale {
         let factor = 10_i128.pow((from_scale - to_scale) as u32);
-        decimal_to_decimal_impl(
-            from,
-            |x: i128| x.checked_div(factor),
-            to_precision,
-            to_scale,
-        )
+        let values = from
+            .iter()
+            .map(|x| x.map(|x| to_decimal_bounds(div_round(*x, factor, options.rounding), bounds)));
+        collect_casted(values, &to_type, options)
     } else {
         let factor = 10_i128.pow((to_scale - from_scale) as u32);
-        decimal_to_decimal_impl(
-            from,
-            |x: i128| x.checked_mul(factor),
-            to_precision,
-            to_scale,
-        )
+        let values = from.iter().map(|x| {
+            x.map(|x| match x.checked_mul(factor) {
+                Some(x) => to_decimal_bounds(x, bounds),
+                None if *x > 0 => Casted::Overflow(bounds.1),
+                None => Casted::Overflow(bounds.0),
+            })
+        });
+        collect_casted(values, &to_type, options)
     }
 }
 
@@ -74,9 +169,10 @@ pub(super) fn decimal_to_decimal_dyn(
     from: &dyn Array,
     to_precision: usize,
     to_scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)))
+    decimal_to_decimal_with_options(from, to_precision, to_scale, options).map(|x| x.boxed())
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
@@ -113,7 +209,22 @@ where
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
 pub fn decimal_to_integer<T>(from: &PrimitiveArray<i128>) -> PrimitiveArray<T>
 where
-    T: NativeType + NumCast,
+    T: NativeType + NumCast + Bounded,
+{
+    // the default options never error
+    decimal_to_integer_with_options(from, CastOptions::default()).unwrap()
+}
+
+/// Returns a [`PrimitiveArray`] with the casted values, rounded according to `options.rounding`.
+/// Values that overflow `T` are saturated, error or are `None`, depending on `options`.
+/// # Errors
+/// This function errors iff `options.strict` and a value overflows `T`
+pub fn decimal_to_integer_with_options<T>(
+    from: &PrimitiveArray<i128>,
+    options: CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + NumCast + Bounded,
 {
     let (_, from_scale) = if let DataType::Decimal(p, s) = from.data_type().to_logical_type() {
         (*p, *s)
@@ -122,15 +233,26 @@ where
     };
 
     let factor = 10_i128.pow(from_scale as u32);
-    let values = from.iter().map(|x| x.and_then(|x| T::from(*x / factor)));
-
-    PrimitiveArray::from_trusted_len_iter(values)
+    let values = from.iter().map(|x| {
+        x.map(|x| {
+            let x = div_round(*x, factor, options.rounding);
+            match T::from(x) {
+                Some(x) => Casted::Value(x),
+                None if x > 0 => Casted::Overflow(T::max_value()),
+                None => Casted::Overflow(T::min_value()),
+            }
+        })
+    });
+    collect_casted(values, &T::PRIMITIVE.into(), options)
 }
 
-pub(super) fn decimal_to_integer_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+pub(super) fn decimal_to_integer_dyn<T>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
 where
-    T: NativeType + NumCast,
+    T: NativeType + NumCast + Bounded,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_integer::<T>(from)))
+    decimal_to_integer_with_options::<T>(from, options).map(|x| x.boxed())
 }
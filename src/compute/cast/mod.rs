@@ -31,13 +31,68 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether a value that cannot be cast (e.g. an unparsable string or an overflowing number)
+    /// errors the cast instead of being converted to `None`.
+    /// This is honored by numeric casts that are not `wrapped`, by casts from strings and binary
+    /// to numbers and decimals that are not `partial`, and by casts to and from decimals.
+    pub strict: bool,
+    /// default to false
+    /// whether a value that overflows is saturated to the closest representable value instead
+    /// of being converted to `None` (or erroring when `strict`).
+    /// This is honored by numeric casts that are not `wrapped` and by casts to and from decimals.
+    pub saturate: bool,
+    /// default to [`RoundingMode::TowardZero`]
+    /// how values are rounded when casting from floats to decimals, from decimals to
+    /// integers, and to decimals of a smaller scale.
+    pub rounding: RoundingMode,
+}
+
+/// How values are rounded to the precision of the type they are casted to
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds toward zero, i.e. truncates
+    #[default]
+    TowardZero,
+    /// Rounds to the nearest value, with ties away from zero
+    HalfAwayFromZero,
+    /// Rounds to the nearest value, with ties to the even value
+    HalfEven,
+    /// Rounds toward negative infinity
+    Floor,
+    /// Rounds toward positive infinity
+    Ceil,
 }
 
 impl CastOptions {
-    fn with_wrapped(&self, v: bool) -> Self {
-        let mut option = *self;
-        option.wrapped = v;
-        option
+    /// Returns these options with [`CastOptions::wrapped`] set to `wrapped`.
+    pub fn with_wrapped(mut self, wrapped: bool) -> Self {
+        self.wrapped = wrapped;
+        self
+    }
+
+    /// Returns these options with [`CastOptions::partial`] set to `partial`.
+    pub fn with_partial(mut self, partial: bool) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    /// Returns these options with [`CastOptions::strict`] set to `strict`.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns these options with [`CastOptions::saturate`] set to `saturate`.
+    pub fn with_saturate(mut self, saturate: bool) -> Self {
+        self.saturate = saturate;
+        self
+    }
+
+    /// Returns these options with [`CastOptions::rounding`] set to `rounding`.
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
     }
 }
 
@@ -126,14 +181,23 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    LargeUtf8 | Binary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    LargeUtf8
+                        | Binary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
                 )
         }
         (LargeUtf8, to_type) => {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    Utf8 | LargeBinary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    Utf8 | LargeBinary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
                 )
         }
 
@@ -165,6 +229,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (UInt8, Int16) => true,
         (UInt8, Int32) => true,
         (UInt8, Int64) => true,
+        (UInt8, Float16) => true,
         (UInt8, Float32) => true,
         (UInt8, Float64) => true,
         (UInt8, Decimal(_, _)) => true,
@@ -176,6 +241,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (UInt16, Int16) => true,
         (UInt16, Int32) => true,
         (UInt16, Int64) => true,
+        (UInt16, Float16) => true,
         (UInt16, Float32) => true,
         (UInt16, Float64) => true,
         (UInt16, Decimal(_, _)) => true,
@@ -187,6 +253,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (UInt32, Int16) => true,
         (UInt32, Int32) => true,
         (UInt32, Int64) => true,
+        (UInt32, Float16) => true,
         (UInt32, Float32) => true,
         (UInt32, Float64) => true,
         (UInt32, Decimal(_, _)) => true,
@@ -198,6 +265,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (UInt64, Int16) => true,
         (UInt64, Int32) => true,
         (UInt64, Int64) => true,
+        (UInt64, Float16) => true,
         (UInt64, Float32) => true,
         (UInt64, Float64) => true,
         (UInt64, Decimal(_, _)) => true,
@@ -209,6 +277,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int8, Int16) => true,
         (Int8, Int32) => true,
         (Int8, Int64) => true,
+        (Int8, Float16) => true,
         (Int8, Float32) => true,
         (Int8, Float64) => true,
         (Int8, Decimal(_, _)) => true,
@@ -220,6 +289,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int16, Int8) => true,
         (Int16, Int32) => true,
         (Int16, Int64) => true,
+        (Int16, Float16) => true,
         (Int16, Float32) => true,
         (Int16, Float64) => true,
         (Int16, Decimal(_, _)) => true,
@@ -231,6 +301,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int32, Int8) => true,
         (Int32, Int16) => true,
         (Int32, Int64) => true,
+        (Int32, Float16) => true,
         (Int32, Float32) => true,
         (Int32, Float64) => true,
         (Int32, Decimal(_, _)) => true,
@@ -242,11 +313,22 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int64, Int8) => true,
         (Int64, Int16) => true,
         (Int64, Int32) => true,
+        (Int64, Float16) => true,
         (Int64, Float32) => true,
         (Int64, Float64) => true,
         (Int64, Decimal(_, _)) => true,
 
+        (Float16, UInt8) => true,
+        (Float16, UInt16) => true,
+        (Float16, UInt32) => true,
+        (Float16, UInt64) => true,
+        (Float16, Int8) => true,
+        (Float16, Int16) => true,
+        (Float16, Int32) => true,
+        (Float16, Int64) => true,
         (Float16, Float32) => true,
+        (Float16, Float64) => true,
+        (Float16, Decimal(_, _)) => true,
 
         (Float32, UInt8) => true,
         (Float32, UInt16) => true,
@@ -256,6 +338,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float32, Int16) => true,
         (Float32, Int32) => true,
         (Float32, Int64) => true,
+        (Float32, Float16) => true,
         (Float32, Float64) => true,
         (Float32, Decimal(_, _)) => true,
 
@@ -267,6 +350,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int16) => true,
         (Float64, Int32) => true,
         (Float64, Int64) => true,
+        (Float64, Float16) => true,
         (Float64, Float32) => true,
         (Float64, Decimal(_, _)) => true,
 
@@ -280,6 +364,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int16
             | Int32
             | Int64
+            | Float16
             | Float32
             | Float64
             | Decimal(_, _),
@@ -425,6 +510,8 @@ fn cast_list_to_fixed_size_list<O: Offset>(
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
+/// * Utf8 to decimal: digits beyond the scale are rounded half away from zero; strings that
+///   can't be parsed or overflow the precision return null, or error when `options.strict`
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
 /// * Fixed Size List to List: the underlying data type is cast
@@ -434,6 +521,12 @@ fn cast_list_to_fixed_size_list<O: Offset>(
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp with a timezone to a timestamp with another timezone: the instants (stored in UTC)
+///   are preserved, only the timezone changes
+/// * Timestamp with a timezone to a naive timestamp: the wall clock time in the timezone
+/// * Naive timestamp to a timestamp with a timezone: the naive timestamp is interpreted as the
+///   wall clock time in the timezone; ambiguous times resolve to the earliest instant and
+///   nonexistent ones are null
 /// * Temporal to/from backing primitive: zero-copy with data type change
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -577,6 +670,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i32>(array, *p, *s, options),
             Date32 => utf8_to_date32_dyn::<i32>(array),
             Date64 => utf8_to_date64_dyn::<i32>(array),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
@@ -606,6 +700,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i64>(array, *p, *s, options),
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap()).map(|x| x.boxed()),
@@ -796,9 +891,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt8, Int16) => primitive_to_primitive_dyn::<u8, i16>(array, to_type, options),
         (UInt8, Int32) => primitive_to_primitive_dyn::<u8, i32>(array, to_type, options),
         (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
+        (UInt8, Float16) => primitive_to_f16_dyn::<u8>(array),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
-        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
+        (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s, options),
 
         (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type, options),
         (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type, as_options),
@@ -807,9 +903,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt16, Int16) => primitive_to_primitive_dyn::<u16, i16>(array, to_type, options),
         (UInt16, Int32) => primitive_to_primitive_dyn::<u16, i32>(array, to_type, options),
         (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
+        (UInt16, Float16) => primitive_to_f16_dyn::<u16>(array),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
-        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
+        (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s, options),
 
         (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type, options),
         (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type, options),
@@ -818,9 +915,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt32, Int16) => primitive_to_primitive_dyn::<u32, i16>(array, to_type, options),
         (UInt32, Int32) => primitive_to_primitive_dyn::<u32, i32>(array, to_type, options),
         (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
+        (UInt32, Float16) => primitive_to_f16_dyn::<u32>(array),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
-        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
+        (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s, options),
 
         (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type, options),
         (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type, options),
@@ -829,9 +927,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (UInt64, Int16) => primitive_to_primitive_dyn::<u64, i16>(array, to_type, options),
         (UInt64, Int32) => primitive_to_primitive_dyn::<u64, i32>(array, to_type, options),
         (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
+        (UInt64, Float16) => primitive_to_f16_dyn::<u64>(array),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
-        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
+        (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s, options),
 
         (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type, options),
         (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type, options),
@@ -840,9 +939,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int8, Int16) => primitive_to_primitive_dyn::<i8, i16>(array, to_type, as_options),
         (Int8, Int32) => primitive_to_primitive_dyn::<i8, i32>(array, to_type, as_options),
         (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, as_options),
+        (Int8, Float16) => primitive_to_f16_dyn::<i8>(array),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
-        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
+        (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s, options),
 
         (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type, options),
         (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type, options),
@@ -851,9 +951,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int16, Int8) => primitive_to_primitive_dyn::<i16, i8>(array, to_type, options),
         (Int16, Int32) => primitive_to_primitive_dyn::<i16, i32>(array, to_type, as_options),
         (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, as_options),
+        (Int16, Float16) => primitive_to_f16_dyn::<i16>(array),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
-        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
+        (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s, options),
 
         (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type, options),
         (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type, options),
@@ -862,9 +963,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int32, Int8) => primitive_to_primitive_dyn::<i32, i8>(array, to_type, options),
         (Int32, Int16) => primitive_to_primitive_dyn::<i32, i16>(array, to_type, options),
         (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, as_options),
+        (Int32, Float16) => primitive_to_f16_dyn::<i32>(array),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
-        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
+        (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s, options),
 
         (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type, options),
         (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type, options),
@@ -873,14 +975,24 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Int64, Int8) => primitive_to_primitive_dyn::<i64, i8>(array, to_type, options),
         (Int64, Int16) => primitive_to_primitive_dyn::<i64, i16>(array, to_type, options),
         (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
+        (Int64, Float16) => primitive_to_f16_dyn::<i64>(array),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
-        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
+        (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s, options),
 
         (Float16, Float32) => {
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         }
+        (Float16, Float64) => primitive_dyn!(array, f16_to_f64),
+        (
+            Float16,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Decimal(_, _),
+        ) => {
+            // every `f16` is exactly an `f32`
+            let from = f16_to_f32(array.as_any().downcast_ref().unwrap());
+            cast(&from, to_type, options)
+        }
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -890,8 +1002,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float32, Int16) => primitive_to_primitive_dyn::<f32, i16>(array, to_type, options),
         (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type, options),
         (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
+        (Float32, Float16) => primitive_dyn!(array, f32_to_f16),
         (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, as_options),
-        (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s),
+        (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s, options),
 
         (Float64, UInt8) => primitive_to_primitive_dyn::<f64, u8>(array, to_type, options),
         (Float64, UInt16) => primitive_to_primitive_dyn::<f64, u16>(array, to_type, options),
@@ -901,20 +1014,27 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float64, Int16) => primitive_to_primitive_dyn::<f64, i16>(array, to_type, options),
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
+        (Float64, Float16) => primitive_to_f16_dyn::<f64>(array),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
-        (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
-
-        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array),
-        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array),
-        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array),
-        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array),
-        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array),
-        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array),
-        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array),
-        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
+        (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s, options),
+
+        (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array, options),
+        (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array, options),
+        (Decimal(_, _), UInt32) => decimal_to_integer_dyn::<u32>(array, options),
+        (Decimal(_, _), UInt64) => decimal_to_integer_dyn::<u64>(array, options),
+        (Decimal(_, _), Int8) => decimal_to_integer_dyn::<i8>(array, options),
+        (Decimal(_, _), Int16) => decimal_to_integer_dyn::<i16>(array, options),
+        (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array, options),
+        (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array, options),
+        (Decimal(_, _), Float16) => {
+            let from = decimal_to_float::<f64>(array.as_any().downcast_ref().unwrap());
+            Ok(primitive_to_f16(&from).boxed())
+        }
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
-        (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal(_, _), Decimal(to_p, to_s)) => {
+            decimal_to_decimal_dyn(array, *to_p, *to_s, options)
+        }
         // end numeric casts
 
         // temporal casts
@@ -961,8 +1081,16 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         }
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
-        (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
-            primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
+        (Timestamp(from_unit, from_tz), Timestamp(to_unit, to_tz)) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            let from = match (from_tz, to_tz) {
+                (Some(tz), None) => timestamp_to_naive_timestamp(from, *from_unit, tz)?,
+                (None, Some(tz)) => naive_timestamp_to_timestamp(from, *from_unit, tz)?,
+                _ => from.clone(),
+            };
+            Ok(Box::new(timestamp_to_timestamp(
+                &from, *from_unit, *to_unit, to_tz,
+            )))
         }
         (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
         (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
@@ -6,6 +6,8 @@ use crate::datatypes::IntervalUnit;
 use crate::error::Result;
 use crate::offset::{Offset, Offsets};
 use crate::types::{days_ms, f16, months_days_ns};
+
+pub use crate::compute::utils::{f16_to_f32, f32_to_f16};
 use crate::{
     array::*,
     bitmap::Bitmap,
@@ -15,6 +17,7 @@ use crate::{
     types::NativeType,
 };
 
+use super::decimal_to::{collect_casted, decimal_bounds, round_float, to_decimal_bounds, Casted};
 use super::CastOptions;
 
 /// Returns a [`BinaryArray`] where every element is the binary representation of the number.
@@ -137,16 +140,45 @@ pub(super) fn primitive_to_primitive_dyn<I, O>(
 ) -> Result<Box<dyn Array>>
 where
     I: NativeType + num_traits::NumCast + num_traits::AsPrimitive<O>,
-    O: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
 {
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
     if options.wrapped {
         Ok(Box::new(primitive_as_primitive::<I, O>(from, to_type)))
+    } else if options.strict || options.saturate {
+        primitive_to_primitive_with_options::<I, O>(from, to_type, options).map(|x| x.boxed())
     } else {
         Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
     }
 }
 
+/// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion.
+/// Values that overflow `O` are saturated, error or are `None`, depending on `options`.
+/// `NaN` is never saturated.
+/// # Errors
+/// This function errors iff `options.strict` and a value is `NaN` or overflows `O`
+pub fn primitive_to_primitive_with_options<I, O>(
+    from: &PrimitiveArray<I>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<PrimitiveArray<O>>
+where
+    I: NativeType + num_traits::NumCast,
+    O: NativeType + num_traits::NumCast + num_traits::Bounded,
+{
+    let values = from.iter().map(|x| {
+        x.map(|x| match num_traits::cast::cast::<I, O>(*x) {
+            Some(x) => Casted::Value(x),
+            None => match x.to_f64() {
+                Some(x) if x > 0.0 => Casted::Overflow(O::max_value()),
+                Some(x) if x < 0.0 => Casted::Overflow(O::min_value()),
+                _ => Casted::Invalid,
+            },
+        })
+    });
+    collect_casted(values, to_type, options)
+}
+
 /// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion.
 pub fn primitive_to_primitive<I, O>(
     from: &PrimitiveArray<I>,
@@ -168,39 +200,47 @@ pub fn integer_to_decimal<T: NativeType + AsPrimitive<i128>>(
     to_precision: usize,
     to_scale: usize,
 ) -> PrimitiveArray<i128> {
-    let multiplier = 10_i128.pow(to_scale as u32);
+    // the default options never error
+    integer_to_decimal_with_options(from, to_precision, to_scale, CastOptions::default()).unwrap()
+}
 
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values that overflow
+/// `to_precision` are saturated, error or are `None`, depending on `options`.
+/// # Errors
+/// This function errors iff `options.strict` and a value overflows `to_precision`
+pub fn integer_to_decimal_with_options<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>> {
+    let multiplier = 10_i128.pow(to_scale as u32);
+    let bounds = decimal_bounds(to_precision);
 
     let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            x.as_().checked_mul(multiplier).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
-                } else {
-                    Some(x)
-                }
-            })
+        x.map(|x| {
+            let x: i128 = x.as_();
+            match x.checked_mul(multiplier) {
+                Some(x) => to_decimal_bounds(x, bounds),
+                None if x > 0 => Casted::Overflow(bounds.1),
+                None => Casted::Overflow(bounds.0),
+            }
         })
     });
-
-    PrimitiveArray::<i128>::from_trusted_len_iter(values)
-        .to(DataType::Decimal(to_precision, to_scale))
+    collect_casted(values, &DataType::Decimal(to_precision, to_scale), options)
 }
 
 pub(super) fn integer_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     T: NativeType + AsPrimitive<i128>,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(integer_to_decimal::<T>(from, precision, scale)))
+    integer_to_decimal_with_options::<T>(from, precision, scale, options).map(|x| x.boxed())
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
@@ -209,44 +249,59 @@ pub fn float_to_decimal<T>(
     to_precision: usize,
     to_scale: usize,
 ) -> PrimitiveArray<i128>
+where
+    T: NativeType + Float + ToPrimitive,
+    f64: AsPrimitive<T>,
+{
+    // the default options never error
+    float_to_decimal_with_options(from, to_precision, to_scale, CastOptions::default()).unwrap()
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values, rounded according to
+/// `options.rounding`. Values that overflow `to_precision` are saturated, error or are `None`,
+/// depending on `options`. `NaN` is never saturated.
+/// # Errors
+/// This function errors iff `options.strict` and a value is `NaN` or overflows `to_precision`
+pub fn float_to_decimal_with_options<T>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>>
 where
     T: NativeType + Float + ToPrimitive,
     f64: AsPrimitive<T>,
 {
     // 1.2 => 12
     let multiplier: T = (10_f64).powi(to_scale as i32).as_();
-
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+    let bounds = decimal_bounds(to_precision);
 
     let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            let x = (*x * multiplier).to_i128().unwrap();
-            if x > max_for_precision || x < min_for_precision {
-                None
-            } else {
-                Some(x)
+        x.map(|x| {
+            let x = round_float(*x * multiplier, options.rounding);
+            match x.to_i128() {
+                Some(x) => to_decimal_bounds(x, bounds),
+                None if x.is_nan() => Casted::Invalid,
+                None if x > T::zero() => Casted::Overflow(bounds.1),
+                None => Casted::Overflow(bounds.0),
             }
         })
     });
-
-    PrimitiveArray::<i128>::from_trusted_len_iter(values)
-        .to(DataType::Decimal(to_precision, to_scale))
+    collect_casted(values, &DataType::Decimal(to_precision, to_scale), options)
 }
 
 pub(super) fn float_to_decimal_dyn<T>(
     from: &dyn Array,
     precision: usize,
     scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     T: NativeType + Float + ToPrimitive,
     f64: AsPrimitive<T>,
 {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(float_to_decimal::<T>(from, precision, scale)))
+    float_to_decimal_with_options::<T>(from, precision, scale, options).map(|x| x.boxed())
 }
 
 /// Cast [`PrimitiveArray`] as a [`PrimitiveArray`]
@@ -419,6 +474,144 @@ pub fn timestamp_to_timestamp(
     }
 }
 
+fn timestamp_to_naive_timestamp_impl<T: chrono::TimeZone>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+) -> PrimitiveArray<i64> {
+    use chrono::Offset;
+    let multiple = time_unit_multiple(time_unit);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            let datetime = timestamp_to_naive_datetime_opt(*x, time_unit)?;
+            let offset = timezone.offset_from_utc_datetime(&datetime).fix();
+            x.checked_add(offset.local_minus_utc() as i64 * multiple)
+        })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(time_unit, None))
+}
+
+fn naive_timestamp_to_timestamp_impl<T: chrono::TimeZone>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    timezone_str: &str,
+) -> PrimitiveArray<i64> {
+    use chrono::Offset;
+    let multiple = time_unit_multiple(time_unit);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            let datetime = timestamp_to_naive_datetime_opt(*x, time_unit)?;
+            let offset = timezone
+                .offset_from_local_datetime(&datetime)
+                .earliest()?
+                .fix();
+            x.checked_sub(offset.local_minus_utc() as i64 * multiple)
+        })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(
+        time_unit,
+        Some(timezone_str.to_string()),
+    ))
+}
+
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+fn chrono_tz_timestamp_to_naive_timestamp(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(timestamp_to_naive_timestamp_impl(from, time_unit, timezone))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_timestamp_to_naive_timestamp(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    use crate::error::Error;
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+fn chrono_tz_naive_timestamp_to_timestamp(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(naive_timestamp_to_timestamp_impl(
+        from,
+        time_unit,
+        timezone,
+        timezone_str,
+    ))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_naive_timestamp_to_timestamp(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    use crate::error::Error;
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Converts a timezone-aware timestamp into a naive timestamp (i.e. `Timestamp(time_unit, None)`)
+/// with the wall clock time of `timezone_str` at each instant.
+///
+/// Values whose wall clock time is out of range are null.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone_str`.
+/// # Errors
+/// This function errors iff `timezone_str` is not parsable to an offset.
+pub fn timestamp_to_naive_timestamp(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    if let Ok(timezone) = parse_offset(timezone_str) {
+        Ok(timestamp_to_naive_timestamp_impl(from, time_unit, timezone))
+    } else {
+        chrono_tz_timestamp_to_naive_timestamp(from, time_unit, timezone_str)
+    }
+}
+
+/// Converts a naive timestamp, interpreted as the wall clock time of `timezone_str`, into a
+/// timezone-aware timestamp (i.e. `Timestamp(time_unit, Some(timezone_str))`).
+///
+/// Ambiguous wall clock times (e.g. repeated by a daylight saving transition) resolve to the
+/// earliest instant; wall clock times that do not exist in `timezone_str` are null.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone_str`.
+/// # Errors
+/// This function errors iff `timezone_str` is not parsable to an offset.
+pub fn naive_timestamp_to_timestamp(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<PrimitiveArray<i64>> {
+    if let Ok(timezone) = parse_offset(timezone_str) {
+        Ok(naive_timestamp_to_timestamp_impl(
+            from,
+            time_unit,
+            timezone,
+            timezone_str,
+        ))
+    } else {
+        chrono_tz_naive_timestamp_to_timestamp(from, time_unit, timezone_str)
+    }
+}
+
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
     from: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -583,7 +776,25 @@ pub fn months_to_months_days_ns(from: &PrimitiveArray<i32>) -> PrimitiveArray<mo
     )
 }
 
-/// Casts f16 into f32
-pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
-    unary(from, |x| x.to_f32(), DataType::Float32)
+/// Casts f16 into f64
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), DataType::Float64)
+}
+
+/// Casts a numeric [`PrimitiveArray`] into f16, rounding to the nearest value.
+/// Values that overflow `f16` are cast to infinity.
+///
+/// Values are cast via `f64`, which rounds only once: every integer that `f64` does not
+/// represent exactly (i.e. of magnitude above 2^53) overflows `f16` regardless.
+pub fn primitive_to_f16<I: NativeType + AsPrimitive<f64>>(
+    from: &PrimitiveArray<I>,
+) -> PrimitiveArray<f16> {
+    unary(from, |x| f16::from_f64(x.as_()), DataType::Float16)
+}
+
+pub(super) fn primitive_to_f16_dyn<I: NativeType + AsPrimitive<f64>>(
+    from: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(primitive_to_f16::<I>(from)))
 }
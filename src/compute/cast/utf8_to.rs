@@ -3,7 +3,7 @@ use chrono::Datelike;
 use crate::{
     array::*,
     datatypes::DataType,
-    error::Result,
+    error::{Error, Result},
     offset::Offset,
     temporal_conversions::{
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
@@ -43,6 +43,29 @@ where
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
 }
 
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray`].
+/// # Errors
+/// This function errors iff any non-null value cannot be parsed into `T`
+pub fn try_utf8_to_primitive<O: Offset, T>(
+    from: &Utf8Array<O>,
+    to: &DataType,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + lexical_core::FromLexical,
+{
+    let iter = from.iter().map(|x| {
+        x.map(|x| {
+            lexical_core::parse::<T>(x.as_bytes()).map_err(|_| {
+                Error::InvalidArgumentError(format!("\"{x}\" cannot be parsed into {to:?}"))
+            })
+        })
+        .transpose()
+    });
+
+    let array: PrimitiveArray<T> = MutablePrimitiveArray::try_from_trusted_len_iter(iter)?.into();
+    Ok(array.to(to.clone()))
+}
+
 pub(super) fn utf8_to_primitive_dyn<O: Offset, T>(
     from: &dyn Array,
     to: &DataType,
@@ -54,11 +77,135 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     if options.partial {
         Ok(Box::new(partial_utf8_to_primitive::<O, T>(from, to)))
+    } else if options.strict {
+        try_utf8_to_primitive::<O, T>(from, to).map(|x| x.boxed())
     } else {
         Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
     }
 }
 
+/// Parses `bytes` (e.g. `"-123.4500"` or `"1.5e3"`) into the integer representation of a
+/// decimal with `scale` digits after the decimal point.
+///
+/// Digits beyond `scale` are rounded half away from zero. Returns `None` if `bytes` is not
+/// a decimal number or if the number does not fit in `precision` digits.
+pub(crate) fn parse_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
+    let (negative, bytes) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+
+    let (mantissa, exponent) = match bytes.iter().position(|x| *x == b'e' || *x == b'E') {
+        Some(position) => {
+            let exponent = lexical_core::parse::<i32>(&bytes[position + 1..]).ok()?;
+            (&bytes[..position], exponent as i64)
+        }
+        None => (bytes, 0),
+    };
+    let (integer, fraction) = match mantissa.iter().position(|x| *x == b'.') {
+        Some(position) => (&mantissa[..position], &mantissa[position + 1..]),
+        None => (mantissa, &mantissa[mantissa.len()..]),
+    };
+    let is_digits = |x: &[u8]| x.iter().all(|x| x.is_ascii_digit());
+    if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+    let mut digits = integer
+        .iter()
+        .chain(fraction.iter())
+        .map(|x| (x - b'0') as i128);
+    let num_digits = integer.len() + fraction.len();
+
+    // the number of digits that the mantissa must be shifted to the left to have `scale`
+    let shift = scale as i64 + exponent - fraction.len() as i64;
+    let mut value = if shift >= 0 {
+        let value = digits.try_fold(0i128, |acc, digit| acc.checked_mul(10)?.checked_add(digit))?;
+        if value == 0 {
+            0
+        } else {
+            10i128
+                .checked_pow(u32::try_from(shift).ok()?)
+                .and_then(|factor| value.checked_mul(factor))?
+        }
+    } else {
+        let dropped = (-shift) as usize;
+        if dropped > num_digits {
+            0
+        } else {
+            let value = digits
+                .by_ref()
+                .take(num_digits - dropped)
+                .try_fold(0i128, |acc, digit| acc.checked_mul(10)?.checked_add(digit))?;
+            let round_up = digits.next().map(|digit| digit >= 5).unwrap_or(false);
+            value.checked_add(round_up as i128)?
+        }
+    };
+    if negative {
+        value = -value;
+    }
+
+    if precision < 39 && value.unsigned_abs() >= 10u128.pow(precision as u32) {
+        return None;
+    }
+    Some(value)
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray`] of `Decimal(precision, scale)`, making any
+/// uncastable value a Null.
+///
+/// Digits beyond `scale` are rounded half away from zero; numbers with more than `precision`
+/// digits are uncastable.
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i128> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| parse_decimal(x.as_bytes(), precision, scale)));
+
+    PrimitiveArray::<i128>::from_trusted_len_iter(iter).to(DataType::Decimal(precision, scale))
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray`] of `Decimal(precision, scale)`.
+/// # Errors
+/// This function errors iff any non-null value is uncastable, see [`utf8_to_decimal`].
+pub fn try_utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+) -> Result<PrimitiveArray<i128>> {
+    let iter = from.iter().map(|x| {
+        x.map(|x| {
+            parse_decimal(x.as_bytes(), precision, scale).ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "\"{x}\" cannot be cast to Decimal({precision}, {scale})"
+                ))
+            })
+        })
+        .transpose()
+    });
+
+    let array: PrimitiveArray<i128> =
+        MutablePrimitiveArray::try_from_trusted_len_iter(iter)?.into();
+    Ok(array.to(DataType::Decimal(precision, scale)))
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    if options.strict {
+        try_utf8_to_decimal::<O>(from, precision, scale).map(|x| x.boxed())
+    } else {
+        Ok(Box::new(utf8_to_decimal::<O>(from, precision, scale)))
+    }
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -0,0 +1,262 @@
+//! Contains the operators [`clamp`], [`greatest`] and [`least`], that bound the values of
+//! [`PrimitiveArray`]s.
+//!
+//! Like SQL's `GREATEST` and `LEAST`, [`greatest`] and [`least`] ignore null values: a slot is
+//! null only when it is null in all arrays.
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
+use crate::types::NativeType;
+
+use super::arity::{binary, unary};
+
+/// Returns `array` with its values smaller than `min` replaced by `min` and its values larger
+/// than `max` replaced by `max`. The validity is not changed.
+/// # Errors
+/// This function errors iff `min` is larger than `max`.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::clamp::clamp;
+///
+/// let array = Int32Array::from([Some(-5), None, Some(3), Some(12)]);
+/// let result = clamp(&array, 0, 10).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(0), None, Some(3), Some(10)]));
+/// ```
+pub fn clamp<T>(array: &PrimitiveArray<T>, min: T, max: T) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    if min > max {
+        return Err(Error::InvalidArgumentError(format!(
+            "`clamp` requires the minimum to be smaller or equal to the maximum, but got {min:?} > {max:?}"
+        )));
+    }
+    let op = |x: T| {
+        if x < min {
+            min
+        } else if x > max {
+            max
+        } else {
+            x
+        }
+    };
+    Ok(unary(array, op, array.data_type().clone()))
+}
+
+fn pairwise<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    if lhs.null_count() == 0 && rhs.null_count() == 0 {
+        return binary(lhs, rhs, lhs.data_type().clone(), op);
+    }
+    let values = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(lhs, rhs)| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => op(*lhs, *rhs),
+            (Some(value), None) | (None, Some(value)) => *value,
+            (None, None) => T::default(),
+        })
+        .collect::<Vec<_>>();
+    let validity = match (lhs.validity(), rhs.validity()) {
+        (Some(lhs), Some(rhs)) => Some(lhs | rhs),
+        _ => None,
+    };
+    PrimitiveArray::new(lhs.data_type().clone(), values.into(), validity)
+}
+
+fn variadic<T, F>(arrays: &[&PrimitiveArray<T>], name: &str, op: F) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T + Copy,
+{
+    let (first, others) = arrays.split_first().ok_or_else(|| {
+        Error::InvalidArgumentError(format!("`{name}` requires at least one array"))
+    })?;
+    if let Some(array) = others.iter().find(|array| array.len() != first.len()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "`{name}` requires all arrays to have the same length, but got {} and {}",
+            first.len(),
+            array.len()
+        )));
+    }
+    Ok(others
+        .iter()
+        .fold((*first).clone(), |acc, array| pairwise(&acc, array, op)))
+}
+
+/// Returns the element-wise maximum of `arrays`, ignoring nulls.
+/// # Errors
+/// This function errors iff `arrays` is empty or the arrays do not have the same length.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::clamp::greatest;
+///
+/// let a = Int32Array::from([Some(1), None, None]);
+/// let b = Int32Array::from([Some(3), Some(2), None]);
+/// let result = greatest(&[&a, &b]).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(3), Some(2), None]));
+/// ```
+pub fn greatest<T>(arrays: &[&PrimitiveArray<T>]) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    variadic(
+        arrays,
+        "greatest",
+        |lhs, rhs| if rhs > lhs { rhs } else { lhs },
+    )
+}
+
+/// Returns the element-wise minimum of `arrays`, ignoring nulls.
+/// # Errors
+/// This function errors iff `arrays` is empty or the arrays do not have the same length.
+pub fn least<T>(arrays: &[&PrimitiveArray<T>]) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    variadic(
+        arrays,
+        "least",
+        |lhs, rhs| if rhs < lhs { rhs } else { lhs },
+    )
+}
+
+/// Returns whether the operators of this module can be applied to arrays of `data_type`.
+pub fn can_clamp(data_type: &DataType) -> bool {
+    if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
+        use PrimitiveType::*;
+        matches!(
+            primitive,
+            Int8 | Int16
+                | Int32
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float32
+                | Float64
+        )
+    } else {
+        false
+    }
+}
+
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => unreachable!(),
+    }
+})}
+
+/// Returns the primitive type of `data_type`, checked to be supported by [`can_clamp`].
+fn primitive_type(data_type: &DataType, name: &str) -> Result<PrimitiveType> {
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) if can_clamp(data_type) => Ok(primitive),
+        _ => Err(Error::NotYetImplemented(format!(
+            "`{name}` is not implemented for type {data_type:?}"
+        ))),
+    }
+}
+
+/// Dynamically-typed version of [`clamp`].
+/// # Errors
+/// This function errors iff the bounds are null or not of the type of the array, the type is
+/// not supported (see [`can_clamp`]), or `min` is larger than `max`.
+pub fn clamp_dyn(array: &dyn Array, min: &dyn Scalar, max: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if min.data_type() != array.data_type() || max.data_type() != array.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`clamp` requires bounds of the type of the array, {:?}, but got {:?} and {:?}",
+            array.data_type(),
+            min.data_type(),
+            max.data_type()
+        )));
+    }
+    if !min.is_valid() || !max.is_valid() {
+        return Err(Error::InvalidArgumentError(
+            "`clamp` requires non-null bounds".to_string(),
+        ));
+    }
+    let primitive = primitive_type(array.data_type(), "clamp")?;
+    with_match_primitive_type!(primitive, |$T| {
+        let bound = |scalar: &dyn Scalar| {
+            scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<$T>>()
+                .unwrap()
+                .value()
+                .unwrap()
+        };
+        let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+        clamp(array, bound(min), bound(max)).map(|array| array.boxed())
+    })
+}
+
+/// Returns the primitive type of `arrays`, checked to be non-empty and of the same type.
+fn variadic_type(arrays: &[&dyn Array], name: &str) -> Result<PrimitiveType> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError(format!("`{name}` requires at least one array"))
+    })?;
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.data_type() != first.data_type())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "`{name}` requires all arrays to have the same type, but got {:?} and {:?}",
+            first.data_type(),
+            array.data_type()
+        )));
+    }
+    primitive_type(first.data_type(), name)
+}
+
+/// Dynamically-typed version of [`greatest`].
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays do not have the same type and
+/// length, or the type is not supported (see [`can_clamp`]).
+pub fn greatest_dyn(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let primitive = variadic_type(arrays, "greatest")?;
+    with_match_primitive_type!(primitive, |$T| {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap())
+            .collect::<Vec<_>>();
+        greatest(&arrays).map(|array| array.boxed())
+    })
+}
+
+/// Dynamically-typed version of [`least`].
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays do not have the same type and
+/// length, or the type is not supported (see [`can_clamp`]).
+pub fn least_dyn(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let primitive = variadic_type(arrays, "least")?;
+    with_match_primitive_type!(primitive, |$T| {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap())
+            .collect::<Vec<_>>();
+        least(&arrays).map(|array| array.boxed())
+    })
+}
@@ -0,0 +1,281 @@
+//! Comparison functions for [`PrimitiveArray`]s of [`DataType::Decimal`].
+//!
+//! Contrarily to [`super::primitive`], the decimals compared may have different scales, in
+//! which case the values of smaller scale are rescaled before being compared, without loss of
+//! precision.
+//! ```
+//! use arrow2::array::{BooleanArray, PrimitiveArray};
+//! use arrow2::compute::comparison::decimal::lt;
+//! use arrow2::datatypes::DataType;
+//!
+//! // 1.5 and 2.0
+//! let lhs = PrimitiveArray::from_slice([15i128, 20]).to(DataType::Decimal(10, 1));
+//! // 1.75 and 1.99
+//! let rhs = PrimitiveArray::from_slice([175i128, 199]).to(DataType::Decimal(10, 2));
+//! assert_eq!(lt(&lhs, &rhs), BooleanArray::from_slice([true, false]));
+//! ```
+use std::cmp::Ordering;
+
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{BooleanArray, PrimitiveArray},
+    bitmap::Bitmap,
+    datatypes::DataType,
+    scalar::{PrimitiveScalar, Scalar},
+};
+
+use super::super::utils::combine_validities;
+
+/// Returns the scale of a decimal `data_type`.
+/// # Panics
+/// iff `data_type` is not a decimal.
+fn scale(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::Decimal(_, scale) => *scale,
+        other => panic!("Decimal comparisons require decimals, but got {other:?}"),
+    }
+}
+
+/// Compares `value * factor` with `other`, where `factor` is `None` when it overflows.
+#[inline]
+fn compare_rescaled(value: i128, factor: Option<i128>, other: i128) -> Ordering {
+    match factor.and_then(|factor| value.checked_mul(factor)) {
+        Some(value) => value.cmp(&other),
+        // the rescaled value is larger (in absolute value) than any `i128`
+        None => value.signum().cmp(&0),
+    }
+}
+
+/// Returns a function that compares a decimal of `lhs_scale` with a decimal of `rhs_scale`.
+fn comparator(lhs_scale: usize, rhs_scale: usize) -> impl Fn(i128, i128) -> Ordering {
+    let factor = u32::try_from(lhs_scale.abs_diff(rhs_scale))
+        .ok()
+        .and_then(|exponent| 10i128.checked_pow(exponent));
+    move |lhs, rhs| match lhs_scale.cmp(&rhs_scale) {
+        Ordering::Equal => lhs.cmp(&rhs),
+        Ordering::Less => compare_rescaled(lhs, factor, rhs),
+        Ordering::Greater => compare_rescaled(rhs, factor, lhs).reverse(),
+    }
+}
+
+/// Evaluate `op(lhs.cmp(rhs))` for every pair of values of two decimal arrays, ignoring their
+/// validities.
+fn compare_values<F>(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>, op: F) -> Bitmap
+where
+    F: Fn(Ordering) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let compare = comparator(scale(lhs.data_type()), scale(rhs.data_type()));
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(compare(*lhs, *rhs)));
+    Bitmap::from_trusted_len_iter(values)
+}
+
+/// Evaluate `op(lhs.cmp(rhs))` for every value of a decimal array and a decimal `rhs` of
+/// `rhs_scale`, ignoring its validity.
+fn compare_values_scalar<F>(
+    lhs: &PrimitiveArray<i128>,
+    rhs: i128,
+    rhs_scale: usize,
+    op: F,
+) -> Bitmap
+where
+    F: Fn(Ordering) -> bool,
+{
+    let compare = comparator(scale(lhs.data_type()), rhs_scale);
+    let values = lhs.values_iter().map(|lhs| op(compare(*lhs, rhs)));
+    Bitmap::from_trusted_len_iter(values)
+}
+
+fn compare_op<F>(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>, op: F) -> BooleanArray
+where
+    F: Fn(Ordering) -> bool,
+{
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let values = compare_values(lhs, rhs, op);
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn compare_op_scalar<F>(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+    op: F,
+) -> BooleanArray
+where
+    F: Fn(Ordering) -> bool,
+{
+    let Some(value) = rhs.value() else {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    };
+    let values = compare_values_scalar(lhs, *value, scale(rhs.data_type()), op);
+    BooleanArray::new(DataType::Boolean, values, lhs.validity().cloned())
+}
+
+/// Perform `lhs == rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn eq(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_eq)
+}
+
+/// Perform `lhs == rhs` operation on two decimal arrays and include validities in comparison.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn eq_and_validity(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    let values = compare_values(lhs, rhs, Ordering::is_eq);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs == rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn eq_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_eq)
+}
+
+/// Perform `lhs == rhs` operation on a decimal array and a decimal scalar and include
+/// validities in comparison.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn eq_scalar_and_validity(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> BooleanArray {
+    let validity = lhs.validity().cloned();
+    let out = compare_op_scalar(&lhs.clone().with_validity(None), rhs, Ordering::is_eq);
+    finish_eq_validities(out, validity, None)
+}
+
+/// Perform `lhs != rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn neq(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_ne)
+}
+
+/// Perform `lhs != rhs` operation on two decimal arrays and include validities in comparison.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn neq_and_validity(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    let values = compare_values(lhs, rhs, Ordering::is_ne);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn neq_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_ne)
+}
+
+/// Perform `lhs != rhs` operation on a decimal array and a decimal scalar and include
+/// validities in comparison.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn neq_scalar_and_validity(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> BooleanArray {
+    let validity = lhs.validity().cloned();
+    let out = compare_op_scalar(&lhs.clone().with_validity(None), rhs, Ordering::is_ne);
+    finish_neq_validities(out, validity, None)
+}
+
+/// Perform `lhs < rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn lt(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_lt)
+}
+
+/// Perform `lhs < rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn lt_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_lt)
+}
+
+/// Perform `lhs <= rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn lt_eq(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_le)
+}
+
+/// Perform `lhs <= rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn lt_eq_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_le)
+}
+
+/// Perform `lhs > rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn gt(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_gt)
+}
+
+/// Perform `lhs > rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn gt_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_gt)
+}
+
+/// Perform `lhs >= rhs` operation on two decimal arrays.
+/// # Panics
+/// iff the arrays are not decimals or have different lengths.
+pub fn gt_eq(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> BooleanArray {
+    compare_op(lhs, rhs, Ordering::is_ge)
+}
+
+/// Perform `lhs >= rhs` operation on a decimal array and a decimal scalar.
+/// # Panics
+/// iff the array or the scalar are not decimals.
+pub fn gt_eq_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_ge)
+}
+
+/// Returns whether every value of a decimal array is between `low` and `high` (inclusive), as
+/// SQL's `array BETWEEN low AND high`, in a single pass over the values.
+///
+/// The result is null when the value is null, and all null when `low` or `high` is null.
+/// # Panics
+/// iff the array or the bounds are not decimals.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, PrimitiveArray};
+/// use arrow2::compute::comparison::decimal::between;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = PrimitiveArray::from([Some(99i128), Some(150), None, Some(201)])
+///     .to(DataType::Decimal(10, 2));
+/// // between 1.0 and 2.01
+/// let low = PrimitiveScalar::new(DataType::Decimal(5, 0), Some(1i128));
+/// let high = PrimitiveScalar::new(DataType::Decimal(5, 2), Some(201i128));
+/// let result = between(&array, &low, &high);
+/// assert_eq!(result, BooleanArray::from([Some(false), Some(true), None, Some(true)]));
+/// ```
+pub fn between(
+    array: &PrimitiveArray<i128>,
+    low: &PrimitiveScalar<i128>,
+    high: &PrimitiveScalar<i128>,
+) -> BooleanArray {
+    let (Some(low_value), Some(high_value)) = (low.value(), high.value()) else {
+        return BooleanArray::new_null(DataType::Boolean, array.len());
+    };
+    let array_scale = scale(array.data_type());
+    let compare_low = comparator(array_scale, scale(low.data_type()));
+    let compare_high = comparator(array_scale, scale(high.data_type()));
+    let values = array.values_iter().map(|value| {
+        compare_low(*value, *low_value).is_ge() && compare_high(*value, *high_value).is_le()
+    });
+    let values = Bitmap::from_trusted_len_iter(values);
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
@@ -0,0 +1,261 @@
+//! Comparison functions for [`DictionaryArray`]
+//!
+//! The values of the dictionaries are ranked once, and rows are compared by the ranks of their
+//! keys, so that values are never unpacked. Dictionaries are ranked together unless both arrays
+//! share the same dictionary. Values are ranked by their total order (see [`build_compare`]),
+//! so that e.g. `NaN` is equal to itself.
+//!
+//! Comparisons of a [`DictionaryArray`] against a scalar (e.g. [`eq_scalar`]) compare the
+//! scalar to every value of the dictionary once, with the same total order, and map the result
+//! by key. The scalar may be of the type of the dictionary or of its values.
+use std::cmp::Ordering;
+
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::{
+    array::{
+        growable::make_growable, ord::build_compare, Array, BooleanArray, DictionaryArray,
+        DictionaryKey,
+    },
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::DataType,
+    scalar::{DictionaryScalar, Scalar},
+};
+
+use super::super::utils::{combine_validities, scalar_to_array};
+
+/// The dense rank of every value of `values`: equal values have equal ranks, and the ranks
+/// follow the order of the values.
+/// # Panics
+/// iff the type of `values` has no natural order.
+fn dense_ranks(values: &dyn Array) -> Vec<usize> {
+    let compare = build_compare(values, values).unwrap();
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_unstable_by(|lhs, rhs| compare(*lhs, *rhs));
+
+    let mut ranks = vec![0; values.len()];
+    let mut rank = 0;
+    for (i, index) in indices.iter().enumerate() {
+        if i > 0 && compare(indices[i - 1], *index) != Ordering::Equal {
+            rank += 1;
+        }
+        ranks[*index] = rank;
+    }
+    ranks
+}
+
+/// The validity of every row of `array`, that is null when its key or its value is null.
+fn validity<K: DictionaryKey>(array: &DictionaryArray<K>) -> Option<Bitmap> {
+    let values = array.values();
+    if values.null_count() == 0 {
+        return array.validity().cloned();
+    }
+    let validity = array
+        .keys_iter()
+        .map(|key| key.map(|key| values.is_valid(key)).unwrap_or(false))
+        .collect::<MutableBitmap>();
+    Some(validity.into())
+}
+
+/// Evaluates `op` over the ranks of the values of every pair of rows of `lhs` and `rhs`,
+/// ignoring their validities.
+fn compare_ranks<K, F>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>, op: F) -> Bitmap
+where
+    K: DictionaryKey,
+    F: Fn(usize, usize) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let (ranks, offset) = if lhs.values() == rhs.values() {
+        (dense_ranks(lhs.values().as_ref()), 0)
+    } else {
+        let values = [lhs.values().as_ref(), rhs.values().as_ref()];
+        let mut growable = make_growable(&values, false, values[0].len() + values[1].len());
+        growable.extend(0, 0, values[0].len());
+        growable.extend(1, 0, values[1].len());
+        (dense_ranks(growable.as_box().as_ref()), values[0].len())
+    };
+
+    // null keys may be out of bounds: they are compared as the first value
+    lhs.keys_iter()
+        .zip(rhs.keys_iter())
+        .map(|(lhs, rhs)| {
+            let lhs = lhs.map(|key| ranks[key]).unwrap_or(0);
+            let rhs = rhs.map(|key| ranks[offset + key]).unwrap_or(0);
+            op(lhs, rhs)
+        })
+        .collect::<MutableBitmap>()
+        .into()
+}
+
+/// Evaluate `op(lhs, rhs)` for [`DictionaryArray`]s using a specified comparison function of
+/// the ranks of their values.
+fn compare_op<K, F>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>, op: F) -> BooleanArray
+where
+    K: DictionaryKey,
+    F: Fn(usize, usize) -> bool,
+{
+    let values = compare_ranks(lhs, rhs, op);
+    let validity = combine_validities(validity(lhs).as_ref(), validity(rhs).as_ref());
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs == rhs` operation on [`DictionaryArray`].
+pub fn eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a == b)
+}
+
+/// Perform `lhs == rhs` operation on [`DictionaryArray`] and include validities in comparison.
+pub fn eq_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> BooleanArray {
+    let values = compare_ranks(lhs, rhs, |a, b| a == b);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_eq_validities(out, validity(lhs), validity(rhs))
+}
+
+/// Perform `lhs != rhs` operation on [`DictionaryArray`].
+pub fn neq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a != b)
+}
+
+/// Perform `lhs != rhs` operation on [`DictionaryArray`] and include validities in comparison.
+pub fn neq_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> BooleanArray {
+    let values = compare_ranks(lhs, rhs, |a, b| a != b);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_neq_validities(out, validity(lhs), validity(rhs))
+}
+
+/// Perform `lhs < rhs` operation on [`DictionaryArray`].
+pub fn lt<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a < b)
+}
+
+/// Perform `lhs <= rhs` operation on [`DictionaryArray`].
+pub fn lt_eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a <= b)
+}
+
+/// Perform `lhs > rhs` operation on [`DictionaryArray`].
+pub fn gt<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a > b)
+}
+
+/// Perform `lhs >= rhs` operation on [`DictionaryArray`].
+pub fn gt_eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |a, b| a >= b)
+}
+
+/// Returns `rhs`, a scalar of the type of `lhs` or of its values, as an array of the values of
+/// `lhs` with a single row, or `None` when `rhs` is null.
+fn scalar_value<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+) -> Option<Box<dyn Array>> {
+    let rhs = match rhs.as_any().downcast_ref::<DictionaryScalar<K>>() {
+        Some(rhs) => rhs.value()?.as_ref(),
+        None => rhs,
+    };
+    if !rhs.is_valid() {
+        return None;
+    }
+    // unions, the only scalars without an array, have no natural order
+    Some(scalar_to_array(rhs, lhs.values().data_type()).unwrap())
+}
+
+/// Evaluates `op` over the ordering of the value of every row of `lhs` and `rhs`, an array with
+/// a single row, ignoring their validities.
+/// # Panics
+/// iff the type of the values has no natural order.
+fn compare_scalar_values<K, F>(lhs: &DictionaryArray<K>, rhs: &dyn Array, op: F) -> Bitmap
+where
+    K: DictionaryKey,
+    F: Fn(Ordering) -> bool,
+{
+    let values = lhs.values().as_ref();
+    let compare = build_compare(values, rhs).unwrap();
+    let matches = (0..values.len())
+        .map(|value| op(compare(value, 0)))
+        .collect::<MutableBitmap>();
+    let matches: Bitmap = matches.into();
+
+    // null keys may be out of bounds
+    lhs.keys_iter()
+        .map(|key| key.map(|key| matches.get_bit(key)).unwrap_or(false))
+        .collect::<MutableBitmap>()
+        .into()
+}
+
+/// Evaluate `op(lhs, rhs)` for a [`DictionaryArray`] and a scalar using a specified comparison
+/// function of the ordering of their values. The result is null when `rhs` is null.
+fn compare_op_scalar<K, F>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar, op: F) -> BooleanArray
+where
+    K: DictionaryKey,
+    F: Fn(Ordering) -> bool,
+{
+    let Some(rhs) = scalar_value(lhs, rhs) else {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    };
+    let values = compare_scalar_values(lhs, rhs.as_ref(), op);
+    BooleanArray::new(DataType::Boolean, values, validity(lhs))
+}
+
+/// Perform `lhs == rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn eq_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_eq)
+}
+
+/// Perform `lhs == rhs` operation on a [`DictionaryArray`] and a scalar and include validities
+/// in comparison.
+pub fn eq_scalar_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+) -> BooleanArray {
+    let Some(rhs) = scalar_value(lhs, rhs) else {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    };
+    let values = compare_scalar_values(lhs, rhs.as_ref(), Ordering::is_eq);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_eq_validities(out, validity(lhs), None)
+}
+
+/// Perform `lhs != rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn neq_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_ne)
+}
+
+/// Perform `lhs != rhs` operation on a [`DictionaryArray`] and a scalar and include validities
+/// in comparison.
+pub fn neq_scalar_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+) -> BooleanArray {
+    let Some(rhs) = scalar_value(lhs, rhs) else {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    };
+    let values = compare_scalar_values(lhs, rhs.as_ref(), Ordering::is_ne);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_neq_validities(out, validity(lhs), None)
+}
+
+/// Perform `lhs < rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn lt_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_lt)
+}
+
+/// Perform `lhs <= rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn lt_eq_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_le)
+}
+
+/// Perform `lhs > rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn gt_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_gt)
+}
+
+/// Perform `lhs >= rhs` operation on a [`DictionaryArray`] and a scalar.
+pub fn gt_eq_scalar<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+    compare_op_scalar(lhs, rhs, Ordering::is_ge)
+}
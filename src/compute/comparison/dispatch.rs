@@ -0,0 +1,296 @@
+//! Type-erased comparisons of the dynamically typed functions, enabled by the feature
+//! `compute_dyn_dispatch`.
+//!
+//! Every physical type has a single [`Comparator`], whose functions evaluate any [`Operator`]
+//! at runtime, instead of one monomorphized kernel per operator and type. This trades the
+//! SIMD kernels of [`super::primitive`] for a much smaller binary and faster compilation.
+use std::cmp::Ordering;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+use crate::scalar::*;
+use crate::types::{days_ms, f16, i256, months_days_ns, NativeType};
+
+use super::super::utils::combine_validities;
+use super::{finish_eq_validities, finish_neq_validities};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    Neq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Operator {
+    #[inline]
+    fn eval(self, ordering: Option<Ordering>) -> bool {
+        use Ordering::*;
+        match (self, ordering) {
+            (Operator::Neq, ordering) => ordering != Some(Equal),
+            (Operator::Eq, Some(Equal))
+            | (Operator::Lt, Some(Less))
+            | (Operator::LtEq, Some(Less | Equal))
+            | (Operator::Gt, Some(Greater))
+            | (Operator::GtEq, Some(Greater | Equal)) => true,
+            _ => false,
+        }
+    }
+
+    fn is_ord(self) -> bool {
+        !matches!(self, Operator::Eq | Operator::Neq)
+    }
+}
+
+/// The type-erased comparisons of the values (ignoring validities) of arrays of a physical type.
+struct Comparator {
+    /// whether the values are ordered, i.e. support the operators other than `==` and `!=`
+    ord: bool,
+    array: fn(&dyn Array, &dyn Array, Operator) -> Bitmap,
+    scalar: fn(&dyn Array, &dyn Scalar, Operator) -> Bitmap,
+}
+
+fn compare<T, I>(lhs: I, rhs: I, op: Operator) -> Bitmap
+where
+    T: PartialOrd,
+    I: Iterator<Item = T>,
+{
+    lhs.zip(rhs)
+        .map(|(lhs, rhs)| op.eval(lhs.partial_cmp(&rhs)))
+        .collect()
+}
+
+fn compare_scalar<T, I>(lhs: I, rhs: T, op: Operator) -> Bitmap
+where
+    T: PartialOrd,
+    I: Iterator<Item = T>,
+{
+    lhs.map(|lhs| op.eval(lhs.partial_cmp(&rhs))).collect()
+}
+
+/// Wraps a [`NativeType`] without an order, so that it is only compared for equality.
+#[derive(PartialEq)]
+struct EqOnly<T>(T);
+
+impl<T: PartialEq> PartialOrd for EqOnly<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.0 == other.0).then_some(Ordering::Equal)
+    }
+}
+
+fn primitive<T: NativeType + PartialOrd>(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    compare(lhs.values().iter(), rhs.values().iter(), op)
+}
+
+fn primitive_scalar<T: NativeType + PartialOrd>(
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+    op: Operator,
+) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    compare_scalar(lhs.values().iter().copied(), rhs.value().unwrap(), op)
+}
+
+fn primitive_eq<T: NativeType>(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    compare(
+        lhs.values().iter().map(EqOnly),
+        rhs.values().iter().map(EqOnly),
+        op,
+    )
+}
+
+fn primitive_eq_scalar<T: NativeType>(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    compare_scalar(
+        lhs.values().iter().copied().map(EqOnly),
+        EqOnly(rhs.value().unwrap()),
+        op,
+    )
+}
+
+fn boolean(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+    compare(lhs.values_iter(), rhs.values_iter(), op)
+}
+
+fn boolean_scalar(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+    compare_scalar(lhs.values_iter(), rhs.value().unwrap(), op)
+}
+
+fn utf8<O: Offset>(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    compare(lhs.values_iter(), rhs.values_iter(), op)
+}
+
+fn utf8_scalar<O: Offset>(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    compare_scalar(lhs.values_iter(), rhs.value().unwrap(), op)
+}
+
+fn binary<O: Offset>(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    compare(lhs.values_iter(), rhs.values_iter(), op)
+}
+
+fn binary_scalar<O: Offset>(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Bitmap {
+    let lhs = lhs.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    compare_scalar(lhs.values_iter(), rhs.value().unwrap(), op)
+}
+
+macro_rules! comparator {
+    ($array:expr, $scalar:expr) => {
+        comparator!($array, $scalar, true)
+    };
+    ($array:expr, $scalar:expr, $ord:expr) => {
+        Some(&Comparator {
+            ord: $ord,
+            array: $array,
+            scalar: $scalar,
+        })
+    };
+}
+
+/// Returns the [`Comparator`] of a physical type that supports `op`.
+fn comparator(data_type: &DataType, op: Operator) -> Result<&'static Comparator> {
+    use PhysicalType::*;
+    let comparator = match data_type.to_physical_type() {
+        Boolean => comparator!(boolean, boolean_scalar),
+        Primitive(primitive_type) => {
+            use PrimitiveType::*;
+            match primitive_type {
+                Int8 => comparator!(primitive::<i8>, primitive_scalar::<i8>),
+                Int16 => comparator!(primitive::<i16>, primitive_scalar::<i16>),
+                Int32 => comparator!(primitive::<i32>, primitive_scalar::<i32>),
+                Int64 => comparator!(primitive::<i64>, primitive_scalar::<i64>),
+                Int128 => comparator!(primitive::<i128>, primitive_scalar::<i128>),
+                Int256 => comparator!(primitive::<i256>, primitive_scalar::<i256>),
+                UInt8 => comparator!(primitive::<u8>, primitive_scalar::<u8>),
+                UInt16 => comparator!(primitive::<u16>, primitive_scalar::<u16>),
+                UInt32 => comparator!(primitive::<u32>, primitive_scalar::<u32>),
+                UInt64 => comparator!(primitive::<u64>, primitive_scalar::<u64>),
+                Float16 => comparator!(primitive::<f16>, primitive_scalar::<f16>),
+                Float32 => comparator!(primitive::<f32>, primitive_scalar::<f32>),
+                Float64 => comparator!(primitive::<f64>, primitive_scalar::<f64>),
+                DaysMs => comparator!(
+                    primitive_eq::<days_ms>,
+                    primitive_eq_scalar::<days_ms>,
+                    false
+                ),
+                MonthDayNano => comparator!(
+                    primitive_eq::<months_days_ns>,
+                    primitive_eq_scalar::<months_days_ns>,
+                    false
+                ),
+            }
+        }
+        Utf8 => comparator!(utf8::<i32>, utf8_scalar::<i32>),
+        LargeUtf8 => comparator!(utf8::<i64>, utf8_scalar::<i64>),
+        Binary => comparator!(binary::<i32>, binary_scalar::<i32>),
+        LargeBinary => comparator!(binary::<i64>, binary_scalar::<i64>),
+        _ => None,
+    };
+    comparator
+        .filter(|comparator| comparator.ord || !op.is_ord())
+        .ok_or_else(|| {
+            Error::NotYetImplemented(format!(
+                "Comparison {:?} between {:?} is not yet supported",
+                op, data_type
+            ))
+        })
+}
+
+fn compare_values(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Result<Bitmap> {
+    assert_eq!(lhs.len(), rhs.len());
+    Ok((comparator(lhs.data_type(), op)?.array)(lhs, rhs, op))
+}
+
+fn compare_scalar_values(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Result<Bitmap> {
+    Ok((comparator(lhs.data_type(), op)?.scalar)(lhs, rhs, op))
+}
+
+macro_rules! compare_op {
+    ($name:ident, $op:ident) => {
+        pub(super) fn $name(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+            let values = compare_values(lhs, rhs, Operator::$op)?;
+            let validity = combine_validities(lhs.validity(), rhs.validity());
+            Ok(BooleanArray::new(DataType::Boolean, values, validity))
+        }
+    };
+}
+
+macro_rules! compare_scalar_op {
+    ($name:ident, $op:ident) => {
+        pub(super) fn $name(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+            let values = compare_scalar_values(lhs, rhs, Operator::$op)?;
+            Ok(BooleanArray::new(
+                DataType::Boolean,
+                values,
+                lhs.validity().cloned(),
+            ))
+        }
+    };
+}
+
+compare_op!(eq, Eq);
+compare_op!(neq, Neq);
+compare_op!(lt, Lt);
+compare_op!(lt_eq, LtEq);
+compare_op!(gt, Gt);
+compare_op!(gt_eq, GtEq);
+compare_scalar_op!(eq_scalar, Eq);
+compare_scalar_op!(neq_scalar, Neq);
+compare_scalar_op!(lt_scalar, Lt);
+compare_scalar_op!(lt_eq_scalar, LtEq);
+compare_scalar_op!(gt_scalar, Gt);
+compare_scalar_op!(gt_eq_scalar, GtEq);
+
+pub(super) fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    let values = compare_values(lhs, rhs, Operator::Eq)?;
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    Ok(finish_eq_validities(
+        out,
+        lhs.validity().cloned(),
+        rhs.validity().cloned(),
+    ))
+}
+
+pub(super) fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    let values = compare_values(lhs, rhs, Operator::Neq)?;
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    Ok(finish_neq_validities(
+        out,
+        lhs.validity().cloned(),
+        rhs.validity().cloned(),
+    ))
+}
+
+pub(super) fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    let values = compare_scalar_values(lhs, rhs, Operator::Eq)?;
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    Ok(finish_eq_validities(out, lhs.validity().cloned(), None))
+}
+
+pub(super) fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    let values = compare_scalar_values(lhs, rhs, Operator::Neq)?;
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    Ok(finish_neq_validities(out, lhs.validity().cloned(), None))
+}
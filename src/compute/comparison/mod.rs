@@ -10,6 +10,10 @@
 //! The statically typed are available under each module of this module (e.g. [`primitive::eq`], [`primitive::lt_scalar`])
 //! The dynamically typed are available in this module (e.g. [`eq`] or [`lt_scalar`]).
 //!
+//! With the feature `compute_dyn_dispatch`, the dynamically typed functions do not use the
+//! statically typed ones, but a single type-erased function per physical type, which reduces
+//! compile time and binary size at the expense of performance.
+//!
 //! # Examples
 //!
 //! Compare two [`PrimitiveArray`]s:
@@ -50,13 +54,18 @@ use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod decimal;
+pub mod dictionary;
+pub mod nested;
 pub mod primitive;
+pub mod total;
 pub mod utf8;
 
+#[cfg(feature = "compute_dyn_dispatch")]
+mod dispatch;
 mod simd;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
-use super::take::take_boolean;
 use crate::bitmap::{binary, Bitmap};
 use crate::compute;
 pub(crate) use primitive::{
@@ -64,12 +73,13 @@ pub(crate) use primitive::{
     compare_values_op_scalar as primitive_compare_values_op_scalar,
 };
 
+#[cfg_attr(feature = "compute_dyn_dispatch", allow(unused_macros))]
 macro_rules! match_eq_ord {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::i256;
+    use crate::types::{f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -83,12 +93,13 @@ macro_rules! match_eq_ord {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        Float16 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
 })}
 
+#[cfg_attr(feature = "compute_dyn_dispatch", allow(unused_macros))]
 macro_rules! match_eq {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -114,15 +125,12 @@ macro_rules! match_eq {(
     }
 })}
 
-macro_rules! compare {
+// Compares two arrays of a boolean, primitive, utf8 or binary type, with the typed kernels
+#[cfg(not(feature = "compute_dyn_dispatch"))]
+macro_rules! compare_values {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
-
         use crate::datatypes::PhysicalType::*;
         match lhs.data_type().to_physical_type() {
             Boolean => {
@@ -155,6 +163,51 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            // the callers only compare the physical types above
+            _ => unreachable!(),
+        }
+    }};
+}
+
+// Compares two arrays of a boolean, primitive, utf8 or binary type, with the type-erased
+// comparisons of `dispatch`
+#[cfg(feature = "compute_dyn_dispatch")]
+macro_rules! compare_values {
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {
+        dispatch::$op($lhs, $rhs).unwrap()
+    };
+}
+
+macro_rules! compare {
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if let (DataType::Decimal(_, _), DataType::Decimal(_, _)) = (
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type(),
+        ) {
+            // decimals of different precisions and scales are comparable
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            return decimal::$op(lhs, rhs);
+        }
+        assert_eq!(
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type()
+        );
+
+        use crate::datatypes::PhysicalType::*;
+        match lhs.data_type().to_physical_type() {
+            Boolean | Primitive(_) | Utf8 | LargeUtf8 | Binary | LargeBinary => {
+                compare_values!(lhs, rhs, $op, $p)
+            }
+            Dictionary(key_type) => {
+                match_integer_type!(key_type, |$T| {
+                    let lhs = lhs.as_any().downcast_ref().unwrap();
+                    let rhs = rhs.as_any().downcast_ref().unwrap();
+                    dictionary::$op::<$T>(lhs, rhs)
+                })
+            }
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -171,6 +224,9 @@ macro_rules! compare {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::eq(lhs, rhs);
+    }
     compare!(lhs, rhs, eq, match_eq)
 }
 
@@ -182,6 +238,9 @@ pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::eq_and_validity(lhs, rhs);
+    }
     compare!(lhs, rhs, eq_and_validity, match_eq)
 }
 
@@ -198,6 +257,9 @@ pub fn can_eq(data_type: &DataType) -> bool {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::neq(lhs, rhs);
+    }
     compare!(lhs, rhs, neq, match_eq)
 }
 
@@ -209,6 +271,9 @@ pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::neq_and_validity(lhs, rhs);
+    }
     compare!(lhs, rhs, neq_and_validity, match_eq)
 }
 
@@ -281,24 +346,19 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
-macro_rules! compare_scalar {
+// Compares an array and a scalar of a boolean, primitive, utf8 or binary type, with the typed
+// kernels
+#[cfg(not(feature = "compute_dyn_dispatch"))]
+macro_rules! compare_scalar_values {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
-        if !rhs.is_valid() {
-            return BooleanArray::new_null(DataType::Boolean, lhs.len());
-        }
-
         use crate::datatypes::PhysicalType::*;
         match lhs.data_type().to_physical_type() {
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
-                // validity checked above
+                // the validity of the scalar is checked by the caller
                 boolean::$op(lhs, rhs.value().unwrap())
             }
             Primitive(primitive) => $p!(primitive, |$T| {
@@ -326,13 +386,53 @@ macro_rules! compare_scalar {
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
                 binary::$op::<i64>(lhs, rhs.value().unwrap())
             }
-            Dictionary(key_type) => {
-                match_integer_type!(key_type, |$T| {
-                    let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
-                    let values = $op(lhs.values().as_ref(), rhs);
+            // the callers only compare the physical types above
+            _ => unreachable!(),
+        }
+    }};
+}
 
-                    take_boolean(&values, lhs.keys())
-                })
+// Compares an array and a scalar of a boolean, primitive, utf8 or binary type, with the
+// type-erased comparisons of `dispatch`
+#[cfg(feature = "compute_dyn_dispatch")]
+macro_rules! compare_scalar_values {
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {
+        dispatch::$op($lhs, $rhs).unwrap()
+    };
+}
+
+macro_rules! compare_scalar {
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if let crate::datatypes::PhysicalType::Dictionary(key_type) =
+            lhs.data_type().to_physical_type()
+        {
+            return match_integer_type!(key_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                dictionary::$op::<$T>(lhs, rhs)
+            });
+        }
+        if let (DataType::Decimal(_, _), DataType::Decimal(_, _)) = (
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type(),
+        ) {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            return decimal::$op(lhs, rhs);
+        }
+        assert_eq!(
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type()
+        );
+        if !rhs.is_valid() {
+            return BooleanArray::new_null(DataType::Boolean, lhs.len());
+        }
+
+        use crate::datatypes::PhysicalType::*;
+        match lhs.data_type().to_physical_type() {
+            Boolean | Primitive(_) | Utf8 | LargeUtf8 | Binary | LargeBinary => {
+                compare_scalar_values!(lhs, rhs, $op, $p)
             }
             _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
         }
@@ -346,6 +446,9 @@ macro_rules! compare_scalar {
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
 pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::eq_scalar(lhs, rhs);
+    }
     compare_scalar!(lhs, rhs, eq_scalar, match_eq)
 }
 
@@ -371,6 +474,9 @@ pub fn can_eq_scalar(data_type: &DataType) -> bool {
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
 pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::neq_scalar(lhs, rhs);
+    }
     compare_scalar!(lhs, rhs, neq_scalar, match_eq)
 }
 
@@ -459,8 +565,13 @@ fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        // dictionaries are compared by the order of their values
+        return can_partial_eq_and_ord(values.as_ref())
+            && !matches!(values.as_ref(), DataType::Timestamp(_, Some(_)));
+    }
     matches!(
-        data_type,
+        data_type.to_logical_type(),
         DataType::Boolean
             | DataType::Int8
             | DataType::Int16
@@ -477,6 +588,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
@@ -489,18 +601,19 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord(data_type)
+    nested::is_nested(data_type)
+        || can_partial_eq_and_ord(data_type)
         || matches!(
             data_type.to_logical_type(),
-            DataType::Float16
-                | DataType::Interval(IntervalUnit::DayTime)
+            DataType::Interval(IntervalUnit::DayTime)
                 | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
 
 // The list of operations currently supported.
 fn can_partial_eq_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
+    nested::is_nested(data_type)
+        || can_partial_eq_and_ord_scalar(data_type)
         || matches!(
             data_type.to_logical_type(),
             DataType::Interval(IntervalUnit::DayTime)
@@ -0,0 +1,239 @@
+//! Comparison functions for nested arrays: [`StructArray`], [`ListArray`],
+//! [`FixedSizeListArray`] and [`MapArray`].
+//!
+//! Rows are compared recursively, field by field and item by item. As in the other comparisons,
+//! a null row of either side results in null, while nested nulls (e.g. null fields or items)
+//! are equal to each other and different from any value, as in [`Array`]'s `PartialEq`. Maps
+//! are compared as lists of entries, i.e. their entries must be in the same order.
+//! ```
+//! use arrow2::array::{Array, BooleanArray, Int32Array, StructArray};
+//! use arrow2::compute::comparison::nested::eq;
+//! use arrow2::datatypes::{DataType, Field};
+//!
+//! let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+//! let lhs = StructArray::new(
+//!     data_type.clone(),
+//!     vec![Int32Array::from([Some(1), None, None]).boxed()],
+//!     None,
+//! );
+//! let rhs = StructArray::new(
+//!     data_type,
+//!     vec![Int32Array::from([Some(1), None, Some(3)]).boxed()],
+//!     None,
+//! );
+//! assert_eq!(eq(&lhs, &rhs), BooleanArray::from_slice([true, true, false]));
+//! ```
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::offset::Offset;
+use crate::scalar::{new_scalar, Scalar, StructScalar};
+
+use super::super::utils::combine_validities;
+
+/// Returns whether the row `i` of an array equals the row `j` of another array.
+type RowEq<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+/// Returns a [`RowEq`] of `lhs` and `rhs`, where two null rows are equal.
+fn build_eq<'a>(lhs: &'a dyn Array, rhs: &'a dyn Array) -> RowEq<'a> {
+    let values = build_eq_values(lhs, rhs);
+    if lhs.validity().is_none() && rhs.validity().is_none() {
+        return values;
+    }
+    Box::new(move |i, j| match (lhs.is_valid(i), rhs.is_valid(j)) {
+        (true, true) => values(i, j),
+        (false, false) => true,
+        _ => false,
+    })
+}
+
+fn build_eq_list<'a, O: Offset>(lhs: &'a ListArray<O>, rhs: &'a ListArray<O>) -> RowEq<'a> {
+    let items = build_eq(lhs.values().as_ref(), rhs.values().as_ref());
+    Box::new(move |i, j| {
+        let (lhs_start, lhs_end) = lhs.offsets().start_end(i);
+        let (rhs_start, rhs_end) = rhs.offsets().start_end(j);
+        lhs_end - lhs_start == rhs_end - rhs_start
+            && (0..lhs_end - lhs_start).all(|k| items(lhs_start + k, rhs_start + k))
+    })
+}
+
+/// Returns a [`RowEq`] of `lhs` and `rhs`, ignoring their validities.
+fn build_eq_values<'a>(lhs: &'a dyn Array, rhs: &'a dyn Array) -> RowEq<'a> {
+    macro_rules! values {
+        ($array:ty) => {{
+            let lhs = lhs.as_any().downcast_ref::<$array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<$array>().unwrap();
+            Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+        }};
+    }
+    use PhysicalType::*;
+    match lhs.data_type().to_physical_type() {
+        Null => Box::new(|_, _| true),
+        Boolean => values!(BooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            values!(PrimitiveArray<$T>)
+        }),
+        Binary => values!(BinaryArray<i32>),
+        LargeBinary => values!(BinaryArray<i64>),
+        FixedSizeBinary => values!(FixedSizeBinaryArray),
+        Utf8 => values!(Utf8Array<i32>),
+        LargeUtf8 => values!(Utf8Array<i64>),
+        Struct => {
+            let lhs = lhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = lhs
+                .values()
+                .iter()
+                .zip(rhs.values())
+                .map(|(lhs, rhs)| build_eq(lhs.as_ref(), rhs.as_ref()))
+                .collect::<Vec<_>>();
+            Box::new(move |i, j| fields.iter().all(|field| field(i, j)))
+        }
+        List => build_eq_list::<i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        LargeList => build_eq_list::<i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        ),
+        FixedSizeList => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = lhs.size();
+            let items = build_eq(lhs.values().as_ref(), rhs.values().as_ref());
+            Box::new(move |i, j| (0..size).all(|k| items(i * size + k, j * size + k)))
+        }
+        Map => {
+            let lhs = lhs.as_any().downcast_ref::<MapArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<MapArray>().unwrap();
+            let entries = build_eq(lhs.field().as_ref(), rhs.field().as_ref());
+            Box::new(move |i, j| {
+                let (lhs_start, lhs_end) = lhs.offsets().start_end(i);
+                let (rhs_start, rhs_end) = rhs.offsets().start_end(j);
+                lhs_end - lhs_start == rhs_end - rhs_start
+                    && (0..lhs_end - lhs_start).all(|k| entries(lhs_start + k, rhs_start + k))
+            })
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref());
+            Box::new(move |i, j| values(lhs.key_value(i), rhs.key_value(j)))
+        }),
+        Union => Box::new(move |i, j| lhs.sliced(i, 1) == rhs.sliced(j, 1)),
+    }
+}
+
+/// Returns whether every row of `lhs` equals the row of `rhs`, ignoring their validities.
+fn eq_values(lhs: &dyn Array, rhs: &dyn Array) -> Bitmap {
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+    let eq = build_eq_values(lhs, rhs);
+    (0..lhs.len())
+        .map(|row| eq(row, row))
+        .collect::<MutableBitmap>()
+        .into()
+}
+
+/// Returns whether every row of `lhs` equals `rhs`, where null rows equal a null `rhs`.
+fn eq_scalar_values(lhs: &dyn Array, rhs: &dyn Scalar) -> Bitmap {
+    if !rhs.is_valid() {
+        return (0..lhs.len())
+            .map(|row| !lhs.is_valid(row))
+            .collect::<MutableBitmap>()
+            .into();
+    }
+    if let Some(rhs) = rhs.as_any().downcast_ref::<StructScalar>() {
+        // compare field by field, without allocating a scalar per row
+        let lhs = lhs.as_any().downcast_ref::<StructArray>().unwrap();
+        let mut values = Bitmap::new_trued(lhs.len());
+        for (field, value) in lhs.values().iter().zip(rhs.values()) {
+            values = &values & &eq_scalar_values(field.as_ref(), value.as_ref());
+        }
+        return match lhs.validity() {
+            Some(validity) => &values & validity,
+            None => values,
+        };
+    }
+    (0..lhs.len())
+        .map(|row| lhs.is_valid(row) && new_scalar(lhs, row).as_ref() == rhs)
+        .collect::<MutableBitmap>()
+        .into()
+}
+
+/// Perform `lhs == rhs` operation on two nested arrays.
+/// # Panics
+/// iff the arrays do not have the same logical type or length.
+pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let values = eq_values(lhs, rhs);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs == rhs` operation on two nested arrays and include validities in comparison.
+/// # Panics
+/// iff the arrays do not have the same logical type or length.
+pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let values = eq_values(lhs, rhs);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on two nested arrays.
+/// # Panics
+/// iff the arrays do not have the same logical type or length.
+pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let values = !&eq_values(lhs, rhs);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs != rhs` operation on two nested arrays and include validities in comparison.
+/// # Panics
+/// iff the arrays do not have the same logical type or length.
+pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let values = !&eq_values(lhs, rhs);
+    let out = BooleanArray::new(DataType::Boolean, values, None);
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Perform `lhs == rhs` operation on a nested array and a scalar, e.g. a struct literal.
+/// # Panics
+/// iff the array and the scalar do not have the same logical type.
+pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    if !rhs.is_valid() {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    }
+    let values = eq_scalar_values(lhs, rhs);
+    BooleanArray::new(DataType::Boolean, values, lhs.validity().cloned())
+}
+
+/// Perform `lhs != rhs` operation on a nested array and a scalar, e.g. a struct literal.
+/// # Panics
+/// iff the array and the scalar do not have the same logical type.
+pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    let eq = eq_scalar(lhs, rhs);
+    let (_, values, validity) = eq.into_inner();
+    BooleanArray::new(DataType::Boolean, !&values, validity)
+}
+
+/// Returns whether `data_type` is a nested type compared by this module.
+pub(super) fn is_nested(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+    )
+}
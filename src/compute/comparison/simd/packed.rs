@@ -72,8 +72,7 @@ simd8!(i32, i32x8);
 simd8!(i64, i64x8);
 simd8_native_all!(i128);
 simd8_native_all!(i256);
-simd8_native!(f16);
-simd8_native_partial_eq!(f16);
+simd8_native_all!(f16);
 simd8!(f32, f32x8);
 simd8!(f64, f64x8);
 simd8_native!(days_ms);
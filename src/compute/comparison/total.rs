@@ -0,0 +1,110 @@
+//! Comparison functions for floating point [`PrimitiveArray`]s following
+//! the IEEE 754 total order.
+//!
+//! Contrarily to [`super::primitive`], whose comparisons follow [`PartialOrd`], under this order
+//! `NaN`s are canonicalised, so that
+//! * `NaN == NaN` (regardless of their sign and payload)
+//! * `NaN` is larger than any other value
+//! * `-0.0 < 0.0`
+//!
+//! Its equality is the equality of [`crate::array::Array`]s of floats, and, up to the
+//! canonicalisation of `NaN`s, its order is the order used by [`crate::compute::sort`].
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{
+    array::{ord, BooleanArray, PrimitiveArray},
+    bitmap::Bitmap,
+    datatypes::DataType,
+    types::NativeType,
+};
+
+use super::super::utils::combine_validities;
+
+/// Trait implemented by the floating point types with an IEEE 754 total order.
+pub trait TotalOrd: NativeType {
+    /// Compares `self` and `other` according to the IEEE 754 total order, after
+    /// canonicalising `NaN`s.
+    fn tot_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// Compares `lhs` and `rhs` under the total order `total_cmp` after canonicalising `NaN`s.
+#[inline]
+fn canonical_cmp<T: Float>(lhs: &T, rhs: &T, total_cmp: fn(&T, &T) -> Ordering) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => total_cmp(lhs, rhs),
+    }
+}
+
+impl TotalOrd for f32 {
+    #[inline]
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        canonical_cmp(self, other, ord::total_cmp_f32)
+    }
+}
+
+impl TotalOrd for f64 {
+    #[inline]
+    fn tot_cmp(&self, other: &Self) -> Ordering {
+        canonical_cmp(self, other, ord::total_cmp_f64)
+    }
+}
+
+fn compare_op<T, F>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>, op: F) -> BooleanArray
+where
+    T: TotalOrd,
+    F: Fn(Ordering) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| op(lhs.tot_cmp(rhs)));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn compare_op_scalar<T, F>(lhs: &PrimitiveArray<T>, rhs: T, op: F) -> BooleanArray
+where
+    T: TotalOrd,
+    F: Fn(Ordering) -> bool,
+{
+    let values = lhs.values().iter().map(|lhs| op(lhs.tot_cmp(&rhs)));
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    BooleanArray::new(DataType::Boolean, values, lhs.validity().cloned())
+}
+
+macro_rules! total_ops {
+    ($name:ident, $name_scalar:ident, $op:literal, $pattern:pat) => {
+        #[doc = concat!("Perform `lhs ", $op, " rhs` operation on two arrays according to the total order.")]
+        pub fn $name<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray {
+            compare_op(lhs, rhs, |ordering| matches!(ordering, $pattern))
+        }
+
+        #[doc = concat!("Perform `lhs ", $op, " rhs` operation on an array and a scalar value according to the total order.")]
+        pub fn $name_scalar<T: TotalOrd>(lhs: &PrimitiveArray<T>, rhs: T) -> BooleanArray {
+            compare_op_scalar(lhs, rhs, |ordering| matches!(ordering, $pattern))
+        }
+    };
+}
+
+total_ops!(eq, eq_scalar, "==", Ordering::Equal);
+total_ops!(neq, neq_scalar, "!=", Ordering::Less | Ordering::Greater);
+total_ops!(lt, lt_scalar, "<", Ordering::Less);
+total_ops!(lt_eq, lt_eq_scalar, "<=", Ordering::Less | Ordering::Equal);
+total_ops!(gt, gt_scalar, ">", Ordering::Greater);
+total_ops!(
+    gt_eq,
+    gt_eq_scalar,
+    ">=",
+    Ordering::Greater | Ordering::Equal
+);
@@ -13,11 +13,17 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use crate::array::growable::{make_growable_with_capacities, Capacities};
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, IntegerType, PhysicalType};
 use crate::error::{Error, Result};
+use crate::offset::{Offset, OffsetsBuffer};
 
-/// Concatenate multiple [Array] of the same type into a single [`Array`].
-pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+use super::unique::{can_unique, unique};
+
+fn check_arrays(arrays: &[&dyn Array]) -> Result<()> {
     if arrays.is_empty() {
         return Err(Error::InvalidArgumentError(
             "concat requires input of at least one array".to_string(),
@@ -32,15 +38,378 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
             "It is not possible to concatenate arrays of different data types.".to_string(),
         ));
     }
+    Ok(())
+}
+
+/// Returns the validity spanning `validities` without copying, or `None` if they are not
+/// adjacent slices of the same [`Bitmap`].
+fn join_validities<'a, I>(mut validities: I) -> Option<Option<Bitmap>>
+where
+    I: Iterator<Item = Option<&'a Bitmap>>,
+{
+    let first = validities.next()?.cloned();
+    validities.try_fold(first, |joined, validity| match (joined, validity) {
+        (None, None) => Some(None),
+        (Some(joined), Some(validity)) => joined.try_join(validity).map(Some),
+        _ => None,
+    })
+}
+
+fn join_buffers<'a, T: Clone + 'a, I>(mut buffers: I) -> Option<Buffer<T>>
+where
+    I: Iterator<Item = &'a Buffer<T>>,
+{
+    let first = buffers.next()?.clone();
+    buffers.try_fold(first, |joined, buffer| joined.try_join(buffer))
+}
+
+fn join_offsets<'a, O: Offset, I>(mut offsets: I) -> Option<OffsetsBuffer<O>>
+where
+    I: Iterator<Item = &'a OffsetsBuffer<O>>,
+{
+    let first = offsets.next()?.clone();
+    offsets.try_fold(first, |joined, offsets| joined.try_join(offsets))
+}
+
+/// Whether all `buffers` are the same region of the same [`Buffer`].
+fn same_buffers<T>(buffers: &[&Buffer<T>]) -> bool {
+    buffers.iter().all(|buffer| {
+        buffer.as_ptr() == buffers[0].as_ptr()
+            && buffer.offset() == buffers[0].offset()
+            && buffer.len() == buffers[0].len()
+    })
+}
+
+macro_rules! join_binary {
+    ($array:ty, $arrays:expr) => {{
+        let arrays = $arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<$array>().unwrap())
+            .collect::<Vec<_>>();
+        let values = arrays
+            .iter()
+            .map(|array| array.values())
+            .collect::<Vec<_>>();
+        if !same_buffers(&values) {
+            return None;
+        }
+        let offsets = join_offsets(arrays.iter().map(|array| array.offsets()))?;
+        let validity = join_validities(arrays.iter().map(|array| array.validity()))?;
+        <$array>::new(
+            arrays[0].data_type().clone(),
+            offsets,
+            values[0].clone(),
+            validity,
+        )
+        .boxed()
+    }};
+}
+
+/// Returns `arrays` as a single array without copying, iff they are adjacent slices of the
+/// same array (e.g. the chunks of an array split with [`Array::sliced`]).
+fn try_join(arrays: &[&dyn Array]) -> Option<Box<dyn Array>> {
+    use PhysicalType::*;
+    Some(match arrays[0].data_type().to_physical_type() {
+        Boolean => {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<BooleanArray>().unwrap())
+                .collect::<Vec<_>>();
+            let values = arrays.iter().map(|array| array.values());
+            let values = join_validities(values.map(Some))??;
+            let validity = join_validities(arrays.iter().map(|array| array.validity()))?;
+            BooleanArray::new(arrays[0].data_type().clone(), values, validity).boxed()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap())
+                .collect::<Vec<_>>();
+            let values = join_buffers(arrays.iter().map(|array| array.values()))?;
+            let validity = join_validities(arrays.iter().map(|array| array.validity()))?;
+            PrimitiveArray::<$T>::new(arrays[0].data_type().clone(), values, validity).boxed()
+        }),
+        Utf8 => join_binary!(Utf8Array<i32>, arrays),
+        LargeUtf8 => join_binary!(Utf8Array<i64>, arrays),
+        Binary => join_binary!(BinaryArray<i32>, arrays),
+        LargeBinary => join_binary!(BinaryArray<i64>, arrays),
+        _ => return None,
+    })
+}
+
+/// The number of bytes spanned by the offsets of a binary or string array.
+fn values_len<O: Offset>(offsets: &OffsetsBuffer<O>) -> usize {
+    (*offsets.last() - *offsets.first()).to_usize()
+}
+
+/// The items of every row of a list or map array, i.e. its values sliced to its offsets.
+fn items<O: Offset>(offsets: &OffsetsBuffer<O>, values: &dyn Array) -> Box<dyn Array> {
+    let start = offsets.first().to_usize();
+    values.sliced(start, offsets.last().to_usize() - start)
+}
+
+/// The exact capacities of the concatenation of `arrays`, including the number of bytes of
+/// strings and binaries and the capacities of the children of nested arrays.
+fn capacities(arrays: &[&dyn Array]) -> Capacities {
+    macro_rules! downcast {
+        ($array:ty) => {
+            arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<$array>().unwrap())
+        };
+    }
+    macro_rules! nested {
+        ($children:expr) => {{
+            let children = $children.collect::<Vec<_>>();
+            capacities(&children.iter().map(|x| x.as_ref()).collect::<Vec<_>>())
+        }};
+    }
 
-    let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
-    let capacity = lengths.iter().sum();
+    let length = arrays.iter().map(|array| array.len()).sum();
+    use PhysicalType::*;
+    match arrays[0].data_type().to_physical_type() {
+        Utf8 => Capacities::Binary(
+            length,
+            downcast!(Utf8Array<i32>)
+                .map(|array| values_len(array.offsets()))
+                .sum(),
+        ),
+        LargeUtf8 => Capacities::Binary(
+            length,
+            downcast!(Utf8Array<i64>)
+                .map(|array| values_len(array.offsets()))
+                .sum(),
+        ),
+        Binary => Capacities::Binary(
+            length,
+            downcast!(BinaryArray<i32>)
+                .map(|array| values_len(array.offsets()))
+                .sum(),
+        ),
+        LargeBinary => Capacities::Binary(
+            length,
+            downcast!(BinaryArray<i64>)
+                .map(|array| values_len(array.offsets()))
+                .sum(),
+        ),
+        List => Capacities::List(
+            length,
+            Box::new(nested!(downcast!(ListArray<i32>)
+                .map(|array| items(array.offsets(), array.values().as_ref())))),
+        ),
+        LargeList => Capacities::List(
+            length,
+            Box::new(nested!(downcast!(ListArray<i64>)
+                .map(|array| items(array.offsets(), array.values().as_ref())))),
+        ),
+        FixedSizeList => Capacities::List(
+            length,
+            Box::new(nested!(
+                downcast!(FixedSizeListArray).map(|array| array.values().clone())
+            )),
+        ),
+        Map => Capacities::List(
+            length,
+            Box::new(nested!(
+                downcast!(MapArray).map(|array| items(array.offsets(), array.field().as_ref()))
+            )),
+        ),
+        Struct => {
+            let fields = (0..arrays[0]
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap()
+                .values()
+                .len())
+                .map(|i| nested!(downcast!(StructArray).map(|array| array.values()[i].clone())))
+                .collect();
+            Capacities::Struct(length, fields)
+        }
+        _ => Capacities::Array(length),
+    }
+}
 
-    let mut mutable = make_growable(arrays, false, capacity);
+/// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// The concatenation of a single array, or of adjacent slices of the same array of booleans,
+/// primitives, strings or binaries, shares the buffers of the arrays instead of copying them.
+/// Otherwise, the concatenation is pre-allocated with the exact capacities of its buffers
+/// (including the bytes of strings and binaries and the children of nested arrays).
+pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    check_arrays(arrays)?;
+    if arrays.len() == 1 {
+        return Ok(arrays[0].to_boxed());
+    }
+    if let Some(array) = try_join(arrays) {
+        return Ok(array);
+    }
+
+    let mut mutable = make_growable_with_capacities(arrays, false, capacities(arrays));
 
-    for (i, len) in lengths.iter().enumerate() {
-        mutable.extend(i, 0, *len)
+    for (i, array) in arrays.iter().enumerate() {
+        mutable.extend(i, 0, array.len())
     }
 
     Ok(mutable.as_box())
 }
+
+/// Returns the next wider key type of `key_type`, of the same signedness.
+fn wider_key_type(key_type: IntegerType) -> Option<IntegerType> {
+    use IntegerType::*;
+    match key_type {
+        Int8 => Some(Int16),
+        Int16 => Some(Int32),
+        Int32 => Some(Int64),
+        UInt8 => Some(UInt16),
+        UInt16 => Some(UInt32),
+        UInt32 => Some(UInt64),
+        Int64 | UInt64 => None,
+    }
+}
+
+fn new_dictionary<K: DictionaryKey>(
+    keys: Vec<usize>,
+    validity: Option<MutableBitmap>,
+    values: Box<dyn Array>,
+    is_sorted: bool,
+) -> Result<Box<dyn Array>> {
+    let keys = keys
+        .into_iter()
+        // the keys were checked to fit in `K`
+        .map(|key| K::try_from(key).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let keys =
+        PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity.map(|x| x.into()));
+    let data_type =
+        DataType::Dictionary(K::KEY_TYPE, Box::new(values.data_type().clone()), is_sorted);
+    Ok(DictionaryArray::try_new(data_type, keys, values)?.boxed())
+}
+
+fn concatenate_dictionaries_typed<K: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+    promote_keys: bool,
+) -> Result<Box<dyn Array>> {
+    let first = arrays[0].values();
+    let first_type = arrays[0].data_type();
+    let same_values = arrays.iter().all(|array| array.values() == first);
+    // the values, the offset of the values of every array in them, and the inverse indices that
+    // map the offset values to the deduplicated values
+    let (values, offsets, inverse) = if same_values {
+        (first.clone(), vec![0; arrays.len()], None)
+    } else {
+        let values = arrays
+            .iter()
+            .map(|array| array.values().as_ref())
+            .collect::<Vec<_>>();
+        let offsets = values
+            .iter()
+            .scan(0, |offset, values| {
+                let current = *offset;
+                *offset += values.len();
+                Some(current)
+            })
+            .collect();
+        let values = concatenate(&values)?;
+        if can_unique(values.data_type()) {
+            let (values, inverse) = unique(values.as_ref(), true)?;
+            let inverse = inverse
+                .unwrap()
+                .values()
+                .iter()
+                .map(|x| *x as usize)
+                .collect::<Vec<_>>();
+            (values, offsets, Some(inverse))
+        } else {
+            (values, offsets, None)
+        }
+    };
+
+    let length = arrays.iter().map(|array| array.len()).sum();
+    let mut keys = Vec::with_capacity(length);
+    let mut validity = arrays
+        .iter()
+        .any(|array| array.null_count() > 0)
+        .then(|| MutableBitmap::with_capacity(length));
+    for (array, offset) in arrays.iter().zip(offsets) {
+        for key in array.keys_iter() {
+            let key = key.map(|key| {
+                let key = offset + key;
+                inverse.as_ref().map(|inverse| inverse[key]).unwrap_or(key)
+            });
+            keys.push(key.unwrap_or_default());
+            if let Some(validity) = validity.as_mut() {
+                validity.push(key.is_some());
+            }
+        }
+    }
+
+    // a shared dictionary remains sorted
+    let is_sorted = same_values && matches!(first_type, DataType::Dictionary(_, _, true));
+    let max_key = values.len().saturating_sub(1);
+    let mut key_type = K::KEY_TYPE;
+    while !match_integer_type!(key_type, |$T| $T::try_from(max_key).is_ok()) {
+        key_type = match wider_key_type(key_type) {
+            Some(wider) if promote_keys => wider,
+            _ => {
+                return Err(Error::InvalidArgumentError(format!(
+                "The concatenated dictionary has {} values, more than keys of type {:?} can index",
+                values.len(),
+                key_type
+            )))
+            }
+        };
+    }
+    match_integer_type!(key_type, |$T| {
+        new_dictionary::<$T>(keys, validity, values, is_sorted)
+    })
+}
+
+/// Concatenates dictionary arrays of the same type into a single dictionary array, merging
+/// their dictionaries.
+///
+/// When the arrays share the same dictionary, only their keys are concatenated. Otherwise,
+/// the values of the dictionaries are deduplicated (when [`can_unique`] supports their type)
+/// and the keys are re-mapped to the merged dictionary, in the order of first occurrence.
+///
+/// When the merged dictionary has more values than the key type can index, the keys are
+/// promoted to the next wider integer type of the same signedness if `promote_keys` is `true`.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays are not dictionary arrays of the same
+/// type, or the merged dictionary is too large for the (promoted) key type.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, DictionaryArray, MutableDictionaryArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::concatenate::concatenate_dictionaries;
+///
+/// let dictionary = |values: &[&str]| -> DictionaryArray<u8> {
+///     let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+///     array.try_extend(values.iter().map(Some)).unwrap();
+///     array.into()
+/// };
+/// let a = dictionary(&["a", "b", "a"]);
+/// let b = dictionary(&["c", "b"]);
+///
+/// let result = concatenate_dictionaries(&[&a, &b], false).unwrap();
+/// let result = result.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+/// assert_eq!(result.keys().values().as_slice(), &[0, 1, 0, 2, 1]);
+/// assert_eq!(result.values().len(), 3);
+/// ```
+pub fn concatenate_dictionaries(
+    arrays: &[&dyn Array],
+    promote_keys: bool,
+) -> Result<Box<dyn Array>> {
+    check_arrays(arrays)?;
+    let PhysicalType::Dictionary(key_type) = arrays[0].data_type().to_physical_type() else {
+        return Err(Error::InvalidArgumentError(format!(
+            "Expected dictionary arrays, but got {:?}",
+            arrays[0].data_type()
+        )));
+    };
+    match_integer_type!(key_type, |$T| {
+        let arrays = arrays
+            .iter()
+            .map(|array| array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap())
+            .collect::<Vec<_>>();
+        concatenate_dictionaries_typed(&arrays, promote_keys)
+    })
+}
@@ -0,0 +1,214 @@
+//! Contains cumulative (running) operators, such as [`cumsum`] and [`cummax`], that return for
+//! every slot the aggregate of all slots up to and including it.
+//!
+//! How null slots are handled is set by [`CumulativeNulls`].
+use crate::array::{Array, PrimitiveArray};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::types::{NativeType, WrappingArithmetic};
+
+/// How the cumulative operators handle null slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CumulativeNulls {
+    /// A null slot is null and ignored by the aggregate so far: the following slots aggregate
+    /// the non-null slots before them.
+    #[default]
+    Skip,
+    /// A null slot poisons the aggregate: it and all slots after it are null.
+    Propagate,
+}
+
+fn cumulative<T, F>(array: &PrimitiveArray<T>, nulls: CumulativeNulls, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let validity = array
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0);
+    let values = array.values().as_slice();
+
+    let scan = |values: &[T]| -> Vec<T> {
+        let mut acc = None;
+        values
+            .iter()
+            .map(|x| {
+                let value = acc.map_or(*x, |acc| op(acc, *x));
+                acc = Some(value);
+                value
+            })
+            .collect()
+    };
+
+    let (values, validity) = match (validity, nulls) {
+        (None, _) => (scan(values), None),
+        (Some(validity), CumulativeNulls::Skip) => {
+            let mut acc: Option<T> = None;
+            let values = values
+                .iter()
+                .zip(validity.iter())
+                .map(|(x, is_valid)| {
+                    if is_valid {
+                        acc = Some(acc.map_or(*x, |acc| op(acc, *x)));
+                    }
+                    acc.unwrap_or_default()
+                })
+                .collect();
+            (values, Some(validity.clone()))
+        }
+        (Some(validity), CumulativeNulls::Propagate) => {
+            let valid = validity.iter().take_while(|is_valid| *is_valid).count();
+            let mut result = scan(&values[..valid]);
+            result.resize(values.len(), T::default());
+
+            let mut validity = MutableBitmap::with_capacity(values.len());
+            validity.extend_constant(valid, true);
+            validity.extend_constant(values.len() - valid, false);
+            (result, Some(validity.into()))
+        }
+    };
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns the cumulative sum of `array`. Integer overflows wrap around
+/// (see [`WrappingArithmetic`]).
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::cumulative::{cumsum, CumulativeNulls};
+///
+/// let array = Int32Array::from([Some(1), None, Some(2), Some(3)]);
+///
+/// let result = cumsum(&array, CumulativeNulls::Skip);
+/// assert_eq!(result, Int32Array::from([Some(1), None, Some(3), Some(6)]));
+///
+/// let result = cumsum(&array, CumulativeNulls::Propagate);
+/// assert_eq!(result, Int32Array::from([Some(1), None, None, None]));
+/// ```
+pub fn cumsum<T>(array: &PrimitiveArray<T>, nulls: CumulativeNulls) -> PrimitiveArray<T>
+where
+    T: WrappingArithmetic,
+{
+    cumulative(array, nulls, T::wrapping_add)
+}
+
+/// Returns the cumulative product of `array`. Integer overflows wrap around
+/// (see [`WrappingArithmetic`]).
+pub fn cumprod<T>(array: &PrimitiveArray<T>, nulls: CumulativeNulls) -> PrimitiveArray<T>
+where
+    T: WrappingArithmetic,
+{
+    cumulative(array, nulls, T::wrapping_mul)
+}
+
+/// Returns the cumulative minimum of `array`. `NaN`s are ignored, unless
+/// the first value is `NaN`.
+pub fn cummin<T>(array: &PrimitiveArray<T>, nulls: CumulativeNulls) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, nulls, |acc, x| if x < acc { x } else { acc })
+}
+
+/// Returns the cumulative maximum of `array`. `NaN`s are ignored, unless
+/// the first value is `NaN`.
+pub fn cummax<T>(array: &PrimitiveArray<T>, nulls: CumulativeNulls) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    cumulative(array, nulls, |acc, x| if x > acc { x } else { acc })
+}
+
+/// Returns whether the operators of this module can be applied to arrays of `data_type`.
+pub fn can_cumulative(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+    )
+}
+
+// `can_cumulative` restricts the arrays reaching this to the types below
+macro_rules! with_match_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => unreachable!(),
+    }
+})}
+
+fn check(array: &dyn Array, op: &str) -> Result<()> {
+    if can_cumulative(array.data_type()) {
+        Ok(())
+    } else {
+        Err(Error::NotYetImplemented(format!(
+            "`{op}` is not implemented for type {:?}",
+            array.data_type()
+        )))
+    }
+}
+
+/// Dynamically-typed version of [`cumsum`].
+/// # Errors
+/// This function errors iff the array's type is not supported (see [`can_cumulative`]).
+pub fn cumsum_dyn(array: &dyn Array, nulls: CumulativeNulls) -> Result<Box<dyn Array>> {
+    check(array, "cumsum")?;
+    let PhysicalType::Primitive(primitive) = array.data_type().to_physical_type() else {
+        unreachable!()
+    };
+    Ok(with_match_primitive_type!(primitive, |$T| {
+        cumsum::<$T>(array.as_any().downcast_ref().unwrap(), nulls).boxed()
+    }))
+}
+
+/// Dynamically-typed version of [`cumprod`].
+/// # Errors
+/// This function errors iff the array's type is not supported (see [`can_cumulative`]).
+pub fn cumprod_dyn(array: &dyn Array, nulls: CumulativeNulls) -> Result<Box<dyn Array>> {
+    check(array, "cumprod")?;
+    let PhysicalType::Primitive(primitive) = array.data_type().to_physical_type() else {
+        unreachable!()
+    };
+    Ok(with_match_primitive_type!(primitive, |$T| {
+        cumprod::<$T>(array.as_any().downcast_ref().unwrap(), nulls).boxed()
+    }))
+}
+
+/// Dynamically-typed version of [`cummin`].
+/// # Errors
+/// This function errors iff the array's type is not supported (see [`can_cumulative`]).
+pub fn cummin_dyn(array: &dyn Array, nulls: CumulativeNulls) -> Result<Box<dyn Array>> {
+    check(array, "cummin")?;
+    let PhysicalType::Primitive(primitive) = array.data_type().to_physical_type() else {
+        unreachable!()
+    };
+    Ok(with_match_primitive_type!(primitive, |$T| {
+        cummin::<$T>(array.as_any().downcast_ref().unwrap(), nulls).boxed()
+    }))
+}
+
+/// Dynamically-typed version of [`cummax`].
+/// # Errors
+/// This function errors iff the array's type is not supported (see [`can_cumulative`]).
+pub fn cummax_dyn(array: &dyn Array, nulls: CumulativeNulls) -> Result<Box<dyn Array>> {
+    check(array, "cummax")?;
+    let PhysicalType::Primitive(primitive) = array.data_type().to_physical_type() else {
+        unreachable!()
+    };
+    Ok(with_match_primitive_type!(primitive, |$T| {
+        cummax::<$T>(array.as_any().downcast_ref().unwrap(), nulls).boxed()
+    }))
+}
@@ -0,0 +1,201 @@
+//! Contains the dispatch of kernels to arrays of [`DataType::Extension`].
+//!
+//! An extension array is laid out as an array of its storage type (see
+//! [`DataType::to_logical_type`]). Kernels that do not support extension types can be
+//! dispatched through [`unary`], that runs them on the storage of the array and re-wraps their
+//! result in the extension type, so that user-defined types flow through compute untouched.
+//! ```
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::compute::{extension, sort::{sort, SortOptions}};
+//! use arrow2::datatypes::DataType;
+//!
+//! let data_type = DataType::Extension("id".to_string(), Box::new(DataType::Int32), None);
+//! let array = Int32Array::from_slice([3, 1, 2]).to(data_type.clone());
+//!
+//! let sorted = extension::unary(&array, |array| sort(array, &SortOptions::default(), None)).unwrap();
+//! assert_eq!(sorted.data_type(), &data_type);
+//! assert_eq!(sorted.as_ref(), &Int32Array::from_slice([1, 2, 3]).to(data_type) as &dyn Array);
+//! ```
+use crate::array::*;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::Result;
+
+/// Returns `array` with the [`DataType`] `data_type`, sharing its buffers.
+/// # Panics
+/// iff `data_type` is not valid for the physical layout of `array`.
+pub fn with_data_type(array: &dyn Array, data_type: DataType) -> Box<dyn Array> {
+    macro_rules! layout {
+        ($array:ty) => {
+            array.as_any().downcast_ref::<$array>().unwrap()
+        };
+    }
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => NullArray::new(data_type, array.len()).boxed(),
+        Boolean => {
+            let array = layout!(BooleanArray);
+            BooleanArray::new(data_type, array.values().clone(), array.validity().cloned()).boxed()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            layout!(PrimitiveArray<$T>).clone().to(data_type).boxed()
+        }),
+        Binary => {
+            let array = layout!(BinaryArray<i32>);
+            BinaryArray::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        LargeBinary => {
+            let array = layout!(BinaryArray<i64>);
+            BinaryArray::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        FixedSizeBinary => layout!(FixedSizeBinaryArray).clone().to(data_type).boxed(),
+        Utf8 => {
+            let array = layout!(Utf8Array<i32>);
+            Utf8Array::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        LargeUtf8 => {
+            let array = layout!(Utf8Array<i64>);
+            Utf8Array::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        List => {
+            let array = layout!(ListArray<i32>);
+            ListArray::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        LargeList => {
+            let array = layout!(ListArray<i64>);
+            ListArray::new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        FixedSizeList => {
+            let array = layout!(FixedSizeListArray);
+            FixedSizeListArray::new(data_type, array.values().clone(), array.validity().cloned())
+                .boxed()
+        }
+        Struct => {
+            let array = layout!(StructArray);
+            StructArray::new(
+                data_type,
+                array.values().to_vec(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        Union => {
+            let array = layout!(UnionArray);
+            let fields = match array.offsets() {
+                Some(_) => array.fields().clone(),
+                // the rows of a sparse union are the rows of its fields
+                None => array
+                    .fields()
+                    .iter()
+                    .map(|field| field.sliced(array.fields_offset(), array.len()))
+                    .collect(),
+            };
+            UnionArray::new(
+                data_type,
+                array.types().clone(),
+                fields,
+                array.offsets().cloned(),
+            )
+            .boxed()
+        }
+        Map => {
+            let array = layout!(MapArray);
+            MapArray::new(
+                data_type,
+                array.offsets().clone(),
+                array.field().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = layout!(DictionaryArray<$T>);
+            DictionaryArray::try_new(data_type, array.keys().clone(), array.values().clone())
+                .unwrap()
+                .boxed()
+        }),
+    }
+}
+
+/// Returns the storage of `array`, i.e. `array` with its [`DataType::Extension`] replaced by
+/// its logical type, or `None` if `array` is not of an extension type.
+pub fn storage(array: &dyn Array) -> Option<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Extension(_, _, _) => Some(with_data_type(
+            array,
+            array.data_type().to_logical_type().clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// Runs the kernel `op` on the storage of `array` (see [`storage`]) and re-wraps its result in
+/// the extension type of `array`, when the result is of the storage type.
+///
+/// When `array` is not of an extension type, this is `op(array)`.
+/// # Errors
+/// This function errors iff `op` errors.
+pub fn unary<F>(array: &dyn Array, op: F) -> Result<Box<dyn Array>>
+where
+    F: FnOnce(&dyn Array) -> Result<Box<dyn Array>>,
+{
+    let storage = match storage(array) {
+        Some(storage) => storage,
+        None => return op(array),
+    };
+    let result = op(storage.as_ref())?;
+    Ok(if result.data_type() == storage.data_type() {
+        with_data_type(result.as_ref(), array.data_type().clone())
+    } else {
+        result
+    })
+}
+
+/// Runs the function `op` on the storage of `array` (see [`storage`]), for kernels whose result
+/// is not an array of the type of `array`, such as indices or masks.
+///
+/// When `array` is not of an extension type, this is `op(array)`.
+pub fn on_storage<T, F>(array: &dyn Array, op: F) -> T
+where
+    F: FnOnce(&dyn Array) -> T,
+{
+    match storage(array) {
+        Some(storage) => op(storage.as_ref()),
+        None => op(array),
+    }
+}
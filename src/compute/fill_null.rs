@@ -0,0 +1,495 @@
+//! Contains the operator [`fill_null`], that replaces the null slots of an array, for example
+//! to fill the gaps of a time series, and the operator [`interpolate`], that fills them from
+//! the values around them.
+//!
+//! The slots that are filled are valid in the result; slots that cannot be filled (e.g. the
+//! leading nulls when filling forward) remain null.
+use crate::array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::PhysicalType;
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+use crate::scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use num_traits::AsPrimitive;
+
+use super::take::take;
+
+/// How [`fill_null`] fills the null slots of an array.
+#[derive(Debug, Clone, Copy)]
+pub enum FillNullStrategy<'a> {
+    /// Fills every null slot with the last non-null value before it.
+    Forward,
+    /// Fills every null slot with the first non-null value after it.
+    Backward,
+    /// Fills every null slot with the value of the scalar. A null scalar leaves the array as is.
+    Value(&'a dyn Scalar),
+    /// Fills every null slot with the mean of the non-null values, truncated for integers.
+    Mean,
+    /// Fills every null slot with the minimum of the non-null values.
+    Min,
+    /// Fills every null slot with the maximum of the non-null values.
+    Max,
+}
+
+/// Returns the validity of an array of `length` slots whose `valid` slots are valid.
+fn gap_validity(valid: std::ops::Range<usize>, length: usize) -> Option<Bitmap> {
+    let mut validity = MutableBitmap::with_capacity(length);
+    validity.extend_constant(valid.start, false);
+    validity.extend_constant(valid.end - valid.start, true);
+    validity.extend_constant(length - valid.end, false);
+    validity.into()
+}
+
+/// Returns the validity of `array` if it has null slots.
+fn nulls(array: &dyn Array) -> Option<&Bitmap> {
+    array
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+}
+
+/// Returns `array` with every null slot replaced by `value`.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_null_primitive;
+///
+/// let array = Int32Array::from([Some(1), None, Some(3)]);
+/// let result = fill_null_primitive(&array, 0);
+/// assert_eq!(result, Int32Array::from_slice([1, 0, 3]));
+/// ```
+pub fn fill_null_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    value: T,
+) -> PrimitiveArray<T> {
+    let Some(validity) = nulls(array) else {
+        return array.clone();
+    };
+    let values = array
+        .values()
+        .iter()
+        .zip(validity.iter())
+        .map(|(x, is_valid)| if is_valid { *x } else { value })
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(array.data_type().clone(), values.into(), None)
+}
+
+/// Returns `array` with every null slot replaced by the last non-null value before it.
+/// The null slots before the first non-null value remain null.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_null_forward;
+///
+/// let array = Int32Array::from([None, Some(1), None, None, Some(4), None]);
+/// let result = fill_null_forward(&array);
+/// assert_eq!(result, Int32Array::from([None, Some(1), Some(1), Some(1), Some(4), Some(4)]));
+/// ```
+pub fn fill_null_forward<T: NativeType>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    let Some(validity) = nulls(array) else {
+        return array.clone();
+    };
+    let mut last = None;
+    let values = array
+        .values()
+        .iter()
+        .zip(validity.iter())
+        .map(|(x, is_valid)| {
+            if is_valid {
+                last = Some(*x);
+            }
+            last.unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let leading = validity.iter().take_while(|is_valid| !is_valid).count();
+    let validity = gap_validity(leading..array.len(), array.len());
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns `array` with every null slot replaced by the first non-null value after it.
+/// The null slots after the last non-null value remain null.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::fill_null::fill_null_backward;
+///
+/// let array = Int32Array::from([None, Some(1), None, None, Some(4), None]);
+/// let result = fill_null_backward(&array);
+/// assert_eq!(result, Int32Array::from([Some(1), Some(1), Some(4), Some(4), Some(4), None]));
+/// ```
+pub fn fill_null_backward<T: NativeType>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    let Some(validity) = nulls(array) else {
+        return array.clone();
+    };
+    let mut next = None;
+    let mut values = array
+        .values()
+        .iter()
+        .zip(validity.iter())
+        .rev()
+        .map(|(x, is_valid)| {
+            if is_valid {
+                next = Some(*x);
+            }
+            next.unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    values.reverse();
+
+    let trailing = validity
+        .iter()
+        .rev()
+        .take_while(|is_valid| !is_valid)
+        .count();
+    let validity = gap_validity(0..array.len() - trailing, array.len());
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns the indices taken by filling the null slots of `validity` forward or backward.
+fn fill_indices(validity: &Bitmap, forward: bool) -> PrimitiveArray<u64> {
+    let mut indices = vec![None; validity.len()];
+    let mut fill = |index: usize, last: &mut Option<u64>, is_valid: bool| {
+        if is_valid {
+            *last = Some(index as u64);
+        }
+        indices[index] = *last;
+    };
+    let mut last = None;
+    if forward {
+        validity
+            .iter()
+            .enumerate()
+            .for_each(|(index, is_valid)| fill(index, &mut last, is_valid));
+    } else {
+        validity
+            .iter()
+            .enumerate()
+            .rev()
+            .for_each(|(index, is_valid)| fill(index, &mut last, is_valid));
+    }
+    PrimitiveArray::from(indices)
+}
+
+fn fill_null_boolean(array: &BooleanArray, value: bool) -> BooleanArray {
+    let Some(validity) = nulls(array) else {
+        return array.clone();
+    };
+    let values = if value {
+        array.values() | &!validity
+    } else {
+        array.values() & validity
+    };
+    BooleanArray::new(array.data_type().clone(), values, None)
+}
+
+fn fill_null_utf8<O: Offset>(array: &Utf8Array<O>, value: &str) -> Utf8Array<O> {
+    if nulls(array).is_none() {
+        return array.clone();
+    }
+    Utf8Array::<O>::from_trusted_len_values_iter(array.iter().map(|x| x.unwrap_or(value)))
+}
+
+fn fill_null_binary<O: Offset>(array: &BinaryArray<O>, value: &[u8]) -> BinaryArray<O> {
+    if nulls(array).is_none() {
+        return array.clone();
+    }
+    BinaryArray::<O>::from_trusted_len_values_iter(array.iter().map(|x| x.unwrap_or(value)))
+}
+
+fn fill_null_value(array: &dyn Array, value: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if array.data_type() != value.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`fill_null` requires a scalar of the type of the array, {:?}, but got {:?}",
+            array.data_type(),
+            value.data_type()
+        )));
+    }
+    if !value.is_valid() {
+        return Ok(array.to_boxed());
+    }
+
+    macro_rules! fill {
+        ($array:ty, $scalar:ty, $op:ident) => {{
+            let scalar = value.as_any().downcast_ref::<$scalar>().unwrap();
+            $op(
+                array.as_any().downcast_ref::<$array>().unwrap(),
+                scalar.value().unwrap(),
+            )
+            .boxed()
+        }};
+    }
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = value.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            fill_null_primitive::<$T>(array.as_any().downcast_ref().unwrap(), scalar.value().unwrap())
+                .boxed()
+        }),
+        Boolean => fill!(BooleanArray, BooleanScalar, fill_null_boolean),
+        Utf8 => fill!(Utf8Array<i32>, Utf8Scalar<i32>, fill_null_utf8),
+        LargeUtf8 => fill!(Utf8Array<i64>, Utf8Scalar<i64>, fill_null_utf8),
+        Binary => fill!(BinaryArray<i32>, BinaryScalar<i32>, fill_null_binary),
+        LargeBinary => fill!(BinaryArray<i64>, BinaryScalar<i64>, fill_null_binary),
+        _ => {
+            return Err(Error::NotYetImplemented(format!(
+                "`fill_null` with a value is not implemented for type {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+macro_rules! with_match_numeric_type {(
+    $key_type:expr, $error:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => return Err($error),
+    }
+})}
+
+fn aggregate_error(array: &dyn Array, strategy: FillNullStrategy) -> Error {
+    Error::NotYetImplemented(format!(
+        "`fill_null` with {strategy:?} is not implemented for type {:?}",
+        array.data_type()
+    ))
+}
+
+fn fill_null_aggregate<T>(
+    array: &PrimitiveArray<T>,
+    strategy: FillNullStrategy,
+) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd + AsPrimitive<f64>,
+    f64: AsPrimitive<T>,
+{
+    let mut valid = array.non_null_values_iter().map(|(_, x)| x);
+    let value = match strategy {
+        FillNullStrategy::Mean => {
+            let (sum, count) =
+                valid.fold((0.0, 0usize), |(sum, count), x| (sum + x.as_(), count + 1));
+            (count > 0).then(|| (sum / count as f64).as_())
+        }
+        FillNullStrategy::Min => {
+            let first = valid.next();
+            first.map(|first| valid.fold(first, |acc, x| if x < acc { x } else { acc }))
+        }
+        FillNullStrategy::Max => {
+            let first = valid.next();
+            first.map(|first| valid.fold(first, |acc, x| if x > acc { x } else { acc }))
+        }
+        _ => unreachable!(),
+    };
+    match value {
+        Some(value) => fill_null_primitive(array, value),
+        None => array.clone(),
+    }
+}
+
+/// Returns `array` with its null slots filled according to `strategy`.
+///
+/// [`FillNullStrategy::Forward`] and [`FillNullStrategy::Backward`] support every type,
+/// [`FillNullStrategy::Value`] supports primitive, boolean, utf8 and binary types, and
+/// [`FillNullStrategy::Mean`], [`FillNullStrategy::Min`] and [`FillNullStrategy::Max`] support
+/// integer and float types.
+/// # Errors
+/// This function errors iff the type of the array is not supported by the strategy, or the
+/// scalar of [`FillNullStrategy::Value`] is not of the type of the array.
+/// # Examples
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array};
+/// use arrow2::compute::fill_null::{fill_null, FillNullStrategy};
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Float64Array::from([Some(1.0), None, Some(3.0), None]);
+/// let result = fill_null(&array, FillNullStrategy::Mean).unwrap();
+/// assert_eq!(Float64Array::from_slice([1.0, 2.0, 3.0, 2.0]), result.as_ref());
+///
+/// let array = Int32Array::from([Some(1), None]);
+/// let value = PrimitiveScalar::from(Some(7i32));
+/// let result = fill_null(&array, FillNullStrategy::Value(&value)).unwrap();
+/// assert_eq!(Int32Array::from_slice([1, 7]), result.as_ref());
+/// ```
+pub fn fill_null(array: &dyn Array, strategy: FillNullStrategy) -> Result<Box<dyn Array>> {
+    let physical_type = array.data_type().to_physical_type();
+    match strategy {
+        FillNullStrategy::Forward | FillNullStrategy::Backward => {
+            let forward = matches!(strategy, FillNullStrategy::Forward);
+            if let PhysicalType::Primitive(primitive) = physical_type {
+                return Ok(with_match_primitive_type!(primitive, |$T| {
+                    let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                    if forward {
+                        fill_null_forward(array).boxed()
+                    } else {
+                        fill_null_backward(array).boxed()
+                    }
+                }));
+            }
+            match nulls(array) {
+                Some(validity) => take(array, &fill_indices(validity, forward)),
+                None => Ok(array.to_boxed()),
+            }
+        }
+        FillNullStrategy::Value(value) => fill_null_value(array, value),
+        FillNullStrategy::Mean | FillNullStrategy::Min | FillNullStrategy::Max => {
+            let PhysicalType::Primitive(primitive) = physical_type else {
+                return Err(aggregate_error(array, strategy));
+            };
+            Ok(
+                with_match_numeric_type!(primitive, aggregate_error(array, strategy), |$T| {
+                    let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                    fill_null_aggregate(array, strategy).boxed()
+                }),
+            )
+        }
+    }
+}
+
+/// How [`interpolate`] computes the values of the null slots between two non-null slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolationMethod {
+    /// The value on the line between the non-null slots around the null slot.
+    Linear,
+    /// The value of the non-null slot nearest to the null slot, or the one before it on ties.
+    Nearest,
+}
+
+fn interpolate_impl<T, F>(
+    values: &PrimitiveArray<T>,
+    x: F,
+    method: InterpolationMethod,
+) -> PrimitiveArray<T>
+where
+    T: NativeType + AsPrimitive<f64>,
+    f64: AsPrimitive<T>,
+    F: Fn(usize) -> f64,
+{
+    let Some(validity) = nulls(values) else {
+        return values.clone();
+    };
+    let length = values.len();
+    let mut result = values.values().to_vec();
+
+    let mut previous = None;
+    for (index, is_valid) in validity.iter().enumerate() {
+        if !is_valid {
+            continue;
+        }
+        // fill the gap between the previous non-null slot and this one, if any
+        if let Some(start) = previous.filter(|start| start + 1 < index) {
+            let (x0, x1) = (x(start), x(index));
+            let (y0, y1) = (result[start], result[index]);
+            for (i, slot) in result.iter_mut().enumerate().take(index).skip(start + 1) {
+                let xi = x(i);
+                *slot = match method {
+                    InterpolationMethod::Linear if x1 != x0 => {
+                        let (y0, y1): (f64, f64) = (y0.as_(), y1.as_());
+                        (y0 + (y1 - y0) * (xi - x0) / (x1 - x0)).as_()
+                    }
+                    InterpolationMethod::Linear => y0,
+                    InterpolationMethod::Nearest => {
+                        if (x1 - xi).abs() < (xi - x0).abs() {
+                            y1
+                        } else {
+                            y0
+                        }
+                    }
+                };
+            }
+        }
+        previous = Some(index);
+    }
+
+    let leading = validity.iter().take_while(|is_valid| !is_valid).count();
+    let trailing = validity
+        .iter()
+        .rev()
+        .take_while(|is_valid| !is_valid)
+        .count();
+    let validity = gap_validity(
+        leading..length.saturating_sub(trailing).max(leading),
+        length,
+    );
+    PrimitiveArray::new(values.data_type().clone(), result.into(), validity)
+}
+
+/// Returns `values` with every null slot between two non-null slots interpolated from them
+/// according to `method`, taking the slots to be equally spaced. The null slots before the
+/// first and after the last non-null slot remain null. Interpolated integers are truncated.
+/// # Examples
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::fill_null::{interpolate, InterpolationMethod};
+///
+/// let values = Float64Array::from([None, Some(1.0), None, None, Some(4.0), None]);
+///
+/// let result = interpolate(&values, InterpolationMethod::Linear);
+/// let expected = Float64Array::from([None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]);
+/// assert_eq!(result, expected);
+///
+/// let result = interpolate(&values, InterpolationMethod::Nearest);
+/// let expected = Float64Array::from([None, Some(1.0), Some(1.0), Some(4.0), Some(4.0), None]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn interpolate<T>(values: &PrimitiveArray<T>, method: InterpolationMethod) -> PrimitiveArray<T>
+where
+    T: NativeType + AsPrimitive<f64>,
+    f64: AsPrimitive<T>,
+{
+    interpolate_impl(values, |i| i as f64, method)
+}
+
+/// Returns `values` with every null slot between two non-null slots interpolated from them
+/// according to `method`, where slot `i` is at position `x[i]` (e.g. a timestamp). The null
+/// slots before the first and after the last non-null slot remain null. Interpolated integers
+/// are truncated.
+/// # Errors
+/// This function errors iff `x` does not have the length of `values` or has null slots.
+/// # Examples
+/// ```
+/// use arrow2::array::{Float64Array, Int64Array};
+/// use arrow2::compute::fill_null::{interpolate_by, InterpolationMethod};
+///
+/// let values = Float64Array::from([Some(0.0), None, Some(4.0)]);
+/// let x = Int64Array::from_slice([0, 3, 4]);
+///
+/// let result = interpolate_by(&values, &x, InterpolationMethod::Linear).unwrap();
+/// assert_eq!(result, Float64Array::from_slice([0.0, 3.0, 4.0]));
+/// ```
+pub fn interpolate_by<T>(
+    values: &PrimitiveArray<T>,
+    x: &PrimitiveArray<i64>,
+    method: InterpolationMethod,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + AsPrimitive<f64>,
+    f64: AsPrimitive<T>,
+{
+    if x.len() != values.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`interpolate_by` requires one position per value, but there are {} positions for {} values",
+            x.len(),
+            values.len()
+        )));
+    }
+    if x.null_count() > 0 {
+        return Err(Error::InvalidArgumentError(
+            "`interpolate_by` requires positions without nulls".to_string(),
+        ));
+    }
+    let x = x.values();
+    Ok(interpolate_impl(values, |i| x[i] as f64, method))
+}
@@ -1,4 +1,6 @@
 //! Contains operators to filter arrays such as [`filter`].
+use multiversion::multiversion;
+
 use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
@@ -12,15 +14,31 @@ use crate::{array::*, types::NativeType};
 /// Function that can filter arbitrary arrays
 pub type Filter<'a> = Box<dyn Fn(&dyn Array) -> Box<dyn Array> + 'a + Send + Sync>;
 
+/// Calls `f(start, length)` for every run of set bits of `mask`, starting from its least
+/// significant bit.
 #[inline]
-fn get_leading_ones(chunk: u64) -> u32 {
-    if cfg!(target_endian = "little") {
-        chunk.trailing_ones()
-    } else {
-        chunk.leading_ones()
+fn for_each_run<F: FnMut(usize, usize)>(mut mask: u64, mut f: F) {
+    let mut offset = 0;
+    while mask != 0 {
+        let zeros = mask.trailing_zeros();
+        mask >>= zeros;
+        offset += zeros;
+        let ones = mask.trailing_ones();
+        f(offset as usize, ones as usize);
+        offset += ones;
+        mask = mask.checked_shr(ones).unwrap_or(0);
     }
 }
 
+/// Returns whether the `ones` set bits of `mask` are clustered enough (runs of at least 4 bits
+/// on average) for copying whole runs to be faster than extracting bit by bit.
+#[inline]
+fn is_dense(mask: u64, ones: u32) -> bool {
+    let runs = (mask & !(mask << 1)).count_ones();
+    ones >= runs * 4
+}
+
+#[multiversion(targets = "simd")]
 /// # Safety
 /// This assumes that the `mask_chunks` contains a number of set/true items equal
 /// to `filter_count`
@@ -38,21 +56,21 @@ where
         .zip(mask_chunks.by_ref())
         .for_each(|(chunk, mask_chunk)| {
             let ones = mask_chunk.count_ones();
-            let leading_ones = get_leading_ones(mask_chunk);
 
-            if ones == leading_ones {
-                let size = leading_ones as usize;
-                unsafe {
-                    std::ptr::copy(chunk.as_ptr(), dst, size);
-                    dst = dst.add(size);
-                }
+            if is_dense(mask_chunk, ones) {
+                for_each_run(mask_chunk, |start, length| unsafe {
+                    std::ptr::copy_nonoverlapping(chunk.as_ptr().add(start), dst, length);
+                    dst = dst.add(length);
+                });
                 return;
             }
 
             let ones_iter = BitChunkOnes::from_known_count(mask_chunk, ones as usize);
             for pos in ones_iter {
-                dst.write(*chunk.get_unchecked(pos));
-                dst = dst.add(1);
+                unsafe {
+                    dst.write(*chunk.get_unchecked(pos));
+                    dst = dst.add(1);
+                }
             }
         });
 
@@ -73,6 +91,7 @@ where
     new
 }
 
+#[multiversion(targets = "simd")]
 /// # Safety
 /// This assumes that the `mask_chunks` contains a number of set/true items equal
 /// to `filter_count`
@@ -100,30 +119,30 @@ where
         .zip(mask_chunks.by_ref())
         .for_each(|((chunk, validity_chunk), mask_chunk)| {
             let ones = mask_chunk.count_ones();
-            let leading_ones = get_leading_ones(mask_chunk);
 
-            if ones == leading_ones {
-                let size = leading_ones as usize;
-                unsafe {
-                    std::ptr::copy(chunk.as_ptr(), dst, size);
-                    dst = dst.add(size);
+            if is_dense(mask_chunk, ones) {
+                for_each_run(mask_chunk, |start, length| unsafe {
+                    std::ptr::copy_nonoverlapping(chunk.as_ptr().add(start), dst, length);
+                    dst = dst.add(length);
 
-                    // safety: invariant offset + length <= slice.len()
+                    // safety: invariant start + length <= 64
                     new_validity.extend_from_slice_unchecked(
                         validity_chunk.to_ne_bytes().as_ref(),
-                        0,
-                        size,
+                        start,
+                        length,
                     );
-                }
+                });
                 return;
             }
 
             // this triggers a bitcount
             let ones_iter = BitChunkOnes::from_known_count(mask_chunk, ones as usize);
             for pos in ones_iter {
-                dst.write(*chunk.get_unchecked(pos));
-                dst = dst.add(1);
-                new_validity.push_unchecked(validity_chunk & (1 << pos) > 0);
+                unsafe {
+                    dst.write(*chunk.get_unchecked(pos));
+                    dst = dst.add(1);
+                    new_validity.push_unchecked(validity_chunk & (1 << pos) > 0);
+                }
             }
         });
 
@@ -201,6 +220,59 @@ fn filter_primitive<T: NativeType + Simd>(
     filter_nonnull_primitive(array, mask.values())
 }
 
+/// Appends the bits of `values` selected by `mask` to `dst`.
+/// # Safety
+/// `dst` must have capacity for the set bits of `mask`
+#[inline]
+unsafe fn filter_bitmap_chunk(dst: &mut MutableBitmap, values: u64, mask: u64) {
+    let ones = mask.count_ones();
+    if is_dense(mask, ones) {
+        let bytes = values.to_ne_bytes();
+        for_each_run(mask, |start, length| {
+            dst.extend_from_slice_unchecked(bytes.as_ref(), start, length)
+        });
+    } else {
+        for pos in BitChunkOnes::from_known_count(mask, ones as usize) {
+            dst.push_unchecked(values & (1 << pos) != 0);
+        }
+    }
+}
+
+/// Returns the bits of `bitmap` whose corresponding bit in `mask` is set.
+fn filter_bitmap(bitmap: &Bitmap, mask: &Bitmap, filter_count: usize) -> MutableBitmap {
+    assert_eq!(bitmap.len(), mask.len());
+    let mut new = MutableBitmap::with_capacity(filter_count);
+
+    let mut chunks = bitmap.chunks::<u64>();
+    let mut mask_chunks = mask.chunks::<u64>();
+    chunks
+        .by_ref()
+        .zip(mask_chunks.by_ref())
+        .for_each(|(chunk, mask_chunk)| unsafe {
+            filter_bitmap_chunk(&mut new, chunk, mask_chunk)
+        });
+
+    // bits beyond the mask's length may be set in its remainder
+    let remainder_mask = mask_chunks.remainder() & ((1u64 << mask_chunks.remainder_len()) - 1);
+    unsafe { filter_bitmap_chunk(&mut new, chunks.remainder(), remainder_mask) };
+    new
+}
+
+fn filter_boolean(array: &BooleanArray, mask: &Bitmap) -> BooleanArray {
+    assert_eq!(array.len(), mask.len());
+    let filter_count = mask.len() - mask.unset_bits();
+
+    let values = filter_bitmap(array.values(), mask, filter_count);
+    let validity = array
+        .validity()
+        .map(|validity| filter_bitmap(validity, mask, filter_count));
+    BooleanArray::new(
+        array.data_type().clone(),
+        values.into(),
+        validity.map(|x| x.into()),
+    )
+}
+
 fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize)]) {
     chunks
         .iter()
@@ -210,22 +282,48 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
-pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
-    let iter = SlicesIterator::new(filter.values());
-    let filter_count = iter.slots();
-    let chunks = iter.collect::<Vec<_>>();
+///
+/// The selection is analyzed once: nulls of `filter` are merged into its values, fully
+/// selective and empty filters short-circuit, and the runs of selected slots are computed
+/// upfront for the arrays that are filtered run by run.
+/// # Panics
+/// The returned function panics iff it is called with an array whose length differs from `filter`
+pub fn build_filter(filter: &BooleanArray) -> Result<Filter<'static>> {
+    // the nulls of the filter are interpreted as `false`
+    let mask = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+    let length = mask.len();
+    let filter_count = length - mask.unset_bits();
+
+    if filter_count == 0 {
+        return Ok(Box::new(move |array: &dyn Array| {
+            assert_eq!(array.len(), length);
+            new_empty_array(array.data_type().clone())
+        }));
+    }
+    if filter_count == length {
+        return Ok(Box::new(move |array: &dyn Array| {
+            assert_eq!(array.len(), length);
+            array.to_boxed()
+        }));
+    }
+
+    let chunks = SlicesIterator::new(&mask).collect::<Vec<_>>();
 
     use crate::datatypes::PhysicalType::*;
     Ok(Box::new(move |array: &dyn Array| {
+        assert_eq!(array.len(), length);
         match array.data_type().to_physical_type() {
             Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
                 let array = array.as_any().downcast_ref().unwrap();
-                let mut growable =
-                    growable::GrowablePrimitive::<$T>::new(vec![array], false, filter_count);
-                filter_growable(&mut growable, &chunks);
-                let array: PrimitiveArray<$T> = growable.into();
-                Box::new(array)
+                Box::new(filter_nonnull_primitive::<$T>(array, &mask))
             }),
+            Boolean => {
+                let array = array.as_any().downcast_ref().unwrap();
+                Box::new(filter_boolean(array, &mask))
+            }
             Utf8 => {
                 let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
                 let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
@@ -296,6 +394,10 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
+        Boolean => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_boolean(array, filter.values())))
+        }
         _ => {
             let iter = SlicesIterator::new(filter.values());
             let mut mutable = make_growable(&[array], false, iter.slots());
@@ -306,6 +408,8 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
 }
 
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
+/// When the chunk has more than one column, the filter is analyzed once via [`build_filter`]
+/// and shared by all of them.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
     columns: &Chunk<A>,
@@ -0,0 +1,278 @@
+//! Contains the building blocks of a hash group-by: [`group_by`] hashes the rows of multiple
+//! columns with [`hash_columns`], assigns a group id to every row and gathers the keys of each
+//! group, and kernels such as [`sum_by_group`] accumulate the values of each group.
+//!
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::compute::group_by::{group_by, sum_by_group};
+//!
+//! let keys = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+//! let values = Int32Array::from([Some(1), Some(2), Some(3), None]);
+//!
+//! let groups = group_by(&[&keys]).unwrap();
+//! assert_eq!(groups.group_ids, vec![0, 1, 2, 0]);
+//! assert_eq!(
+//!     groups.keys[0].as_ref(),
+//!     &Utf8Array::<i32>::from([Some("a"), Some("b"), None]) as &dyn Array
+//! );
+//!
+//! let sums = sum_by_group(&values, &groups.group_ids, groups.num_groups()).unwrap();
+//! assert_eq!(sums, Int32Array::from([Some(1), Some(2), Some(3)]));
+//! ```
+
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use num_traits::AsPrimitive;
+
+use crate::array::ord::{build_compare, DynComparator};
+use crate::array::{Array, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::types::{NativeType, WrappingArithmetic};
+
+use super::hash::{can_hash_columns, hash_columns, HashFunction};
+use super::take::take;
+
+/// Whether columns of `data_type` can be grouped by [`group_by`].
+pub fn can_group_by(data_type: &DataType) -> bool {
+    can_hash_columns(data_type)
+}
+
+/// The groups of the rows of a set of columns, see [`group_by`].
+#[derive(Debug, Clone)]
+pub struct Groups {
+    /// The group id of every row. Ids are assigned in order of first appearance.
+    pub group_ids: Vec<u32>,
+    /// The keys of every group, one array per grouped column, whose `i`-th slot is the key
+    /// of the group `i`.
+    pub keys: Vec<Box<dyn Array>>,
+}
+
+impl Groups {
+    /// Returns the number of groups.
+    #[inline]
+    pub fn num_groups(&self) -> usize {
+        self.keys.first().map(|keys| keys.len()).unwrap_or(0)
+    }
+}
+
+/// Assigns a group id to every row of `columns`, so that two rows have the same group id iff
+/// they are equal in all columns, and returns the key of every group.
+///
+/// Null slots are equal to each other, and floats are equal iff their bits are equal (so
+/// that `NaN`s form a group).
+/// # Errors
+/// This function errors under the same conditions as [`hash_columns`], or when there are
+/// `u32::MAX` groups or more.
+pub fn group_by(columns: &[&dyn Array]) -> Result<Groups> {
+    let hashes = hash_columns(columns, HashFunction::AHash, 0)?;
+
+    let comparators = columns
+        .iter()
+        .map(|column| Ok((build_compare(*column, *column)?, column.validity())))
+        .collect::<Result<Vec<(DynComparator, Option<&Bitmap>)>>>()?;
+    let equal = |lhs: usize, rhs: usize| {
+        comparators.iter().all(|(compare, validity)| {
+            match validity.map(|validity| (validity.get_bit(lhs), validity.get_bit(rhs))) {
+                Some((false, false)) => true,
+                Some((true, false) | (false, true)) => false,
+                _ => compare(lhs, rhs).is_eq(),
+            }
+        })
+    };
+
+    const NONE: u32 = u32::MAX;
+    // the first group of each hash; groups with the same hash are chained via `next`
+    let mut heads = HashMap::<u64, u32>::new();
+    let mut next = Vec::<u32>::new();
+    let mut first_rows = Vec::<u32>::new();
+
+    let mut group_ids = Vec::with_capacity(hashes.len());
+    for (row, hash) in hashes.values().iter().enumerate() {
+        let new_group = first_rows.len() as u32;
+        let group = match heads.entry(*hash) {
+            Entry::Vacant(entry) => {
+                entry.insert(new_group);
+                new_group
+            }
+            Entry::Occupied(entry) => {
+                let mut group = *entry.get();
+                loop {
+                    if equal(first_rows[group as usize] as usize, row) {
+                        break group;
+                    }
+                    if next[group as usize] == NONE {
+                        next[group as usize] = new_group;
+                        break new_group;
+                    }
+                    group = next[group as usize];
+                }
+            }
+        };
+        if group == new_group {
+            if new_group == NONE {
+                return Err(Error::InvalidArgumentError(
+                    "Grouping is limited to u32::MAX - 1 groups".to_string(),
+                ));
+            }
+            first_rows.push(row as u32);
+            next.push(NONE);
+        }
+        group_ids.push(group);
+    }
+
+    let first_rows = PrimitiveArray::new(DataType::UInt32, first_rows.into(), None);
+    let keys = columns
+        .iter()
+        .map(|column| take(*column, &first_rows))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Groups { group_ids, keys })
+}
+
+fn check_group_ids(length: usize, group_ids: &[u32], num_groups: usize) -> Result<()> {
+    if length != group_ids.len() {
+        return Err(Error::InvalidArgumentError(
+            "Values and group ids must have the same length".to_string(),
+        ));
+    }
+    if let Some(max) = group_ids.iter().max() {
+        if *max as usize >= num_groups {
+            return Err(Error::InvalidArgumentError(format!(
+                "The group id {max} is out of bounds for {num_groups} groups"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Applies `op` to the state of the group of every non-null value of `values`.
+#[inline]
+fn accumulate<T, S, F>(values: &PrimitiveArray<T>, group_ids: &[u32], states: &mut [S], mut op: F)
+where
+    T: NativeType,
+    F: FnMut(&mut S, T),
+{
+    let iter = values.values().iter().zip(group_ids.iter());
+    match values
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+    {
+        None => iter.for_each(|(x, group)| op(&mut states[*group as usize], *x)),
+        Some(validity) => iter
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .for_each(|((x, group), _)| op(&mut states[*group as usize], *x)),
+    }
+}
+
+/// Returns the number of non-null values of `values` in each group, where the group of the
+/// `i`-th value is `group_ids[i]`.
+/// # Errors
+/// This function errors iff `values` and `group_ids` have different lengths or a group id is
+/// not smaller than `num_groups`.
+pub fn count_by_group(
+    values: &dyn Array,
+    group_ids: &[u32],
+    num_groups: usize,
+) -> Result<PrimitiveArray<u64>> {
+    check_group_ids(values.len(), group_ids, num_groups)?;
+    let mut counts = vec![0u64; num_groups];
+    match values.validity() {
+        None => group_ids
+            .iter()
+            .for_each(|group| counts[*group as usize] += 1),
+        Some(validity) => group_ids
+            .iter()
+            .zip(validity.iter())
+            .for_each(|(group, is_valid)| counts[*group as usize] += is_valid as u64),
+    }
+    Ok(PrimitiveArray::new(DataType::UInt64, counts.into(), None))
+}
+
+/// Returns the sum of the non-null values of `values` in each group, where the group of the
+/// `i`-th value is `group_ids[i]`. The sum of a group without non-null values is null.
+///
+/// Integer sums wrap around on overflow (see [`WrappingArithmetic`]) rather than erroring,
+/// so that this kernel never fails on the values themselves.
+/// # Errors
+/// This function errors under the same conditions as [`count_by_group`].
+pub fn sum_by_group<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &[u32],
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: WrappingArithmetic,
+{
+    check_group_ids(values.len(), group_ids, num_groups)?;
+    let mut sums = vec![None::<T>; num_groups];
+    accumulate(values, group_ids, &mut sums, |sum, x| {
+        *sum = Some(sum.map_or(x, |sum| sum.wrapping_add(x)))
+    });
+    Ok(PrimitiveArray::from(sums).to(values.data_type().clone()))
+}
+
+/// Returns the minimum of the non-null values of `values` in each group, see [`sum_by_group`].
+/// # Errors
+/// This function errors under the same conditions as [`count_by_group`].
+pub fn min_by_group<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &[u32],
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    check_group_ids(values.len(), group_ids, num_groups)?;
+    let mut mins = vec![None; num_groups];
+    accumulate(values, group_ids, &mut mins, |min, x| match min {
+        Some(min) if *min <= x => {}
+        _ => *min = Some(x),
+    });
+    Ok(PrimitiveArray::from(mins).to(values.data_type().clone()))
+}
+
+/// Returns the maximum of the non-null values of `values` in each group, see [`sum_by_group`].
+/// # Errors
+/// This function errors under the same conditions as [`count_by_group`].
+pub fn max_by_group<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &[u32],
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    check_group_ids(values.len(), group_ids, num_groups)?;
+    let mut maxs = vec![None; num_groups];
+    accumulate(values, group_ids, &mut maxs, |max, x| match max {
+        Some(max) if *max >= x => {}
+        _ => *max = Some(x),
+    });
+    Ok(PrimitiveArray::from(maxs).to(values.data_type().clone()))
+}
+
+/// Returns the mean of the non-null values of `values` in each group as [`DataType::Float64`],
+/// see [`sum_by_group`].
+/// # Errors
+/// This function errors under the same conditions as [`count_by_group`].
+pub fn mean_by_group<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &[u32],
+    num_groups: usize,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    check_group_ids(values.len(), group_ids, num_groups)?;
+    let mut states = vec![(0.0f64, 0u64); num_groups];
+    accumulate(values, group_ids, &mut states, |(sum, count), x| {
+        *sum += x.as_();
+        *count += 1;
+    });
+    Ok(states
+        .into_iter()
+        .map(|(sum, count)| (count > 0).then(|| sum / count as f64))
+        .collect())
+}
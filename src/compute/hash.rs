@@ -1,4 +1,9 @@
-//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
+//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators, the seeded [`hash_with`]
+//! over a choice of [`HashFunction`]s and the multi-column [`hash_columns`].
+//!
+//! Dictionary-encoded arrays are hashed by hashing every value of the dictionary once and
+//! gathering the hashes by key (see [`hash_dictionary`]), which is equal to, but much faster
+//! than, hashing the expanded values.
 // multiversion does not copy documentation, causing a false positive
 #![allow(missing_docs)]
 use ahash::RandomState;
@@ -12,7 +17,10 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+    },
+    bitmap::Bitmap,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{Error, Result},
     offset::Offset,
@@ -20,6 +28,7 @@ use crate::{
 };
 
 use super::arity::unary;
+use super::utils::combine_validities;
 
 #[multiversion(targets("x86_64+aes+sse3+ssse3+avx+avx2"))]
 /// Element-wise hash of a [`PrimitiveArray`]. Validity is preserved.
@@ -69,6 +78,44 @@ pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
     PrimitiveArray::<u64>::new(DataType::UInt64, values, array.validity().cloned())
 }
 
+/// Gathers the hashes of the values of a dictionary by `keys`. A slot is null iff its key or
+/// its value is null.
+fn gather_hashes<K: DictionaryKey>(
+    keys: &PrimitiveArray<K>,
+    hashes: &PrimitiveArray<u64>,
+) -> PrimitiveArray<u64> {
+    // safety: the keys of a dictionary are valid indices of its values
+    let indices = || keys.values().iter().map(|key| unsafe { key.as_usize() });
+
+    let values = indices()
+        .map(|index| hashes.values()[index])
+        .collect::<Vec<_>>()
+        .into();
+
+    let validity = match hashes.validity() {
+        Some(validity) if validity.unset_bits() > 0 => {
+            let validity =
+                Bitmap::from_trusted_len_iter(indices().map(|index| validity.get_bit(index)));
+            combine_validities(keys.validity(), Some(&validity))
+        }
+        _ => keys.validity().cloned(),
+    };
+
+    PrimitiveArray::<u64>::new(DataType::UInt64, values, validity)
+}
+
+/// Element-wise hash of a [`DictionaryArray`], equal to the hash of its expanded values. Every
+/// value of the dictionary is hashed once and the hashes are gathered by key. A slot is null
+/// iff its key or its value is null.
+/// # Errors
+/// This function errors iff the values of the dictionary are not supported by [`hash`].
+pub fn hash_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+) -> Result<PrimitiveArray<u64>> {
+    let hashes = hash(array.values().as_ref())?;
+    Ok(gather_hashes(array.keys(), &hashes))
+}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -99,7 +146,8 @@ macro_rules! with_match_primitive_type {(
 /// * Boolean types
 /// * All primitive types except `Float32` and `Float64`
 /// * `[Large]Utf8`;
-/// * `[Large]Binary`.
+/// * `[Large]Binary`;
+/// * `Dictionary` of any key type whose values are supported.
 /// # Errors
 /// This function errors whenever it does not support the specific `DataType`.
 pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
@@ -113,6 +161,9 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
         LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            hash_dictionary::<$T>(array.as_any().downcast_ref().unwrap())?
+        }),
         t => {
             return Err(Error::NotYetImplemented(format!(
                 "Hash not implemented for type {t:?}"
@@ -135,6 +186,9 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 /// assert_eq!(can_hash(&data_type), false);
 /// ```
 pub fn can_hash(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        return can_hash(values.as_ref());
+    }
     matches!(
         data_type.to_physical_type(),
         PhysicalType::Boolean
@@ -155,3 +209,275 @@ pub fn can_hash(data_type: &DataType) -> bool {
             | PhysicalType::LargeUtf8
     )
 }
+
+/// The hash functions of [`hash_with`] and [`hash_columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HashFunction {
+    /// [aHash](https://github.com/tkaitchuck/aHash), the hash of [`hash`]. Its results
+    /// depend on the version of the crate and on the CPU features of the target.
+    #[default]
+    AHash,
+    /// [XXH64](https://github.com/Cyan4973/xxHash), whose results are stable across
+    /// processes and platforms.
+    XxHash64,
+    /// [wyhash](https://github.com/wangyi-fudan/wyhash), whose results are stable across
+    /// processes and platforms.
+    WyHash,
+}
+
+/// A hash function seeded per value. Values are hashed from their little-endian bytes unless
+/// stated otherwise.
+trait SeededHash {
+    fn hash_bytes(bytes: &[u8], seed: u64) -> u64;
+
+    #[inline]
+    fn hash_native<T: NativeType + Hash>(value: T, seed: u64) -> u64 {
+        Self::hash_bytes(value.to_le_bytes().as_ref(), seed)
+    }
+
+    #[inline]
+    fn hash_bool(value: bool, seed: u64) -> u64 {
+        Self::hash_bytes(&[value as u8], seed)
+    }
+}
+
+struct AHash;
+
+impl SeededHash for AHash {
+    #[inline]
+    fn hash_bytes(bytes: &[u8], seed: u64) -> u64 {
+        RandomState::with_seeds(seed, 0, 0, 0).hash_one(bytes)
+    }
+
+    // hashed as `Hash`, so that [`hash`] is [`hash_with`] with a zero seed
+    #[inline]
+    fn hash_native<T: NativeType + Hash>(value: T, seed: u64) -> u64 {
+        RandomState::with_seeds(seed, 0, 0, 0).hash_one(value)
+    }
+
+    #[inline]
+    fn hash_bool(value: bool, seed: u64) -> u64 {
+        RandomState::with_seeds(seed, 0, 0, 0).hash_one(value)
+    }
+}
+
+struct XxHash64;
+
+impl SeededHash for XxHash64 {
+    #[inline]
+    fn hash_bytes(bytes: &[u8], seed: u64) -> u64 {
+        xxhash_rust::xxh64::xxh64(bytes, seed)
+    }
+}
+
+struct WyHash;
+
+impl SeededHash for WyHash {
+    #[inline]
+    fn hash_bytes(bytes: &[u8], seed: u64) -> u64 {
+        wyhash::wyhash(bytes, seed)
+    }
+}
+
+macro_rules! with_match_hash_function {(
+    $function:expr, | $_:tt $H:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $H:ident ) => ( $($body)* )}
+    match $function {
+        HashFunction::AHash => __with_ty__! { AHash },
+        HashFunction::XxHash64 => __with_ty__! { XxHash64 },
+        HashFunction::WyHash => __with_ty__! { WyHash },
+    }
+})}
+
+fn hash_values<H: SeededHash>(array: &dyn Array, seed: u64) -> Result<PrimitiveArray<u64>> {
+    macro_rules! hash_values {
+        ($array:ty, $hash:expr) => {{
+            let array = array.as_any().downcast_ref::<$array>().unwrap();
+            let values = array.values_iter().map($hash).collect::<Vec<_>>();
+            PrimitiveArray::new(DataType::UInt64, values.into(), array.validity().cloned())
+        }};
+    }
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => hash_values!(BooleanArray, |x| H::hash_bool(x, seed)),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            hash_values!(PrimitiveArray<$T>, |x: &$T| H::hash_native(*x, seed))
+        }),
+        Binary => hash_values!(BinaryArray<i32>, |x| H::hash_bytes(x, seed)),
+        LargeBinary => hash_values!(BinaryArray<i64>, |x| H::hash_bytes(x, seed)),
+        Utf8 => hash_values!(Utf8Array<i32>, |x: &str| H::hash_bytes(x.as_bytes(), seed)),
+        LargeUtf8 => hash_values!(Utf8Array<i64>, |x: &str| H::hash_bytes(x.as_bytes(), seed)),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let hashes = hash_values::<H>(array.values().as_ref(), seed)?;
+            gather_hashes(array.keys(), &hashes)
+        }),
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "Hash not implemented for type {t:?}"
+            )))
+        }
+    })
+}
+
+/// The value hashed in place of null slots by [`hash_columns`]
+const NULL_HASH: u64 = 0x5bd1_e995_5bd1_e995;
+
+/// Re-hashes every slot of `hashes` with the value of `array` in the slot, using the hash of
+/// the slot as the seed. Null slots are re-hashed with [`NULL_HASH`].
+///
+/// Floats are hashed by their bits, and the values of a dictionary are hashed once, after
+/// which the hash of the value of every slot is re-hashed. Null keys and null values are
+/// both null slots.
+fn update_hashes<H: SeededHash>(array: &dyn Array, hashes: &mut [u64]) -> Result<()> {
+    macro_rules! update {
+        ($iter:expr, $hash:expr) => {{
+            hashes.iter_mut().zip($iter).for_each(|(hash, value)| {
+                *hash = match value {
+                    Some(value) => $hash(value, *hash),
+                    None => H::hash_native(NULL_HASH, *hash),
+                }
+            })
+        }};
+    }
+    macro_rules! downcast {
+        ($array:ty) => {
+            array.as_any().downcast_ref::<$array>().unwrap().iter()
+        };
+    }
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => update!(downcast!(BooleanArray), H::hash_bool),
+        Primitive(PrimitiveType::Float32) => {
+            update!(downcast!(PrimitiveArray<f32>), |x: &f32, seed| {
+                H::hash_native(x.to_bits(), seed)
+            })
+        }
+        Primitive(PrimitiveType::Float64) => {
+            update!(downcast!(PrimitiveArray<f64>), |x: &f64, seed| {
+                H::hash_native(x.to_bits(), seed)
+            })
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            update!(downcast!(PrimitiveArray<$T>), |x: &$T, seed| H::hash_native(*x, seed))
+        }),
+        Binary => update!(downcast!(BinaryArray<i32>), H::hash_bytes),
+        LargeBinary => update!(downcast!(BinaryArray<i64>), H::hash_bytes),
+        Utf8 => update!(downcast!(Utf8Array<i32>), |x: &str, seed| H::hash_bytes(
+            x.as_bytes(),
+            seed
+        )),
+        LargeUtf8 => update!(downcast!(Utf8Array<i64>), |x: &str, seed| H::hash_bytes(
+            x.as_bytes(),
+            seed
+        )),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let mut values = vec![0; array.values().len()];
+            update_hashes::<H>(array.values().as_ref(), &mut values)?;
+            let validity = array.values().validity().cloned();
+            let values = PrimitiveArray::new(DataType::UInt64, values.into(), validity);
+            let values = gather_hashes(array.keys(), &values);
+            update!(values.iter(), |x: &u64, seed| H::hash_native(*x, seed))
+        }),
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "Hash not implemented for type {t:?}"
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// Checks if an array of type `data_type` can be used in [`hash_columns`], i.e. if it can be
+/// used in [`hash`] or is a float or a dictionary of floats.
+pub fn can_hash_columns(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => can_hash_columns(values.as_ref()),
+        _ => {
+            can_hash(data_type)
+                || matches!(
+                    data_type.to_physical_type(),
+                    PhysicalType::Primitive(PrimitiveType::Float32 | PrimitiveType::Float64)
+                )
+        }
+    }
+}
+
+/// Returns the element-wise hash of an [`Array`] with the hash function `function` seeded
+/// with `seed`. Validity is preserved. [`hash`] is this function with [`HashFunction::AHash`]
+/// and a zero seed.
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType` (see [`can_hash`]).
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::hash::{hash_with, HashFunction};
+///
+/// let array = Int32Array::from([Some(1), None]);
+/// let result = hash_with(&array, HashFunction::XxHash64, 42).unwrap();
+/// assert_eq!(result.validity(), array.validity());
+/// ```
+pub fn hash_with(
+    array: &dyn Array,
+    function: HashFunction,
+    seed: u64,
+) -> Result<PrimitiveArray<u64>> {
+    with_match_hash_function!(function, |$H| hash_values::<$H>(array, seed))
+}
+
+/// Returns the row-wise hash of `columns` with the hash function `function`, as e.g. used to
+/// partition rows by multiple columns.
+///
+/// The hash of a row is computed column by column: the value of each column is hashed seeded
+/// with the hash of the columns before it (and `seed` for the first one). Null values are
+/// hashed as a sentinel, so that e.g. the rows `(null, "a")` and `("a", null)` have different
+/// hashes. The hashes are updated in place and the result has no nulls. On its non-null slots,
+/// the hash of a single column that is neither a float nor a dictionary is the hash of
+/// [`hash_with`].
+///
+/// Floats are hashed by their bits, so that equal bits (e.g. of `NaN`s) have equal hashes.
+/// The values of a dictionary are hashed once, and the hash of every slot is the hash of the
+/// hash of its value.
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths, or the type
+/// of a column is not supported (see [`can_hash_columns`]).
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::hash::{hash_columns, HashFunction};
+///
+/// let a = Int32Array::from_slice([1, 1, 2]);
+/// let b = Utf8Array::<i32>::from_slice(["x", "x", "y"]);
+/// let result = hash_columns(&[&a, &b], HashFunction::WyHash, 0).unwrap();
+/// assert_eq!(result.value(0), result.value(1));
+/// assert_ne!(result.value(0), result.value(2));
+/// ```
+pub fn hash_columns(
+    columns: &[&dyn Array],
+    function: HashFunction,
+    seed: u64,
+) -> Result<PrimitiveArray<u64>> {
+    let length = columns
+        .first()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("`hash_columns` requires at least one column".to_string())
+        })?
+        .len();
+    if let Some(column) = columns.iter().find(|column| column.len() != length) {
+        return Err(Error::InvalidArgumentError(format!(
+            "`hash_columns` requires all columns to have the same length, but got {} and {}",
+            length,
+            column.len()
+        )));
+    }
+
+    let mut hashes = vec![seed; length];
+    with_match_hash_function!(function, |$H| {
+        columns
+            .iter()
+            .try_for_each(|column| update_hashes::<$H>(*column, &mut hashes))?
+    });
+    Ok(PrimitiveArray::new(DataType::UInt64, hashes.into(), None))
+}
@@ -1,10 +1,22 @@
-//! Contains the operator [`if_then_else`].
-use crate::array::{growable, Array, BooleanArray};
+//! Contains the operator [`if_then_else`] and its variants over scalars, such as
+//! [`if_then_else_scalar`].
+use crate::array::{growable, Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array};
 use crate::bitmap::utils::SlicesIterator;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
 use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets, OffsetsBuffer};
+use crate::scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use super::utils::combine_validities;
 
 /// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
+///
+/// All types are supported, including nested types. The result of dictionary arrays has the
+/// values of both dictionaries, and null slots of a union are null slots of its first field.
 /// # Example
 /// ```rust
 /// # use arrow2::error::Result;
@@ -73,3 +85,313 @@ pub fn if_then_else(
     };
     Ok(result)
 }
+
+/// A side of [`if_then_else_scalar`] and its variants.
+#[derive(Clone, Copy)]
+enum Side<'a> {
+    Array(&'a dyn Array),
+    Scalar(&'a dyn Scalar),
+}
+
+impl<'a> Side<'a> {
+    fn data_type(&self) -> &'a DataType {
+        match self {
+            Side::Array(array) => array.data_type(),
+            Side::Scalar(scalar) => scalar.data_type(),
+        }
+    }
+}
+
+/// A [`Side`] downcasted to its array of type `A` or to the value of its scalar.
+enum Operand<'a, A, S> {
+    Array(&'a A),
+    Scalar(Option<S>),
+}
+
+fn operand<'a, A: 'static, S>(
+    side: Side<'a>,
+    value: impl Fn(&'a dyn Scalar) -> Option<S>,
+) -> Operand<'a, A, S> {
+    match side {
+        Side::Array(array) => Operand::Array(array.as_any().downcast_ref().unwrap()),
+        Side::Scalar(scalar) => Operand::Scalar(value(scalar)),
+    }
+}
+
+/// Calls `f(is_set, start, length)` for every run of set and of unset bits of `mask`, in order.
+fn for_each_run(mask: &Bitmap, mut f: impl FnMut(bool, usize, usize) -> Result<()>) -> Result<()> {
+    let mut end = 0;
+    for (start, length) in SlicesIterator::new(mask) {
+        if start != end {
+            f(false, end, start - end)?;
+        }
+        f(true, start, length)?;
+        end = start + length;
+    }
+    if end != mask.len() {
+        f(false, end, mask.len() - end)?;
+    }
+    Ok(())
+}
+
+fn extend_validity(validity: &mut MutableBitmap, array: &dyn Array, start: usize, length: usize) {
+    match array.validity() {
+        Some(bitmap) => {
+            let (slice, offset, _) = bitmap.as_slice();
+            validity.extend_from_slice(slice, offset + start, length)
+        }
+        None => validity.extend_constant(length, true),
+    }
+}
+
+fn finish_validity(predicate: &BooleanArray, validity: MutableBitmap) -> Option<Bitmap> {
+    let validity: Option<Bitmap> = validity.into();
+    combine_validities(predicate.validity(), validity.as_ref())
+}
+
+fn primitive_sides<T: NativeType>(
+    predicate: &BooleanArray,
+    data_type: DataType,
+    lhs: Operand<PrimitiveArray<T>, T>,
+    rhs: Operand<PrimitiveArray<T>, T>,
+) -> Result<PrimitiveArray<T>> {
+    let mut values = Vec::with_capacity(predicate.len());
+    let mut validity = MutableBitmap::with_capacity(predicate.len());
+    for_each_run(predicate.values(), |is_set, start, length| {
+        match if is_set { &lhs } else { &rhs } {
+            Operand::Array(array) => {
+                values.extend_from_slice(&array.values()[start..start + length]);
+                extend_validity(&mut validity, *array, start, length);
+            }
+            Operand::Scalar(value) => {
+                values.resize(values.len() + length, value.unwrap_or_default());
+                validity.extend_constant(length, value.is_some());
+            }
+        }
+        Ok(())
+    })?;
+    let validity = finish_validity(predicate, validity);
+    Ok(PrimitiveArray::new(data_type, values.into(), validity))
+}
+
+fn boolean_sides(
+    predicate: &BooleanArray,
+    data_type: DataType,
+    lhs: Operand<BooleanArray, bool>,
+    rhs: Operand<BooleanArray, bool>,
+) -> Result<BooleanArray> {
+    let mut values = MutableBitmap::with_capacity(predicate.len());
+    let mut validity = MutableBitmap::with_capacity(predicate.len());
+    for_each_run(predicate.values(), |is_set, start, length| {
+        match if is_set { &lhs } else { &rhs } {
+            Operand::Array(array) => {
+                let (slice, offset, _) = array.values().as_slice();
+                values.extend_from_slice(slice, offset + start, length);
+                extend_validity(&mut validity, *array, start, length);
+            }
+            Operand::Scalar(value) => {
+                values.extend_constant(length, value.unwrap_or_default());
+                validity.extend_constant(length, value.is_some());
+            }
+        }
+        Ok(())
+    })?;
+    let validity = finish_validity(predicate, validity);
+    Ok(BooleanArray::new(data_type, values.into(), validity))
+}
+
+/// The offsets, values and validity of a binary or utf8 array.
+type BinaryParts<O> = (OffsetsBuffer<O>, Buffer<u8>, Option<Bitmap>);
+
+fn binary_sides<'a, O: Offset, A: Array>(
+    predicate: &BooleanArray,
+    lhs: Operand<'a, A, &'a [u8]>,
+    rhs: Operand<'a, A, &'a [u8]>,
+    parts: impl Fn(&'a A) -> (&'a OffsetsBuffer<O>, &'a [u8]),
+) -> Result<BinaryParts<O>> {
+    let mut offsets = Offsets::<O>::with_capacity(predicate.len());
+    let mut values = Vec::new();
+    let mut validity = MutableBitmap::with_capacity(predicate.len());
+    for_each_run(predicate.values(), |is_set, start, length| {
+        match if is_set { &lhs } else { &rhs } {
+            Operand::Array(array) => {
+                let (array_offsets, array_values) = parts(array);
+                offsets.try_extend_from_slice(array_offsets, start, length)?;
+                let array_offsets = array_offsets.buffer();
+                let range =
+                    array_offsets[start].to_usize()..array_offsets[start + length].to_usize();
+                values.extend_from_slice(&array_values[range]);
+                extend_validity(&mut validity, *array, start, length);
+            }
+            Operand::Scalar(value) => {
+                let slot = value.unwrap_or_default();
+                for _ in 0..length {
+                    offsets.try_push_usize(slot.len())?;
+                    values.extend_from_slice(slot);
+                }
+                validity.extend_constant(length, value.is_some());
+            }
+        }
+        Ok(())
+    })?;
+    let validity = finish_validity(predicate, validity);
+    Ok((offsets.into(), values.into(), validity))
+}
+
+fn if_then_else_sides<'a>(
+    predicate: &BooleanArray,
+    lhs: Side<'a>,
+    rhs: Side<'a>,
+) -> Result<Box<dyn Array>> {
+    let data_type = lhs.data_type();
+    if data_type != rhs.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
+            data_type,
+            rhs.data_type()
+        )));
+    }
+    for side in [lhs, rhs] {
+        if let Side::Array(array) = side {
+            if array.len() != predicate.len() {
+                return Err(Error::InvalidArgumentError(format!(
+                    "If then else requires all arrays to have the same length (predicate = {}, array = {})",
+                    predicate.len(),
+                    array.len()
+                )));
+            }
+        }
+    }
+
+    macro_rules! binary {
+        ($array:ty, $scalar:ty, $as_bytes:expr) => {{
+            let value = |scalar: &'a dyn Scalar| {
+                let value = scalar.as_any().downcast_ref::<$scalar>().unwrap().value();
+                value.map($as_bytes)
+            };
+            let parts = |array: &'a $array| (array.offsets(), array.values().as_slice());
+            binary_sides(
+                predicate,
+                operand::<$array, _>(lhs, value),
+                operand::<$array, _>(rhs, value),
+                parts,
+            )?
+        }};
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let value = |scalar: &dyn Scalar| {
+                *scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap().value()
+            };
+            primitive_sides::<$T>(
+                predicate,
+                data_type.clone(),
+                operand(lhs, value),
+                operand(rhs, value),
+            )?
+            .boxed()
+        }),
+        Boolean => {
+            let value = |scalar: &dyn Scalar| {
+                scalar
+                    .as_any()
+                    .downcast_ref::<BooleanScalar>()
+                    .unwrap()
+                    .value()
+            };
+            boolean_sides(
+                predicate,
+                data_type.clone(),
+                operand(lhs, value),
+                operand(rhs, value),
+            )?
+            .boxed()
+        }
+        Utf8 => {
+            let (offsets, values, validity) =
+                binary!(Utf8Array<i32>, Utf8Scalar<i32>, str::as_bytes);
+            // Safety: the values are whole slots of utf8 arrays and scalars
+            unsafe { Utf8Array::<i32>::new_unchecked(data_type.clone(), offsets, values, validity) }
+                .boxed()
+        }
+        LargeUtf8 => {
+            let (offsets, values, validity) =
+                binary!(Utf8Array<i64>, Utf8Scalar<i64>, str::as_bytes);
+            // Safety: the values are whole slots of utf8 arrays and scalars
+            unsafe { Utf8Array::<i64>::new_unchecked(data_type.clone(), offsets, values, validity) }
+                .boxed()
+        }
+        Binary => {
+            let (offsets, values, validity) = binary!(BinaryArray<i32>, BinaryScalar<i32>, |x| x);
+            BinaryArray::<i32>::new(data_type.clone(), offsets, values, validity).boxed()
+        }
+        LargeBinary => {
+            let (offsets, values, validity) = binary!(BinaryArray<i64>, BinaryScalar<i64>, |x| x);
+            BinaryArray::<i64>::new(data_type.clone(), offsets, values, validity).boxed()
+        }
+        _ => {
+            return Err(Error::NotYetImplemented(format!(
+                "If then else with scalars is not implemented for type {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
+/// Returns `lhs` if the predicate is `true` or the values from `rhs` if the predicate is false,
+/// and `None` if the predicate is `None`.
+///
+/// Unlike [`if_then_else`], the scalar is not materialized into an array: the result is built
+/// from the runs of set and unset bits of the predicate. Primitive, boolean, utf8 and binary
+/// types are supported.
+/// # Errors
+/// This function errors iff the arguments do not have the same type, `rhs` does not have the
+/// length of the predicate, or the type is not supported.
+/// # Example
+/// ```rust
+/// use arrow2::compute::if_then_else::if_then_else_scalar;
+/// use arrow2::array::{Int32Array, BooleanArray};
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let lhs = PrimitiveScalar::<i32>::from(Some(0));
+/// let rhs = Int32Array::from_slice(&[4, 5, 6]);
+/// let predicate = BooleanArray::from(&[Some(true), None, Some(false)]);
+/// let result = if_then_else_scalar(&predicate, &lhs, &rhs).unwrap();
+///
+/// assert_eq!(Int32Array::from(&[Some(0), None, Some(6)]), result.as_ref());
+/// ```
+pub fn if_then_else_scalar(
+    predicate: &BooleanArray,
+    lhs: &dyn Scalar,
+    rhs: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    if_then_else_sides(predicate, Side::Scalar(lhs), Side::Array(rhs))
+}
+
+/// Returns the values from `lhs` if the predicate is `true` or `rhs` if the predicate is false,
+/// and `None` if the predicate is `None`. See [`if_then_else_scalar`].
+/// # Errors
+/// This function errors iff the arguments do not have the same type, `lhs` does not have the
+/// length of the predicate, or the type is not supported.
+pub fn if_then_else_array_scalar(
+    predicate: &BooleanArray,
+    lhs: &dyn Array,
+    rhs: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    if_then_else_sides(predicate, Side::Array(lhs), Side::Scalar(rhs))
+}
+
+/// Returns `lhs` if the predicate is `true` or `rhs` if the predicate is false, and `None` if
+/// the predicate is `None`. See [`if_then_else_scalar`].
+/// # Errors
+/// This function errors iff the arguments do not have the same type or the type is not
+/// supported.
+pub fn if_then_else_scalars(
+    predicate: &BooleanArray,
+    lhs: &dyn Scalar,
+    rhs: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    if_then_else_sides(predicate, Side::Scalar(lhs), Side::Scalar(rhs))
+}
@@ -0,0 +1,171 @@
+//! Contains the operator [`is_in`], SQL's `IN` over a list of values.
+use ahash::RandomState;
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+use super::utils::combine_validities;
+
+/// Lists of at most this number of non-null values are scanned instead of hashed.
+const LINEAR_SCAN_MAX_LEN: usize = 16;
+
+/// Returns whether the value of every row of `probe` is in `haystack`, comparing values by the
+/// bytes returned by `haystack_value` and `probe_value`.
+fn is_in_by<B, H, P>(
+    haystack: &dyn Array,
+    haystack_value: H,
+    probe: &dyn Array,
+    probe_value: P,
+) -> BooleanArray
+where
+    B: AsRef<[u8]>,
+    H: Fn(usize) -> B,
+    P: Fn(usize) -> B,
+{
+    let rows = (0..haystack.len())
+        .filter(|row| haystack.is_valid(*row))
+        .collect::<Vec<_>>();
+    let has_null = rows.len() < haystack.len();
+
+    let values: Bitmap = if rows.len() <= LINEAR_SCAN_MAX_LEN {
+        (0..probe.len())
+            .map(|row| {
+                let value = probe_value(row);
+                rows.iter()
+                    .any(|other| haystack_value(*other).as_ref() == value.as_ref())
+            })
+            .collect::<MutableBitmap>()
+            .into()
+    } else {
+        let state = RandomState::new();
+        // the distinct values of the haystack, represented by their row
+        let mut set = HashMap::<usize, (), RandomState>::with_hasher(state.clone());
+        for row in rows {
+            let value = haystack_value(row);
+            let value = value.as_ref();
+            let hash = state.hash_one(value);
+            let entry = set
+                .raw_entry_mut()
+                .from_hash(hash, |other| haystack_value(*other).as_ref() == value);
+            if let RawEntryMut::Vacant(entry) = entry {
+                entry.insert_with_hasher(hash, row, (), |other| {
+                    state.hash_one(haystack_value(*other).as_ref())
+                });
+            }
+        }
+        (0..probe.len())
+            .map(|row| {
+                let value = probe_value(row);
+                let value = value.as_ref();
+                set.raw_entry()
+                    .from_hash(state.hash_one(value), |other| {
+                        haystack_value(*other).as_ref() == value
+                    })
+                    .is_some()
+            })
+            .collect::<MutableBitmap>()
+            .into()
+    };
+
+    // a value that is not in a list with nulls may be equal to them: its membership is null
+    let validity = if has_null {
+        combine_validities(probe.validity(), Some(&values))
+    } else {
+        probe.validity().cloned()
+    };
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn is_in_dictionary<K: DictionaryKey>(
+    haystack: &dyn Array,
+    probe: &DictionaryArray<K>,
+) -> Result<BooleanArray> {
+    let values = is_in(haystack, probe.values().as_ref())?;
+    Ok(probe
+        .keys_iter()
+        .map(|key| key.and_then(|key| values.get(key)))
+        .collect())
+}
+
+/// Returns whether every value of `probe` is in `haystack_values`, following the semantics of
+/// SQL's `probe IN (haystack_values)`:
+/// * a null value of `probe` results in null;
+/// * a value that is in `haystack_values` results in `true`;
+/// * otherwise, the result is null if `haystack_values` contains a null, and `false` if not.
+///
+/// Values are compared by their bytes, so that floats are equal iff their bits are equal.
+/// `haystack_values` is hashed once unless it is small, in which case it is scanned per value.
+/// A dictionary-encoded `probe` is evaluated once per value of its dictionary.
+/// # Errors
+/// This function errors iff the types of `haystack_values` and of (the values of) `probe`
+/// differ or are not supported (primitives, booleans, strings and binaries).
+/// # Examples
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::is_in::is_in;
+///
+/// let probe = Int32Array::from([Some(1), Some(2), None]);
+/// let result = is_in(&Int32Array::from_slice([1, 3]), &probe).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+///
+/// let result = is_in(&Int32Array::from([Some(1), None]), &probe).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, None]));
+/// ```
+pub fn is_in(haystack_values: &dyn Array, probe: &dyn Array) -> Result<BooleanArray> {
+    if let PhysicalType::Dictionary(key_type) = probe.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let probe = probe
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            is_in_dictionary(haystack_values, probe)
+        });
+    }
+    if haystack_values.data_type() != probe.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`is_in` requires values of the same type, but got {:?} and {:?}",
+            haystack_values.data_type(),
+            probe.data_type()
+        )));
+    }
+
+    macro_rules! is_in {
+        ($array:ty, |$a:ident, $row:ident| $value:expr) => {{
+            let haystack = haystack_values.as_any().downcast_ref::<$array>().unwrap();
+            let probe = probe.as_any().downcast_ref::<$array>().unwrap();
+            Ok(is_in_by(
+                haystack,
+                |$row| {
+                    let $a = haystack;
+                    $value
+                },
+                probe,
+                |$row| {
+                    let $a = probe;
+                    $value
+                },
+            ))
+        }};
+    }
+    use PhysicalType::*;
+    match probe.data_type().to_physical_type() {
+        Boolean => is_in!(BooleanArray, |a, row| [a.value(row) as u8]),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            is_in!(PrimitiveArray<$T>, |a, row| a.value(row).to_le_bytes())
+        }),
+        Binary => is_in!(BinaryArray<i32>, |a, row| a.value(row)),
+        LargeBinary => is_in!(BinaryArray<i64>, |a, row| a.value(row)),
+        Utf8 => is_in!(Utf8Array<i32>, |a, row| a.value(row).as_bytes()),
+        LargeUtf8 => is_in!(Utf8Array<i64>, |a, row| a.value(row).as_bytes()),
+        t => Err(Error::NotYetImplemented(format!(
+            "`is_in` is not implemented for type {t:?}"
+        ))),
+    }
+}
@@ -19,34 +19,78 @@
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     datatypes::DataType,
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, OffsetsBuffer},
     types::NativeType,
 };
 
-fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+fn unary_offsets<O, F>(
+    offsets: &OffsetsBuffer<O>,
+    validity: Option<&Bitmap>,
+    op: F,
+) -> PrimitiveArray<O>
 where
     O: Offset + NativeType,
     F: Fn(O) -> O,
 {
-    let values = array
-        .offsets()
+    let values = offsets
         .buffer()
         .windows(2)
         .map(|offset| op(offset[1] - offset[0]))
         .collect::<Vec<_>>();
 
-    let data_type = if O::IS_LARGE {
+    PrimitiveArray::<O>::new(length_type::<O>(), values.into(), validity.cloned())
+}
+
+fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+where
+    O: Offset + NativeType,
+    F: Fn(O) -> O,
+{
+    unary_offsets(array.offsets(), array.validity(), op)
+}
+
+fn unary_offsets_binary<O, F>(array: &BinaryArray<O>, op: F) -> PrimitiveArray<O>
+where
+    O: Offset + NativeType,
+    F: Fn(O) -> O,
+{
+    unary_offsets(array.offsets(), array.validity(), op)
+}
+
+fn fixed_size(size: usize, length: usize, validity: Option<&Bitmap>) -> PrimitiveArray<i32> {
+    let values = vec![size as i32; length];
+    PrimitiveArray::<i32>::new(DataType::Int32, values.into(), validity.cloned())
+}
+
+fn length_type<O: Offset>() -> DataType {
+    if O::IS_LARGE {
         DataType::Int64
     } else {
         DataType::Int32
-    };
-
-    PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
+    }
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
+/// Returns an array of integers with the length of each row of the array:
+/// * the number of bytes of strings and binaries;
+/// * the number of items of lists and the number of entries of maps.
+///
+/// Lengths are computed from the offsets (or the fixed size) of the array, without reading its
+/// values. The result is [`DataType::Int64`] for large types and [`DataType::Int32`] otherwise.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::length::length;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend([Some(vec![Some(1), None]), None, Some(vec![])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = length(&array).unwrap();
+/// assert_eq!(Int32Array::from([Some(2), None, Some(0)]), result.as_ref());
+/// ```
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Utf8 => {
@@ -57,6 +101,44 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
             Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
         }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Ok(Box::new(unary_offsets_binary::<i32, _>(array, |x| x)))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            Ok(Box::new(unary_offsets_binary::<i64, _>(array, |x| x)))
+        }
+        DataType::FixedSizeBinary(size) => {
+            Ok(Box::new(fixed_size(*size, array.len(), array.validity())))
+        }
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
+        }
+        DataType::FixedSizeList(_, size) => {
+            Ok(Box::new(fixed_size(*size, array.len(), array.validity())))
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
+        }
         _ => Err(Error::InvalidArgumentError(format!(
             "length not supported for {:?}",
             array.data_type()
@@ -64,7 +146,69 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     }
 }
 
-/// Checks if an array of type `datatype` can perform length operation
+/// Returns an array of integers with the number of bits on each string of the array.
+pub fn bit_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(unary_offsets_string::<i32, _>(array, |x| x * 8)))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x * 8)))
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "bit_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+fn char_length_string<O: Offset + NativeType>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    let offsets = array.offsets();
+    let values = &array.values()[offsets.first().to_usize()..offsets.last().to_usize()];
+    // the number of chars of ASCII strings is their number of bytes
+    if values.is_ascii() {
+        return unary_offsets_string::<O, _>(array, |x| x);
+    }
+
+    let values = array
+        .values_iter()
+        .map(|value| O::from_as_usize(value.chars().count()))
+        .collect::<Vec<_>>();
+    PrimitiveArray::<O>::new(length_type::<O>(), values.into(), array.validity().cloned())
+}
+
+/// Returns an array of integers with the number of chars (unicode code points) on each string
+/// of the array. The strings are only decoded when they are not all ASCII.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::char_length;
+///
+/// let array = Utf8Array::<i32>::from([Some("olá"), None, Some("")]);
+/// let result = char_length(&array).unwrap();
+/// assert_eq!(Int32Array::from([Some(3), None, Some(0)]), result.as_ref());
+/// ```
+pub fn char_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Ok(Box::new(char_length_string(array)))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Ok(Box::new(char_length_string(array)))
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "char_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform [`length`]. [`char_length`] and
+/// [`bit_length`] are only supported by strings.
 ///
 /// # Examples
 /// ```
@@ -78,5 +222,16 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+    )
 }
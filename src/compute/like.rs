@@ -2,7 +2,6 @@
 
 use ahash::AHashMap;
 use regex::bytes::Regex as BytesRegex;
-use regex::Regex;
 
 use crate::{
     array::{BinaryArray, BooleanArray, Utf8Array},
@@ -55,10 +54,152 @@ fn replace_pattern(pattern: &str) -> String {
     result
 }
 
+/// A token of a LIKE pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A literal, matched as is
+    Literal(String),
+    /// `_`, matching exactly one character
+    AnyChar,
+    /// `%`, matching any number of characters
+    AnyString,
+}
+
+/// Splits a LIKE `pattern` into [`Token`]s, where `\` escapes `%` and `_`. Consecutive
+/// literals are merged and consecutive `%` collapsed.
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let push_literal = |tokens: &mut Vec<Token>, c: char| match tokens.last_mut() {
+        Some(Token::Literal(literal)) => literal.push(c),
+        _ => tokens.push(Token::Literal(c.to_string())),
+    };
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek().copied().map(is_like_pattern).unwrap_or(false) => {
+                push_literal(&mut tokens, chars.next().unwrap());
+            }
+            '%' if tokens.last() == Some(&Token::AnyString) => {}
+            '%' => tokens.push(Token::AnyString),
+            '_' => tokens.push(Token::AnyChar),
+            c => push_literal(&mut tokens, c),
+        }
+    }
+    tokens
+}
+
+/// A compiled LIKE pattern. Patterns made of a single literal and `%`s are matched via
+/// comparisons or a substring search, and all others via [`match_tokens`].
+enum LikeMatcher {
+    Any,
+    Equals(String),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(Box<memchr::memmem::Finder<'static>>),
+    Tokens(Vec<Token>),
+}
+
+impl LikeMatcher {
+    /// Compiles `pattern`, whose literals are lowercased when `case_insensitive`, so that it
+    /// must be matched against lowercased values.
+    fn new(pattern: &str, case_insensitive: bool) -> Self {
+        let mut tokens = tokenize(pattern);
+        if case_insensitive {
+            tokens.iter_mut().for_each(|token| {
+                if let Token::Literal(literal) = token {
+                    *literal = literal.to_lowercase()
+                }
+            })
+        }
+        use Token::*;
+        match tokens.as_slice() {
+            [] => Self::Equals(String::new()),
+            [AnyString] => Self::Any,
+            [Literal(literal)] => Self::Equals(literal.clone()),
+            [Literal(literal), AnyString] => Self::StartsWith(literal.clone()),
+            [AnyString, Literal(literal)] => Self::EndsWith(literal.clone()),
+            [AnyString, Literal(literal), AnyString] => Self::Contains(Box::new(
+                memchr::memmem::Finder::new(literal.as_bytes()).into_owned(),
+            )),
+            _ => Self::Tokens(tokens),
+        }
+    }
+
+    #[inline]
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Equals(literal) => value == literal,
+            Self::StartsWith(literal) => value.starts_with(literal.as_str()),
+            Self::EndsWith(literal) => value.ends_with(literal.as_str()),
+            Self::Contains(finder) => finder.find(value.as_bytes()).is_some(),
+            Self::Tokens(tokens) => match_tokens(tokens, value),
+        }
+    }
+}
+
+/// Returns whether `value` matches `tokens`. On a mismatch, only the last `%` is backtracked
+/// (by making it match one more character), which is sufficient since every earlier `%` can
+/// absorb anything the later one would.
+fn match_tokens(tokens: &[Token], value: &str) -> bool {
+    let next_char_len = |position: usize| value[position..].chars().next().map(char::len_utf8);
+
+    let (mut token, mut position) = (0, 0);
+    // the token after the last `%` and the position it is matched from
+    let mut backtrack: Option<(usize, usize)> = None;
+    loop {
+        match tokens.get(token) {
+            Some(Token::AnyString) => {
+                backtrack = Some((token + 1, position));
+                token += 1;
+                continue;
+            }
+            Some(Token::Literal(literal)) if value[position..].starts_with(literal.as_str()) => {
+                position += literal.len();
+                token += 1;
+                continue;
+            }
+            Some(Token::AnyChar) => {
+                if let Some(length) = next_char_len(position) {
+                    position += length;
+                    token += 1;
+                    continue;
+                }
+            }
+            None if position == value.len() => return true,
+            _ => {}
+        }
+        match backtrack {
+            Some((after, from)) => match next_char_len(from) {
+                Some(length) => {
+                    backtrack = Some((after, from + length));
+                    token = after;
+                    position = from + length;
+                }
+                None => return false,
+            },
+            None => return false,
+        }
+    }
+}
+
+/// Returns `value` lowercased, using `buffer` to hold it when it is not lowercase already.
+#[inline]
+fn to_lowercase<'a>(value: &'a str, buffer: &'a mut String) -> &'a str {
+    if value.is_ascii() && !value.bytes().any(|x| x.is_ascii_uppercase()) {
+        value
+    } else {
+        buffer.clear();
+        buffer.extend(value.chars().flat_map(char::to_lowercase));
+        buffer
+    }
+}
+
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -70,28 +211,25 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     let validity = combine_validities(lhs.validity(), rhs.validity());
 
     let mut map = AHashMap::new();
+    let mut buffer = String::new();
 
     let values =
-        Bitmap::try_from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
+        Bitmap::from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
             match (lhs, rhs) {
                 (Some(lhs), Some(pattern)) => {
-                    let pattern = if let Some(pattern) = map.get(pattern) {
-                        pattern
+                    let matcher = map
+                        .entry(pattern)
+                        .or_insert_with(|| LikeMatcher::new(pattern, case_insensitive));
+                    let lhs = if case_insensitive {
+                        to_lowercase(lhs, &mut buffer)
                     } else {
-                        let re_pattern = replace_pattern(pattern);
-                        let re = Regex::new(&format!("^{re_pattern}$")).map_err(|e| {
-                            Error::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {e}"
-                            ))
-                        })?;
-                        map.insert(pattern, re);
-                        map.get(pattern).unwrap()
+                        lhs
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    op(matcher.matches(lhs))
                 }
-                _ => Ok(false),
+                _ => false,
             }
-        }))?;
+        }));
 
     Ok(BooleanArray::new(DataType::Boolean, values, validity))
 }
@@ -103,10 +241,11 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards are matched literally when escaped by `\`. Each distinct pattern is compiled
+/// once, and patterns such as `abc%`, `%abc` and `%abc%` are matched without a general
+/// matcher.
 /// # Error
-/// Errors iff:
-/// * the arrays have a different length
-/// * any of the patterns is not valid
+/// Errors iff the arrays have a different length.
 /// # Example
 /// ```
 /// use arrow2::array::{Utf8Array, BooleanArray};
@@ -119,75 +258,68 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, false, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`], see [`like_utf8`].
+pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, false, |x| !x)
 }
 
-/// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. [`like_utf8`] ignoring the
+/// (Unicode) case of both sides.
+/// # Error
+/// Errors iff the arrays have a different length.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8;
 ///
-/// There are two wildcards supported:
+/// let strings = Utf8Array::<i32>::from_slice(&["Arrow", "ARROW", "Ärrow"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["a%", "%rOw", "ä%"]);
 ///
-/// * `%` - The percent sign represents zero, one, or multiple characters
-/// * `_` - The underscore represents a single character
-pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+/// let result = ilike_utf8(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, true]));
+/// ```
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`], see [`ilike_utf8`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
-    let validity = lhs.validity();
-
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if rhs.ends_with('%')
-        && !rhs.ends_with("\\%")
-        && !rhs[..rhs.len() - 1].contains(is_like_pattern)
-    {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else if rhs.starts_with('%')
-        && rhs.ends_with('%')
-        && !rhs.ends_with("\\%")
-        && !rhs[1..rhs.len() - 1].contains(is_like_pattern)
-    {
-        let needle = &rhs[1..rhs.len() - 1];
-        let finder = memchr::memmem::Finder::new(needle);
+    let matcher = LikeMatcher::new(rhs, case_insensitive);
+    let values = if case_insensitive {
+        let mut buffer = String::new();
         Bitmap::from_trusted_len_iter(
             lhs.values_iter()
-                .map(|x| op(finder.find(x.as_bytes()).is_some())),
+                .map(|x| op(matcher.matches(to_lowercase(x, &mut buffer)))),
         )
     } else {
-        let re_pattern = replace_pattern(rhs);
-        let re = Regex::new(&format!("^{re_pattern}$")).map_err(|e| {
-            Error::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {e}"))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(matcher.matches(x))))
     };
     Ok(BooleanArray::new(
         DataType::Boolean,
         values,
-        validity.cloned(),
+        lhs.validity().cloned(),
     ))
 }
 
-/// Returns `lhs LIKE rhs` operation.
+/// Returns `lhs LIKE rhs` operation, see [`like_utf8`].
 ///
 /// There are two wildcards supported:
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
-/// # Error
-/// Errors iff:
-/// * the arrays have a different length
-/// * any of the patterns is not valid
 /// # Example
 /// ```
 /// use arrow2::array::{Utf8Array, BooleanArray};
@@ -199,17 +331,22 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| x)
 }
 
-/// Returns `lhs NOT LIKE rhs` operation.
-///
-/// There are two wildcards supported:
-///
-/// * `%` - The percent sign represents zero, one, or multiple characters
-/// * `_` - The underscore represents a single character
+/// Returns `lhs NOT LIKE rhs` operation, see [`like_utf8`].
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, see [`ilike_utf8`].
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation, see [`ilike_utf8`].
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| !x)
 }
 
 #[inline]
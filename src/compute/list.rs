@@ -0,0 +1,538 @@
+//! Contains operators over the items of list arrays ([`ListArray`] and
+//! [`FixedSizeListArray`]), such as [`explode`], [`flatten`], [`list_contains`] and
+//! [`list_concat`].
+use std::cmp::Ordering;
+
+use crate::array::{
+    growable::make_growable, ord::build_compare, Array, BooleanArray, DictionaryArray,
+    FixedSizeListArray, ListArray, PrimitiveArray,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, Field, PhysicalType};
+use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets};
+use crate::scalar::Scalar;
+use crate::types::{Index, NativeType};
+
+use super::utils::{combine_validities, scalar_to_array};
+
+/// The items of a list array: its values and the offsets of every slot in them.
+pub(crate) struct Items<'a> {
+    pub values: &'a dyn Array,
+    pub offsets: Vec<usize>,
+    pub validity: Option<&'a Bitmap>,
+}
+
+impl<'a> Items<'a> {
+    pub fn try_new(array: &'a dyn Array) -> Result<Self> {
+        let (values, offsets) = match array.data_type().to_logical_type() {
+            DataType::List(_) => {
+                let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+                let offsets = array.offsets().buffer().iter().map(|x| x.to_usize());
+                (array.values().as_ref(), offsets.collect())
+            }
+            DataType::LargeList(_) => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let offsets = array.offsets().buffer().iter().map(|x| x.to_usize());
+                (array.values().as_ref(), offsets.collect())
+            }
+            DataType::FixedSizeList(_, size) => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                let offsets = (0..=array.len()).map(|row| row * size);
+                (array.values().as_ref(), offsets.collect())
+            }
+            other => {
+                return Err(Error::InvalidArgumentError(format!(
+                    "Expected a list array, but got {other:?}"
+                )))
+            }
+        };
+        Ok(Self {
+            values,
+            offsets,
+            validity: array.validity(),
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the start and the length of the items of `row`, with no items for null slots.
+    #[inline]
+    pub fn slot(&self, row: usize) -> (usize, usize) {
+        if self.validity.map(|x| x.get_bit(row)).unwrap_or(true) {
+            (self.offsets[row], self.offsets[row + 1] - self.offsets[row])
+        } else {
+            (self.offsets[row], 0)
+        }
+    }
+}
+
+/// Returns the field of the items of a list array of `data_type`.
+fn child_field(data_type: &DataType) -> Field {
+    match data_type.to_logical_type() {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            field.as_ref().clone()
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the items of all slots of the list array `array`, and the row of `array` of every
+/// item, as used to implement `UNNEST`: the other columns are [`take`](crate::compute::take)n
+/// by the returned rows.
+///
+/// Null and empty slots have no items. When `array` has no nulls, the items are a slice of
+/// its values.
+/// # Errors
+/// This function errors iff `array` is not a `List`, `LargeList` or `FixedSizeList`.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend, UInt64Array};
+/// use arrow2::compute::list::explode;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![]), Some(vec![Some(3)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let (values, rows) = explode(&array).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn Array);
+/// assert_eq!(rows, UInt64Array::from_slice([0, 0, 3]));
+/// ```
+pub fn explode(array: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<u64>)> {
+    let items = Items::try_new(array)?;
+
+    let mut rows = Vec::with_capacity(items.offsets[items.len()] - items.offsets[0]);
+    for row in 0..items.len() {
+        let (_, length) = items.slot(row);
+        rows.resize(rows.len() + length, row as u64);
+    }
+    let rows = PrimitiveArray::new(DataType::UInt64, rows.into(), None);
+
+    let values = if array.null_count() == 0 {
+        items.values.sliced(
+            items.offsets[0],
+            items.offsets[items.len()] - items.offsets[0],
+        )
+    } else {
+        let mut growable = make_growable(&[items.values], false, rows.len());
+        for row in 0..items.len() {
+            let (start, length) = items.slot(row);
+            growable.extend(0, start, length);
+        }
+        growable.as_box()
+    };
+    Ok((values, rows))
+}
+
+fn flatten_impl<O: Offset>(
+    array: &dyn Array,
+    outer: &Items,
+    inner: &Items,
+) -> Result<ListArray<O>> {
+    let mut offsets = Offsets::<O>::with_capacity(outer.len());
+    let mut growable = make_growable(&[inner.values], false, 0);
+    for row in 0..outer.len() {
+        let (start, length) = outer.slot(row);
+        let mut row_length = 0;
+        for inner_row in start..start + length {
+            let (start, length) = inner.slot(inner_row);
+            growable.extend(0, start, length);
+            row_length += length;
+        }
+        offsets.try_push_usize(row_length)?;
+    }
+
+    let field = child_field(outer.values.data_type());
+    let data_type = if O::IS_LARGE {
+        DataType::LargeList(Box::new(field))
+    } else {
+        DataType::List(Box::new(field))
+    };
+    ListArray::try_new(
+        data_type,
+        offsets.into(),
+        growable.as_box(),
+        array.validity().cloned(),
+    )
+}
+
+/// Flattens a list array of list arrays by one level, concatenating the items of the inner
+/// lists of every slot.
+///
+/// Null inner lists are treated as empty, and null slots of `array` remain null. The result is
+/// a `LargeList` if `array` is a `LargeList`, and a `List` otherwise.
+/// # Errors
+/// This function errors iff `array` is not a list of lists (`List`, `LargeList` or
+/// `FixedSizeList`), or the items of a slot do not fit in the offsets of the result.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::flatten;
+///
+/// let mut array = MutableListArray::<i32, MutableListArray<i32, MutablePrimitiveArray<i32>>>::new();
+/// array.try_extend(vec![
+///     Some(vec![Some(vec![Some(1)]), Some(vec![Some(2), Some(3)])]),
+///     Some(vec![None, Some(vec![Some(4)])]),
+/// ]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = flatten(&array).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(vec![Some(vec![Some(1), Some(2), Some(3)]), Some(vec![Some(4)])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn flatten(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let outer = Items::try_new(array)?;
+    let inner = Items::try_new(outer.values)?;
+    Ok(match array.data_type().to_logical_type() {
+        DataType::LargeList(_) => flatten_impl::<i64>(array, &outer, &inner)?.boxed(),
+        _ => flatten_impl::<i32>(array, &outer, &inner)?.boxed(),
+    })
+}
+
+/// Returns whether the item `item` of `values` is equal to the needle `row` of `needles`.
+type Matcher<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+fn build_matcher<'a>(values: &'a dyn Array, needles: &'a dyn Array) -> Result<Matcher<'a>> {
+    Ok(match values.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) if values.data_type() == needles.data_type() => {
+            with_match_primitive_type!(primitive, |$T| {
+                let values = values.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                let needles = needles.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+                Box::new(move |item, row| {
+                    values.is_valid(item)
+                        && values.value(item).to_le_bytes().as_ref()
+                            == needles.value(row).to_le_bytes().as_ref()
+                })
+            })
+        }
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let values = values.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let matcher = build_matcher(values.values().as_ref(), needles)?;
+            if needles.len() == 1 {
+                // a single needle is compared once per value of the dictionary
+                let matches = (0..values.values().len())
+                    .map(|value| matcher(value, 0))
+                    .collect::<MutableBitmap>();
+                let matches: Bitmap = matches.into();
+                Box::new(move |item, _| {
+                    values.keys().is_valid(item) && matches.get_bit(values.key_value(item))
+                })
+            } else {
+                Box::new(move |item, row| {
+                    values.keys().is_valid(item) && matcher(values.key_value(item), row)
+                })
+            }
+        }),
+        _ => {
+            let compare = build_compare(values, needles)?;
+            Box::new(move |item, row| {
+                values.is_valid(item) && compare(item, row) == Ordering::Equal
+            })
+        }
+    })
+}
+
+/// Returns the index in its slot of the first item of every slot of `array` that is equal to
+/// the needle `needle_row(row)` of `needles`, and the validity of the result.
+fn find<F: Fn(usize) -> usize>(
+    array: &dyn Array,
+    needles: &dyn Array,
+    needle_row: F,
+) -> Result<(Vec<Option<usize>>, Option<Bitmap>)> {
+    let items = Items::try_new(array)?;
+    let matcher = build_matcher(items.values, needles)?;
+
+    let positions = (0..items.len())
+        .map(|row| {
+            let (start, length) = items.slot(row);
+            let needle = needle_row(row);
+            if needles.is_null(needle) {
+                return None;
+            }
+            (0..length).find(|position| matcher(start + position, needle))
+        })
+        .collect::<Vec<_>>();
+
+    let validity = match (array.validity(), needles.null_count()) {
+        (validity, 0) => validity.cloned(),
+        (validity, _) => {
+            let needles = (0..items.len())
+                .map(|row| needles.is_valid(needle_row(row)))
+                .collect::<MutableBitmap>();
+            let needles: Bitmap = needles.into();
+            Some(
+                validity
+                    .map(|validity| validity & &needles)
+                    .unwrap_or(needles),
+            )
+        }
+    };
+    Ok((positions, validity))
+}
+
+fn check_needles(array: &dyn Array, needles: &dyn Array) -> Result<()> {
+    if array.len() != needles.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Searching lists requires one needle per slot, but got {} slots and {} needles",
+            array.len(),
+            needles.len()
+        )));
+    }
+    Ok(())
+}
+
+fn contains_from(positions: Vec<Option<usize>>, validity: Option<Bitmap>) -> BooleanArray {
+    let values = positions
+        .iter()
+        .map(|position| position.is_some())
+        .collect::<MutableBitmap>();
+    BooleanArray::new(DataType::Boolean, values.into(), validity)
+}
+
+fn position_from(positions: Vec<Option<usize>>, validity: Option<Bitmap>) -> PrimitiveArray<u64> {
+    let array = positions
+        .into_iter()
+        .map(|position| position.map(|position| position as u64 + 1))
+        .collect::<PrimitiveArray<u64>>();
+    match validity {
+        Some(validity) => {
+            let found = array.validity().cloned();
+            let validity = found.map(|found| &found & &validity).unwrap_or(validity);
+            array.with_validity(Some(validity))
+        }
+        None => array,
+    }
+}
+
+/// Returns whether every slot of the list array `array` contains the needle of the same row of
+/// `needles`, comparing the items of the slots in place.
+///
+/// The result is null when the slot or the needle is null, and null items never match.
+/// Primitive items are compared by their bytes; items of a dictionary-encoded child are compared
+/// via the values of the dictionary.
+/// # Errors
+/// This function errors iff `array` is not a list array, the arrays have different lengths, or
+/// the type of `needles` is not the type of the items (or of their dictionary values) or has no
+/// natural order.
+/// # Examples
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_contains;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3)]), None]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let needles = Int32Array::from_slice([2, 2, 2]);
+/// let result = list_contains(&array, &needles).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
+pub fn list_contains(array: &dyn Array, needles: &dyn Array) -> Result<BooleanArray> {
+    check_needles(array, needles)?;
+    let (positions, validity) = find(array, needles, |row| row)?;
+    Ok(contains_from(positions, validity))
+}
+
+/// Returns whether every slot of the list array `array` contains `needle`, see
+/// [`list_contains`].
+/// # Errors
+/// This function errors under the same conditions as [`list_contains`], or when `needle` is a
+/// union.
+pub fn list_contains_scalar(array: &dyn Array, needle: &dyn Scalar) -> Result<BooleanArray> {
+    let needles = scalar_to_array(needle, needle.data_type())?;
+    let (positions, validity) = find(array, needles.as_ref(), |_| 0)?;
+    Ok(contains_from(positions, validity))
+}
+
+/// Returns the (1-based) position of the first item of every slot of the list array `array`
+/// that is equal to the needle of the same row of `needles`.
+///
+/// The result is null when the slot or the needle is null, or when the slot does not contain
+/// the needle; see [`list_contains`] for how items are compared.
+/// # Errors
+/// This function errors under the same conditions as [`list_contains`].
+/// # Examples
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, UInt64Array};
+/// use arrow2::compute::list::array_position_scalar;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some("a"), Some("b")]), Some(vec![Some("c")])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = array_position_scalar(&array, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+/// assert_eq!(result, UInt64Array::from([Some(2), None]));
+/// ```
+pub fn array_position(array: &dyn Array, needles: &dyn Array) -> Result<PrimitiveArray<u64>> {
+    check_needles(array, needles)?;
+    let (positions, validity) = find(array, needles, |row| row)?;
+    Ok(position_from(positions, validity))
+}
+
+/// Returns the (1-based) position of the first item of every slot of the list array `array`
+/// that is equal to `needle`, see [`array_position`].
+/// # Errors
+/// This function errors under the same conditions as [`list_contains_scalar`].
+pub fn array_position_scalar(
+    array: &dyn Array,
+    needle: &dyn Scalar,
+) -> Result<PrimitiveArray<u64>> {
+    let needles = scalar_to_array(needle, needle.data_type())?;
+    let (positions, validity) = find(array, needles.as_ref(), |_| 0)?;
+    Ok(position_from(positions, validity))
+}
+
+/// Returns the lists of `arrays` concatenated row by row: the `i`-th slot of the result holds
+/// the items of the `i`-th slots of all arrays, in order.
+///
+/// A slot is null when it is null in any of the arrays. The offsets are computed from the
+/// lengths of the slots, and the items of every slot are copied in a single slice.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or types, or
+/// the items of a slot do not fit in the offsets.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_concat;
+///
+/// let list = |data: Vec<Option<Vec<Option<i32>>>>| {
+///     let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+///     array.try_extend(data).unwrap();
+///     ListArray::<i32>::from(array)
+/// };
+/// let a = list(vec![Some(vec![Some(1)]), Some(vec![])]);
+/// let b = list(vec![Some(vec![Some(2), Some(3)]), None]);
+///
+/// let result = list_concat(&[&a, &b]).unwrap();
+/// assert_eq!(result, list(vec![Some(vec![Some(1), Some(2), Some(3)]), None]));
+/// ```
+pub fn list_concat<O: Offset>(arrays: &[&ListArray<O>]) -> Result<ListArray<O>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("`list_concat` requires at least one array".to_string())
+    })?;
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.len() != first.len() || array.data_type() != first.data_type())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "`list_concat` requires arrays of the same length and type, but got {:?} of length {} and {:?} of length {}",
+            first.data_type(),
+            first.len(),
+            array.data_type(),
+            array.len()
+        )));
+    }
+
+    let validity = arrays.iter().fold(None, |acc: Option<Bitmap>, array| {
+        combine_validities(acc.as_ref(), array.validity())
+    });
+    let is_valid = |row: usize| validity.as_ref().map(|x| x.get_bit(row)).unwrap_or(true);
+
+    let mut offsets = Offsets::<O>::with_capacity(first.len());
+    for row in 0..first.len() {
+        let length = if is_valid(row) {
+            arrays
+                .iter()
+                .map(|array| array.offsets().start_end(row))
+                .map(|(start, end)| end - start)
+                .sum()
+        } else {
+            0
+        };
+        offsets.try_push_usize(length)?;
+    }
+
+    let values = arrays
+        .iter()
+        .map(|array| array.values().as_ref())
+        .collect::<Vec<_>>();
+    let mut growable = make_growable(&values, false, offsets.last().to_usize());
+    for row in (0..first.len()).filter(|row| is_valid(*row)) {
+        for (index, array) in arrays.iter().enumerate() {
+            let (start, end) = array.offsets().start_end(row);
+            growable.extend(index, start, end - start);
+        }
+    }
+
+    ListArray::try_new(
+        first.data_type().clone(),
+        offsets.into(),
+        growable.as_box(),
+        validity,
+    )
+}
+
+/// Returns `array` with the element of the same row of `elements` appended to every slot.
+///
+/// Null slots remain null, and null elements are appended as null items.
+/// # Errors
+/// This function errors iff the arrays have different lengths, the type of `elements` is not
+/// the type of the items of `array`, or the items of a slot do not fit in the offsets.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_append;
+///
+/// let list = |data: Vec<Option<Vec<Option<i32>>>>| {
+///     let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+///     array.try_extend(data).unwrap();
+///     ListArray::<i32>::from(array)
+/// };
+/// let array = list(vec![Some(vec![Some(1)]), None, Some(vec![])]);
+/// let elements = Int32Array::from([Some(2), Some(3), None]);
+///
+/// let result = list_append(&array, &elements).unwrap();
+/// assert_eq!(result, list(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![None])]));
+/// ```
+pub fn list_append<O: Offset>(array: &ListArray<O>, elements: &dyn Array) -> Result<ListArray<O>> {
+    if array.len() != elements.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`list_append` requires one element per slot, but got {} slots and {} elements",
+            array.len(),
+            elements.len()
+        )));
+    }
+    if array.values().data_type() != elements.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "`list_append` requires elements of the type of the items, {:?}, but got {:?}",
+            array.values().data_type(),
+            elements.data_type()
+        )));
+    }
+
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+    for row in 0..array.len() {
+        let length = if array.is_valid(row) {
+            let (start, end) = array.offsets().start_end(row);
+            end - start + 1
+        } else {
+            0
+        };
+        offsets.try_push_usize(length)?;
+    }
+
+    let mut growable = make_growable(
+        &[array.values().as_ref(), elements],
+        elements.null_count() > 0,
+        offsets.last().to_usize(),
+    );
+    for row in (0..array.len()).filter(|row| array.is_valid(*row)) {
+        let (start, end) = array.offsets().start_end(row);
+        growable.extend(0, start, end - start);
+        growable.extend(1, row, 1);
+    }
+
+    ListArray::try_new(
+        array.data_type().clone(),
+        offsets.into(),
+        growable.as_box(),
+        array.validity().cloned(),
+    )
+}
@@ -0,0 +1,316 @@
+//! Contains mathematical functions, such as [`sqrt`], [`ln`], [`sin`] and [`pow`], over
+//! floating point [`PrimitiveArray`]s.
+//!
+//! Each function has a statically-typed version over [`PrimitiveArray<T>`] with `T` `f32` or
+//! `f64` and a dynamically-typed version, suffixed `_dyn`, that also accepts integer arrays by
+//! promoting them to [`DataType::Float64`]. Like Rust's functions, values outside of the domain
+//! of a function (e.g. the square root of a negative number) result in `NaN`; the validity
+//! is not changed.
+//!
+//! [`round`], [`floor`] and [`ceil`] round to a number of decimal places and also support
+//! decimals, see [`round_decimal`].
+use num_traits::{AsPrimitive, Float};
+
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::arity::{binary, try_unary, unary};
+use crate::compute::cast::{div_round, round_float, RoundingMode};
+use crate::compute::utils::check_same_len;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+/// Returns whether the functions of this module can be applied to arrays of `data_type`.
+pub fn can_math(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+    )
+}
+
+fn promote<T, F>(array: &dyn Array, op: F) -> Box<dyn Array>
+where
+    T: NativeType + AsPrimitive<f64>,
+    F: Fn(f64) -> f64,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    unary(array, |x| op(x.as_()), DataType::Float64).boxed()
+}
+
+fn unary_dyn(
+    array: &dyn Array,
+    op32: fn(f32) -> f32,
+    op64: fn(f64) -> f64,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    Ok(match array.data_type().to_logical_type() {
+        Float32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap();
+            unary(array, op32, array.data_type().clone()).boxed()
+        }
+        Float64 => promote::<f64, _>(array, op64),
+        Int8 => promote::<i8, _>(array, op64),
+        Int16 => promote::<i16, _>(array, op64),
+        Int32 => promote::<i32, _>(array, op64),
+        Int64 => promote::<i64, _>(array, op64),
+        UInt8 => promote::<u8, _>(array, op64),
+        UInt16 => promote::<u16, _>(array, op64),
+        UInt32 => promote::<u32, _>(array, op64),
+        UInt64 => promote::<u64, _>(array, op64),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "Mathematical functions are not supported for {other:?}"
+            )))
+        }
+    })
+}
+
+/// Returns `array` as a [`PrimitiveArray<f64>`], promoting integers and `f32`.
+fn to_f64(array: &dyn Array) -> Result<PrimitiveArray<f64>> {
+    if let DataType::Float32 = array.data_type().to_logical_type() {
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f32>>()
+            .unwrap();
+        return Ok(unary(array, |x| x as f64, DataType::Float64));
+    }
+    let array = unary_dyn(array, |x| x, |x| x)?;
+    Ok(array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f64>>()
+        .unwrap()
+        .clone())
+}
+
+macro_rules! unary_math {
+    ($name:ident, $dyn_name:ident, $description:literal) => {
+        #[doc = concat!("Returns the ", $description, " of each value of `array`.")]
+        pub fn $name<T: NativeType + Float>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+            unary(array, |x| x.$name(), array.data_type().clone())
+        }
+
+        #[doc = concat!("Returns the ", $description, " of each value of `array`.")]
+        #[doc = ""]
+        #[doc = "Integers are promoted to [`DataType::Float64`]."]
+        /// # Errors
+        /// This function errors iff the array's type is not supported, see [`can_math`].
+        pub fn $dyn_name(array: &dyn Array) -> Result<Box<dyn Array>> {
+            unary_dyn(array, <f32 as Float>::$name, <f64 as Float>::$name)
+        }
+    };
+}
+
+unary_math!(sqrt, sqrt_dyn, "square root");
+unary_math!(ln, ln_dyn, "natural logarithm");
+unary_math!(log2, log2_dyn, "base 2 logarithm");
+unary_math!(log10, log10_dyn, "base 10 logarithm");
+unary_math!(exp, exp_dyn, "exponential (`e^x`)");
+unary_math!(sin, sin_dyn, "sine (in radians)");
+unary_math!(cos, cos_dyn, "cosine (in radians)");
+unary_math!(tan, tan_dyn, "tangent (in radians)");
+unary_math!(asin, asin_dyn, "arcsine (in radians)");
+unary_math!(acos, acos_dyn, "arccosine (in radians)");
+unary_math!(atan, atan_dyn, "arctangent (in radians)");
+
+/// Raises each value of `base` to the power of the corresponding value of `exponent`.
+/// # Panic
+/// This function panics iff the arrays have different lengths.
+/// # Examples
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::math::pow;
+///
+/// let base = Float64Array::from([Some(2.0), None, Some(4.0)]);
+/// let exponent = Float64Array::from([Some(3.0), Some(1.0), Some(0.5)]);
+/// let result = pow(&base, &exponent);
+/// assert_eq!(result, Float64Array::from([Some(8.0), None, Some(2.0)]));
+/// ```
+pub fn pow<T: NativeType + Float>(
+    base: &PrimitiveArray<T>,
+    exponent: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    binary(base, exponent, base.data_type().clone(), |a, b| a.powf(b))
+}
+
+/// Raises each value of `base` to the power of `exponent`.
+pub fn pow_scalar<T: NativeType + Float>(
+    base: &PrimitiveArray<T>,
+    exponent: T,
+) -> PrimitiveArray<T> {
+    unary(base, |a| a.powf(exponent), base.data_type().clone())
+}
+
+/// Raises each value of `base` to the power of the corresponding value of `exponent`.
+///
+/// The result is [`DataType::Float32`] if both arrays are [`DataType::Float32`] and
+/// [`DataType::Float64`] otherwise, with integers promoted to it.
+/// # Errors
+/// This function errors iff the arrays have different lengths or a type is not supported,
+/// see [`can_math`].
+pub fn pow_dyn(base: &dyn Array, exponent: &dyn Array) -> Result<Box<dyn Array>> {
+    check_same_len(base, exponent)?;
+    if let (DataType::Float32, DataType::Float32) = (
+        base.data_type().to_logical_type(),
+        exponent.data_type().to_logical_type(),
+    ) {
+        let base = base.as_any().downcast_ref::<PrimitiveArray<f32>>().unwrap();
+        let exponent = exponent
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f32>>()
+            .unwrap();
+        return Ok(pow(base, exponent).boxed());
+    }
+    Ok(pow(&to_f64(base)?, &to_f64(exponent)?).boxed())
+}
+
+/// Rounds each value of `array` to `digits` decimal places according to `rounding`. A negative
+/// `digits` rounds to the left of the decimal point, e.g. `round(1234.5, -2)` is `1200.0`.
+/// When `10^|digits|` is not finite in `T`, finite values round to a signed zero for a negative
+/// `digits` and are unchanged for a positive one.
+///
+/// Like any floating point operation, the result is subject to representation errors: e.g.
+/// `2.675` is stored as `2.67499999...` and thus rounds to `2.67` with two digits.
+/// # Examples
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::cast::RoundingMode;
+/// use arrow2::compute::math::round;
+///
+/// let array = Float64Array::from([Some(1.125), None, Some(-1234.5)]);
+/// let result = round(&array, 2, RoundingMode::HalfEven);
+/// assert_eq!(result, Float64Array::from([Some(1.12), None, Some(-1234.5)]));
+///
+/// let result = round(&array, -2, RoundingMode::HalfAwayFromZero);
+/// assert_eq!(result, Float64Array::from([Some(0.0), None, Some(-1200.0)]));
+/// ```
+pub fn round<T: NativeType + Float>(
+    array: &PrimitiveArray<T>,
+    digits: i32,
+    rounding: RoundingMode,
+) -> PrimitiveArray<T> {
+    let factor = T::from(10.0).unwrap().powi(digits.abs());
+    if !factor.is_finite() {
+        // no finite value has digits this far from the decimal point: values are already
+        // rounded to the right of it, and round to zero to the left of it
+        let op = move |x: T| {
+            if digits < 0 && x.is_finite() {
+                T::zero() * x.signum()
+            } else {
+                x
+            }
+        };
+        return unary(array, op, array.data_type().clone());
+    }
+    let op = move |x: T| {
+        let rounded = if digits >= 0 {
+            round_float(x * factor, rounding) / factor
+        } else {
+            round_float(x / factor, rounding) * factor
+        };
+        // e.g. `x * factor` overflows when `x` has no decimal places to round
+        if rounded.is_finite() {
+            rounded
+        } else {
+            x
+        }
+    };
+    unary(array, op, array.data_type().clone())
+}
+
+/// Rounds each value of `array` down to `digits` decimal places, see [`round`].
+pub fn floor<T: NativeType + Float>(array: &PrimitiveArray<T>, digits: i32) -> PrimitiveArray<T> {
+    round(array, digits, RoundingMode::Floor)
+}
+
+/// Rounds each value of `array` up to `digits` decimal places, see [`round`].
+pub fn ceil<T: NativeType + Float>(array: &PrimitiveArray<T>, digits: i32) -> PrimitiveArray<T> {
+    round(array, digits, RoundingMode::Ceil)
+}
+
+/// Rounds each value of a [`DataType::Decimal`] array to `digits` decimal places according to
+/// `rounding`. The result has the precision and scale of `array`.
+/// # Errors
+/// This function errors iff `array` is not a decimal, `digits` is smaller than `scale - 38`,
+/// or a rounded value does not fit the precision (e.g. `99.9` to zero digits with precision 3).
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::cast::RoundingMode;
+/// use arrow2::compute::math::round_decimal;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = PrimitiveArray::from([Some(1_25i128), Some(-1_35)]).to(DataType::Decimal(5, 2));
+/// let result = round_decimal(&array, 1, RoundingMode::HalfEven).unwrap();
+/// let expected = PrimitiveArray::from([Some(1_20i128), Some(-1_40)]).to(DataType::Decimal(5, 2));
+/// assert_eq!(result, expected);
+/// ```
+pub fn round_decimal(
+    array: &PrimitiveArray<i128>,
+    digits: i32,
+    rounding: RoundingMode,
+) -> Result<PrimitiveArray<i128>> {
+    let (precision, scale) = match array.data_type().to_logical_type() {
+        DataType::Decimal(precision, scale) => (*precision, *scale as i32),
+        other => {
+            return Err(Error::InvalidArgumentError(format!(
+                "round_decimal expects a Decimal array, got {other:?}"
+            )))
+        }
+    };
+    if digits >= scale {
+        return Ok(array.clone());
+    }
+    let divisor = 10i128.checked_pow((scale - digits) as u32).ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "Cannot round a decimal of scale {scale} to {digits} digits"
+        ))
+    })?;
+    let max = 10u128
+        .checked_pow(precision as u32)
+        .map_or(u128::MAX, |x| x - 1);
+
+    let op = move |x: i128| {
+        div_round(x, divisor, rounding)
+            .checked_mul(divisor)
+            .filter(|x| x.unsigned_abs() <= max)
+            .ok_or(Error::Overflow)
+    };
+    try_unary(array, op, array.data_type().clone())
+}
+
+/// Rounds each value of `array` to `digits` decimal places according to `rounding`, see
+/// [`round`] and [`round_decimal`].
+/// # Errors
+/// This function errors iff the array is not [`DataType::Float32`], [`DataType::Float64`] or
+/// [`DataType::Decimal`], or under the same conditions as [`round_decimal`].
+pub fn round_dyn(array: &dyn Array, digits: i32, rounding: RoundingMode) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Float32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap();
+            Ok(round(array, digits, rounding).boxed())
+        }
+        DataType::Float64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            Ok(round(array, digits, rounding).boxed())
+        }
+        DataType::Decimal(_, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            round_decimal(array, digits, rounding).map(|x| x.boxed())
+        }
+        other => Err(Error::NotYetImplemented(format!(
+            "Rounding is not supported for {other:?}"
+        ))),
+    }
+}
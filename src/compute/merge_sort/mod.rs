@@ -55,6 +55,12 @@
 //!
 //! To serialize slices, e.g. for checkpointing or transfer via Arrow's IPC, you can store
 //! them as 3 non-null primitive arrays (e.g. `PrimitiveArray<i64>`).
+//!
+//! ## Merge-sort streams of chunks
+//!
+//! When the sorted batches do not fit in memory (e.g. the runs of an external sort),
+//! [`merge_chunks`] merges streams of sorted [`Chunk`](crate::chunk::Chunk)s by multiple
+//! columns into sorted chunks of a target size, holding only the current chunk of every stream.
 
 use ahash::AHashMap;
 use std::cmp::Ordering;
@@ -70,6 +76,9 @@ use crate::array::{
 pub use crate::compute::sort::SortOptions;
 use crate::error::Result;
 
+mod stream;
+pub use stream::{merge_chunks, MergeChunks};
+
 /// A slice denoting `(array_index, start, len)` representing a slice from one of N arrays.
 /// This is used to keep track of contiguous blocks of slots.
 /// An array of MergeSlice, `[MergeSlice]`, represents inter-leaved array slices.
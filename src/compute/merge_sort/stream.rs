@@ -0,0 +1,343 @@
+use ahash::AHashMap;
+use std::cmp::Ordering;
+
+use crate::array::{
+    growable::make_growable,
+    ord::{build_compare, DynComparator},
+    Array,
+};
+use crate::chunk::Chunk;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+use super::{MergeSlice, SortOptions};
+
+/// A row of one of the chunks of [`MergeChunks`], as `(chunk id, row)`.
+type Cursor = (usize, usize);
+
+/// An iterator that merges streams of sorted [`Chunk`]s into sorted [`Chunk`]s of a target
+/// size, see [`merge_chunks`].
+pub struct MergeChunks<I> {
+    streams: Vec<I>,
+    sort_columns: Vec<(usize, SortOptions)>,
+    chunk_size: usize,
+    data_types: Option<Vec<DataType>>,
+    // the current row of every stream, `None` once the stream is exhausted
+    heads: Vec<Option<Cursor>>,
+    // the chunks referenced by `heads` or `slices`, by id
+    chunks: AHashMap<usize, Chunk<Box<dyn Array>>>,
+    next_id: usize,
+    // the comparators of the sort columns between two chunks, by chunk ids
+    comparators: AHashMap<(usize, usize), Vec<DynComparator>>,
+    // the slices of the next merged chunk, as `(chunk id, start, len)`
+    slices: Vec<MergeSlice>,
+    length: usize,
+    started: bool,
+}
+
+/// Returns an iterator that merges `streams` of sorted [`Chunk`]s into sorted [`Chunk`]s of
+/// `chunk_size` rows (the last one may be smaller), without concatenating the inputs.
+///
+/// Each stream must be sorted by `sort_columns`, pairs of a column index and the
+/// [`SortOptions`] of the column, compared lexicographically. Only the current chunk of every
+/// stream and the chunks referenced by the next merged chunk are kept in memory. The merge is
+/// stable: equal rows are yielded in the order of their streams.
+///
+/// Rows are taken in runs: the stream with the smallest row is consumed until its row is larger
+/// than the smallest row of the other streams, and every run is copied at once.
+/// # Errors
+/// This function errors iff `sort_columns` is empty or `chunk_size` is zero. The iterator
+/// yields an error (and stops) when a stream errors, the chunks have different types, a sort
+/// column is out of bounds, or its type has no natural order.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::merge_sort::{merge_chunks, SortOptions};
+/// # use arrow2::error::Result;
+/// # fn main() -> Result<()> {
+/// let chunk = |keys: &[i32], values: &[&str]| -> Result<Chunk<Box<dyn Array>>> {
+///     Ok(Chunk::new(vec![
+///         Int32Array::from_slice(keys).boxed(),
+///         Utf8Array::<i32>::from_slice(values).boxed(),
+///     ]))
+/// };
+/// let a = vec![chunk(&[1, 4], &["a", "b"]), chunk(&[6], &["c"])];
+/// let b = vec![chunk(&[2, 3, 5], &["d", "e", "f"])];
+///
+/// let streams = vec![a.into_iter(), b.into_iter()];
+/// let merged = merge_chunks(streams, vec![(0, SortOptions::default())], 4)?
+///     .collect::<Result<Vec<_>>>()?;
+///
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!(
+///     merged[0].arrays()[0].as_ref(),
+///     &Int32Array::from_slice([1, 2, 3, 4]) as &dyn Array
+/// );
+/// assert_eq!(
+///     merged[1].arrays()[1].as_ref(),
+///     &Utf8Array::<i32>::from_slice(["f", "c"]) as &dyn Array
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge_chunks<I>(
+    streams: Vec<I>,
+    sort_columns: Vec<(usize, SortOptions)>,
+    chunk_size: usize,
+) -> Result<MergeChunks<I>>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    if sort_columns.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "Merging chunks requires at least one sort column".to_string(),
+        ));
+    }
+    if chunk_size == 0 {
+        return Err(Error::InvalidArgumentError(
+            "Merging chunks requires a positive chunk size".to_string(),
+        ));
+    }
+    let heads = vec![None; streams.len()];
+    Ok(MergeChunks {
+        streams,
+        sort_columns,
+        chunk_size,
+        data_types: None,
+        heads,
+        chunks: AHashMap::new(),
+        next_id: 0,
+        comparators: AHashMap::new(),
+        slices: vec![],
+        length: 0,
+        started: false,
+    })
+}
+
+impl<I> MergeChunks<I>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    /// Moves the head of `stream` to its next non-empty chunk.
+    fn pull(&mut self, stream: usize) -> Result<()> {
+        self.heads[stream] = None;
+        for chunk in self.streams[stream].by_ref() {
+            let chunk = chunk?;
+            if chunk.is_empty() {
+                continue;
+            }
+            let data_types = chunk
+                .arrays()
+                .iter()
+                .map(|array| array.data_type().clone())
+                .collect::<Vec<_>>();
+            match &self.data_types {
+                Some(expected) if expected != &data_types => {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "Merged chunks must have the same types, but got {expected:?} and {data_types:?}"
+                    )))
+                }
+                Some(_) => {}
+                None => {
+                    if let Some((column, _)) = self
+                        .sort_columns
+                        .iter()
+                        .find(|(column, _)| *column >= data_types.len())
+                    {
+                        return Err(Error::InvalidArgumentError(format!(
+                            "The sort column {column} is out of bounds for chunks of {} columns",
+                            data_types.len()
+                        )));
+                    }
+                    self.data_types = Some(data_types);
+                }
+            }
+            let id = self.next_id;
+            self.next_id += 1;
+            self.chunks.insert(id, chunk);
+            self.heads[stream] = Some((id, 0));
+            break;
+        }
+        Ok(())
+    }
+
+    fn compare(&mut self, lhs: Cursor, rhs: Cursor) -> Result<Ordering> {
+        let key = (lhs.0, rhs.0);
+        let left = &self.chunks[&lhs.0];
+        let right = &self.chunks[&rhs.0];
+        if !self.comparators.contains_key(&key) {
+            let comparators = self
+                .sort_columns
+                .iter()
+                .map(|(column, _)| {
+                    build_compare(
+                        left.arrays()[*column].as_ref(),
+                        right.arrays()[*column].as_ref(),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            self.comparators.insert(key, comparators);
+        }
+
+        for ((column, options), comparator) in self.sort_columns.iter().zip(&self.comparators[&key])
+        {
+            let is_valid = (
+                left.arrays()[*column].is_valid(lhs.1),
+                right.arrays()[*column].is_valid(rhs.1),
+            );
+            let result = match is_valid {
+                (true, true) => {
+                    let result = comparator(lhs.1, rhs.1);
+                    if options.descending {
+                        result.reverse()
+                    } else {
+                        result
+                    }
+                }
+                (false, true) if options.nulls_first => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (true, false) if options.nulls_first => Ordering::Greater,
+                (true, false) => Ordering::Less,
+                (false, false) => Ordering::Equal,
+            };
+            if result != Ordering::Equal {
+                return Ok(result);
+            }
+        }
+        Ok(Ordering::Equal)
+    }
+
+    /// Appends the next run of rows to `slices`.
+    fn step(&mut self) -> Result<()> {
+        // the streams with the smallest and second smallest heads; ties are resolved in favour
+        // of the first stream, so that the merge is stable
+        let mut first = None;
+        let mut second = None;
+        for stream in 0..self.heads.len() {
+            let Some(head) = self.heads[stream] else {
+                continue;
+            };
+            match first {
+                None => first = Some(stream),
+                Some(current) => {
+                    if self.compare(head, self.heads[current].unwrap())? == Ordering::Less {
+                        second = first;
+                        first = Some(stream);
+                    } else if match second {
+                        None => true,
+                        Some(other) => {
+                            self.compare(head, self.heads[other].unwrap())? == Ordering::Less
+                        }
+                    } {
+                        second = Some(stream);
+                    }
+                }
+            }
+        }
+        let Some(first) = first else {
+            return Ok(());
+        };
+
+        let (id, start) = self.heads[first].unwrap();
+        let chunk_length = self.chunks[&id].len();
+        let end = chunk_length.min(start + self.chunk_size - self.length);
+        let end = if let Some(second) = second {
+            let bound = self.heads[second].unwrap();
+            let mut row = start + 1;
+            while row < end {
+                match self.compare((id, row), bound)? {
+                    Ordering::Less => row += 1,
+                    Ordering::Equal if first < second => row += 1,
+                    _ => break,
+                }
+            }
+            row
+        } else {
+            end
+        };
+
+        self.slices.push((id, start, end - start));
+        self.length += end - start;
+        if end == chunk_length {
+            self.pull(first)
+        } else {
+            self.heads[first] = Some((id, end));
+            Ok(())
+        }
+    }
+
+    /// Takes the rows of `slices` into a new [`Chunk`] and releases the chunks that are no
+    /// longer referenced.
+    fn flush(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        let slices = std::mem::take(&mut self.slices);
+        let length = std::mem::take(&mut self.length);
+
+        let mut ids = vec![];
+        let mut positions = AHashMap::new();
+        for (id, _, _) in &slices {
+            positions.entry(*id).or_insert_with(|| {
+                ids.push(*id);
+                ids.len() - 1
+            });
+        }
+        let num_columns = self.chunks[&ids[0]].arrays().len();
+        let arrays = (0..num_columns)
+            .map(|column| {
+                let arrays = ids
+                    .iter()
+                    .map(|id| self.chunks[id].arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                let mut growable = make_growable(&arrays, false, length);
+                for (id, start, len) in &slices {
+                    growable.extend(positions[id], *start, *len);
+                }
+                growable.as_box()
+            })
+            .collect();
+
+        let heads = &self.heads;
+        let is_live = |id: &usize| heads.iter().flatten().any(|(head, _)| head == id);
+        self.chunks.retain(|id, _| is_live(id));
+        self.comparators
+            .retain(|(lhs, rhs), _| is_live(lhs) && is_live(rhs));
+        Chunk::try_new(arrays)
+    }
+
+    fn try_next(&mut self) -> Result<Option<Chunk<Box<dyn Array>>>> {
+        if !self.started {
+            self.started = true;
+            for stream in 0..self.streams.len() {
+                self.pull(stream)?;
+            }
+        }
+        while self.length < self.chunk_size && self.heads.iter().any(|head| head.is_some()) {
+            self.step()?;
+        }
+        if self.length == 0 {
+            Ok(None)
+        } else {
+            self.flush().map(Some)
+        }
+    }
+}
+
+impl<I> Iterator for MergeChunks<I>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().map_or_else(
+            |error| {
+                // stop after the first error
+                self.heads.iter_mut().for_each(|head| *head = None);
+                self.slices.clear();
+                self.length = 0;
+                Some(Err(error))
+            },
+            |chunk| chunk.map(Ok),
+        )
+    }
+}
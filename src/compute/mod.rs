@@ -31,6 +31,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_clamp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_clamp")))]
+pub mod clamp;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -40,15 +43,28 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_cumulative")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cumulative")))]
+pub mod cumulative;
+pub mod extension;
+#[cfg(feature = "compute_fill_null")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_fill_null")))]
+pub mod fill_null;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_group_by")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_group_by")))]
+pub mod group_by;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_is_in")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_is_in")))]
+pub mod is_in;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -58,9 +74,18 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_math")))]
+pub mod math;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
+#[cfg(feature = "compute_nested")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_nested")))]
+pub mod nested;
 #[cfg(feature = "compute_nullif")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_nullif")))]
 pub mod nullif;
@@ -70,6 +95,15 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_rle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rle")))]
+pub mod rle;
+#[cfg(feature = "compute_sample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_sample")))]
+pub mod sample;
+#[cfg(feature = "compute_selection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_selection")))]
+pub mod selection;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
@@ -82,6 +116,9 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_unique")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_unique")))]
+pub mod unique;
 #[cfg(feature = "compute_utf8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
 pub mod utf8;
@@ -0,0 +1,206 @@
+//! Contains the operator [`get_field`], that extracts a nested child of an array by a path
+//! such as `"a.b[2].c"`, drilling through structs, lists and maps.
+use crate::array::{Array, MapArray, PrimitiveArray, StructArray, Utf8Array};
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::types::Index;
+
+use super::list::Items;
+use super::take::take;
+use super::utils::combine_validities;
+
+/// A segment of the path of [`get_field_by`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// The field of a struct, or the value of a key of a map with string keys
+    Name(String),
+    /// The item of a list at a 0-based index; negative indices count from the end of the list
+    Index(i64),
+}
+
+fn parse_error(path: &str, message: &str) -> Error {
+    Error::InvalidArgumentError(format!("Invalid field path \"{path}\": {message}"))
+}
+
+/// Parses `path` into [`PathSegment`]s. A path is a sequence of
+/// * names, separated by `.` (`a.b`), that may not contain `.`, `[` or `]`;
+/// * indices in brackets (`a[2]`, `a[-1]`);
+/// * quoted names in brackets (`a["b.c"]`, `a['b']`), for names with special characters.
+/// # Errors
+/// This function errors iff `path` is empty or malformed.
+pub fn parse_path(path: &str) -> Result<Vec<PathSegment>> {
+    let mut segments = vec![];
+    let mut rest = path;
+    loop {
+        if let Some(bracket) = rest.strip_prefix('[') {
+            let end = bracket
+                .find(']')
+                .ok_or_else(|| parse_error(path, "unclosed `[`"))?;
+            let inner = &bracket[..end];
+            let quoted = ['"', '\''].iter().find_map(|quote| {
+                inner
+                    .strip_prefix(*quote)
+                    .and_then(|inner| inner.strip_suffix(*quote))
+            });
+            let segment = match quoted {
+                Some(name) => PathSegment::Name(name.to_string()),
+                None => PathSegment::Index(
+                    inner
+                        .trim()
+                        .parse()
+                        .map_err(|_| parse_error(path, &format!("invalid index `{inner}`")))?,
+                ),
+            };
+            segments.push(segment);
+            rest = &bracket[end + 1..];
+        } else {
+            let name = if segments.is_empty() {
+                rest
+            } else if let Some(name) = rest.strip_prefix('.') {
+                name
+            } else {
+                return Err(parse_error(path, "expected `.` or `[`"));
+            };
+            let end = name.find(['.', '[', ']']).unwrap_or(name.len());
+            if end == 0 {
+                return Err(parse_error(path, "empty field name"));
+            }
+            segments.push(PathSegment::Name(name[..end].to_string()));
+            rest = &name[end..];
+        }
+        if rest.is_empty() {
+            return Ok(segments);
+        }
+    }
+}
+
+fn struct_field(array: &StructArray, name: &str) -> Result<Box<dyn Array>> {
+    let index = array
+        .fields()
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!("The struct has no field named \"{name}\""))
+        })?;
+    let child = &array.values()[index];
+    // a null struct has null fields
+    Ok(child.with_validity(combine_validities(array.validity(), child.validity())))
+}
+
+fn map_value(array: &MapArray, key: &str) -> Result<Box<dyn Array>> {
+    let entries = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    let keys = entries.values()[0].as_ref();
+    let find = |start: usize, end: usize| -> Result<Option<usize>> {
+        Ok(match keys.data_type().to_logical_type() {
+            DataType::Utf8 => {
+                let keys = keys.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                (start..end).find(|row| keys.is_valid(*row) && keys.value(*row) == key)
+            }
+            DataType::LargeUtf8 => {
+                let keys = keys.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                (start..end).find(|row| keys.is_valid(*row) && keys.value(*row) == key)
+            }
+            other => {
+                return Err(Error::NotYetImplemented(format!(
+                    "Looking up a key in a map is not implemented for keys of type {other:?}"
+                )))
+            }
+        })
+    };
+
+    let offsets = array.offsets().buffer();
+    let indices = (0..array.len())
+        .map(|row| {
+            if !array.is_valid(row) {
+                return Ok(None);
+            }
+            let found = find(offsets[row].to_usize(), offsets[row + 1].to_usize())?;
+            Ok(found.map(|entry| entry as u64))
+        })
+        .collect::<Result<PrimitiveArray<u64>>>()?;
+    take(entries.values()[1].as_ref(), &indices)
+}
+
+fn list_item(array: &dyn Array, index: i64) -> Result<Box<dyn Array>> {
+    let items = Items::try_new(array)?;
+    let indices = (0..items.len())
+        .map(|row| {
+            let (start, length) = items.slot(row);
+            let index = if index < 0 {
+                length as i64 + index
+            } else {
+                index
+            };
+            (0..length as i64)
+                .contains(&index)
+                .then(|| (start as i64 + index) as u64)
+        })
+        .collect::<PrimitiveArray<u64>>();
+    take(items.values, &indices)
+}
+
+fn step(array: &dyn Array, segment: &PathSegment) -> Result<Box<dyn Array>> {
+    match (array.data_type().to_logical_type(), segment) {
+        (DataType::Struct(_), PathSegment::Name(name)) => {
+            struct_field(array.as_any().downcast_ref().unwrap(), name)
+        }
+        (DataType::Map(_, _), PathSegment::Name(key)) => {
+            map_value(array.as_any().downcast_ref().unwrap(), key)
+        }
+        (
+            DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _),
+            PathSegment::Index(index),
+        ) => list_item(array, *index),
+        (other, segment) => Err(Error::InvalidArgumentError(format!(
+            "Cannot select {segment:?} of an array of type {other:?}"
+        ))),
+    }
+}
+
+/// Returns the nested child of `array` at `path`, see [`get_field`].
+/// # Errors
+/// This function errors iff a segment of `path` does not apply to the type it is applied to,
+/// a field does not exist, or a map has keys other than strings.
+pub fn get_field_by(array: &dyn Array, path: &[PathSegment]) -> Result<Box<dyn Array>> {
+    let mut array = array.to_boxed();
+    for segment in path {
+        array = step(array.as_ref(), segment)?;
+    }
+    Ok(array)
+}
+
+/// Returns the nested child of `array` at `path`, e.g. `"a.b[2].c"` or `"a['key'][-1]"`
+/// (see [`parse_path`]), with one row per row of `array`.
+///
+/// A name selects the field of a struct or the value of a key of a map with string keys, and
+/// an index selects the item of a list. A row of the result is null when any of its ancestors
+/// is null, its list is shorter than the index, or its map has no such key.
+/// # Errors
+/// This function errors iff `path` is malformed or does not apply to `array`, see
+/// [`get_field_by`].
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, StructArray, TryExtend};
+/// use arrow2::compute::nested::get_field;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), Some(2)]), Some(vec![Some(3)]), None]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let fields = vec![Field::new("b", list.data_type().clone(), true)];
+/// let array = StructArray::new(DataType::Struct(fields), vec![list.boxed()], None);
+///
+/// let result = get_field(&array, "b[1]").unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(2), None, None]) as &dyn Array);
+///
+/// let result = get_field(&array, "b[-1]").unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(2), Some(3), None]) as &dyn Array);
+/// ```
+pub fn get_field(array: &dyn Array, path: &str) -> Result<Box<dyn Array>> {
+    get_field_by(array, &parse_path(path)?)
+}
@@ -18,7 +18,12 @@
 //! Defines partition kernel for [`crate::array::Array`]
 
 use crate::array::ord::DynComparator;
+use crate::array::{Array, PrimitiveArray};
+use crate::chunk::Chunk;
+use crate::compute::hash::{hash_columns, HashFunction};
 use crate::compute::sort::{build_compare, SortColumn};
+use crate::compute::take::take;
+use crate::datatypes::DataType;
 use crate::error::{Error, Result};
 use std::cmp::Ordering;
 use std::iter::Iterator;
@@ -115,3 +120,196 @@ impl Iterator for LexicographicalPartitionIterator {
         }
     }
 }
+
+/// Splits `array` in `num_partitions` arrays, where the i-th array contains the values of
+/// `array` whose partition id is `i`, in their original order.
+///
+/// This is used e.g. by hash-shuffles and window partitioning, and is faster than running one
+/// [`filter`](crate::compute::filter::filter) per partition.
+/// # Implementation
+/// The partition ids are counting sorted into a single permutation, the array is taken once
+/// by it and then sliced per partition. Consequently, the returned arrays share the same
+/// buffers. This operation is `O(N)` over the length of the array.
+/// # Errors
+/// This function errors iff `partition_ids` does not have the length of `array`, any
+/// partition id is not smaller than `num_partitions`, or `array`'s type is not supported
+/// by [`take`].
+pub fn partition_by_indices(
+    array: &dyn Array,
+    partition_ids: &[u32],
+    num_partitions: usize,
+) -> Result<Vec<Box<dyn Array>>> {
+    if array.len() != partition_ids.len() {
+        return Err(Error::InvalidArgumentError(
+            "The partition ids must have the length of the array".to_string(),
+        ));
+    }
+    let (indices, offsets) = partition_permutation(partition_ids, num_partitions)?;
+    let array = take(array, &indices)?;
+    Ok(offsets
+        .windows(2)
+        .map(|w| array.sliced(w[0], w[1] - w[0]))
+        .collect())
+}
+
+/// Splits the rows of `columns` in `num_partitions` [`Chunk`]s, where the i-th chunk contains
+/// the rows whose partition id is `i`, in their original order.
+///
+/// The permutation is computed once and shared by all columns; see [`partition_by_indices`].
+/// # Errors
+/// This function errors under the same conditions as [`partition_by_indices`].
+pub fn partition_chunk_by_indices<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    partition_ids: &[u32],
+    num_partitions: usize,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    if columns.len() != partition_ids.len() {
+        return Err(Error::InvalidArgumentError(
+            "The partition ids must have the length of the chunk".to_string(),
+        ));
+    }
+    let (indices, offsets) = partition_permutation(partition_ids, num_partitions)?;
+    chunks_by_permutation(columns.arrays(), &indices, &offsets)
+}
+
+/// Returns the partition id of every row of `columns` and the number of rows of each
+/// partition, assigning rows to partitions by hash.
+///
+/// The rows are hashed by [`hash_columns`] with [`HashFunction::XxHash64`] and a zero seed,
+/// so that equal rows are assigned to the same partition across processes and platforms, as
+/// required by distributed shuffles. The hashes are reduced modulo `num_partitions` and counted
+/// in a single pass.
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths, the type of
+/// a column cannot be hashed, or `num_partitions` is zero or does not fit in a `u32`.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::partition::hash_partition;
+///
+/// let array = Int32Array::from_slice([1, 2, 1, 3]);
+/// let (partition_ids, counts) = hash_partition(&[&array], 2).unwrap();
+/// assert_eq!(partition_ids[0], partition_ids[2]);
+/// assert_eq!(counts.iter().sum::<usize>(), 4);
+/// ```
+pub fn hash_partition(
+    columns: &[&dyn Array],
+    num_partitions: usize,
+) -> Result<(Vec<u32>, Vec<usize>)> {
+    if num_partitions == 0 || num_partitions > u32::MAX as usize {
+        return Err(Error::InvalidArgumentError(format!(
+            "`hash_partition` requires between 1 and {} partitions, but got {num_partitions}",
+            u32::MAX
+        )));
+    }
+    let hashes = hash_columns(columns, HashFunction::XxHash64, 0)?;
+
+    let mut counts = vec![0usize; num_partitions];
+    let partition_ids = hashes
+        .values()
+        .iter()
+        .map(|hash| {
+            let id = (hash % num_partitions as u64) as u32;
+            counts[id as usize] += 1;
+            id
+        })
+        .collect();
+    Ok((partition_ids, counts))
+}
+
+/// Splits the rows of `columns` in `num_partitions` [`Chunk`]s by the hash of the columns
+/// at the positions `keys`, keeping the rows of each partition in their original order.
+///
+/// This is [`hash_partition`] followed by [`partition_chunk_by_indices`], but the permutation
+/// is scattered directly from the counts of [`hash_partition`] and the partition ids are not
+/// validated again.
+/// # Errors
+/// This function errors iff `keys` is empty or out of bounds, or under the same conditions as
+/// [`hash_partition`] and [`partition_chunk_by_indices`].
+pub fn hash_partition_chunk<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    keys: &[usize],
+    num_partitions: usize,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let arrays = columns.arrays();
+    let keys = keys
+        .iter()
+        .map(|key| {
+            arrays.get(*key).map(|array| array.as_ref()).ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The key {key} is out of bounds for a chunk of {} columns",
+                    arrays.len()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (partition_ids, counts) = hash_partition(&keys, num_partitions)?;
+
+    let mut offsets = Vec::with_capacity(num_partitions + 1);
+    offsets.push(0);
+    offsets.extend(counts.iter().scan(0, |offset, count| {
+        *offset += count;
+        Some(*offset)
+    }));
+    let indices = scatter(&partition_ids, &offsets);
+    chunks_by_permutation(arrays, &indices, &offsets)
+}
+
+/// Takes `arrays` by `indices` and slices them in one [`Chunk`] per pair of `offsets`.
+fn chunks_by_permutation<A: AsRef<dyn Array>>(
+    arrays: &[A],
+    indices: &PrimitiveArray<u64>,
+    offsets: &[usize],
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let arrays = arrays
+        .iter()
+        .map(|array| take(array.as_ref(), indices))
+        .collect::<Result<Vec<_>>>()?;
+
+    offsets
+        .windows(2)
+        .map(|w| {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.sliced(w[0], w[1] - w[0]))
+                .collect();
+            Chunk::try_new(arrays)
+        })
+        .collect()
+}
+
+/// Returns the stable permutation that groups `partition_ids` by partition, given the
+/// offsets of each partition in it.
+fn scatter(partition_ids: &[u32], offsets: &[usize]) -> PrimitiveArray<u64> {
+    let mut positions = offsets[..offsets.len() - 1].to_vec();
+    let mut indices = vec![0u64; partition_ids.len()];
+    for (index, id) in partition_ids.iter().enumerate() {
+        let position = &mut positions[*id as usize];
+        indices[*position] = index as u64;
+        *position += 1;
+    }
+    PrimitiveArray::new(DataType::UInt64, indices.into(), None)
+}
+
+/// Returns the stable permutation that groups `partition_ids` by partition, and the
+/// offsets of each partition in it.
+fn partition_permutation(
+    partition_ids: &[u32],
+    num_partitions: usize,
+) -> Result<(PrimitiveArray<u64>, Vec<usize>)> {
+    let mut offsets = vec![0usize; num_partitions + 1];
+    for id in partition_ids {
+        let id = *id as usize;
+        if id >= num_partitions {
+            return Err(Error::InvalidArgumentError(format!(
+                "The partition id {id} is out of bounds for {num_partitions} partitions"
+            )));
+        }
+        offsets[id + 1] += 1;
+    }
+    for i in 1..offsets.len() {
+        offsets[i] += offsets[i - 1];
+    }
+
+    Ok((scatter(partition_ids, &offsets), offsets))
+}
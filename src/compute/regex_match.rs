@@ -1,9 +1,19 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`], and the
+//! `regexp_*` kernels ([`regexp_is_match`], [`regexp_extract`] and [`regexp_replace`]) with
+//! their `_scalar` variants.
+//!
+//! The array-pattern kernels compile every distinct pattern once per call. The scalar-pattern
+//! kernels keep the last compiled patterns in a per-thread cache, so that applying the same
+//! pattern to many batches compiles it only once.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::hash_map::Entry;
 
 use ahash::AHashMap;
 use regex::Regex;
 
-use crate::array::{BooleanArray, Utf8Array};
+use crate::array::{Array, BooleanArray, Utf8Array};
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{Error, Result};
@@ -79,3 +89,247 @@ fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     let values = Bitmap::from_trusted_len_iter(iterator);
     BooleanArray::new(DataType::Boolean, values, validity)
 }
+
+/// The maximum number of patterns compiled by the scalar-pattern kernels cached per thread.
+const CACHE_CAPACITY: usize = 32;
+
+thread_local! {
+    static REGEX_CACHE: RefCell<AHashMap<String, Regex>> = RefCell::new(AHashMap::new());
+}
+
+/// Returns `pattern` with the inline `flags` (e.g. `"i"` or `"ms"`) prepended.
+fn with_flags<'a>(pattern: &'a str, flags: Option<&str>) -> Cow<'a, str> {
+    match flags {
+        Some(flags) if !flags.is_empty() => Cow::Owned(format!("(?{flags}){pattern}")),
+        _ => Cow::Borrowed(pattern),
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern)
+        .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {e}")))
+}
+
+/// Compiles `pattern`, re-using the regex compiled by a previous call on this thread, if any.
+fn compile_cached(pattern: &str) -> Result<Regex> {
+    REGEX_CACHE.with(|cache| {
+        if let Some(regex) = cache.borrow().get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = compile(pattern)?;
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    })
+}
+
+/// The regexes of the patterns of an array, compiled once per distinct pattern.
+struct PatternCache<'a> {
+    flags: Option<&'a str>,
+    regexes: AHashMap<&'a str, Regex>,
+}
+
+impl<'a> PatternCache<'a> {
+    fn new(flags: Option<&'a str>) -> Self {
+        Self {
+            flags,
+            regexes: AHashMap::new(),
+        }
+    }
+
+    fn get(&mut self, pattern: &'a str) -> Result<&Regex> {
+        Ok(match self.regexes.entry(pattern) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(compile(&with_flags(pattern, self.flags))?),
+        })
+    }
+}
+
+fn check_lengths(values: &dyn Array, patterns: &dyn Array) -> Result<()> {
+    if values.len() != patterns.len() {
+        return Err(Error::InvalidArgumentError(
+            "Cannot perform regex operation on arrays of different length".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn check_group(regex: &Regex, group: usize) -> Result<()> {
+    let groups = regex.captures_len() - 1;
+    if group > groups {
+        return Err(Error::InvalidArgumentError(format!(
+            "The regex \"{}\" has {groups} capture groups, but group {group} was requested",
+            regex.as_str()
+        )));
+    }
+    Ok(())
+}
+
+#[inline]
+fn extract<'a>(regex: &Regex, value: &'a str, group: usize) -> Option<&'a str> {
+    if group == 0 {
+        regex.find(value).map(|m| m.as_str())
+    } else {
+        regex
+            .captures(value)
+            .and_then(|captures| captures.get(group))
+            .map(|m| m.as_str())
+    }
+}
+
+/// Returns whether every value of `values` matches the pattern of the same slot of `patterns`,
+/// compiled with the inline `flags`, such as `"i"` for case-insensitive matching.
+///
+/// A slot is null iff the value or the pattern is null.
+/// # Errors
+/// This function errors iff the arrays have different lengths or a pattern is not a valid regex.
+pub fn regexp_is_match<O: Offset>(
+    values: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+    flags: Option<&str>,
+) -> Result<BooleanArray> {
+    check_lengths(values, patterns)?;
+
+    let mut cache = PatternCache::new(flags);
+    let validity = combine_validities(values.validity(), patterns.validity());
+    let iterator = values.iter().zip(patterns.iter()).map(|pair| match pair {
+        (Some(value), Some(pattern)) => Result::Ok(cache.get(pattern)?.is_match(value)),
+        // regex is expensive => short-circuit if null
+        _ => Ok(false),
+    });
+    let values = Bitmap::try_from_trusted_len_iter(iterator)?;
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+/// Returns whether every value of `values` matches `pattern`, compiled with the inline `flags`.
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::regex_match::regexp_is_match_scalar;
+///
+/// let strings = Utf8Array::<i32>::from([Some("Arrow"), None, Some("parquet")]);
+///
+/// let result = regexp_is_match_scalar(&strings, "^a", Some("i")).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+pub fn regexp_is_match_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    flags: Option<&str>,
+) -> Result<BooleanArray> {
+    let regex = compile_cached(&with_flags(pattern, flags))?;
+    Ok(unary_utf8_boolean(values, |x| regex.is_match(x)))
+}
+
+/// Returns the capture `group` of the first match of the pattern of the same slot of
+/// `patterns` in every value of `values`, where group `0` is the whole match.
+///
+/// A slot is null iff the value or the pattern is null, the pattern does not match the value,
+/// or the group did not participate in the match.
+/// # Errors
+/// This function errors iff the arrays have different lengths, a pattern is not a valid regex or
+/// a pattern has less than `group` capture groups.
+pub fn regexp_extract<O: Offset>(
+    values: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+    group: usize,
+) -> Result<Utf8Array<O>> {
+    check_lengths(values, patterns)?;
+
+    let mut cache = PatternCache::new(None);
+    let iterator = values.iter().zip(patterns.iter()).map(|pair| match pair {
+        (Some(value), Some(pattern)) => {
+            let regex = cache.get(pattern)?;
+            check_group(regex, group)?;
+            Ok(extract(regex, value, group))
+        }
+        _ => Ok(None),
+    });
+    Utf8Array::try_from_trusted_len_iter(iterator)
+}
+
+/// Returns the capture `group` of the first match of `pattern` in every value of `values`,
+/// where group `0` is the whole match, see [`regexp_extract`].
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex or has less than `group` capture groups.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regexp_extract_scalar;
+///
+/// let strings = Utf8Array::<i32>::from([Some("id=12"), Some("id="), None]);
+///
+/// let result = regexp_extract_scalar(&strings, r"id=(\d+)", 1).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("12"), None, None]));
+/// ```
+pub fn regexp_extract_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    group: usize,
+) -> Result<Utf8Array<O>> {
+    let regex = compile_cached(pattern)?;
+    check_group(&regex, group)?;
+    let iterator = values
+        .iter()
+        .map(|value| value.and_then(|value| extract(&regex, value, group)));
+    Ok(Utf8Array::from_trusted_len_iter(iterator))
+}
+
+/// Replaces the first `limit` matches (all matches if `limit` is `0`) of the pattern of the
+/// same slot of `patterns` in every value of `values` by `replacement`, in which `$name` and
+/// `$index` are replaced by the corresponding capture groups, as [`Regex::replacen`] does.
+///
+/// A slot is null iff the value or the pattern is null.
+/// # Errors
+/// This function errors iff the arrays have different lengths or a pattern is not a valid regex.
+pub fn regexp_replace<O: Offset>(
+    values: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+    replacement: &str,
+    limit: usize,
+) -> Result<Utf8Array<O>> {
+    check_lengths(values, patterns)?;
+
+    let mut cache = PatternCache::new(None);
+    let iterator = values.iter().zip(patterns.iter()).map(|pair| match pair {
+        (Some(value), Some(pattern)) => Ok(Some(cache.get(pattern)?.replacen(
+            value,
+            limit,
+            replacement,
+        ))),
+        _ => Ok(None),
+    });
+    Utf8Array::try_from_trusted_len_iter(iterator)
+}
+
+/// Replaces the first `limit` matches (all matches if `limit` is `0`) of `pattern` in every
+/// value of `values` by `replacement`, see [`regexp_replace`].
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regexp_replace_scalar;
+///
+/// let strings = Utf8Array::<i32>::from([Some("2023-01-02"), None]);
+///
+/// let result = regexp_replace_scalar(&strings, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", 0).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("02/01/2023"), None]));
+/// ```
+pub fn regexp_replace_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    replacement: &str,
+    limit: usize,
+) -> Result<Utf8Array<O>> {
+    let regex = compile_cached(pattern)?;
+    let iterator = values
+        .iter()
+        .map(|value| value.map(|value| regex.replacen(value, limit, replacement)));
+    Ok(Utf8Array::from_trusted_len_iter(iterator))
+}
@@ -0,0 +1,129 @@
+//! Contains the operators [`runs`] and [`dedup_consecutive`], that detect runs of equal
+//! adjacent values, e.g. to run-length encode an array or to collapse sorted keys.
+use crate::array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+use super::take::take;
+
+/// Returns the first row of every run of equal adjacent rows, comparing values by the bytes
+/// returned by `value`. Adjacent nulls are equal.
+fn run_starts<B, F>(array: &dyn Array, value: F) -> Vec<u64>
+where
+    B: AsRef<[u8]>,
+    F: Fn(usize) -> B,
+{
+    let equal = |lhs: usize, rhs: usize| match (array.is_valid(lhs), array.is_valid(rhs)) {
+        (true, true) => value(lhs).as_ref() == value(rhs).as_ref(),
+        (false, false) => true,
+        _ => false,
+    };
+    (0..array.len())
+        .filter(|row| *row == 0 || !equal(row - 1, *row))
+        .map(|row| row as u64)
+        .collect()
+}
+
+fn primitive_run_starts<T: NativeType>(array: &PrimitiveArray<T>) -> Vec<u64> {
+    if array.null_count() > 0 {
+        return run_starts(array, |row| array.value(row).to_le_bytes());
+    }
+    // compare the lanes of the values directly
+    let values = array.values();
+    let mut starts = vec![];
+    if !values.is_empty() {
+        starts.push(0);
+    }
+    starts.extend(
+        values
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0].to_le_bytes().as_ref() != pair[1].to_le_bytes().as_ref())
+            .map(|(row, _)| row as u64 + 1),
+    );
+    starts
+}
+
+fn starts_dyn(array: &dyn Array) -> Result<Vec<u64>> {
+    macro_rules! starts {
+        ($array:ty, |$a:ident, $row:ident| $value:expr) => {{
+            let $a = array.as_any().downcast_ref::<$array>().unwrap();
+            run_starts($a, |$row| $value)
+        }};
+    }
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => starts!(BooleanArray, |a, row| [a.value(row) as u8]),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_run_starts(array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap())
+        }),
+        Binary => starts!(BinaryArray<i32>, |a, row| a.value(row)),
+        LargeBinary => starts!(BinaryArray<i64>, |a, row| a.value(row)),
+        Utf8 => starts!(Utf8Array<i32>, |a, row| a.value(row).as_bytes()),
+        LargeUtf8 => starts!(Utf8Array<i64>, |a, row| a.value(row).as_bytes()),
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "Run detection is not implemented for type {t:?}"
+            )))
+        }
+    })
+}
+
+/// Returns the value and the length of every run of equal adjacent values of `array`.
+///
+/// Values are compared by their bytes, so that floats are equal iff their bits are equal, and
+/// adjacent nulls form a single (null) run.
+/// # Errors
+/// This function errors iff the type of `array` is not supported (primitives, booleans,
+/// strings and binaries).
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array, UInt64Array};
+/// use arrow2::compute::rle::runs;
+///
+/// let array = Int32Array::from([Some(1), Some(1), None, None, Some(2), Some(1)]);
+/// let (values, lengths) = runs(&array).unwrap();
+/// let expected = Int32Array::from([Some(1), None, Some(2), Some(1)]);
+/// assert_eq!(values.as_ref(), &expected as &dyn Array);
+/// assert_eq!(lengths, UInt64Array::from_slice([2, 2, 1, 1]));
+/// ```
+pub fn runs(array: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<u64>)> {
+    let starts = starts_dyn(array)?;
+    let lengths = starts
+        .iter()
+        .zip(
+            starts
+                .iter()
+                .skip(1)
+                .chain(std::iter::once(&(array.len() as u64))),
+        )
+        .map(|(start, end)| end - start)
+        .collect::<Vec<_>>();
+
+    let starts = PrimitiveArray::new(DataType::UInt64, starts.into(), None);
+    let values = take(array, &starts)?;
+    Ok((
+        values,
+        PrimitiveArray::new(DataType::UInt64, lengths.into(), None),
+    ))
+}
+
+/// Returns `array` without its adjacent duplicates, i.e. the values of [`runs`].
+/// # Errors
+/// This function errors under the same conditions as [`runs`].
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::rle::dedup_consecutive;
+///
+/// let array = Utf8Array::<i32>::from_slice(["a", "a", "b", "b", "a"]);
+/// let result = dedup_consecutive(&array).unwrap();
+/// let expected = Utf8Array::<i32>::from_slice(["a", "b", "a"]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn dedup_consecutive(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let starts = starts_dyn(array)?;
+    let starts = PrimitiveArray::new(DataType::UInt64, starts.into(), None);
+    take(array, &starts)
+}
@@ -0,0 +1,143 @@
+//! Contains the operators [`sample`] and [`shuffle`], that select and reorder the rows of
+//! arrays and [`Chunk`]s at random.
+//!
+//! Both are implemented by drawing a permutation of indices, seeded for reproducibility, and
+//! [`take`]-ing the arrays by it, so that the `_chunk` variants apply the same permutation to
+//! all columns.
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::chunk::Chunk;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+use super::take::take;
+
+/// The number of rows of [`sample`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleSize {
+    /// A fraction, between 0 and 1, of the rows (rounded to the nearest integer)
+    Fraction(f64),
+    /// A number of rows, or all rows if there are fewer
+    Count(usize),
+}
+
+impl SampleSize {
+    fn count(self, length: usize) -> Result<usize> {
+        match self {
+            SampleSize::Fraction(fraction) if (0.0..=1.0).contains(&fraction) => {
+                Ok((fraction * length as f64).round() as usize)
+            }
+            SampleSize::Fraction(fraction) => Err(Error::InvalidArgumentError(format!(
+                "The fraction of a sample must be between 0 and 1, but got {fraction}"
+            ))),
+            SampleSize::Count(count) => Ok(count.min(length)),
+        }
+    }
+}
+
+fn indices(values: Vec<u64>) -> PrimitiveArray<u64> {
+    PrimitiveArray::new(DataType::UInt64, values.into(), None)
+}
+
+/// Returns the (sorted) indices of a sample of `size` rows, without replacement, out of
+/// `length` rows. The same `seed` results in the same indices.
+/// # Errors
+/// This function errors iff `size` is a fraction outside `[0, 1]`.
+pub fn sample_indices(length: usize, size: SampleSize, seed: u64) -> Result<PrimitiveArray<u64>> {
+    let count = size.count(length)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut values = rand::seq::index::sample(&mut rng, length, count)
+        .into_iter()
+        .map(|index| index as u64)
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    Ok(indices(values))
+}
+
+/// Returns a random permutation of the `length` rows. The same `seed` results in the same
+/// permutation.
+pub fn shuffle_indices(length: usize, seed: u64) -> PrimitiveArray<u64> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut values = (0..length as u64).collect::<Vec<_>>();
+    values.shuffle(&mut rng);
+    indices(values)
+}
+
+/// Returns a sample of `size` rows of `array`, without replacement and in their original order.
+/// The same `seed` results in the same sample.
+/// # Errors
+/// This function errors iff `size` is a fraction outside `[0, 1]` or the type of `array` is not
+/// supported by [`take`].
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sample::{sample, SampleSize};
+///
+/// let array = Int32Array::from_slice([1, 2, 3, 4]);
+/// let result = sample(&array, SampleSize::Fraction(0.5), 42).unwrap();
+/// assert_eq!(result.len(), 2);
+/// assert_eq!(result, sample(&array, SampleSize::Count(2), 42).unwrap());
+/// ```
+pub fn sample(array: &dyn Array, size: SampleSize, seed: u64) -> Result<Box<dyn Array>> {
+    take(array, &sample_indices(array.len(), size, seed)?)
+}
+
+/// Returns a sample of `size` rows of `columns`, see [`sample`].
+/// # Errors
+/// This function errors under the same conditions as [`sample`].
+pub fn sample_chunk<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    size: SampleSize,
+    seed: u64,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let indices = sample_indices(columns.len(), size, seed)?;
+    take_chunk(columns, &indices)
+}
+
+/// Returns the rows of `array` in a random order. The same `seed` results in the same order.
+/// # Errors
+/// This function errors iff the type of `array` is not supported by [`take`].
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::sample::shuffle;
+///
+/// let array = Int32Array::from_slice([1, 2, 3, 4]);
+/// let result = shuffle(&array, 42).unwrap();
+/// let mut values = result
+///     .as_any()
+///     .downcast_ref::<Int32Array>()
+///     .unwrap()
+///     .values()
+///     .to_vec();
+/// values.sort();
+/// assert_eq!(values, vec![1, 2, 3, 4]);
+/// ```
+pub fn shuffle(array: &dyn Array, seed: u64) -> Result<Box<dyn Array>> {
+    take(array, &shuffle_indices(array.len(), seed))
+}
+
+/// Returns the rows of `columns` in a random order, see [`shuffle`].
+/// # Errors
+/// This function errors under the same conditions as [`shuffle`].
+pub fn shuffle_chunk<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    seed: u64,
+) -> Result<Chunk<Box<dyn Array>>> {
+    take_chunk(columns, &shuffle_indices(columns.len(), seed))
+}
+
+fn take_chunk<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    indices: &PrimitiveArray<u64>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let arrays = columns
+        .arrays()
+        .iter()
+        .map(|array| take(array.as_ref(), indices))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
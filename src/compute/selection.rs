@@ -0,0 +1,150 @@
+//! Contains [`Selection`], a set of rows of an [`Array`], and [`gather`] to materialize it.
+//!
+//! Engines that perform late materialization carry a [`Selection`] through their operators
+//! (e.g. a filter produces a mask, a join produces row ids) and only gather the payload
+//! columns at the end.
+use crate::array::{Array, BooleanArray, PrimitiveArray};
+use crate::bitmap::{utils::TrueIdxIter, Bitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+use super::{filter, take};
+
+/// The rows selected from an [`Array`] of a given length.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// The rows whose bit is set. The mask must have the length of the array it is applied to.
+    Mask(Bitmap),
+    /// The rows at the given ids, in order. Ids may repeat, and null ids select a null row,
+    /// as the results of (outer) joins do.
+    Indices(PrimitiveArray<u32>),
+}
+
+impl Selection {
+    /// Returns a new [`Selection`] from a filter mask, where nulls are interpreted as `false`.
+    pub fn from_filter(filter: &BooleanArray) -> Self {
+        match filter.validity() {
+            Some(validity) => Self::Mask(filter.values() & validity),
+            None => Self::Mask(filter.values().clone()),
+        }
+    }
+
+    /// Returns the number of rows selected.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Mask(mask) => mask.len() - mask.unset_bits(),
+            Self::Indices(indices) => indices.len(),
+        }
+    }
+
+    /// Returns whether no rows are selected.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the row ids of this [`Selection`].
+    /// # Panics
+    /// This function panics iff a [`Selection::Mask`] is longer than `u32::MAX`.
+    pub fn to_indices(&self) -> PrimitiveArray<u32> {
+        match self {
+            Self::Mask(mask) => {
+                assert!(mask.len() <= u32::MAX as usize);
+                let indices = TrueIdxIter::new(mask.len(), Some(mask))
+                    .map(|index| index as u32)
+                    .collect::<Vec<_>>();
+                PrimitiveArray::new(DataType::UInt32, indices.into(), None)
+            }
+            Self::Indices(indices) => indices.clone(),
+        }
+    }
+
+    /// Returns the [`Selection`] of the rows selected by `selection` out of the rows selected by
+    /// `self`, i.e. the composition of both, so that
+    /// `gather(&gather(array, self)?, selection)? == gather(array, &self.select(selection)?)?`.
+    /// # Errors
+    /// This function errors iff `selection` does not fit the rows selected by `self`.
+    pub fn select(&self, selection: &Selection) -> Result<Self> {
+        let indices = gather(&self.to_indices(), selection)?;
+        let indices = indices
+            .as_any()
+            .downcast_ref::<PrimitiveArray<u32>>()
+            .unwrap()
+            .clone();
+        Ok(Self::Indices(indices))
+    }
+}
+
+impl From<Bitmap> for Selection {
+    fn from(mask: Bitmap) -> Self {
+        Self::Mask(mask)
+    }
+}
+
+impl From<PrimitiveArray<u32>> for Selection {
+    fn from(indices: PrimitiveArray<u32>) -> Self {
+        Self::Indices(indices)
+    }
+}
+
+impl From<Vec<u32>> for Selection {
+    fn from(indices: Vec<u32>) -> Self {
+        Self::Indices(PrimitiveArray::new(DataType::UInt32, indices.into(), None))
+    }
+}
+
+/// Returns a new [`Array`] with the rows of `array` selected by `selection`.
+///
+/// A [`Selection::Mask`] is applied via [`filter::filter`] and a [`Selection::Indices`]
+/// via [`take::take`], and so all array types supported by them are supported.
+/// # Errors
+/// This function errors iff a [`Selection::Mask`] does not have the length of `array`,
+/// or the array's type is not supported.
+pub fn gather(array: &dyn Array, selection: &Selection) -> Result<Box<dyn Array>> {
+    match selection {
+        Selection::Mask(mask) => {
+            check_mask(mask, array.len())?;
+            let mask = BooleanArray::new(DataType::Boolean, mask.clone(), None);
+            filter::filter(array, &mask)
+        }
+        Selection::Indices(indices) => take::take(array, indices),
+    }
+}
+
+/// Returns a new [`Chunk`] with the rows of `columns` selected by `selection`.
+///
+/// A [`Selection::Mask`] is analyzed once and shared by all columns, see [`filter::filter_chunk`].
+/// # Errors
+/// This function errors under the same conditions as [`gather`].
+pub fn gather_chunk<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    selection: &Selection,
+) -> Result<Chunk<Box<dyn Array>>> {
+    match selection {
+        Selection::Mask(mask) => {
+            check_mask(mask, columns.len())?;
+            let mask = BooleanArray::new(DataType::Boolean, mask.clone(), None);
+            filter::filter_chunk(columns, &mask)
+        }
+        Selection::Indices(indices) => {
+            let arrays = columns
+                .arrays()
+                .iter()
+                .map(|array| take::take(array.as_ref(), indices))
+                .collect::<Result<Vec<_>>>()?;
+            Chunk::try_new(arrays)
+        }
+    }
+}
+
+fn check_mask(mask: &Bitmap, length: usize) -> Result<()> {
+    if mask.len() != length {
+        return Err(Error::InvalidArgumentError(format!(
+            "The selection mask has {} rows but the array has {length}",
+            mask.len()
+        )));
+    }
+    Ok(())
+}
@@ -1,7 +1,101 @@
-use crate::{array::PrimitiveArray, bitmap::Bitmap, types::Index};
+use std::cmp::Ordering;
+
+use crate::{
+    array::PrimitiveArray,
+    bitmap::{utils::TrueIdxIter, Bitmap},
+    types::Index,
+};
 
 use super::SortOptions;
 
+/// Whether the `limit` first items of `length` items are faster found with [`top_k_by`]
+/// than by selecting them over all items.
+#[inline]
+pub(super) fn use_top_k(limit: usize, length: usize) -> bool {
+    limit.saturating_mul(8) <= length
+}
+
+/// Returns the `limit` smallest items of `iter` according to `cmp`, sorted.
+///
+/// This keeps a bounded max-heap of the smallest items seen so far, so that it runs in
+/// `O(N log(limit))` and only allocates `limit` items.
+pub(super) fn top_k_by<T, F>(iter: impl Iterator<Item = T>, limit: usize, mut cmp: F) -> Vec<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut heap = Vec::with_capacity(limit);
+    if limit == 0 {
+        return heap;
+    }
+    for item in iter {
+        if heap.len() < limit {
+            heap.push(item);
+            sift_up(&mut heap, &mut cmp);
+        } else if cmp(&item, &heap[0]) == Ordering::Less {
+            heap[0] = item;
+            sift_down(&mut heap, &mut cmp);
+        }
+    }
+    heap.sort_unstable_by(cmp);
+    heap
+}
+
+/// Moves the last item of the max-heap `heap` up to its position
+fn sift_up<T, F: FnMut(&T, &T) -> Ordering>(heap: &mut [T], cmp: &mut F) {
+    let mut child = heap.len() - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if cmp(&heap[child], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        child = parent;
+    }
+}
+
+/// Moves the first item of the max-heap `heap` down to its position
+fn sift_down<T, F: FnMut(&T, &T) -> Ordering>(heap: &mut [T], cmp: &mut F) {
+    let mut parent = 0;
+    loop {
+        let left = 2 * parent + 1;
+        if left >= heap.len() {
+            break;
+        }
+        let right = left + 1;
+        let child = if right < heap.len() && cmp(&heap[right], &heap[left]) == Ordering::Greater {
+            right
+        } else {
+            left
+        };
+        if cmp(&heap[child], &heap[parent]) != Ordering::Greater {
+            break;
+        }
+        heap.swap(child, parent);
+        parent = child;
+    }
+}
+
+/// Returns the `limit` first indices of `indices` sorted according to `cmp` over `get`.
+fn top_k_indices<I, T, G, F>(
+    indices: impl Iterator<Item = usize>,
+    get: G,
+    cmp: F,
+    descending: bool,
+    limit: usize,
+) -> Vec<I>
+where
+    I: Index,
+    G: Fn(usize) -> T,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let indices = if descending {
+        top_k_by(indices, limit, |lhs, rhs| cmp(&get(*rhs), &get(*lhs)))
+    } else {
+        top_k_by(indices, limit, |lhs, rhs| cmp(&get(*lhs), &get(*rhs)))
+    };
+    indices.into_iter().map(I::from_as_usize).collect()
+}
+
 /// # Safety
 /// This function guarantees that:
 /// * `get` is only called for `0 <= i < limit`
@@ -15,7 +109,7 @@ fn k_element_sort_inner<I: Index, T, G, F>(
     mut cmp: F,
 ) where
     G: Fn(usize) -> T,
-    F: FnMut(&T, &T) -> std::cmp::Ordering,
+    F: FnMut(&T, &T) -> Ordering,
 {
     if descending {
         let mut compare = |lhs: &I, rhs: &I| {
@@ -50,7 +144,7 @@ fn sort_unstable_by<I, T, G, F>(
 ) where
     I: Index,
     G: Fn(usize) -> T,
-    F: FnMut(&T, &T) -> std::cmp::Ordering,
+    F: FnMut(&T, &T) -> Ordering,
 {
     if limit != indices.len() {
         return k_element_sort_inner(indices, get, descending, limit, cmp);
@@ -87,7 +181,7 @@ pub(super) fn indices_sorted_unstable_by<I, T, G, F>(
 where
     I: Index,
     G: Fn(usize) -> T,
-    F: Fn(&T, &T) -> std::cmp::Ordering,
+    F: Fn(&T, &T) -> Ordering,
 {
     let descending = options.descending;
 
@@ -95,7 +189,32 @@ where
     // Safety: without this, we go out of bounds when limit >= length.
     let limit = limit.min(length);
 
-    let indices = if let Some(validity) = validity {
+    let indices = if use_top_k(limit, length) {
+        // only `limit` indices are allocated
+        let nulls = || {
+            validity
+                .into_iter()
+                .flat_map(|validity| validity.iter())
+                .enumerate()
+                .filter(|(_, is_valid)| !is_valid)
+                .map(|(index, _)| I::from_as_usize(index))
+        };
+        let null_count = validity.map(|x| x.unset_bits()).unwrap_or(0);
+        let valids = TrueIdxIter::new(length, validity);
+        if options.nulls_first {
+            let mut indices = nulls().take(limit).collect::<Vec<_>>();
+            let limit = limit.saturating_sub(null_count);
+            indices.extend(top_k_indices::<I, _, _, _>(
+                valids, get, cmp, descending, limit,
+            ));
+            indices
+        } else {
+            let valid_limit = limit.min(length - null_count);
+            let mut indices = top_k_indices(valids, get, cmp, descending, valid_limit);
+            indices.extend(nulls().take(limit - valid_limit));
+            indices
+        }
+    } else if let Some(validity) = validity {
         let mut indices = vec![I::default(); length];
         if options.nulls_first {
             let mut nulls = 0;
@@ -0,0 +1,50 @@
+use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::error::Result;
+use crate::types::Index;
+
+use super::common;
+use super::{sort_to_indices, SortOptions};
+
+/// Sorts the keys of `array` by the rank of the values they point to.
+///
+/// The (usually small) dictionary is sorted once, after which keys are compared by the
+/// position of their value in the sorted dictionary, without comparing values.
+pub(super) fn indices_sorted_unstable_by<I: Index, K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let keys = array.keys();
+
+    // null values are ranked so that, once sorted by `options`, they follow `nulls_first`
+    let values_options = SortOptions {
+        descending: false,
+        nulls_first: options.nulls_first != options.descending,
+    };
+    let sorted = sort_to_indices::<u64>(array.values().as_ref(), &values_options, None)?;
+    let mut ranks = vec![0; sorted.len()];
+    sorted
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(rank, index)| ranks[*index as usize] = rank);
+
+    let get = |index| unsafe {
+        // safety: indices_sorted_unstable_by is guaranteed to get items in bounds
+        let index = keys.value_unchecked(index);
+        // safety: dictionaries are guaranteed to have valid usize keys
+        let index = index.as_usize();
+        // safety: dictionaries are guaranteed to have keys in bounds
+        *ranks.get_unchecked(index)
+    };
+
+    let cmp = |lhs: &usize, rhs: &usize| lhs.cmp(rhs);
+    Ok(common::indices_sorted_unstable_by(
+        keys.validity(),
+        get,
+        cmp,
+        array.len(),
+        options,
+        limit,
+    ))
+}
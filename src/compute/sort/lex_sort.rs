@@ -7,6 +7,7 @@ use crate::{
     types::Index,
 };
 
+use super::common::{top_k_by, use_top_k};
 use super::{sort_to_indices, SortOptions};
 use crate::array::ord::DynComparator;
 
@@ -194,6 +195,13 @@ pub fn lexsort_to_indices_impl<I: Index>(
         Ordering::Equal
     };
 
+    if let Some(limit) = limit.filter(|limit| use_top_k(*limit, row_count)) {
+        // only `limit` indices are allocated
+        let values = top_k_by(I::range(0, row_count).unwrap(), limit, lex_comparator);
+        let data_type = I::PRIMITIVE.into();
+        return Ok(PrimitiveArray::<I>::new(data_type, values.into(), None));
+    }
+
     let mut values = I::range(0, row_count).unwrap().collect::<Vec<_>>();
 
     if let Some(limit) = limit {
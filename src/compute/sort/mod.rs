@@ -1,23 +1,27 @@
 //! Contains operators to sort individual and slices of [`Array`]s.
-use std::cmp::Ordering;
 
 use crate::array::ord;
-use crate::compute::take;
+use crate::compute::{extension, take};
 use crate::datatypes::*;
 use crate::error::{Error, Result};
-use crate::offset::Offset;
-use crate::{array::*, types::Index};
+use crate::{
+    array::*,
+    types::{f16, Index},
+};
 
 mod binary;
 mod boolean;
 mod common;
+mod dictionary;
 mod lex_sort;
 mod primitive;
+mod search;
 mod utf8;
 
 pub mod row;
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, lexsort_to_indices_impl, SortColumn};
+pub use search::{search_sorted, SearchSide};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -43,6 +47,9 @@ pub fn sort(
     limit: Option<usize>,
 ) -> Result<Box<dyn Array>> {
     match values.data_type() {
+        DataType::Extension(_, _, _) => {
+            extension::unary(values, |values| sort(values, options, limit))
+        }
         DataType::Int8 => dyn_sort!(i8, values, ord::total_cmp, options, limit),
         DataType::Int16 => dyn_sort!(i16, values, ord::total_cmp, options, limit),
         DataType::Int32
@@ -60,6 +67,7 @@ pub fn sort(
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
+        DataType::Float16 => dyn_sort!(f16, values, ord::total_cmp_f16, options, limit),
         DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
         _ => {
@@ -99,6 +107,9 @@ pub fn sort_to_indices<I: Index>(
     limit: Option<usize>,
 ) -> Result<PrimitiveArray<I>> {
     match values.data_type() {
+        DataType::Extension(_, _, _) => {
+            extension::on_storage(values, |values| sort_to_indices(values, options, limit))
+        }
         DataType::Boolean => {
             let (v, n) = partition_validity(values);
             Ok(boolean::sort_boolean(
@@ -128,6 +139,7 @@ pub fn sort_to_indices<I: Index>(
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
+        DataType::Float16 => dyn_sort_indices!(I, f16, values, ord::total_cmp_f16, options, limit),
         DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
@@ -150,82 +162,23 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
-        DataType::List(field) => {
-            let (v, n) = partition_validity(values);
-            match &field.data_type {
-                DataType::Int8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::LargeList(field) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::FixedSizeList(field, _) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(Error::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {t:?}"
-            ))),
-        },
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => sort_nested(values, options, limit),
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dictionary::indices_sorted_unstable_by::<I, $T>(
+                values.as_any().downcast_ref().unwrap(),
+                options,
+                limit,
+            )
+        }),
         t => Err(Error::NotYetImplemented(format!(
             "Sort not supported for data type {t:?}"
         ))),
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
-    values: &dyn Array,
-    key_type: &IntegerType,
-    options: &SortOptions,
-    limit: Option<usize>,
-) -> PrimitiveArray<I> {
-    match_integer_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
-            values.as_any().downcast_ref().unwrap(),
-            options,
-            limit,
-        )
-    })
-}
-
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -257,6 +210,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
         | DataType::Utf8
@@ -264,21 +218,11 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Binary
         | DataType::LargeBinary => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
-        }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
+            can_sort(field.data_type())
         }
+        DataType::Struct(fields) => fields.iter().all(|field| can_sort(field.data_type())),
+        DataType::Dictionary(_, value_type, _) => can_sort(value_type.as_ref()),
+        DataType::Extension(_, inner, _) => can_sort(inner.as_ref()),
         _ => false,
     }
 }
@@ -302,68 +246,19 @@ impl Default for SortOptions {
     }
 }
 
-fn sort_list<I, O>(
+/// Sorts nested arrays by comparing their items (or fields) lexicographically.
+fn sort_nested<I: Index>(
     values: &dyn Array,
-    value_indices: Vec<I>,
-    null_indices: Vec<I>,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I>
-where
-    I: Index,
-    O: Offset,
-{
-    let mut valids: Vec<(I, Box<dyn Array>)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = values.as_any().downcast_ref::<ListArray<O>>().unwrap();
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-        );
-
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
-    } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
-    }
-
-    let values = valids.iter().map(|tuple| tuple.0);
-
-    let mut values = if options.nulls_first {
-        null_indices.into_iter().chain(values).collect::<Vec<I>>()
-    } else {
-        values.chain(null_indices.into_iter()).collect::<Vec<I>>()
-    };
-
-    values.truncate(limit.unwrap_or(values.len()));
-
-    let data_type = I::PRIMITIVE.into();
-    PrimitiveArray::<I>::new(data_type, values.into(), None)
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
-    let length = a.len().min(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    a.len().cmp(&b.len())
+) -> Result<PrimitiveArray<I>> {
+    let comparator = ord::build_compare(values, values)?;
+    Ok(common::indices_sorted_unstable_by(
+        values.validity(),
+        |index| index,
+        |lhs: &usize, rhs: &usize| (comparator)(*lhs, *rhs),
+        values.len(),
+        options,
+        limit,
+    ))
 }
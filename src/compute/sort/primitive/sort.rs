@@ -35,7 +35,7 @@ where
 {
     if descending {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(y, x));
-        before.sort_unstable_by(|x, y| cmp(x, y));
+        before.sort_unstable_by(|x, y| cmp(y, x));
     } else {
         let (before, _, _) = values.select_nth_unstable_by(limit, |x, y| cmp(x, y));
         before.sort_unstable_by(|x, y| cmp(x, y));
@@ -114,7 +114,7 @@ where
             buffer.as_mut_slice(),
             cmp,
             options.descending,
-            limit - validity.unset_bits(),
+            limit.min(values.len() - validity.unset_bits()),
         );
 
         if limit > values.len() - validity.unset_bits() {
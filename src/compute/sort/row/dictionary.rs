@@ -15,16 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::{hash_map::Entry, HashMap};
+
 use crate::{
     array::{Array, BinaryArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array},
+    bitmap::MutableBitmap,
     compute::sort::SortOptions,
-    datatypes::PhysicalType,
+    datatypes::{DataType, PhysicalType},
     error::*,
+    offset::{Offset, Offsets},
     with_match_primitive_without_interval_type,
 };
 
 use super::{
-    fixed::FixedLengthEncoding,
+    fixed::{FixedLengthEncoding, FromSlice},
     interner::{Interned, OrderPreservingInterner},
     null_sentinel, Rows,
 };
@@ -117,3 +121,109 @@ pub fn encode_dictionary<K: DictionaryKey>(
         }
     }
 }
+
+/// Decodes a [`DictionaryArray`] from `rows`, advancing the rows past it.
+///
+/// The values of the returned array are the distinct values referenced by the rows.
+pub fn decode_dictionary<K: DictionaryKey>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    interner: &OrderPreservingInterner,
+    opts: SortOptions,
+) -> Result<DictionaryArray<K>> {
+    let null = null_sentinel(opts);
+    let invert = |v: u8| if opts.descending { !v } else { v };
+
+    let mut positions = HashMap::<Interned, K>::new();
+    let mut values = vec![];
+
+    let mut keys = Vec::with_capacity(rows.len());
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let mut normalized_key = vec![];
+    for row in rows.iter_mut() {
+        if row[0] == null {
+            keys.push(K::default());
+            validity.push(false);
+            *row = &row[1..];
+            continue;
+        }
+
+        // the normalized key is null-terminated
+        normalized_key.clear();
+        normalized_key.extend(row[1..].iter().map(|v| invert(*v)));
+        let length = normalized_key.iter().position(|v| *v == 0).ok_or_else(|| {
+            Error::oos("The normalized key of a dictionary must be null-terminated")
+        })? + 1;
+        normalized_key.truncate(length);
+
+        let interned = interner.lookup(&normalized_key).ok_or_else(|| {
+            Error::InvalidArgumentError(
+                "The rows contain a dictionary value unknown to this RowConverter".to_string(),
+            )
+        })?;
+        let key = match positions.entry(interned) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let key = K::try_from(values.len()).map_err(|_| Error::Overflow)?;
+                values.push(interner.value(interned));
+                *entry.insert(key)
+            }
+        };
+        keys.push(key);
+        validity.push(true);
+        *row = &row[1 + length..];
+    }
+
+    let values = match data_type.to_logical_type() {
+        DataType::Dictionary(_, values_type, _) => {
+            decode_values(&values, values_type.as_ref().clone())?
+        }
+        _ => unreachable!(),
+    };
+    let keys = PrimitiveArray::<K>::from_vec(keys).with_validity(validity.into());
+    DictionaryArray::try_new(data_type, keys, values)
+}
+
+/// Decodes the interned values of a dictionary
+fn decode_values(values: &[&[u8]], data_type: DataType) -> Result<Box<dyn Array>> {
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => {
+            with_match_primitive_without_interval_type!(primitive, |$T| {
+                let values = values
+                    .iter()
+                    .map(|v| $T::decode(FromSlice::from_slice(v, false)))
+                    .collect::<Vec<_>>();
+                PrimitiveArray::<$T>::new(data_type, values.into(), None).boxed()
+            })
+        }
+        PhysicalType::Binary => {
+            let offsets = lengths::<i32>(values)?;
+            BinaryArray::<i32>::try_new(data_type, offsets.into(), values.concat().into(), None)?
+                .boxed()
+        }
+        PhysicalType::LargeBinary => {
+            let offsets = lengths::<i64>(values)?;
+            BinaryArray::<i64>::try_new(data_type, offsets.into(), values.concat().into(), None)?
+                .boxed()
+        }
+        PhysicalType::Utf8 => {
+            let offsets = lengths::<i32>(values)?;
+            Utf8Array::<i32>::try_new(data_type, offsets.into(), values.concat().into(), None)?
+                .boxed()
+        }
+        PhysicalType::LargeUtf8 => {
+            let offsets = lengths::<i64>(values)?;
+            Utf8Array::<i64>::try_new(data_type, offsets.into(), values.concat().into(), None)?
+                .boxed()
+        }
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "dictionary value {t:?} is not supported"
+            )))
+        }
+    })
+}
+
+fn lengths<O: Offset>(values: &[&[u8]]) -> Result<Offsets<O>> {
+    Offsets::try_from_lengths(values.iter().map(|v| v.len()))
+}
@@ -16,8 +16,10 @@
 // under the License.
 
 use crate::{
-    array::PrimitiveArray,
+    array::{BooleanArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
     compute::sort::SortOptions,
+    datatypes::DataType,
     types::{f16, i256, NativeType},
 };
 
@@ -196,3 +198,38 @@ pub fn encode<T: FixedLengthEncoding, I: IntoIterator<Item = Option<T>>>(
         *offset = end_offset;
     }
 }
+
+/// Decodes a value from each row, advancing the rows past it, returning the values and
+/// whether they are valid
+fn decode<T: FixedLengthEncoding>(
+    rows: &mut [&[u8]],
+    opts: SortOptions,
+) -> (Vec<T>, MutableBitmap) {
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let values = rows
+        .iter_mut()
+        .map(|row| {
+            let (encoded, remaining) = row.split_at(T::ENCODED_LEN);
+            *row = remaining;
+            validity.push(encoded[0] == 1);
+            T::decode(T::Encoded::from_slice(&encoded[1..], opts.descending))
+        })
+        .collect();
+    (values, validity)
+}
+
+/// Decodes a [`PrimitiveArray`] from `rows`, advancing the rows past it
+pub fn decode_primitive<T: NativeType + FixedLengthEncoding>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    opts: SortOptions,
+) -> PrimitiveArray<T> {
+    let (values, validity) = decode::<T>(rows, opts);
+    PrimitiveArray::new(data_type, values.into(), validity.into())
+}
+
+/// Decodes a [`BooleanArray`] from `rows`, advancing the rows past it
+pub fn decode_bool(rows: &mut [&[u8]], data_type: DataType, opts: SortOptions) -> BooleanArray {
+    let (values, validity) = decode::<bool>(rows, opts);
+    BooleanArray::new(data_type, Bitmap::from_bool_slice(&values), validity.into())
+}
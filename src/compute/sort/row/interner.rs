@@ -156,7 +156,6 @@ impl OrderPreservingInterner {
         self.keys.index(key)
     }
 
-    #[cfg(test)]
     /// Converts a normalized key returned by [`Self::normalized_key`] to [`Interned`]
     /// returning `None` if it cannot be found
     pub fn lookup(&self, normalized_key: &[u8]) -> Option<Interned> {
@@ -182,7 +181,6 @@ impl OrderPreservingInterner {
         Some(bucket.slots.get(slot_idx as usize)?.value)
     }
 
-    #[cfg(test)]
     /// Returns the interned value for a given [`Interned`]
     pub fn value(&self, key: Interned) -> &[u8] {
         self.values.index(key)
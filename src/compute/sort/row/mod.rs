@@ -38,14 +38,18 @@ use std::{
 };
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, DictionaryArray, PrimitiveArray, Utf8Array},
+    array::{
+        new_null_array, Array, BinaryArray, BooleanArray, DictionaryArray, NullArray,
+        PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::MutableBitmap,
     datatypes::PhysicalType,
     error::*,
 };
 use crate::{compute::sort::SortOptions, datatypes::DataType};
 
 use self::{
-    dictionary::{compute_dictionary_mapping, encode_dictionary},
+    dictionary::{compute_dictionary_mapping, decode_dictionary, encode_dictionary},
     interner::OrderPreservingInterner,
 };
 
@@ -114,6 +118,14 @@ mod variable;
 /// A non-null dictionary value is encoded as `1_u8` followed by a null-terminated byte array
 /// key determined by the order-preserving dictionary encoding
 ///
+/// ## Struct Encoding
+///
+/// A null struct is encoded as a `0_u8`, followed by the encoding of a row whose fields are
+/// all null.
+///
+/// A valid struct is encoded as `1_u8`, followed by the row encoding of its fields, which
+/// inherit the [`SortOptions`] of the struct.
+///
 /// # Ordering
 ///
 /// ## Float Ordering
@@ -140,8 +152,44 @@ mod variable;
 pub struct RowConverter {
     /// Sort fields
     fields: Arc<[SortField]>,
-    /// interning state for column `i`, if column`i` is a dictionary
-    interners: Vec<Option<Box<OrderPreservingInterner>>>,
+    /// State required to encode and decode column `i`
+    codecs: Vec<Codec>,
+}
+
+/// The state maintained by a [`RowConverter`] for a column
+#[derive(Debug)]
+enum Codec {
+    /// The column requires no state
+    Stateless,
+    /// The interning state of a dictionary column
+    Dictionary(Box<OrderPreservingInterner>),
+    /// The converter of the fields of a struct column
+    Struct(RowConverter),
+}
+
+impl Codec {
+    fn new(field: &SortField) -> Self {
+        match field.data_type.to_logical_type() {
+            DataType::Dictionary(_, _, _) => Self::Dictionary(Default::default()),
+            DataType::Struct(fields) => Self::Struct(RowConverter::new(
+                fields
+                    .iter()
+                    .map(|x| SortField::new_with_options(x.data_type.clone(), field.options))
+                    .collect(),
+            )),
+            _ => Self::Stateless,
+        }
+    }
+}
+
+/// The state required to encode a column by [`RowConverter::convert_columns`]
+enum Encoder<'a> {
+    /// The column requires no state
+    Stateless,
+    /// The normalized key of each value of the dictionary
+    Dictionary(Vec<Option<&'a [u8]>>),
+    /// The encoded fields of a struct and the encoding of a row of null fields
+    Struct(Rows, Rows),
 }
 
 /// Configure the data type and sort order for a given column
@@ -168,10 +216,10 @@ impl SortField {
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Self {
-        let interners = vec![None; fields.len()];
+        let codecs = fields.iter().map(Codec::new).collect();
         Self {
             fields: fields.into(),
-            interners,
+            codecs,
         }
     }
 
@@ -191,11 +239,11 @@ impl RowConverter {
             )));
         }
 
-        let dictionaries = columns
+        let encoders = columns
             .iter()
-            .zip(&mut self.interners)
+            .zip(self.codecs.iter_mut())
             .zip(self.fields.iter())
-            .map(|((column, interner), field)| {
+            .map(|((column, codec), field)| {
                 if column.data_type() != &field.data_type {
                     return Err(Error::InvalidArgumentError(format!(
                         "RowConverter column schema mismatch, expected {:?} got {:?}",
@@ -204,31 +252,49 @@ impl RowConverter {
                     )));
                 }
 
-                let values = match column.data_type().to_logical_type() {
-                    DataType::Dictionary(k, _, _) => match_integer_type!(k, |$T| {
-                        let column = column
-                            .as_any()
-                            .downcast_ref::<DictionaryArray<$T>>()
-                            .unwrap();
-                        column.values()
-                    }),
-                    _ => return Ok(None),
-                };
-
-                let interner = interner.get_or_insert_with(Default::default);
-
-                let mapping = compute_dictionary_mapping(interner, values)?
-                    .into_iter()
-                    .map(|maybe_interned| {
-                        maybe_interned.map(|interned| interner.normalized_key(interned))
-                    })
-                    .collect::<Vec<_>>();
-
-                Ok(Some(mapping))
+                match codec {
+                    Codec::Stateless => Ok(Encoder::Stateless),
+                    Codec::Dictionary(interner) => {
+                        let values = match column.data_type().to_logical_type() {
+                            DataType::Dictionary(k, _, _) => match_integer_type!(k, |$T| {
+                                let column = column
+                                    .as_any()
+                                    .downcast_ref::<DictionaryArray<$T>>()
+                                    .unwrap();
+                                column.values()
+                            }),
+                            _ => unreachable!(),
+                        };
+
+                        let mapping = compute_dictionary_mapping(interner, values)?;
+                        let interner = &**interner;
+                        let mapping = mapping
+                            .into_iter()
+                            .map(|maybe_interned| {
+                                maybe_interned.map(|interned| interner.normalized_key(interned))
+                            })
+                            .collect::<Vec<_>>();
+
+                        Ok(Encoder::Dictionary(mapping))
+                    }
+                    Codec::Struct(converter) => {
+                        let column = column.as_any().downcast_ref::<StructArray>().unwrap();
+                        let rows = converter.convert_columns(column.values())?;
+
+                        let nulls = converter
+                            .fields
+                            .iter()
+                            .map(|field| new_null_array(field.data_type.clone(), 1))
+                            .collect::<Vec<_>>();
+                        let null = converter.convert_columns(&nulls)?;
+
+                        Ok(Encoder::Struct(rows, null))
+                    }
+                }
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let mut rows = new_empty_rows(columns, &dictionaries)?;
+        let mut rows = new_empty_rows(columns, &encoders)?;
 
         // jorgecarleitao's comments in PR#1287:
         // This seems to be embarassibly parallel.
@@ -236,15 +302,47 @@ impl RowConverter {
         // This is almost parallelizable - it is changing rows.
         // However, there is still an optimization since modifying rows is O(1) but encoding is O(C).
         // Will continue to think about this.
-        for ((column, field), dictionary) in
-            columns.iter().zip(self.fields.iter()).zip(dictionaries)
-        {
+        for ((column, field), encoder) in columns.iter().zip(self.fields.iter()).zip(&encoders) {
             // We encode a column at a time to minimise dispatch overheads
-            encode_column(&mut rows, column, field.options, dictionary.as_deref())
+            encode_column(&mut rows, column, field.options, encoder)
         }
 
         Ok(rows)
     }
+
+    /// Convert [`Row`]s back into [`Box<dyn Array>`] columns
+    ///
+    /// # Errors
+    ///
+    /// Errors if the rows contain dictionary values unknown to this [`RowConverter`], or
+    /// decode to invalid utf8
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    pub fn convert_rows<'a, I>(&self, rows: I) -> Result<Vec<Box<dyn Array>>>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        let mut rows = rows.into_iter().map(|row| row.data).collect::<Vec<_>>();
+        let columns = self.decode(&mut rows)?;
+
+        if rows.iter().any(|row| !row.is_empty()) {
+            return Err(Error::InvalidArgumentError(
+                "The rows were not produced by this RowConverter".to_string(),
+            ));
+        }
+        Ok(columns)
+    }
+
+    /// Decodes all columns from `rows`, advancing each row past the decoded bytes
+    fn decode(&self, rows: &mut [&[u8]]) -> Result<Vec<Box<dyn Array>>> {
+        self.fields
+            .iter()
+            .zip(self.codecs.iter())
+            .map(|(field, codec)| decode_column(rows, field, codec))
+            .collect()
+    }
 }
 
 /// A row-oriented representation of arrow data, that is normalized for comparison
@@ -346,9 +444,8 @@ impl<'a> DoubleEndedIterator for RowsIter<'a> {
         if self.end == self.start {
             return None;
         }
-        let row = self.rows.row(self.end);
         self.end -= 1;
-        Some(row)
+        Some(self.rows.row(self.end))
     }
 }
 
@@ -440,16 +537,13 @@ macro_rules! with_match_primitive_without_interval_type {(
 })}
 
 /// Computes the length of each encoded [`Rows`] and returns an empty [`Rows`]
-fn new_empty_rows(
-    cols: &[Box<dyn Array>],
-    dictionaries: &[Option<Vec<Option<&[u8]>>>],
-) -> Result<Rows> {
+fn new_empty_rows(cols: &[Box<dyn Array>], encoders: &[Encoder]) -> Result<Rows> {
     use fixed::FixedLengthEncoding;
 
     let num_rows = cols.first().map(|x| x.len()).unwrap_or(0);
     let mut lengths = vec![0; num_rows];
 
-    for (array, dict) in cols.iter().zip(dictionaries) {
+    for (array, encoder) in cols.iter().zip(encoders) {
         match array.data_type().to_physical_type() {
             PhysicalType::Primitive(primitive) => {
                 with_match_primitive_without_interval_type!(primitive, |$T| {
@@ -499,7 +593,10 @@ fn new_empty_rows(
                     .as_any()
                     .downcast_ref::<DictionaryArray<$T>>()
                     .unwrap();
-                let dict = dict.as_ref().unwrap();
+                let dict = match encoder {
+                    Encoder::Dictionary(dict) => dict,
+                    _ => unreachable!(),
+                };
                 for (v, length) in array.keys().iter().zip(lengths.iter_mut()) {
                     match v.and_then(|v| dict[*v as usize]) {
                         Some(k) => *length += k.len() + 1,
@@ -507,6 +604,26 @@ fn new_empty_rows(
                     }
                 }
             }),
+            PhysicalType::Struct => {
+                let (rows, null) = match encoder {
+                    Encoder::Struct(rows, null) => (rows, null),
+                    _ => unreachable!(),
+                };
+                let null_len = null.row(0).data.len();
+                match array.validity() {
+                    Some(validity) => lengths
+                        .iter_mut()
+                        .zip(validity.iter())
+                        .zip(rows.iter())
+                        .for_each(|((length, is_valid), row)| {
+                            *length += 1 + if is_valid { row.data.len() } else { null_len }
+                        }),
+                    None => lengths
+                        .iter_mut()
+                        .zip(rows.iter())
+                        .for_each(|(length, row)| *length += 1 + row.data.len()),
+                }
+            }
             t => {
                 return Err(Error::NotYetImplemented(format!(
                     "not yet implemented: {t:?}"
@@ -548,12 +665,7 @@ fn new_empty_rows(
 }
 
 /// Encodes a column to the provided [`Rows`] incrementing the offsets as it progresses
-fn encode_column(
-    out: &mut Rows,
-    column: &Box<dyn Array>,
-    opts: SortOptions,
-    dictionary: Option<&[Option<&[u8]>]>,
-) {
+fn encode_column(out: &mut Rows, column: &Box<dyn Array>, opts: SortOptions, encoder: &Encoder) {
     match column.data_type().to_physical_type() {
         PhysicalType::Primitive(primitive) => {
             with_match_primitive_without_interval_type!(primitive, |$T| {
@@ -619,12 +731,94 @@ fn encode_column(
                 .as_any()
                 .downcast_ref::<DictionaryArray<$T>>()
                 .unwrap();
-            encode_dictionary(out, column, dictionary.unwrap(), opts);
+            let dictionary = match encoder {
+                Encoder::Dictionary(dictionary) => dictionary,
+                _ => unreachable!(),
+            };
+            encode_dictionary(out, column, dictionary, opts);
         }),
+        PhysicalType::Struct => {
+            let (rows, null) = match encoder {
+                Encoder::Struct(rows, null) => (rows, null),
+                _ => unreachable!(),
+            };
+            encode_struct(out, column.validity(), rows, null.row(0), opts)
+        }
         t => unimplemented!("not yet implemented: {:?}", t),
     }
 }
 
+/// Struct types are encoded as
+///
+/// - `0_u8` if null, followed by the encoding of a row of null fields
+/// - `1_u8` if valid, followed by the encoding of its fields
+fn encode_struct(
+    out: &mut Rows,
+    validity: Option<&crate::bitmap::Bitmap>,
+    rows: &Rows,
+    null: Row,
+    opts: SortOptions,
+) {
+    for (i, (offset, row)) in out.offsets.iter_mut().skip(1).zip(rows.iter()).enumerate() {
+        let is_valid = validity.map(|x| x.get_bit(i)).unwrap_or(true);
+        let (sentinel, row) = if is_valid {
+            (1, row)
+        } else {
+            (null_sentinel(opts), null)
+        };
+        let end_offset = *offset + 1 + row.data.len();
+        out.buffer[*offset] = sentinel;
+        out.buffer[*offset + 1..end_offset].copy_from_slice(row.data);
+        *offset = end_offset;
+    }
+}
+
+/// Decodes a column from `rows`, advancing each row past the decoded bytes
+fn decode_column(rows: &mut [&[u8]], field: &SortField, codec: &Codec) -> Result<Box<dyn Array>> {
+    let data_type = field.data_type.clone();
+    let opts = field.options;
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => {
+            with_match_primitive_without_interval_type!(primitive, |$T| {
+                fixed::decode_primitive::<$T>(rows, data_type, opts).boxed()
+            })
+        }
+        PhysicalType::Null => NullArray::new(data_type, rows.len()).boxed(),
+        PhysicalType::Boolean => fixed::decode_bool(rows, data_type, opts).boxed(),
+        PhysicalType::Binary => variable::decode_binary::<i32>(rows, data_type, opts)?.boxed(),
+        PhysicalType::LargeBinary => variable::decode_binary::<i64>(rows, data_type, opts)?.boxed(),
+        PhysicalType::Utf8 => variable::decode_utf8::<i32>(rows, data_type, opts)?.boxed(),
+        PhysicalType::LargeUtf8 => variable::decode_utf8::<i64>(rows, data_type, opts)?.boxed(),
+        PhysicalType::Dictionary(k) => {
+            let interner = match codec {
+                Codec::Dictionary(interner) => interner,
+                _ => unreachable!(),
+            };
+            match_integer_type!(k, |$T| {
+                decode_dictionary::<$T>(rows, data_type, interner, opts)?.boxed()
+            })
+        }
+        PhysicalType::Struct => {
+            let converter = match codec {
+                Codec::Struct(converter) => converter,
+                _ => unreachable!(),
+            };
+            let mut validity = MutableBitmap::with_capacity(rows.len());
+            rows.iter_mut().for_each(|row| {
+                validity.push(row[0] == 1);
+                *row = &row[1..];
+            });
+            let values = converter.decode(rows)?;
+            StructArray::try_new(data_type, values, validity.into())?.boxed()
+        }
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "not yet implemented: {t:?}"
+            )))
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
@@ -824,6 +1018,9 @@ mod tests {
                     assert_eq!(row_cmp, lex_cmp);
                 }
             }
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back, arrays);
         }
     }
 }
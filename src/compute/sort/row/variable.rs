@@ -15,7 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::compute::sort::SortOptions;
+use crate::{
+    array::{BinaryArray, Utf8Array},
+    bitmap::MutableBitmap,
+    compute::sort::SortOptions,
+    datatypes::DataType,
+    error::Result,
+    offset::{Offset, Offsets},
+};
 
 use super::{null_sentinel, Rows};
 
@@ -116,3 +123,65 @@ pub fn encode<'a, I: Iterator<Item = Option<&'a [u8]>>>(out: &mut Rows, i: I, op
         }
     }
 }
+
+/// Decodes a byte array from each row, advancing the rows past it, returning the offsets,
+/// values and validity of the byte arrays
+fn decode<O: Offset>(
+    rows: &mut [&[u8]],
+    opts: SortOptions,
+) -> Result<(Offsets<O>, Vec<u8>, MutableBitmap)> {
+    let null = null_sentinel(opts);
+    let invert = |v: u8| if opts.descending { !v } else { v };
+
+    let mut offsets = Offsets::<O>::with_capacity(rows.len());
+    let mut values = vec![];
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    for row in rows.iter_mut() {
+        let sentinel = row[0];
+        if sentinel == null || invert(sentinel) == EMPTY_SENTINEL {
+            validity.push(sentinel != null);
+            offsets.try_push_usize(0)?;
+            *row = &row[1..];
+            continue;
+        }
+
+        let start = values.len();
+        let mut consumed = 1;
+        for block in row[1..].chunks_exact(BLOCK_SIZE + 1) {
+            consumed += BLOCK_SIZE + 1;
+            let marker = invert(block[BLOCK_SIZE]);
+            if marker == BLOCK_CONTINUATION {
+                values.extend(block[..BLOCK_SIZE].iter().map(|v| invert(*v)));
+            } else {
+                values.extend(block[..marker as usize].iter().map(|v| invert(*v)));
+                break;
+            }
+        }
+        validity.push(true);
+        offsets.try_push_usize(values.len() - start)?;
+        *row = &row[consumed..];
+    }
+    Ok((offsets, values, validity))
+}
+
+/// Decodes a [`BinaryArray`] from `rows`, advancing the rows past it
+pub fn decode_binary<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    opts: SortOptions,
+) -> Result<BinaryArray<O>> {
+    let (offsets, values, validity) = decode::<O>(rows, opts)?;
+    BinaryArray::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
+
+/// Decodes a [`Utf8Array`] from `rows`, advancing the rows past it
+/// # Errors
+/// Errors iff the decoded values are not valid utf8
+pub fn decode_utf8<O: Offset>(
+    rows: &mut [&[u8]],
+    data_type: DataType,
+    opts: SortOptions,
+) -> Result<Utf8Array<O>> {
+    let (offsets, values, validity) = decode::<O>(rows, opts)?;
+    Utf8Array::try_new(data_type, offsets.into(), values.into(), validity.into())
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use crate::array::{ord, Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+/// The side of the insertion index of [`search_sorted`] among values equal to a needle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
+pub enum SearchSide {
+    /// The index of the first value equal to the needle
+    #[default]
+    Left,
+    /// The index after the last value equal to the needle
+    Right,
+}
+
+/// Returns the first index in `[start, end)` whose `is_before` is `false`, assuming that
+/// `is_before` is `true` for all indices before it and `false` for all indices after it.
+#[inline]
+fn partition_point<F: Fn(usize) -> bool>(mut start: usize, mut end: usize, is_before: F) -> usize {
+    while start < end {
+        let middle = start + (end - start) / 2;
+        if is_before(middle) {
+            start = middle + 1;
+        } else {
+            end = middle;
+        }
+    }
+    start
+}
+
+/// Same as [`partition_point`] over `[start, end)`, but probing exponentially from `start`,
+/// so that it is `O(log d)` for a partition point at distance `d` of `start`.
+#[inline]
+fn gallop<F: Fn(usize) -> bool>(mut start: usize, end: usize, is_before: F) -> usize {
+    let mut step = 1;
+    let end = loop {
+        let probe = start + step - 1;
+        if probe >= end {
+            break end;
+        }
+        if !is_before(probe) {
+            break probe;
+        }
+        start = probe + 1;
+        step *= 2;
+    };
+    partition_point(start, end, is_before)
+}
+
+/// Returns, for every needle of `needles`, the index at which it would be inserted in
+/// `sorted_haystack` to keep it sorted: for [`SearchSide::Left`], the index of the first value
+/// that is not smaller than the needle; for [`SearchSide::Right`], the index of the first value
+/// that is larger than the needle.
+///
+/// `sorted_haystack` must be sorted by [`sort`](super::sort) with the default
+/// [`SortOptions`](super::SortOptions), i.e. in ascending order with nulls first; the result is
+/// unspecified otherwise. Null needles are inserted among the nulls, and floats are compared by
+/// their total order.
+///
+/// When the needles are themselves sorted, each search gallops from the index of the previous
+/// needle instead of bisecting the whole haystack, which is `O(N + M)` instead of
+/// `O(M log N)` for needles spread over the haystack.
+/// # Errors
+/// This function errors iff the arrays do not have the same type, or their type has no
+/// natural order.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sort::{search_sorted, SearchSide};
+///
+/// let haystack = Int32Array::from_slice([1, 3, 3, 5]);
+/// let needles = Int32Array::from_slice([0, 3, 6]);
+/// let result = search_sorted(&haystack, &needles, SearchSide::Left).unwrap();
+/// assert_eq!(result.values().as_slice(), &[0, 1, 4]);
+/// let result = search_sorted(&haystack, &needles, SearchSide::Right).unwrap();
+/// assert_eq!(result.values().as_slice(), &[0, 3, 4]);
+/// ```
+pub fn search_sorted(
+    sorted_haystack: &dyn Array,
+    needles: &dyn Array,
+    side: SearchSide,
+) -> Result<PrimitiveArray<u64>> {
+    let compare = ord::build_compare(sorted_haystack, needles)?;
+    let compare = |haystack: usize, needle: usize| match (
+        sorted_haystack.is_valid(haystack),
+        needles.is_valid(needle),
+    ) {
+        (true, true) => compare(haystack, needle),
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    };
+    let is_before = |haystack: usize, needle: usize| match side {
+        SearchSide::Left => compare(haystack, needle) == Ordering::Less,
+        SearchSide::Right => compare(haystack, needle) != Ordering::Greater,
+    };
+
+    let length = sorted_haystack.len();
+    let values = if is_sorted(needles)? {
+        let mut start = 0;
+        (0..needles.len())
+            .map(|needle| {
+                start = gallop(start, length, |haystack| is_before(haystack, needle));
+                start as u64
+            })
+            .collect::<Vec<_>>()
+    } else {
+        (0..needles.len())
+            .map(|needle| partition_point(0, length, |haystack| is_before(haystack, needle)) as u64)
+            .collect::<Vec<_>>()
+    };
+    Ok(PrimitiveArray::new(DataType::UInt64, values.into(), None))
+}
+
+/// Returns whether `array` is sorted in ascending order with nulls first.
+fn is_sorted(array: &dyn Array) -> Result<bool> {
+    if array.len() < 2 {
+        return Ok(true);
+    }
+    let compare = ord::build_compare(array, array)?;
+    Ok(
+        (1..array.len()).all(|i| match (array.is_valid(i - 1), array.is_valid(i)) {
+            (true, true) => compare(i - 1, i) != Ordering::Greater,
+            (true, false) => false,
+            _ => true,
+        }),
+    )
+}
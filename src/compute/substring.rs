@@ -24,50 +24,66 @@ use crate::{
     offset::{Offset, Offsets},
 };
 
+/// Returns the substring of `value` starting at its char `start` with `length` chars (until its
+/// end if `None`). Chars are bytes iff `is_ascii`.
+#[inline]
+fn char_slice(value: &str, start: usize, length: Option<usize>, is_ascii: bool) -> &str {
+    if is_ascii {
+        let start = start.min(value.len());
+        let end = length.map_or(value.len(), |length| {
+            start.saturating_add(length).min(value.len())
+        });
+        return &value[start..end];
+    }
+
+    // the byte index of every char, followed by the end of the value
+    let mut indices = value
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(value.len()));
+    let start = match indices.nth(start) {
+        Some(start) => start,
+        None => return "",
+    };
+    let end = match length {
+        None => value.len(),
+        Some(0) => start,
+        Some(length) => indices.nth(length - 1).unwrap_or(value.len()),
+    };
+    &value[start..end]
+}
+
+/// Returns whether all values of `array` are ASCII, in which case chars can be sliced as bytes.
+fn is_ascii<O: Offset>(array: &Utf8Array<O>) -> bool {
+    let start = array.offsets().first().to_usize();
+    let end = array.offsets().last().to_usize();
+    array.values()[start..end].is_ascii()
+}
+
 /// Returns a Utf8Array<O> with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
 /// `start` can be negative, in which case the start counts from the end of the string.
+/// `start` and `length` are in chars, which are only counted when the values are not ASCII.
 pub fn utf8_substring<O: Offset>(
     array: &Utf8Array<O>,
     start: O,
     length: &Option<O>,
 ) -> Utf8Array<O> {
     let length = length.map(|v| v.to_usize());
+    let is_ascii = is_ascii(array);
 
     let iter = array.values_iter().map(|str_val| {
         // compute where we should start slicing this entry.
         let start = if start >= O::zero() {
             start.to_usize()
         } else {
-            let start = (O::zero() - start).to_usize();
-            str_val
-                .char_indices()
-                .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
-                .unwrap_or(0)
+            let chars = if is_ascii {
+                str_val.len()
+            } else {
+                str_val.chars().count()
+            };
+            chars.saturating_sub((O::zero() - start).to_usize())
         };
-
-        let mut iter_chars = str_val.char_indices();
-        if let Some((start_idx, _)) = iter_chars.nth(start) {
-            // length of the str
-            let len_end = str_val.len() - start_idx;
-
-            // length to slice
-            let length = length.unwrap_or(len_end);
-
-            if length == 0 {
-                return "";
-            }
-            // compute
-            let end_idx = iter_chars
-                .nth(length.saturating_sub(1))
-                .map(|(idx, _)| idx)
-                .unwrap_or(str_val.len());
-
-            &str_val[start_idx..end_idx]
-        } else {
-            ""
-        }
+        char_slice(str_val, start, length, is_ascii)
     });
 
     let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
@@ -186,3 +202,126 @@ pub fn can_substring(data_type: &DataType) -> bool {
         DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary
     )
 }
+
+/// Returns the 0-based start and the length of the SQL substring of the 1-based `start` with
+/// `length`, whose positions before the first one are dropped.
+#[inline]
+fn sql_window(start: i64, length: Option<usize>) -> (usize, Option<usize>) {
+    let begin = start.saturating_sub(1);
+    let first = begin.max(0);
+    let length = length.map(|length| {
+        let end = begin.saturating_add(i64::try_from(length).unwrap_or(i64::MAX));
+        (end.max(first) - first) as usize
+    });
+    (first as usize, length)
+}
+
+/// Returns a Utf8Array<O> with the SQL substring of each of the elements in `array`: the
+/// `length` chars (until the end if `None`) starting at the 1-based char `start`. Positions
+/// before the first char are part of the substring but have no char, so that e.g.
+/// `start = -1` and `length = 3` is the first char, like `SUBSTRING(value FROM -1 FOR 3)`.
+pub fn utf8_substring_sql<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: Option<usize>,
+) -> Utf8Array<O> {
+    let (start, length) = sql_window(start, length);
+    let is_ascii = is_ascii(array);
+
+    let iter = array
+        .values_iter()
+        .map(|value| char_slice(value, start, length, is_ascii));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a BinaryArray<O> with the SQL substring of each of the elements in `array`, whose
+/// `start` and `length` are in bytes, see [`utf8_substring_sql`].
+pub fn binary_substring_sql<O: Offset>(
+    array: &BinaryArray<O>,
+    start: i64,
+    length: Option<usize>,
+) -> BinaryArray<O> {
+    let (start, length) = sql_window(start, length);
+
+    let iter = array.values_iter().map(|value| {
+        let start = start.min(value.len());
+        let end = length.map_or(value.len(), |length| {
+            start.saturating_add(length).min(value.len())
+        });
+        &value[start..end]
+    });
+
+    let new = BinaryArray::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns an ArrayRef with the SQL substring, of `length` (until the end if `None`) starting
+/// at the 1-based position `start`, of each of the elements in `array`. Positions are chars for
+/// strings and bytes for binaries, and positions before the first one are part of the
+/// substring but have no value, as SQL's `SUBSTRING(value FROM start FOR length)` does.
+/// this function errors when the passed array is not a \[Large\]String or \[Large\]Binary
+/// array, or `length` is negative.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::substring::substring_sql;
+///
+/// let array = Utf8Array::<i32>::from([Some("olá mundo"), None]);
+///
+/// let result = substring_sql(&array, 3, Some(3)).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("á m"), None]), result.as_ref());
+///
+/// let result = substring_sql(&array, -1, Some(3)).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("o"), None]), result.as_ref());
+/// ```
+pub fn substring_sql(array: &dyn Array, start: i64, length: Option<i64>) -> Result<Box<dyn Array>> {
+    let length = length
+        .map(|length| {
+            usize::try_from(length).map_err(|_| {
+                Error::InvalidArgumentError(format!(
+                    "The length of a substring must not be negative, but it is {length}"
+                ))
+            })
+        })
+        .transpose()?;
+    match array.data_type() {
+        DataType::Binary => Ok(Box::new(binary_substring_sql(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .expect("A binary is expected"),
+            start,
+            length,
+        ))),
+        DataType::LargeBinary => Ok(Box::new(binary_substring_sql(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .expect("A large binary is expected"),
+            start,
+            length,
+        ))),
+        DataType::LargeUtf8 => Ok(Box::new(utf8_substring_sql(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+            start,
+            length,
+        ))),
+        DataType::Utf8 => Ok(Box::new(utf8_substring_sql(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
+            start,
+            length,
+        ))),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "substring does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
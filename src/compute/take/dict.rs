@@ -23,7 +23,8 @@ use super::Index;
 /// `take` implementation for dictionary arrays
 ///
 /// applies `take` to the keys of the dictionary array and returns a new dictionary array
-/// with the same dictionary values and reordered keys
+/// with the same dictionary values and reordered keys. The values that are no longer
+/// referenced can be dropped with [`DictionaryArray::compact`].
 pub fn take<K, I>(values: &DictionaryArray<K>, indices: &PrimitiveArray<I>) -> DictionaryArray<K>
 where
     K: DictionaryKey,
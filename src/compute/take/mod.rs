@@ -30,12 +30,12 @@ mod dict;
 mod fixed_size_list;
 mod generic_binary;
 mod list;
+mod map;
 mod primitive;
 mod structure;
+mod union;
 mod utf8;
 
-pub(crate) use boolean::take as take_boolean;
-
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
@@ -95,6 +95,14 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(fixed_size_list::take::<O>(array, indices)))
         }
+        Map => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(map::take::<O>(array, indices)))
+        }
+        Union => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(union::take::<O>(array, indices)?))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
@@ -141,6 +149,8 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::List(_)
             | DataType::LargeList(_)
             | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Union(_, _, _)
             | DataType::Dictionary(..)
     )
 }
@@ -0,0 +1,69 @@
+use crate::{
+    array::{Array, MutableArray, MutablePrimitiveArray, PrimitiveArray, UnionArray},
+    error::{Error, Result},
+};
+
+use super::Index;
+
+/// `take` implementation for [`UnionArray`]. Since unions have no validity, null indices are
+/// taken as nulls of the first field.
+pub fn take<I: Index>(array: &UnionArray, indices: &PrimitiveArray<I>) -> Result<UnionArray> {
+    let null_type_id = || {
+        array
+            .fields_by_type_id()
+            .next()
+            .map(|(type_id, _, _)| type_id)
+            .ok_or_else(|| {
+                Error::InvalidArgumentError(
+                    "Null indices cannot be taken from a union without fields".to_string(),
+                )
+            })
+    };
+
+    let types = indices
+        .iter()
+        .map(|index| match index {
+            Some(index) => Ok(array.types()[index.to_usize()]),
+            None => null_type_id(),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (fields, offsets) = if array.offsets().is_some() {
+        // each field is taken by the slots that select it
+        let mut slots = vec![MutablePrimitiveArray::<u64>::new(); array.fields().len()];
+        let mut offsets = Vec::with_capacity(indices.len());
+        for index in indices.iter() {
+            let (field, slot) = match index {
+                Some(index) => {
+                    let (field, slot) = array.index(index.to_usize());
+                    (field, Some(slot as u64))
+                }
+                // the first type id always selects the first field
+                None => (0, None),
+            };
+            offsets.push(slots[field].len() as i32);
+            slots[field].push(slot);
+        }
+        let fields = array
+            .fields()
+            .iter()
+            .zip(slots)
+            .map(|(field, slots)| super::take(field.as_ref(), &slots.into()))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, Some(offsets.into()))
+    } else {
+        // all fields are taken by the same slots
+        let slots = indices
+            .iter()
+            .map(|index| index.map(|index| array.index(index.to_usize()).1 as u64))
+            .collect::<PrimitiveArray<u64>>();
+        let fields = array
+            .fields()
+            .iter()
+            .map(|field| super::take(field.as_ref(), &slots))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, None)
+    };
+
+    UnionArray::try_new(array.data_type().clone(), types.into(), fields, offsets)
+}
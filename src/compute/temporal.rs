@@ -17,15 +17,20 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
+use std::cell::RefCell;
+use std::fmt::{Display, Write};
+
+use chrono::format::{parse, Item, Parsed, StrftimeItems};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Offset as _, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
+use crate::offset::{Offset, Offsets};
 use crate::temporal_conversions::*;
 use crate::types::NativeType;
 
-use super::arity::unary;
+use super::arity::{unary, unary_checked};
 
 // Create and implement a trait that converts chrono's `Weekday`
 // type into `u32`
@@ -49,6 +54,37 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that derives the quarter and
+// the day of the week starting on Sunday from `Datelike`
+trait U32DateParts: Datelike {
+    fn u32_quarter(&self) -> u32 {
+        self.month0() / 3 + 1
+    }
+
+    fn u32_day_of_week(&self) -> u32 {
+        self.weekday().num_days_from_sunday()
+    }
+}
+
+impl U32DateParts for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32DateParts for chrono::DateTime<T> {}
+
+// Create and implement a trait that derives the fractions of
+// the second from `Timelike::nanosecond`
+trait U32Subsecond: Timelike {
+    fn u32_millisecond(&self) -> u32 {
+        self.nanosecond() / 1_000_000
+    }
+
+    fn u32_microsecond(&self) -> u32 {
+        self.nanosecond() / 1_000
+    }
+}
+
+impl U32Subsecond for chrono::NaiveTime {}
+impl U32Subsecond for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32Subsecond for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -109,6 +145,28 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Extracts the quarters of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 4.
+/// Use [`can_quarter`] to check if this operation is supported for the target [`DataType`].
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_quarter, array, DataType::UInt32)
+}
+
+/// Extracts the days of the year of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 366 (Last day depends on the year).
+/// Use [`can_day_of_year`] to check if this operation is supported for the target [`DataType`].
+pub fn day_of_year(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(ordinal, array, DataType::UInt32)
+}
+
+/// Extracts the days of the week of a temporal array as [`PrimitiveArray<u32>`].
+/// Sunday is 0, Monday is 1, ..., Saturday is 6, as SQL's `dow`. See [`weekday`] for the
+/// ISO 8601 numbering, where weeks start on Monday.
+/// Use [`can_day_of_week`] to check if this operation is supported for the target [`DataType`].
+pub fn day_of_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_day_of_week, array, DataType::UInt32)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -159,6 +217,20 @@ pub fn second(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     time_like!(second, array, DataType::UInt32)
 }
 
+/// Extracts the milliseconds of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 to 999, the fraction of the second in milliseconds.
+/// Use [`can_millisecond`] to check if this operation is supported for the target [`DataType`].
+pub fn millisecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_like!(u32_millisecond, array, DataType::UInt32)
+}
+
+/// Extracts the microseconds of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 to 999_999, the fraction of the second in microseconds.
+/// Use [`can_microsecond`] to check if this operation is supported for the target [`DataType`].
+pub fn microsecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_like!(u32_microsecond, array, DataType::UInt32)
+}
+
 /// Extracts the nanoseconds of a temporal array as [`PrimitiveArray<u32>`].
 /// Use [`can_nanosecond`] to check if this operation is supported for the target [`DataType`].
 pub fn nanosecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
@@ -272,6 +344,50 @@ where
     )))
 }
 
+/// Caches the offset of a timezone over the UTC hour of the last converted datetime, so that
+/// timezones with transitions (e.g. `chrono_tz::Tz`) are not
+/// searched for every value of an array. An hour is only cached when its offset is the same
+/// at both of its ends, which, since no timezone has two transitions within one hour, means
+/// that it has no transition.
+struct OffsetCache<'a, T: chrono::TimeZone> {
+    timezone: &'a T,
+    cached: RefCell<Option<(i64, T::Offset)>>,
+}
+
+impl<'a, T: chrono::TimeZone> OffsetCache<'a, T> {
+    fn new(timezone: &'a T) -> Self {
+        Self {
+            timezone,
+            cached: RefCell::new(None),
+        }
+    }
+
+    fn offset(&self, datetime: &NaiveDateTime) -> T::Offset {
+        let hour = datetime.and_utc().timestamp().div_euclid(3600);
+        if let Some((cached, offset)) = self.cached.borrow().as_ref() {
+            if *cached == hour {
+                return offset.clone();
+            }
+        }
+
+        let bounds = timestamp_s_to_datetime_opt(hour * 3600)
+            .zip(timestamp_s_to_datetime_opt(hour * 3600 + 3599));
+        match bounds {
+            Some((start, end)) => {
+                let offset = self.timezone.offset_from_utc_datetime(&start);
+                let end = self.timezone.offset_from_utc_datetime(&end);
+                if offset.fix() == end.fix() {
+                    *self.cached.borrow_mut() = Some((hour, offset.clone()));
+                    offset
+                } else {
+                    self.timezone.offset_from_utc_datetime(datetime)
+                }
+            }
+            None => self.timezone.offset_from_utc_datetime(datetime),
+        }
+    }
+}
+
 fn extract_impl<T, A, F>(
     array: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -283,48 +399,21 @@ where
     A: NativeType,
     F: Fn(chrono::DateTime<T>) -> A,
 {
-    match time_unit {
-        TimeUnit::Second => {
-            let op = |x| {
-                let datetime = timestamp_s_to_datetime(x);
-                let offset = timezone.offset_from_utc_datetime(&datetime);
-                extract(chrono::DateTime::<T>::from_naive_utc_and_offset(
-                    datetime, offset,
-                ))
-            };
-            unary(array, op, A::PRIMITIVE.into())
-        }
-        TimeUnit::Millisecond => {
-            let op = |x| {
-                let datetime = timestamp_ms_to_datetime(x);
-                let offset = timezone.offset_from_utc_datetime(&datetime);
-                extract(chrono::DateTime::<T>::from_naive_utc_and_offset(
-                    datetime, offset,
-                ))
-            };
-            unary(array, op, A::PRIMITIVE.into())
-        }
-        TimeUnit::Microsecond => {
-            let op = |x| {
-                let datetime = timestamp_us_to_datetime(x);
-                let offset = timezone.offset_from_utc_datetime(&datetime);
-                extract(chrono::DateTime::<T>::from_naive_utc_and_offset(
-                    datetime, offset,
-                ))
-            };
-            unary(array, op, A::PRIMITIVE.into())
-        }
-        TimeUnit::Nanosecond => {
-            let op = |x| {
-                let datetime = timestamp_ns_to_datetime(x);
-                let offset = timezone.offset_from_utc_datetime(&datetime);
-                extract(chrono::DateTime::<T>::from_naive_utc_and_offset(
-                    datetime, offset,
-                ))
-            };
-            unary(array, op, A::PRIMITIVE.into())
-        }
-    }
+    let func = match time_unit {
+        TimeUnit::Second => timestamp_s_to_datetime,
+        TimeUnit::Millisecond => timestamp_ms_to_datetime,
+        TimeUnit::Microsecond => timestamp_us_to_datetime,
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime,
+    };
+    let offsets = OffsetCache::new(&timezone);
+    let op = |x| {
+        let datetime = func(x);
+        let offset = offsets.offset(&datetime);
+        extract(chrono::DateTime::<T>::from_naive_utc_and_offset(
+            datetime, offset,
+        ))
+    };
+    unary(array, op, A::PRIMITIVE.into())
 }
 
 /// Checks if an array of type `datatype` can perform year operation
@@ -361,6 +450,21 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform quarter operation
+pub fn can_quarter(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of year operation
+pub fn can_day_of_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform day of week operation
+pub fn can_day_of_week(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -392,6 +496,16 @@ pub fn can_second(data_type: &DataType) -> bool {
     can_time(data_type)
 }
 
+/// Checks if an array of type `datatype` can perform millisecond operation
+pub fn can_millisecond(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform microsecond operation
+pub fn can_microsecond(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
 /// Checks if an array of type `datatype` can perform nanosecond operation
 pub fn can_nanosecond(data_type: &DataType) -> bool {
     can_time(data_type)
@@ -409,3 +523,651 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+/// The format of the strings parsed by [`strptime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrptimeFormat<'a> {
+    /// A [chrono format string](chrono::format::strftime), such as `"%d/%m/%Y %H:%M"`
+    Chrono(&'a str),
+    /// RFC3339 and the common ISO 8601 formats, such as `2021-01-02T03:04:05.678Z`,
+    /// `2021-01-02 03:04:05`, `2021-01-02 03:04`, `2021-01-02` and `03:04:05.678`
+    Auto,
+}
+
+/// How [`strptime`] handles non-null strings that can not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StrptimeErrors {
+    /// Strings that can not be parsed are null
+    #[default]
+    Null,
+    /// Strings that can not be parsed are an error
+    Error,
+}
+
+/// The formats tried in order by [`StrptimeFormat::Auto`].
+const AUTO_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f%#z",
+    "%Y-%m-%d %H:%M:%S%.f%#z",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d",
+    "%H:%M:%S%.f",
+    "%H:%M",
+];
+
+fn compile_format(format: &str) -> Result<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(Error::InvalidArgumentError(format!(
+            "\"{format}\" is not a valid format"
+        )));
+    }
+    Ok(items)
+}
+
+/// Returns the datetime of `parsed`, at midnight if it has no time.
+fn parsed_to_datetime(parsed: &Parsed) -> Option<NaiveDateTime> {
+    parsed
+        .to_naive_datetime_with_offset(0)
+        .ok()
+        .or_else(|| parsed.to_naive_date().ok()?.and_hms_opt(0, 0, 0))
+}
+
+fn datetime_to_timestamp(datetime: NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let datetime = datetime.and_utc();
+    match time_unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+}
+
+/// Parses every value of `array` with `op`, where `None` means that the value can not be parsed.
+fn parse_array<O, T, F>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+    errors: StrptimeErrors,
+    op: F,
+) -> Result<Box<dyn Array>>
+where
+    O: Offset,
+    T: NativeType,
+    F: Fn(&str) -> Option<T>,
+{
+    let iter = array
+        .iter()
+        .map(|value| match value.map(|value| (value, op(value))) {
+            None => Ok(None),
+            Some((_, Some(parsed))) => Ok(Some(parsed)),
+            Some((_, None)) if errors == StrptimeErrors::Null => Ok(None),
+            Some((value, None)) => Err(Error::InvalidArgumentError(format!(
+                "\"{value}\" can not be parsed as {data_type:?}"
+            ))),
+        });
+    let array = MutablePrimitiveArray::<T>::try_from_trusted_len_iter(iter)?;
+    Ok(PrimitiveArray::<T>::from(array)
+        .to(data_type.clone())
+        .boxed())
+}
+
+/// Parses every value of `array` to a timestamp in `time_unit` in `timezone`. Values with an
+/// offset are converted to `timezone`, and values without one are local to `timezone`.
+fn parse_timestamp_tz<O: Offset, T: chrono::TimeZone>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+    errors: StrptimeErrors,
+    parse: &dyn Fn(&str) -> Option<Parsed>,
+    time_unit: TimeUnit,
+    timezone: T,
+) -> Result<Box<dyn Array>> {
+    parse_array(array, data_type, errors, |value| {
+        let parsed = parse(value)?;
+        let datetime = parsed_to_datetime(&parsed)?;
+        let datetime = match parsed.to_fixed_offset() {
+            Ok(offset) => datetime - chrono::Duration::seconds(offset.local_minus_utc() as i64),
+            Err(_) => timezone
+                .from_local_datetime(&datetime)
+                .earliest()?
+                .naive_utc(),
+        };
+        datetime_to_timestamp(datetime, time_unit)
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn parse_timestamp_chrono_tz<O: Offset>(
+    array: &Utf8Array<O>,
+    data_type: &DataType,
+    errors: StrptimeErrors,
+    parse: &dyn Fn(&str) -> Option<Parsed>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+) -> Result<Box<dyn Array>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    parse_timestamp_tz(array, data_type, errors, parse, time_unit, timezone)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn parse_timestamp_chrono_tz<O: Offset>(
+    _: &Utf8Array<O>,
+    _: &DataType,
+    _: StrptimeErrors,
+    _: &dyn Fn(&str) -> Option<Parsed>,
+    _: TimeUnit,
+    timezone_str: &str,
+) -> Result<Box<dyn Array>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Parses the strings of `array` with `format` into an array of `data_type`, which must be
+/// `Timestamp`, `Date32` or `Time64`.
+///
+/// Strings without a time are parsed to midnight. For timestamps with a timezone, strings with
+/// an offset are converted to the timezone, and strings without one are local to it (the
+/// earliest instant is used when a local time is ambiguous). Timestamps without a timezone ignore
+/// offsets. Null strings are null, and strings that can not be parsed are handled per `errors`.
+///
+/// The format is compiled once for the whole array. The feature `"chrono-tz"` enables IANA and
+/// zoneinfo timezones.
+/// # Errors
+/// This function errors iff `format` is not a valid format, `data_type` is not supported (see
+/// [`can_strptime`]), its timezone can not be parsed, or a string can not be parsed and
+/// `errors` is [`StrptimeErrors::Error`].
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::temporal::{strptime, StrptimeErrors, StrptimeFormat};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from([Some("02/01/1970"), None, Some("2/13/1970")]);
+/// let format = StrptimeFormat::Chrono("%d/%m/%Y");
+///
+/// let result = strptime(&array, format, &DataType::Date32, StrptimeErrors::Null).unwrap();
+/// let expected = Int32Array::from([Some(1), None, None]).to(DataType::Date32);
+/// assert_eq!(expected, result.as_ref());
+///
+/// assert!(strptime(&array, format, &DataType::Date32, StrptimeErrors::Error).is_err());
+/// ```
+pub fn strptime<O: Offset>(
+    array: &Utf8Array<O>,
+    format: StrptimeFormat,
+    data_type: &DataType,
+    errors: StrptimeErrors,
+) -> Result<Box<dyn Array>> {
+    let formats = match format {
+        StrptimeFormat::Chrono(format) => vec![compile_format(format)?],
+        StrptimeFormat::Auto => AUTO_FORMATS
+            .iter()
+            .map(|format| compile_format(format))
+            .collect::<Result<_>>()?,
+    };
+    let parse = |value: &str| {
+        formats.iter().find_map(|items| {
+            let mut parsed = Parsed::new();
+            parse(&mut parsed, value, items.iter()).ok()?;
+            Some(parsed)
+        })
+    };
+
+    match data_type.to_logical_type() {
+        DataType::Date32 => parse_array(array, data_type, errors, |value| {
+            let date = parse(value)?.to_naive_date().ok()?;
+            Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        }),
+        DataType::Time64(time_unit @ (TimeUnit::Microsecond | TimeUnit::Nanosecond)) => {
+            let divisor = if *time_unit == TimeUnit::Microsecond {
+                1_000
+            } else {
+                1
+            };
+            parse_array(array, data_type, errors, |value| {
+                let time = parse(value)?.to_naive_time().ok()?;
+                let nanoseconds = time.num_seconds_from_midnight() as i64 * NANOSECONDS
+                    + time.nanosecond() as i64;
+                Some(nanoseconds / divisor)
+            })
+        }
+        DataType::Timestamp(time_unit, None) => parse_array(array, data_type, errors, |value| {
+            datetime_to_timestamp(parsed_to_datetime(&parse(value)?)?, *time_unit)
+        }),
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                parse_timestamp_tz(array, data_type, errors, &parse, *time_unit, timezone)
+            } else {
+                parse_timestamp_chrono_tz(
+                    array,
+                    data_type,
+                    errors,
+                    &parse,
+                    *time_unit,
+                    timezone_str,
+                )
+            }
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"strptime\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if strings can be parsed by [`strptime`] into arrays of `data_type`.
+pub fn can_strptime(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Date32
+            | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+            | DataType::Timestamp(_, _)
+    )
+}
+
+/// Formats every value of `array` with `op` into a single values buffer, reserved for all values
+/// once the length of the first value is known.
+fn format_array<T, F>(array: &PrimitiveArray<T>, mut op: F) -> Result<Utf8Array<i32>>
+where
+    T: NativeType,
+    F: FnMut(T, &mut String) -> Option<std::fmt::Result>,
+{
+    let mut values = String::new();
+    let mut offsets = Offsets::<i32>::with_capacity(array.len());
+    let mut is_reserved = false;
+
+    for (index, value) in array.iter().enumerate() {
+        let start = values.len();
+        if let Some(value) = value {
+            op(*value, &mut values)
+                .ok_or_else(|| {
+                    Error::InvalidArgumentError(format!(
+                        "The value {value:?} of type {:?} is out of range",
+                        array.data_type()
+                    ))
+                })?
+                .map_err(|_| {
+                    Error::InvalidArgumentError(format!(
+                        "The format can not be applied to type {:?}",
+                        array.data_type()
+                    ))
+                })?;
+            if !is_reserved {
+                values.reserve((values.len() - start) * (array.len() - index));
+                is_reserved = true;
+            }
+        }
+        offsets.try_push_usize(values.len() - start)?;
+    }
+
+    // Safety: `values` is a `String`, and every offset ends a value written to it
+    Ok(unsafe {
+        Utf8Array::<i32>::new_unchecked(
+            DataType::Utf8,
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+fn format_timestamp_tz<T>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    items: &[Item],
+) -> Result<Utf8Array<i32>>
+where
+    T: chrono::TimeZone,
+    T::Offset: Display,
+{
+    format_array(array, |x, values| {
+        let datetime = timestamp_to_naive_datetime_opt(x, time_unit)?;
+        let datetime = timezone.from_utc_datetime(&datetime);
+        Some(write!(
+            values,
+            "{}",
+            datetime.format_with_items(items.iter())
+        ))
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn format_timestamp_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    items: &[Item],
+) -> Result<Utf8Array<i32>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    format_timestamp_tz(array, time_unit, timezone, items)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn format_timestamp_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: &[Item],
+) -> Result<Utf8Array<i32>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Formats the values of a temporal `array` into strings with the
+/// [chrono format string](chrono::format::strftime) `format`, as SQL's `to_char` does.
+///
+/// Timestamps with a timezone are formatted in their timezone, which is parsed once for the
+/// whole array. The feature `"chrono-tz"` enables IANA and zoneinfo timezones.
+/// # Errors
+/// This function errors iff `format` is not a valid format or uses fields that the type does
+/// not have (e.g. `%Y` for times), the type of `array` is not supported (see
+/// [`can_strftime`]), its timezone can not be parsed, or a value is out of range.
+/// # Examples
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::temporal::strftime;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// let array = Int64Array::from([Some(0), None]).to(data_type);
+///
+/// let result = strftime(&array, "%d/%m/%Y %H:%M %:z").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("01/01/1970 01:00 +01:00"), None]));
+/// ```
+pub fn strftime(array: &dyn Array, format: &str) -> Result<Utf8Array<i32>> {
+    let items = compile_format(format)?;
+    let items = items.as_slice();
+
+    macro_rules! format_with {
+        ($type:ty, $to_temporal:expr) => {{
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$type>>()
+                .unwrap();
+            format_array(array, |x, values| {
+                let temporal = $to_temporal(x)?;
+                Some(write!(
+                    values,
+                    "{}",
+                    temporal.format_with_items(items.iter())
+                ))
+            })
+        }};
+    }
+
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => format_with!(i32, date32_to_date_opt),
+        DataType::Date64 => format_with!(i64, |x: i64| timestamp_ms_to_datetime_opt(x)),
+        DataType::Time32(TimeUnit::Second) => format_with!(i32, |x: i32| {
+            NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(x).ok()?, 0)
+        }),
+        DataType::Time32(TimeUnit::Millisecond) => format_with!(i32, |x: i32| {
+            let x = u32::try_from(x).ok()?;
+            NaiveTime::from_num_seconds_from_midnight_opt(x / 1_000, x % 1_000 * 1_000_000)
+        }),
+        DataType::Time64(TimeUnit::Microsecond) => format_with!(i64, time64us_to_time_opt),
+        DataType::Time64(TimeUnit::Nanosecond) => format_with!(i64, time64ns_to_time_opt),
+        DataType::Timestamp(time_unit, None) => {
+            let time_unit = *time_unit;
+            format_with!(i64, |x: i64| timestamp_to_naive_datetime_opt(x, time_unit))
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                format_timestamp_tz(array, *time_unit, timezone, items)
+            } else {
+                format_timestamp_chrono_tz(array, *time_unit, timezone_str, items)
+            }
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"strftime\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if arrays of type `datatype` can be formatted by [`strftime`].
+pub fn can_strftime(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+            | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+            | DataType::Timestamp(_, _)
+    )
+}
+
+/// The granularity to which [`date_trunc`] truncates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// The first instant of the year
+    Year,
+    /// The first instant of the quarter
+    Quarter,
+    /// The first instant of the month
+    Month,
+    /// The first instant of the ISO week, that starts on Monday
+    Week,
+    /// Midnight
+    Day,
+    /// The first instant of the hour
+    Hour,
+    /// The first instant of the minute
+    Minute,
+    /// The first instant of the second
+    Second,
+}
+
+impl Granularity {
+    /// The duration of the granularity in seconds, if it is fixed.
+    fn seconds(&self) -> Option<i64> {
+        match self {
+            Granularity::Day => Some(SECONDS_IN_DAY),
+            Granularity::Hour => Some(3_600),
+            Granularity::Minute => Some(60),
+            Granularity::Second => Some(1),
+            _ => None,
+        }
+    }
+}
+
+fn truncate_date(date: NaiveDate, granularity: Granularity) -> Option<NaiveDate> {
+    match granularity {
+        Granularity::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+        Granularity::Quarter => {
+            NaiveDate::from_ymd_opt(date.year(), (date.month0() / 3) * 3 + 1, 1)
+        }
+        Granularity::Month => date.with_day(1),
+        Granularity::Week => date.checked_sub_days(chrono::Days::new(
+            date.weekday().num_days_from_monday() as u64,
+        )),
+        _ => Some(date),
+    }
+}
+
+fn truncate_datetime(datetime: NaiveDateTime, granularity: Granularity) -> Option<NaiveDateTime> {
+    let time = datetime.time();
+    let time = match granularity {
+        Granularity::Hour => NaiveTime::from_hms_opt(time.hour(), 0, 0)?,
+        Granularity::Minute => NaiveTime::from_hms_opt(time.hour(), time.minute(), 0)?,
+        Granularity::Second => NaiveTime::from_hms_opt(time.hour(), time.minute(), time.second())?,
+        _ => NaiveTime::MIN,
+    };
+    Some(truncate_date(datetime.date(), granularity)?.and_time(time))
+}
+
+/// Truncates timestamps of `time_unit` without timezone, with integer arithmetic for
+/// granularities of a fixed duration.
+fn truncate_naive_timestamps(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    granularity: Granularity,
+) -> PrimitiveArray<i64> {
+    let data_type = array.data_type().clone();
+    if let Some(seconds) = granularity.seconds() {
+        let size = seconds
+            * match time_unit {
+                TimeUnit::Second => 1,
+                TimeUnit::Millisecond => MILLISECONDS,
+                TimeUnit::Microsecond => MICROSECONDS,
+                TimeUnit::Nanosecond => NANOSECONDS,
+            };
+        return unary(array, |x| x - x.rem_euclid(size), data_type);
+    }
+    unary_checked(
+        array,
+        |x| {
+            let datetime = timestamp_to_naive_datetime_opt(x, time_unit)?;
+            datetime_to_timestamp(truncate_datetime(datetime, granularity)?, time_unit)
+        },
+        data_type,
+    )
+}
+
+fn truncate_timestamps_tz<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    granularity: Granularity,
+) -> PrimitiveArray<i64> {
+    unary_checked(
+        array,
+        |x| {
+            let datetime = timestamp_to_naive_datetime_opt(x, time_unit)?;
+            let offset = timezone.offset_from_utc_datetime(&datetime).fix();
+            let local = truncate_datetime(datetime + offset, granularity)?;
+            let datetime = match timezone.from_local_datetime(&local).earliest() {
+                Some(datetime) => datetime.naive_utc(),
+                None => {
+                    // a local time skipped by a transition is truncated to the transition,
+                    // the skipped local time with the offset from before it
+                    let before = local - offset - chrono::Duration::days(1);
+                    local - timezone.offset_from_utc_datetime(&before).fix()
+                }
+            };
+            datetime_to_timestamp(datetime, time_unit)
+        },
+        array.data_type().clone(),
+    )
+}
+
+#[cfg(feature = "chrono-tz")]
+fn truncate_timestamps_chrono_tz(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    granularity: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(truncate_timestamps_tz(
+        array,
+        time_unit,
+        timezone,
+        granularity,
+    ))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn truncate_timestamps_chrono_tz(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+/// Truncates the values of a `Timestamp`, `Date32` or `Date64` array to `granularity`, as SQL's
+/// `date_trunc` does. The result has the type of `array`.
+///
+/// Timestamps with a timezone are truncated in their timezone, so that e.g. days start at
+/// local midnight. The feature `"chrono-tz"` enables IANA and zoneinfo timezones. Values that
+/// are out of the range of dates are null.
+/// # Errors
+/// This function errors iff the type of `array` is not supported (see [`can_date_trunc`]), its
+/// timezone can not be parsed, or it is a date and `granularity` is finer than a day.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::temporal::{date_trunc, Granularity};
+/// use arrow2::datatypes::DataType;
+///
+/// // 1970-02-15 and 1970-05-31
+/// let array = Int32Array::from([Some(45), None, Some(150)]).to(DataType::Date32);
+///
+/// let result = date_trunc(&array, Granularity::Quarter).unwrap();
+/// // 1970-01-01 and 1970-04-01
+/// let expected = Int32Array::from([Some(0), None, Some(90)]).to(DataType::Date32);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn date_trunc(array: &dyn Array, granularity: Granularity) -> Result<Box<dyn Array>> {
+    if let (
+        DataType::Date32 | DataType::Date64,
+        Granularity::Hour | Granularity::Minute | Granularity::Second,
+    ) = (array.data_type().to_logical_type(), granularity)
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "\"date_trunc\" of {:?} does not support the granularity {:?}",
+            array.data_type(),
+            granularity
+        )));
+    }
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let result = unary_checked(
+                array,
+                |x| {
+                    let date = truncate_date(date32_to_date_opt(x)?, granularity)?;
+                    Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+                },
+                array.data_type().clone(),
+            );
+            Ok(result.boxed())
+        }
+        DataType::Date64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            Ok(truncate_naive_timestamps(array, TimeUnit::Millisecond, granularity).boxed())
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(truncate_naive_timestamps(array, *time_unit, granularity).boxed())
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                Ok(truncate_timestamps_tz(array, *time_unit, timezone, granularity).boxed())
+            } else {
+                truncate_timestamps_chrono_tz(array, *time_unit, timezone_str, granularity)
+                    .map(|array| array.boxed())
+            }
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"date_trunc\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform [`date_trunc`].
+pub fn can_date_trunc(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
+    )
+}
@@ -0,0 +1,211 @@
+//! Contains the operator [`unique`], that returns the distinct values of an [`Array`] and,
+//! optionally, the inverse indices that map every slot to its distinct value.
+//!
+//! The inverse indices are the keys of the array re-encoded as a dictionary:
+//! ```
+//! use arrow2::array::{Array, DictionaryArray, Utf8Array};
+//! use arrow2::compute::unique::unique;
+//! use arrow2::datatypes::{DataType, IntegerType};
+//!
+//! let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+//! let (values, inverse) = unique(&array, true).unwrap();
+//! let keys = inverse.unwrap();
+//! assert_eq!(keys.values().as_slice(), &[0, 1, 2, 0]);
+//!
+//! let data_type = DataType::Dictionary(IntegerType::UInt32, Box::new(DataType::Utf8), false);
+//! let dictionary = DictionaryArray::try_new(data_type, keys, values).unwrap();
+//! assert_eq!(dictionary.len(), 4);
+//! ```
+use ahash::RandomState;
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+use super::take::take;
+
+/// Returns the row of the first occurrence of every distinct value, in order of first
+/// appearance, and (if `return_inverse`) the index of the distinct value of every row.
+///
+/// Values are compared by the bytes returned by `value`, and all null rows are equal.
+fn unique_rows<B, F>(
+    length: usize,
+    validity: Option<&Bitmap>,
+    value: F,
+    return_inverse: bool,
+) -> Result<(Vec<u64>, Option<Vec<u32>>)>
+where
+    B: AsRef<[u8]>,
+    F: Fn(usize) -> B,
+{
+    let state = RandomState::new();
+    // the distinct values, represented by their index in `first_rows`
+    let mut distinct = HashMap::<u32, (), RandomState>::with_hasher(state.clone());
+    let mut first_rows = Vec::<u64>::new();
+    let mut null = None;
+    let mut inverse = return_inverse.then(|| Vec::<u32>::with_capacity(length));
+
+    for row in 0..length {
+        let new = first_rows.len() as u32;
+        if new == u32::MAX {
+            return Err(Error::InvalidArgumentError(
+                "`unique` supports at most u32::MAX - 1 distinct values".to_string(),
+            ));
+        }
+        let index = if validity
+            .map(|validity| validity.get_bit(row))
+            .unwrap_or(true)
+        {
+            let bytes = value(row);
+            let bytes = bytes.as_ref();
+            let hash = state.hash_one(bytes);
+            let entry = distinct.raw_entry_mut().from_hash(hash, |index| {
+                value(first_rows[*index as usize] as usize).as_ref() == bytes
+            });
+            match entry {
+                RawEntryMut::Occupied(entry) => *entry.key(),
+                RawEntryMut::Vacant(entry) => {
+                    entry.insert_with_hasher(hash, new, (), |index| {
+                        state.hash_one(value(first_rows[*index as usize] as usize).as_ref())
+                    });
+                    first_rows.push(row as u64);
+                    new
+                }
+            }
+        } else {
+            *null.get_or_insert_with(|| {
+                first_rows.push(row as u64);
+                new
+            })
+        };
+        if let Some(inverse) = inverse.as_mut() {
+            inverse.push(index)
+        }
+    }
+    Ok((first_rows, inverse))
+}
+
+fn unique_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    return_inverse: bool,
+) -> Result<(Box<dyn Array>, Option<PrimitiveArray<u32>>)> {
+    // keys pointing to equal values are equal: map every key to its distinct value
+    let (values, values_inverse) = unique(array.values().as_ref(), true)?;
+    let values_inverse = values_inverse.unwrap();
+    let canonical = array
+        .keys_values_iter()
+        .map(|key| values_inverse.value(key))
+        .collect::<Vec<_>>();
+    let keys = array.keys();
+
+    let (first_rows, inverse) = unique_rows(
+        canonical.len(),
+        keys.validity(),
+        |row| canonical[row].to_le_bytes(),
+        return_inverse,
+    )?;
+    let keys = first_rows
+        .iter()
+        .map(|row| {
+            keys.get(*row as usize).map(|_| {
+                // the distinct value of a key is never larger than the key
+                K::try_from(canonical[*row as usize] as usize).unwrap_or_else(|_| unreachable!())
+            })
+        })
+        .collect::<PrimitiveArray<K>>();
+    let array = DictionaryArray::try_new(array.data_type().clone(), keys, values)?;
+    Ok((array.boxed(), inverse.map(finish_inverse)))
+}
+
+fn finish_inverse(inverse: Vec<u32>) -> PrimitiveArray<u32> {
+    PrimitiveArray::new(DataType::UInt32, inverse.into(), None)
+}
+
+/// Returns the distinct values of `array`, in order of first appearance, and, if
+/// `return_inverse` is `true`, the index in the distinct values of every slot of `array`.
+///
+/// Values are compared by their bytes, so that floats are equal iff their bits are equal
+/// (e.g. `NaN`s are equal to each other and `-0.0` differs from `0.0`). Null slots are equal
+/// to each other and appear once in the distinct values. For dictionaries, keys pointing to
+/// equal values are equal; the distinct keys index into the distinct values of the dictionary.
+///
+/// The inverse indices are computed in the same pass as the distinct values, and never null.
+/// # Errors
+/// This function errors iff the type is not supported (see [`can_unique`]), or there are
+/// `u32::MAX` distinct values or more.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::unique::unique;
+///
+/// let array = Int32Array::from([Some(3), None, Some(3), Some(1), None]);
+/// let (values, inverse) = unique(&array, false).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from([Some(3), None, Some(1)]) as &dyn Array);
+/// assert!(inverse.is_none());
+/// ```
+pub fn unique(
+    array: &dyn Array,
+    return_inverse: bool,
+) -> Result<(Box<dyn Array>, Option<PrimitiveArray<u32>>)> {
+    macro_rules! unique {
+        ($array:ty, |$a:ident, $row:ident| $value:expr) => {{
+            let $a = array.as_any().downcast_ref::<$array>().unwrap();
+            unique_rows($a.len(), $a.validity(), |$row| $value, return_inverse)?
+        }};
+    }
+    use PhysicalType::*;
+    let (first_rows, inverse) = match array.data_type().to_physical_type() {
+        Boolean => unique!(BooleanArray, |a, row| [a.value(row) as u8]),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            unique!(PrimitiveArray<$T>, |a, row| a.values()[row].to_le_bytes())
+        }),
+        Binary => unique!(BinaryArray<i32>, |a, row| a.value(row)),
+        LargeBinary => unique!(BinaryArray<i64>, |a, row| a.value(row)),
+        Utf8 => unique!(Utf8Array<i32>, |a, row| a.value(row).as_bytes()),
+        LargeUtf8 => unique!(Utf8Array<i64>, |a, row| a.value(row).as_bytes()),
+        Dictionary(key_type) => {
+            return match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                unique_dictionary(array, return_inverse)
+            })
+        }
+        t => {
+            return Err(Error::NotYetImplemented(format!(
+                "`unique` is not implemented for type {t:?}"
+            )))
+        }
+    };
+    let indices = PrimitiveArray::new(DataType::UInt64, first_rows.into(), None);
+    Ok((take(array, &indices)?, inverse.map(finish_inverse)))
+}
+
+/// Returns whether [`unique`] supports arrays of `data_type`.
+pub fn can_unique(data_type: &DataType) -> bool {
+    match data_type.to_physical_type() {
+        PhysicalType::Dictionary(_) => {
+            if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+                can_unique(values.as_ref())
+            } else {
+                false
+            }
+        }
+        physical_type => matches!(
+            physical_type,
+            PhysicalType::Boolean
+                | PhysicalType::Primitive(_)
+                | PhysicalType::Binary
+                | PhysicalType::LargeBinary
+                | PhysicalType::Utf8
+                | PhysicalType::LargeUtf8
+        ),
+    }
+}
@@ -1,10 +1,12 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Utf8Array},
+    array::{Array, BinaryArray, ListArray, MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array},
+    bitmap::Bitmap,
+    compute::utils::combine_validities,
     datatypes::DataType,
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, Offsets, OffsetsBuffer},
 };
 
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
@@ -15,31 +17,110 @@ pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>)
     new.with_validity(array.validity().cloned())
 }
 
-/// Returns a new `Array` where each of each of the elements is upper-cased.
-/// this function errors when the passed array is not a \[Large\]String array.
-pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
+/// Writes the output of `op` for every non-null item of `iter` into a single values buffer,
+/// allocated up front with `capacity` bytes, so that no value is allocated on its own.
+fn utf8_write<O, T, I, F>(
+    data_type: DataType,
+    iter: I,
+    capacity: usize,
+    validity: Option<Bitmap>,
+    mut op: F,
+) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    I: ExactSizeIterator<Item = Option<T>>,
+    F: FnMut(T, &mut String),
+{
+    let mut values = String::with_capacity(capacity);
+    let mut offsets = Offsets::<O>::with_capacity(iter.len());
+
+    for item in iter {
+        let length = values.len();
+        if let Some(item) = item {
+            op(item, &mut values);
+        }
+        offsets.try_push_usize(values.len() - length)?;
+    }
+
+    // Safety: `values` is a `String`, and every offset ends a value appended to it
+    let mutable =
+        unsafe { MutableUtf8Array::new_unchecked(data_type, offsets, values.into_bytes(), None) };
+    let new: Utf8Array<O> = mutable.into();
+    Ok(new.with_validity(validity))
+}
+
+/// Returns the number of bytes of the values of `array`.
+#[inline]
+fn values_len<O: Offset>(array: &Utf8Array<O>) -> usize {
+    array.offsets().range().to_usize()
+}
+
+/// Maps every non-null value of `array` by appending its result to a single values buffer with
+/// `op`. The buffer is allocated up front with the size of the values of `array` plus
+/// `extra_per_row` bytes per row, so that no value is allocated on its own.
+fn utf8_map<O, F>(array: &Utf8Array<O>, extra_per_row: usize, op: F) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    F: FnMut(&str, &mut String),
+{
+    utf8_write(
+        array.data_type().clone(),
+        array.iter(),
+        values_len(array) + extra_per_row * array.len(),
+        array.validity().cloned(),
+        op,
+    )
+}
+
+/// Dynamically-typed version of [`utf8_map`], that errors when `array` is not a
+/// \[Large\]String array.
+fn utf8_map_dyn<F>(
+    array: &dyn Array,
+    name: &str,
+    extra_per_row: usize,
+    op: F,
+) -> Result<Box<dyn Array>>
+where
+    F: FnMut(&str, &mut String),
+{
     match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
+        DataType::LargeUtf8 => Ok(Box::new(utf8_map(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
-        ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
+            extra_per_row,
+            op,
+        )?)),
+        DataType::Utf8 => Ok(Box::new(utf8_map(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
                 .expect("A string is expected"),
-        ))),
+            extra_per_row,
+            op,
+        )?)),
         _ => Err(Error::InvalidArgumentError(format!(
-            "upper does not support type {:?}",
+            "{name} does not support type {:?}",
             array.data_type()
         ))),
     }
 }
 
+/// Returns a new `Array` where each of each of the elements is upper-cased.
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "upper", 0, |value, values| {
+        if value.is_ascii() {
+            let start = values.len();
+            values.push_str(value);
+            values[start..].make_ascii_uppercase();
+        } else {
+            values.extend(value.chars().flat_map(char::to_uppercase))
+        }
+    })
+}
+
 /// Checks if an array of type `datatype` can perform upper operation
 ///
 /// # Examples
@@ -60,41 +141,698 @@ pub fn can_upper(data_type: &DataType) -> bool {
 /// Returns a new `Array` where each of each of the elements is lower-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "lower", 0, |value, values| {
+        if value.is_ascii() {
+            let start = values.len();
+            values.push_str(value);
+            values[start..].make_ascii_lowercase();
+        } else if value.contains('Σ') {
+            // the lowercase of a sigma depends on whether it ends a word
+            values.push_str(&value.to_lowercase())
+        } else {
+            values.extend(value.chars().flat_map(char::to_lowercase))
+        }
+    })
+}
+
+/// Checks if an array of type `datatype` can perform lower operation
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::utf8::can_lower;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_lower(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_lower(&data_type), false);
+/// ```
+pub fn can_lower(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+    Both,
+}
+
+#[inline]
+fn trim_side<'a>(value: &'a str, characters: Option<&str>, side: Side) -> &'a str {
+    match (characters, side) {
+        (None, Side::Left) => value.trim_start(),
+        (None, Side::Right) => value.trim_end(),
+        (None, Side::Both) => value.trim(),
+        (Some(characters), Side::Left) => value.trim_start_matches(|c| characters.contains(c)),
+        (Some(characters), Side::Right) => value.trim_end_matches(|c| characters.contains(c)),
+        (Some(characters), Side::Both) => value.trim_matches(|c| characters.contains(c)),
+    }
+}
+
+/// Returns a new `Array` where the leading and trailing `characters` (whitespace if `None`)
+/// of each of the elements are removed.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::trim;
+///
+/// let array = Utf8Array::<i32>::from([Some("  a b "), None, Some("xxaxy")]);
+///
+/// let result = trim(&array, None).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("a b"), None, Some("xxaxy")]), result.as_ref());
+///
+/// let result = trim(&array, Some("xy")).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("  a b "), None, Some("a")]), result.as_ref());
+/// ```
+pub fn trim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "trim", 0, |value, values| {
+        values.push_str(trim_side(value, characters, Side::Both))
+    })
+}
+
+/// Returns a new `Array` where the leading `characters` (whitespace if `None`) of each of the
+/// elements are removed, see [`trim`].
+pub fn ltrim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "ltrim", 0, |value, values| {
+        values.push_str(trim_side(value, characters, Side::Left))
+    })
+}
+
+/// Returns a new `Array` where the trailing `characters` (whitespace if `None`) of each of the
+/// elements are removed, see [`trim`].
+pub fn rtrim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "rtrim", 0, |value, values| {
+        values.push_str(trim_side(value, characters, Side::Right))
+    })
+}
+
+/// Checks if an array of type `datatype` can perform [`trim`], [`ltrim`] and [`rtrim`].
+pub fn can_trim(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+#[inline]
+fn pad(value: &str, length: usize, fill: &str, side: Side, values: &mut String) {
+    let (chars, end) = if value.is_ascii() {
+        (value.len(), length.min(value.len()))
+    } else {
+        let mut chars = value.char_indices();
+        let end = chars.nth(length).map_or(value.len(), |(i, _)| i);
+        (value.chars().count(), end)
+    };
+    if chars >= length || fill.is_empty() {
+        values.push_str(&value[..end]);
+        return;
+    }
+    let padding = fill.chars().cycle().take(length - chars);
+    match side {
+        Side::Left => {
+            values.extend(padding);
+            values.push_str(value);
+        }
+        _ => {
+            values.push_str(value);
+            values.extend(padding);
+        }
+    }
+}
+
+/// Returns a new `Array` where each of the elements is left-padded with `fill`, repeated as
+/// needed, to `length` characters. Elements longer than `length` are truncated to their first
+/// `length` characters, and elements are not padded if `fill` is empty.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("1"), None, Some("12345")]);
+///
+/// let result = lpad(&array, 3, "0").unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("001"), None, Some("123")]), result.as_ref());
+/// ```
+pub fn lpad(array: &dyn Array, length: usize, fill: &str) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "lpad", length, |value, values| {
+        pad(value, length, fill, Side::Left, values)
+    })
+}
+
+/// Returns a new `Array` where each of the elements is right-padded with `fill`, repeated as
+/// needed, to `length` characters, see [`lpad`].
+pub fn rpad(array: &dyn Array, length: usize, fill: &str) -> Result<Box<dyn Array>> {
+    utf8_map_dyn(array, "rpad", length, |value, values| {
+        pad(value, length, fill, Side::Right, values)
+    })
+}
+
+/// Checks if an array of type `datatype` can perform [`lpad`] and [`rpad`].
+pub fn can_pad(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+fn check_delimiter(delimiter: &str) -> Result<()> {
+    if delimiter.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "The delimiter of a split must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Splits every value of `array` into at most `n` parts (unbounded if `None`), written into a
+/// single values buffer, since the parts of a value are never larger than the value.
+fn split_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    n: Option<usize>,
+) -> Result<ListArray<O>> {
+    let start = array.offsets().first().to_usize();
+    let end = array.offsets().last().to_usize();
+    let mut values = MutableUtf8ValuesArray::<O>::with_capacities(array.len(), end - start);
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+
+    for value in array.iter() {
+        let length = values.len();
+        match (value, n) {
+            (Some(value), None) => value.split(delimiter).for_each(|part| values.push(part)),
+            (Some(value), Some(n)) => value
+                .splitn(n, delimiter)
+                .for_each(|part| values.push(part)),
+            (None, _) => {}
+        }
+        offsets.try_push_usize(values.len() - length)?;
+    }
+
+    let values: Utf8Array<O> = values.into();
+    Ok(ListArray::new(
+        ListArray::<O>::default_datatype(array.data_type().clone()),
+        offsets.into(),
+        values.boxed(),
+        array.validity().cloned(),
+    ))
+}
+
+fn split_dyn(array: &dyn Array, delimiter: &str, n: Option<usize>) -> Result<Box<dyn Array>> {
+    check_delimiter(delimiter)?;
     match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
+        DataType::LargeUtf8 => Ok(Box::new(split_utf8(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
-        ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
+            delimiter,
+            n,
+        )?)),
+        DataType::Utf8 => Ok(Box::new(split_utf8(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
                 .expect("A string is expected"),
-        ))),
+            delimiter,
+            n,
+        )?)),
         _ => Err(Error::InvalidArgumentError(format!(
-            "lower does not support type {:?}",
+            "split does not support type {:?}",
             array.data_type()
         ))),
     }
 }
 
-/// Checks if an array of type `datatype` can perform lower operation
+/// Returns a new `Array` of lists with the parts of each of the elements separated by
+/// `delimiter`. A `Utf8` array is split into a `List` of `Utf8`, and a `LargeUtf8` array into
+/// a `LargeList` of `LargeUtf8`. The lists of null elements are null.
+/// this function errors when the passed array is not a \[Large\]String array or `delimiter`
+/// is empty.
+/// # Examples
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from([Some("a,b,,c"), None, Some("")]);
+/// let result = split(&array, ",").unwrap();
 ///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected
+///     .try_extend([Some(vec![Some("a"), Some("b"), Some(""), Some("c")]), None, Some(vec![Some("")])])
+///     .unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn split(array: &dyn Array, delimiter: &str) -> Result<Box<dyn Array>> {
+    split_dyn(array, delimiter, None)
+}
+
+/// Returns a new `Array` of lists with at most `n` parts of each of the elements separated by
+/// `delimiter`, where the last part contains the remainder of the element, see [`split`].
+/// this function errors when the passed array is not a \[Large\]String array, `delimiter` is
+/// empty or `n` is zero.
+pub fn split_n(array: &dyn Array, delimiter: &str, n: usize) -> Result<Box<dyn Array>> {
+    if n == 0 {
+        return Err(Error::InvalidArgumentError(
+            "A value can not be split in zero parts".to_string(),
+        ));
+    }
+    split_dyn(array, delimiter, Some(n))
+}
+
+/// Returns a new `Array` with the `n`th part of each of the elements separated by `delimiter`,
+/// starting at `1`, or the `-n`th part starting from the end if `n` is negative. Elements with
+/// less than `n` parts are mapped to empty strings.
+/// this function errors when the passed array is not a \[Large\]String array, `delimiter` is
+/// empty or `n` is zero.
 /// # Examples
 /// ```
-/// use arrow2::compute::utf8::can_lower;
-/// use arrow2::datatypes::{DataType};
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::split_part;
 ///
-/// let data_type = DataType::Utf8;
-/// assert_eq!(can_lower(&data_type), true);
+/// let array = Utf8Array::<i32>::from([Some("a.b.c"), None, Some("a")]);
 ///
-/// let data_type = DataType::Null;
-/// assert_eq!(can_lower(&data_type), false);
+/// let result = split_part(&array, ".", 2).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("b"), None, Some("")]), result.as_ref());
+///
+/// let result = split_part(&array, ".", -1).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("c"), None, Some("a")]), result.as_ref());
 /// ```
-pub fn can_lower(data_type: &DataType) -> bool {
+pub fn split_part(array: &dyn Array, delimiter: &str, n: i64) -> Result<Box<dyn Array>> {
+    check_delimiter(delimiter)?;
+    if n == 0 {
+        return Err(Error::InvalidArgumentError(
+            "The parts of split_part start at 1".to_string(),
+        ));
+    }
+    let index = n.unsigned_abs() as usize - 1;
+    utf8_map_dyn(array, "split_part", 0, |value, values| {
+        let part = if n > 0 {
+            value.split(delimiter).nth(index)
+        } else {
+            value.rsplit(delimiter).nth(index)
+        };
+        values.push_str(part.unwrap_or_default())
+    })
+}
+
+/// Checks if an array of type `datatype` can perform [`split`], [`split_n`] and [`split_part`].
+pub fn can_split(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+/// How [`concat_elementwise`] and [`concat_ws`] handle null elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ConcatNulls {
+    /// A null element makes the result null, as SQL's `||` operator does.
+    #[default]
+    Propagate,
+    /// Null elements are skipped, so that they are concatenated as empty values, and
+    /// [`concat_ws`] writes no separator for them, as SQL's `CONCAT` and `CONCAT_WS` do.
+    Skip,
+}
+
+/// The offsets, values and validity of a \[Large\]String or \[Large\]Binary array.
+struct BytesColumn<'a, O: Offset> {
+    offsets: &'a OffsetsBuffer<O>,
+    values: &'a [u8],
+    validity: Option<&'a Bitmap>,
+}
+
+impl<'a, O: Offset> BytesColumn<'a, O> {
+    #[inline]
+    fn value(&self, index: usize) -> &'a [u8] {
+        let (start, end) = self.offsets.start_end(index);
+        &self.values[start..end]
+    }
+
+    #[inline]
+    fn is_valid(&self, index: usize) -> bool {
+        match self.validity {
+            Some(validity) => validity.get_bit(index),
+            None => true,
+        }
+    }
+}
+
+/// Concatenates the elements of every row of `columns` into a single values buffer, whose
+/// size is computed up front from the offsets of `columns`.
+fn concat_bytes<O: Offset>(
+    columns: &[BytesColumn<O>],
+    length: usize,
+    separator: &[u8],
+    nulls: ConcatNulls,
+) -> Result<(OffsetsBuffer<O>, Vec<u8>, Option<Bitmap>)> {
+    let capacity = columns
+        .iter()
+        .map(|column| column.offsets.range().to_usize())
+        .sum::<usize>()
+        + separator.len() * columns.len().saturating_sub(1) * length;
+    let mut values = Vec::<u8>::with_capacity(capacity);
+    let mut offsets = Offsets::<O>::with_capacity(length);
+
+    let validity = match nulls {
+        ConcatNulls::Propagate => columns.iter().fold(None, |acc, column| {
+            combine_validities(acc.as_ref(), column.validity)
+        }),
+        ConcatNulls::Skip => None,
+    };
+
+    for index in 0..length {
+        let start = values.len();
+        let is_valid = match &validity {
+            Some(validity) => validity.get_bit(index),
+            None => true,
+        };
+        if is_valid {
+            let mut is_first = true;
+            for column in columns.iter().filter(|column| column.is_valid(index)) {
+                if !is_first {
+                    values.extend_from_slice(separator);
+                }
+                values.extend_from_slice(column.value(index));
+                is_first = false;
+            }
+        }
+        offsets.try_push_usize(values.len() - start)?;
+    }
+    Ok((offsets.into(), values, validity))
+}
+
+fn concat_dyn(
+    arrays: &[&dyn Array],
+    separator: &str,
+    nulls: ConcatNulls,
+) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("Concatenation requires at least one array".to_string())
+    })?;
+    let data_type = first.data_type();
+    let length = first.len();
+    if arrays
+        .iter()
+        .any(|array| array.data_type() != data_type || array.len() != length)
+    {
+        return Err(Error::InvalidArgumentError(
+            "Element-wise concatenation requires arrays of the same type and length".to_string(),
+        ));
+    }
+
+    macro_rules! columns {
+        ($type:ty) => {
+            arrays
+                .iter()
+                .map(|array| {
+                    let array = array.as_any().downcast_ref::<$type>().unwrap();
+                    BytesColumn {
+                        offsets: array.offsets(),
+                        values: array.values().as_slice(),
+                        validity: array.validity(),
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+    }
+    let separator = separator.as_bytes();
+    let data_type = data_type.clone();
+    match data_type {
+        DataType::Utf8 => {
+            let (offsets, values, validity) =
+                concat_bytes(&columns!(Utf8Array<i32>), length, separator, nulls)?;
+            // Safety: the values are concatenations of utf8 values and of a `&str`
+            Ok(
+                unsafe { Utf8Array::new_unchecked(data_type, offsets, values.into(), validity) }
+                    .boxed(),
+            )
+        }
+        DataType::LargeUtf8 => {
+            let (offsets, values, validity) =
+                concat_bytes(&columns!(Utf8Array<i64>), length, separator, nulls)?;
+            // Safety: the values are concatenations of utf8 values and of a `&str`
+            Ok(
+                unsafe { Utf8Array::new_unchecked(data_type, offsets, values.into(), validity) }
+                    .boxed(),
+            )
+        }
+        DataType::Binary => {
+            let (offsets, values, validity) =
+                concat_bytes(&columns!(BinaryArray<i32>), length, separator, nulls)?;
+            Ok(BinaryArray::new(data_type, offsets, values.into(), validity).boxed())
+        }
+        DataType::LargeBinary => {
+            let (offsets, values, validity) =
+                concat_bytes(&columns!(BinaryArray<i64>), length, separator, nulls)?;
+            Ok(BinaryArray::new(data_type, offsets, values.into(), validity).boxed())
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "concat_elementwise does not support type {data_type:?}"
+        ))),
+    }
+}
+
+/// Returns a new `Array` where each element is the concatenation of the elements of the same
+/// row of `arrays`, of type \[Large\]String or \[Large\]Binary. Null elements are handled
+/// according to `nulls`.
+/// this function errors when `arrays` is empty or its arrays do not have the same length and
+/// one of the supported types.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{concat_elementwise, ConcatNulls};
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from([Some("c"), None, None]);
+///
+/// let result = concat_elementwise(&[&a, &b], ConcatNulls::Propagate).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("ac"), None, None]), result.as_ref());
+///
+/// let result = concat_elementwise(&[&a, &b], ConcatNulls::Skip).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("ac"), Some("b"), Some("")]), result.as_ref());
+/// ```
+pub fn concat_elementwise(arrays: &[&dyn Array], nulls: ConcatNulls) -> Result<Box<dyn Array>> {
+    concat_dyn(arrays, "", nulls)
+}
+
+/// Returns a new `Array` where each element is the concatenation of the elements of the same
+/// row of `arrays` separated by `separator`, see [`concat_elementwise`].
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{concat_ws, ConcatNulls};
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), None]);
+/// let b = Utf8Array::<i32>::from([Some("b"), Some("c")]);
+///
+/// let result = concat_ws(&[&a, &b], ", ", ConcatNulls::Skip).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("a, b"), Some("c")]), result.as_ref());
+/// ```
+pub fn concat_ws(
+    arrays: &[&dyn Array],
+    separator: &str,
+    nulls: ConcatNulls,
+) -> Result<Box<dyn Array>> {
+    concat_dyn(arrays, separator, nulls)
+}
+
+/// Checks if arrays of type `datatype` can perform [`concat_elementwise`] and [`concat_ws`].
+pub fn can_concat_elementwise(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary
+    )
+}
+
+/// Appends `value` with its first `n` non-overlapping occurrences of `from` replaced by `to`.
+#[inline]
+fn replace_into(value: &str, from: &str, to: &str, n: usize, values: &mut String) {
+    if from.is_empty() {
+        values.push_str(value);
+        return;
+    }
+    let mut last = 0;
+    for (start, _) in value.match_indices(from).take(n) {
+        values.push_str(&value[last..start]);
+        values.push_str(to);
+        last = start + from.len();
+    }
+    values.push_str(&value[last..]);
+}
+
+/// Returns the number of bytes by which replacing the first `n` occurrences of `from` by `to`
+/// grows `value`.
+#[inline]
+fn replace_growth(value: &str, from: &str, to: &str, n: usize) -> usize {
+    if to.len() <= from.len() || from.is_empty() {
+        0
+    } else {
+        value.matches(from).take(n).count() * (to.len() - from.len())
+    }
+}
+
+fn replace_scalar<O: Offset>(
+    array: &Utf8Array<O>,
+    from: &str,
+    to: &str,
+    n: usize,
+) -> Result<Utf8Array<O>> {
+    // the values only grow when `to` is longer than `from`
+    let growth = if to.len() > from.len() {
+        array
+            .non_null_values_iter()
+            .map(|(_, value)| replace_growth(value, from, to, n))
+            .sum()
+    } else {
+        0
+    };
+    utf8_write(
+        array.data_type().clone(),
+        array.iter(),
+        values_len(array) + growth,
+        array.validity().cloned(),
+        |value, values| replace_into(value, from, to, n, values),
+    )
+}
+
+fn replace_patterns<O: Offset>(
+    array: &Utf8Array<O>,
+    from: &Utf8Array<O>,
+    to: &str,
+    n: usize,
+) -> Result<Utf8Array<O>> {
+    if array.len() != from.len() {
+        return Err(Error::InvalidArgumentError(
+            "replace requires arrays of the same length".to_string(),
+        ));
+    }
+    let pairs = || {
+        array
+            .iter()
+            .zip(from.iter())
+            .map(|(value, from)| value.zip(from))
+    };
+    let growth = pairs()
+        .flatten()
+        .map(|(value, from)| replace_growth(value, from, to, n))
+        .sum::<usize>();
+    utf8_write(
+        array.data_type().clone(),
+        pairs(),
+        values_len(array) + growth,
+        combine_validities(array.validity(), from.validity()),
+        |(value, from), values| replace_into(value, from, to, n, values),
+    )
+}
+
+fn replace_dyn(array: &dyn Array, from: &str, to: &str, n: usize) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::LargeUtf8 => Ok(Box::new(replace_scalar(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+            from,
+            to,
+            n,
+        )?)),
+        DataType::Utf8 => Ok(Box::new(replace_scalar(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
+            from,
+            to,
+            n,
+        )?)),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "replace does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+fn replace_array_dyn(
+    array: &dyn Array,
+    from: &dyn Array,
+    to: &str,
+    n: usize,
+) -> Result<Box<dyn Array>> {
+    match (array.data_type(), from.data_type()) {
+        (DataType::LargeUtf8, DataType::LargeUtf8) => Ok(Box::new(replace_patterns(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+            from.as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .expect("A large string is expected"),
+            to,
+            n,
+        )?)),
+        (DataType::Utf8, DataType::Utf8) => Ok(Box::new(replace_patterns(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
+            from.as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .expect("A string is expected"),
+            to,
+            n,
+        )?)),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "replace does not support types {:?} and {:?}",
+            array.data_type(),
+            from.data_type()
+        ))),
+    }
+}
+
+/// Returns a new `Array` where all non-overlapping occurrences of `from` in each of the
+/// elements are replaced by `to`. Elements are left unchanged if `from` is empty.
+/// this function errors when the passed array is not a \[Large\]String array.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{replace, replace_n};
+///
+/// let array = Utf8Array::<i32>::from([Some("a-b-c"), None]);
+///
+/// let result = replace(&array, "-", "+").unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("a+b+c"), None]), result.as_ref());
+///
+/// let result = replace_n(&array, "-", "", 1).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from([Some("ab-c"), None]), result.as_ref());
+/// ```
+pub fn replace(array: &dyn Array, from: &str, to: &str) -> Result<Box<dyn Array>> {
+    replace_dyn(array, from, to, usize::MAX)
+}
+
+/// Returns a new `Array` where the first `n` non-overlapping occurrences of `from` in each of
+/// the elements are replaced by `to`, see [`replace`].
+pub fn replace_n(array: &dyn Array, from: &str, to: &str, n: usize) -> Result<Box<dyn Array>> {
+    replace_dyn(array, from, to, n)
+}
+
+/// Returns a new `Array` where all non-overlapping occurrences of the element of `from` in
+/// the element of the same row of `array` are replaced by `to`, see [`replace`]. An element is
+/// null iff it or its element of `from` is null.
+/// this function errors when the passed arrays do not have the same length and are not both
+/// String or both LargeString arrays.
+pub fn replace_array(array: &dyn Array, from: &dyn Array, to: &str) -> Result<Box<dyn Array>> {
+    replace_array_dyn(array, from, to, usize::MAX)
+}
+
+/// Returns a new `Array` where the first `n` non-overlapping occurrences of the element of
+/// `from` in the element of the same row of `array` are replaced by `to`, see
+/// [`replace_array`].
+pub fn replace_n_array(
+    array: &dyn Array,
+    from: &dyn Array,
+    to: &str,
+    n: usize,
+) -> Result<Box<dyn Array>> {
+    replace_array_dyn(array, from, to, n)
+}
+
+/// Checks if an array of type `datatype` can perform [`replace`] and [`replace_n`].
+pub fn can_replace(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
@@ -3,6 +3,12 @@ use crate::{
     bitmap::Bitmap,
     error::{Error, Result},
 };
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_arithmetics",
+    feature = "compute_cast"
+))]
+use crate::{array::PrimitiveArray, datatypes::DataType, types::f16};
 
 pub fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
     match (lhs, rhs) {
@@ -23,3 +29,140 @@ pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     }
     Ok(())
 }
+
+/// Casts f16 into f32, using the F16C instructions of the CPU when available.
+// Kernels of `f16` are computed in `f32`, so this is shared by them and re-exported by `cast`.
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_arithmetics",
+    feature = "compute_cast"
+))]
+pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    PrimitiveArray::new(
+        DataType::Float32,
+        f16::slice_to_f32(from.values()).into(),
+        from.validity().cloned(),
+    )
+}
+
+/// Casts f32 into f16, rounding to the nearest value, using the F16C instructions of the CPU
+/// when available. Values that overflow `f16` are cast to infinity.
+#[cfg(any(feature = "compute_arithmetics", feature = "compute_cast"))]
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    PrimitiveArray::new(
+        DataType::Float16,
+        f16::slice_from_f32(from.values()).into(),
+        from.validity().cloned(),
+    )
+}
+
+/// Returns an array of `data_type` with a single row, `scalar`.
+/// # Errors
+/// This function errors iff `scalar` is a union.
+#[cfg(any(
+    feature = "compute_comparison",
+    feature = "compute_list",
+    feature = "compute_window"
+))]
+pub(crate) fn scalar_to_array(
+    scalar: &dyn crate::scalar::Scalar,
+    data_type: &crate::datatypes::DataType,
+) -> Result<Box<dyn Array>> {
+    use crate::{
+        array::*,
+        datatypes::{DataType, PhysicalType},
+        offset::OffsetsBuffer,
+        scalar::*,
+    };
+
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type.clone(), 1));
+    }
+    let data_type = data_type.clone();
+    macro_rules! downcast {
+        ($scalar:ty) => {
+            scalar.as_any().downcast_ref::<$scalar>().unwrap()
+        };
+    }
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null => NullArray::new(data_type, 1).boxed(),
+        Boolean => {
+            let value = downcast!(BooleanScalar).value().unwrap();
+            BooleanArray::new(data_type, Bitmap::from([value]), None).boxed()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            PrimitiveArray::<$T>::from([*downcast!(PrimitiveScalar<$T>).value()])
+                .to(data_type)
+                .boxed()
+        }),
+        Utf8 => {
+            let value = downcast!(Utf8Scalar<i32>).value().unwrap();
+            let offsets = OffsetsBuffer::try_from(vec![0, value.len() as i32])?;
+            Utf8Array::<i32>::new(data_type, offsets, value.as_bytes().to_vec().into(), None)
+                .boxed()
+        }
+        LargeUtf8 => {
+            let value = downcast!(Utf8Scalar<i64>).value().unwrap();
+            let offsets = OffsetsBuffer::try_from(vec![0, value.len() as i64])?;
+            Utf8Array::<i64>::new(data_type, offsets, value.as_bytes().to_vec().into(), None)
+                .boxed()
+        }
+        Binary => {
+            let value = downcast!(BinaryScalar<i32>).value().unwrap();
+            let offsets = OffsetsBuffer::try_from(vec![0, value.len() as i32])?;
+            BinaryArray::<i32>::new(data_type, offsets, value.to_vec().into(), None).boxed()
+        }
+        LargeBinary => {
+            let value = downcast!(BinaryScalar<i64>).value().unwrap();
+            let offsets = OffsetsBuffer::try_from(vec![0, value.len() as i64])?;
+            BinaryArray::<i64>::new(data_type, offsets, value.to_vec().into(), None).boxed()
+        }
+        FixedSizeBinary => {
+            let value = downcast!(FixedSizeBinaryScalar).value().unwrap();
+            FixedSizeBinaryArray::new(data_type, value.to_vec().into(), None).boxed()
+        }
+        List => {
+            let values = downcast!(ListScalar<i32>).values();
+            let offsets = OffsetsBuffer::try_from(vec![0, values.len() as i32])?;
+            ListArray::<i32>::new(data_type, offsets, values.clone(), None).boxed()
+        }
+        LargeList => {
+            let values = downcast!(ListScalar<i64>).values();
+            let offsets = OffsetsBuffer::try_from(vec![0, values.len() as i64])?;
+            ListArray::<i64>::new(data_type, offsets, values.clone(), None).boxed()
+        }
+        FixedSizeList => {
+            let values = downcast!(FixedSizeListScalar).values().unwrap();
+            FixedSizeListArray::new(data_type, values.clone(), None).boxed()
+        }
+        Map => {
+            let values = downcast!(MapScalar).values();
+            let offsets = OffsetsBuffer::try_from(vec![0, values.len() as i32])?;
+            MapArray::new(data_type, offsets, values.clone(), None).boxed()
+        }
+        Struct => {
+            let values = downcast!(StructScalar)
+                .values()
+                .iter()
+                .zip(StructArray::get_fields(&data_type))
+                .map(|(value, field)| scalar_to_array(value.as_ref(), field.data_type()))
+                .collect::<Result<Vec<_>>>()?;
+            StructArray::new(data_type, values, None).boxed()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let value = downcast!(DictionaryScalar<$T>).value().unwrap();
+            let values = match data_type.to_logical_type() {
+                DataType::Dictionary(_, values, _) => scalar_to_array(value.as_ref(), values)?,
+                _ => unreachable!(),
+            };
+            let keys = PrimitiveArray::<$T>::from_slice([$T::default()]);
+            DictionaryArray::try_new(data_type, keys, values)?.boxed()
+        }),
+        Union => {
+            return Err(Error::NotYetImplemented(
+                "Arrays of a union scalar are not supported".to_string(),
+            ))
+        }
+    })
+}
@@ -15,51 +15,386 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and [`rolling`] aggregates.
+//!
+//! Rolling windows are slid in a single pass over the values: sums are updated by adding the
+//! rows entering the window and subtracting the rows leaving it, and minimums and maximums are
+//! tracked with a monotonic deque, so that the cost does not depend on the window size.
 
-use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use num_traits::{abs, AsPrimitive};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{growable::make_growable, *},
     error::{Error, Result},
+    scalar::*,
+    types::{NativeType, WrappingArithmetic},
 };
 
-/// Shifts array by defined number of items (to left or right)
+use super::utils::scalar_to_array;
+
+/// Shifts array by defined number of items (to left or right), i.e. `lag` (a positive
+/// `offset`) and `lead` (a negative `offset`).
 /// A positive value for `offset` shifts the array to the right
 /// a negative value shifts the array to the left.
+///
+/// The rows shifted in are `fill`, or null when `fill` is `None`. The result is built from a
+/// single bulk copy of the rows of `array` plus the fill region, for arrays of any type.
+/// # Errors
+/// This function errors iff the absolute `offset` is larger than the length of `array`, or
+/// `fill` is not of the logical type of `array`, or `fill` is a union.
 /// # Examples
 /// ```
 /// use arrow2::array::Int32Array;
 /// use arrow2::compute::window::shift;
+/// use arrow2::scalar::PrimitiveScalar;
 ///
 /// let array = Int32Array::from(&[Some(1), None, Some(3)]);
-/// let result = shift(&array, -1).unwrap();
+/// let result = shift(&array, -1, None).unwrap();
 /// let expected = Int32Array::from(&[None, Some(3), None]);
 /// assert_eq!(expected, result.as_ref());
+///
+/// let fill = PrimitiveScalar::from(Some(0i32));
+/// let result = shift(&array, 2, Some(&fill)).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(0), Some(1)]);
+/// assert_eq!(expected, result.as_ref());
 /// ```
-pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
+pub fn shift(array: &dyn Array, offset: i64, fill: Option<&dyn Scalar>) -> Result<Box<dyn Array>> {
     if abs(offset) as usize > array.len() {
         return Err(Error::InvalidArgumentError(format!(
             "Shift's absolute offset must be smaller or equal to the arrays length. Offset is {}, length is {}",
             abs(offset), array.len()
         )));
     }
+    if let Some(fill) = fill {
+        if fill.data_type().to_logical_type() != array.data_type().to_logical_type() {
+            return Err(Error::InvalidArgumentError(format!(
+                "Shift's fill must be of the type of the array. Fill is of type {:?}, the array is of type {:?}",
+                fill.data_type(), array.data_type()
+            )));
+        }
+    }
 
-    // Compute slice
-    let slice_offset = clamp(-offset, 0, array.len() as i64) as usize;
-    let length = array.len() - abs(offset) as usize;
-    let slice = array.sliced(slice_offset, length);
-
-    // Generate array with remaining `null` items
-    let nulls = abs(offset) as usize;
+    let fill = fill
+        .filter(|fill| fill.is_valid())
+        .map(|fill| scalar_to_array(fill, array.data_type()))
+        .transpose()?;
+    let arrays = match &fill {
+        Some(fill) => vec![array, fill.as_ref()],
+        None => vec![array],
+    };
+    let mut growable = make_growable(&arrays, fill.is_none(), array.len());
 
-    let null_array = new_null_array(array.data_type().clone(), nulls);
+    let filled = abs(offset) as usize;
+    let length = array.len() - filled;
+    let extend_fill = |growable: &mut dyn growable::Growable| match fill {
+        Some(_) => (0..filled).for_each(|_| growable.extend(1, 0, 1)),
+        None => growable.extend_validity(filled),
+    };
 
-    // Concatenate both arrays, add nulls after if shift > 0 else before
+    // the fill region is before the rows if shift > 0 else after
     if offset > 0 {
-        concatenate(&[null_array.as_ref(), slice.as_ref()])
+        extend_fill(growable.as_mut());
+        growable.extend(0, 0, length);
     } else {
-        concatenate(&[slice.as_ref(), null_array.as_ref()])
+        growable.extend(0, filled, length);
+        extend_fill(growable.as_mut());
+    }
+    Ok(growable.as_box())
+}
+
+/// The window of rows aggregated for every row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingWindow<'a> {
+    /// The row and the `size - 1` rows before it.
+    Size(usize),
+    /// An explicit window per row, as SQL frames are. Both the starts and the ends of the
+    /// windows must be non-decreasing.
+    Ranges(&'a [Range<usize>]),
+}
+
+/// The aggregate computed over every window by [`rolling`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollingAggregate {
+    /// The sum of the non-null values, of the type of the values. Integer sums wrap around on
+    /// overflow.
+    Sum,
+    /// The minimum of the non-null values, of the type of the values
+    Min,
+    /// The maximum of the non-null values, of the type of the values
+    Max,
+    /// The mean of the non-null values, of type [`crate::datatypes::DataType::Float64`]
+    Mean,
+    /// The number of non-null values, of type [`crate::datatypes::DataType::UInt64`]
+    Count,
+}
+
+fn check_ranges(ranges: &[Range<usize>], length: usize) -> Result<()> {
+    if ranges.len() != length {
+        return Err(Error::InvalidArgumentError(format!(
+            "Rolling windows require one range per row, but there are {} ranges for {length} rows",
+            ranges.len()
+        )));
+    }
+    let mut previous = 0..0;
+    for range in ranges {
+        if range.start > range.end || range.end > length {
+            return Err(Error::InvalidArgumentError(format!(
+                "The rolling window {range:?} is out of bounds for {length} rows"
+            )));
+        }
+        if range.start < previous.start || range.end < previous.end {
+            return Err(Error::InvalidArgumentError(
+                "The starts and ends of rolling windows must be non-decreasing".to_string(),
+            ));
+        }
+        previous = range.clone();
+    }
+    Ok(())
+}
+
+/// The state of a window, updated as rows enter and leave it.
+trait WindowState<T> {
+    type Output;
+
+    /// Adds the valid row `index` to the window.
+    fn push(&mut self, index: usize, value: T);
+    /// Removes the valid row `index`, the oldest row of the window, from the window.
+    fn pop(&mut self, index: usize, value: T);
+    /// Returns the aggregate of the window.
+    fn get(&self) -> Option<Self::Output>;
+}
+
+struct SumState<T> {
+    sum: T,
+    count: usize,
+}
+
+impl<T: WrappingArithmetic> WindowState<T> for SumState<T> {
+    type Output = T;
+
+    #[inline]
+    fn push(&mut self, _: usize, value: T) {
+        // wrapping is exact modulo the width of integers: a window whose sum fits in `T` is
+        // correct even when the running sum overflowed on the way
+        self.sum = self.sum.wrapping_add(value);
+        self.count += 1;
+    }
+
+    #[inline]
+    fn pop(&mut self, _: usize, value: T) {
+        self.count -= 1;
+        // re-starting from zero avoids accumulating floating point errors past empty windows
+        self.sum = if self.count == 0 {
+            T::default()
+        } else {
+            self.sum.wrapping_sub(value)
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<T> {
+        (self.count > 0).then_some(self.sum)
+    }
+}
+
+struct MeanState {
+    sum: f64,
+    count: usize,
+}
+
+impl<T: AsPrimitive<f64>> WindowState<T> for MeanState {
+    type Output = f64;
+
+    #[inline]
+    fn push(&mut self, _: usize, value: T) {
+        self.sum += value.as_();
+        self.count += 1;
     }
+
+    #[inline]
+    fn pop(&mut self, _: usize, value: T) {
+        self.count -= 1;
+        self.sum = if self.count == 0 {
+            0.0
+        } else {
+            self.sum - value.as_()
+        };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+struct CountState(u64);
+
+impl<T> WindowState<T> for CountState {
+    type Output = u64;
+
+    #[inline]
+    fn push(&mut self, _: usize, _: T) {
+        self.0 += 1;
+    }
+
+    #[inline]
+    fn pop(&mut self, _: usize, _: T) {
+        self.0 -= 1;
+    }
+
+    #[inline]
+    fn get(&self) -> Option<u64> {
+        Some(self.0)
+    }
+}
+
+/// A deque of the rows of the window whose values are monotonic, so that its front is the
+/// minimum (or maximum) of the window. A row is dropped as soon as a newer row is smaller (or
+/// larger), since it can no longer be the minimum (or maximum) of any window.
+struct MonotonicState<T> {
+    deque: VecDeque<(usize, T)>,
+    is_max: bool,
+}
+
+impl<T: NativeType + PartialOrd> WindowState<T> for MonotonicState<T> {
+    type Output = T;
+
+    #[inline]
+    fn push(&mut self, index: usize, value: T) {
+        while let Some((_, back)) = self.deque.back() {
+            let dominated = if self.is_max {
+                *back <= value
+            } else {
+                *back >= value
+            };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((index, value));
+    }
+
+    #[inline]
+    fn pop(&mut self, index: usize, _: T) {
+        if self.deque.front().map(|(front, _)| *front) == Some(index) {
+            self.deque.pop_front();
+        }
+    }
+
+    #[inline]
+    fn get(&self) -> Option<T> {
+        self.deque.front().map(|(_, value)| *value)
+    }
+}
+
+fn slide<T, S, I>(values: &PrimitiveArray<T>, windows: I, mut state: S) -> Vec<Option<S::Output>>
+where
+    T: NativeType,
+    S: WindowState<T>,
+    I: Iterator<Item = Range<usize>>,
+{
+    let is_valid = |index: usize| values.is_valid(index);
+    let (mut start, mut end) = (0, 0);
+    windows
+        .map(|window| {
+            while end < window.end {
+                if is_valid(end) {
+                    state.push(end, values.value(end));
+                }
+                end += 1;
+            }
+            while start < window.start {
+                if is_valid(start) {
+                    state.pop(start, values.value(start));
+                }
+                start += 1;
+            }
+            state.get()
+        })
+        .collect()
+}
+
+/// Returns the aggregate `aggregate` of the non-null values of the `window` of every row of
+/// `values`. The aggregate of a window without non-null values is null, except for
+/// [`RollingAggregate::Count`].
+/// # Errors
+/// This function errors iff the window size is zero, or there is not one range per row, or
+/// the ranges are out of bounds or not non-decreasing.
+/// # Examples
+/// ```
+/// use arrow2::array::{Array, Float64Array, Int32Array};
+/// use arrow2::compute::window::{rolling, RollingAggregate, RollingWindow};
+///
+/// let values = Int32Array::from([Some(1), Some(5), None, Some(2)]);
+///
+/// let result = rolling(&values, RollingWindow::Size(2), RollingAggregate::Max).unwrap();
+/// let expected = Int32Array::from([Some(1), Some(5), Some(5), Some(2)]);
+/// assert_eq!(expected, result.as_ref());
+///
+/// let ranges = [0..2, 0..3, 1..4, 3..4];
+/// let result = rolling(&values, RollingWindow::Ranges(&ranges), RollingAggregate::Mean).unwrap();
+/// let expected = Float64Array::from([Some(3.0), Some(3.0), Some(3.5), Some(2.0)]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn rolling<T>(
+    values: &PrimitiveArray<T>,
+    window: RollingWindow,
+    aggregate: RollingAggregate,
+) -> Result<Box<dyn Array>>
+where
+    T: WrappingArithmetic + PartialOrd + AsPrimitive<f64>,
+{
+    let length = values.len();
+    let windows: Box<dyn Iterator<Item = Range<usize>>> = match window {
+        RollingWindow::Size(0) => {
+            return Err(Error::InvalidArgumentError(
+                "The size of a rolling window must be positive".to_string(),
+            ))
+        }
+        RollingWindow::Size(size) => {
+            Box::new((0..length).map(move |i| (i + 1).saturating_sub(size)..i + 1))
+        }
+        RollingWindow::Ranges(ranges) => {
+            check_ranges(ranges, length)?;
+            Box::new(ranges.iter().cloned())
+        }
+    };
+
+    let data_type = values.data_type().clone();
+    let monotonic = |is_max| MonotonicState {
+        deque: VecDeque::new(),
+        is_max,
+    };
+    Ok(match aggregate {
+        RollingAggregate::Sum => {
+            let state = SumState {
+                sum: T::default(),
+                count: 0,
+            };
+            PrimitiveArray::from(slide(values, windows, state))
+                .to(data_type)
+                .boxed()
+        }
+        RollingAggregate::Min => PrimitiveArray::from(slide(values, windows, monotonic(false)))
+            .to(data_type)
+            .boxed(),
+        RollingAggregate::Max => PrimitiveArray::from(slide(values, windows, monotonic(true)))
+            .to(data_type)
+            .boxed(),
+        RollingAggregate::Mean => {
+            let state = MeanState { sum: 0.0, count: 0 };
+            PrimitiveArray::from(slide(values, windows, state)).boxed()
+        }
+        RollingAggregate::Count => {
+            let counts = slide(values, windows, CountState(0))
+                .into_iter()
+                .map(|count| count.unwrap_or_default())
+                .collect::<Vec<_>>();
+            PrimitiveArray::from_vec(counts).boxed()
+        }
+    })
 }
@@ -3,12 +3,60 @@
 use crate::{
     array::{get_display, Array},
     chunk::Chunk,
+    datatypes::Schema,
 };
 
 use comfy_table::{Cell, Table};
 
+/// Options to configure how [`Chunk`]s are represented by [`write_with_options`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// The maximum number of rows to represent. When there are more rows,
+    /// a row of `...` is appended to the table. `None` represents all rows.
+    pub max_rows: Option<usize>,
+    /// The maximum number of characters of a cell. Longer values are truncated and
+    /// terminated by `…`. `None` does not truncate.
+    pub max_column_width: Option<usize>,
+    /// The representation of null values
+    pub null: &'static str,
+}
+
 /// Returns a visual representation of [`Chunk`]
 pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(chunks: &[Chunk<A>], names: &[N]) -> String {
+    write_with_options(chunks, names, &PrintOptions::default())
+}
+
+/// Returns a visual representation of [`Chunk`] whose column names are the fields of `schema`
+pub fn write_schema<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+    schema: &Schema,
+    options: &PrintOptions,
+) -> String {
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    write_with_options(chunks, &names, options)
+}
+
+fn truncate(string: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max_width) if string.chars().count() > max_width => string
+            .chars()
+            .take(max_width.saturating_sub(1))
+            .chain(std::iter::once('…'))
+            .collect(),
+        _ => string,
+    }
+}
+
+/// Returns a visual representation of [`Chunk`] according to `options`
+pub fn write_with_options<A: AsRef<dyn Array>, N: AsRef<str>>(
+    chunks: &[Chunk<A>],
+    names: &[N],
+    options: &PrintOptions,
+) -> String {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
@@ -19,22 +67,33 @@ pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(chunks: &[Chunk<A>], names: &[N
     let header = names.iter().map(|name| Cell::new(name.as_ref()));
     table.set_header(header);
 
+    let total = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+    let mut remaining = options.max_rows.unwrap_or(total);
     for chunk in chunks {
+        if remaining == 0 {
+            break;
+        }
         let displayes = chunk
             .arrays()
             .iter()
-            .map(|array| get_display(array.as_ref(), ""))
+            .map(|array| get_display(array.as_ref(), options.null))
             .collect::<Vec<_>>();
 
-        for row in 0..chunk.len() {
+        let rows = chunk.len().min(remaining);
+        remaining -= rows;
+        for row in 0..rows {
             let mut cells = Vec::new();
             (0..chunk.arrays().len()).for_each(|col| {
                 let mut string = String::new();
                 displayes[col](&mut string, row).unwrap();
-                cells.push(Cell::new(string));
+                cells.push(Cell::new(truncate(string, options.max_column_width)));
             });
             table.add_row(cells);
         }
     }
+
+    if options.max_rows.map(|max| max < total).unwrap_or(false) {
+        table.add_row(names.iter().map(|_| Cell::new("...")));
+    }
     table.to_string()
 }
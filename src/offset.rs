@@ -7,6 +7,7 @@ pub use crate::types::Offset;
 
 /// A wrapper type of [`Vec<O>`] representing the invariants of Arrow's offsets.
 /// It is guaranteed to (sound to assume that):
+/// * always contain an element
 /// * every element is `>= 0`
 /// * element at position `i` is >= than element at position `i-1`.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -229,25 +230,22 @@ impl<O: Offset> Offsets<O> {
         &mut self,
         lengths: I,
     ) -> Result<(), Error> {
-        let mut total_length = 0;
-        let mut offset = *self.last();
-        let original_offset = offset.to_usize();
-
-        let lengths = lengths.map(|length| {
-            total_length += length;
-            O::from_as_usize(length)
-        });
+        let original_len = self.0.len();
+        let original_offset = self.last().to_usize();
+        let mut offset = original_offset;
 
         let offsets = lengths.map(|length| {
-            offset += length; // this may overflow, checked below
-            offset
+            offset += length;
+            // this may be lossy, checked below
+            O::from_as_usize(offset)
         });
         self.0.extend(offsets);
 
-        let last_offset = original_offset
-            .checked_add(total_length)
-            .ok_or(Error::Overflow)?;
-        O::from_usize(last_offset).ok_or(Error::Overflow)?;
+        if O::from_usize(offset).is_none() {
+            // restore the invariants of this struct
+            self.0.truncate(original_len);
+            return Err(Error::Overflow);
+        }
         Ok(())
     }
 
@@ -304,7 +302,7 @@ impl<O: Offset> Offsets<O> {
 }
 
 /// Checks that `offsets` is monotonically increasing.
-fn try_check_offsets<O: Offset>(offsets: &[O]) -> Result<(), Error> {
+pub(crate) fn try_check_offsets<O: Offset>(offsets: &[O]) -> Result<(), Error> {
     // this code is carefully constructed to auto-vectorize, don't change naively!
     match offsets.first() {
         None => Err(Error::oos("offsets must have at least one element")),
@@ -377,6 +375,18 @@ impl<O: Offset> OffsetsBuffer<O> {
         &self.0
     }
 
+    /// Returns the [`OffsetsBuffer`] spanning `self` and `other` without copying, iff `other`
+    /// starts at the last offset of `self` in the same region, e.g. when both are the offsets
+    /// of adjacent slices of an array.
+    #[cfg(feature = "compute_concatenate")]
+    pub(crate) fn try_join(&self, other: &Self) -> Option<Self> {
+        let other = other.0.clone().sliced(1, other.0.len() - 1);
+        // Safety: the joined offsets are a slice of monotonically increasing offsets
+        self.0
+            .try_join(&other)
+            .map(|offsets| unsafe { Self::new_unchecked(offsets) })
+    }
+
     /// Returns the length an array with these offsets would be.
     #[inline]
     pub fn len_proxy(&self) -> usize {
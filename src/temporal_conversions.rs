@@ -2,7 +2,7 @@
 
 use chrono::{
     format::{parse, Parsed, StrftimeItems},
-    Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
+    Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset as _,
 };
 
 use crate::error::Result;
@@ -50,7 +50,9 @@ pub fn date32_to_date(days: i32) -> NaiveDate {
 /// converts a `i32` representing a `date32` to [`NaiveDate`]
 #[inline]
 pub fn date32_to_date_opt(days: i32) -> Option<NaiveDate> {
-    NaiveDate::from_num_days_from_ce_opt(EPOCH_DAYS_FROM_CE + days)
+    EPOCH_DAYS_FROM_CE
+        .checked_add(days)
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
 }
 
 /// converts a `i64` representing a `date64` to [`NaiveDateTime`]
@@ -267,6 +269,21 @@ pub fn timestamp_to_naive_datetime(timestamp: i64, time_unit: TimeUnit) -> chron
     }
 }
 
+/// Converts a timestamp in `time_unit` into [`chrono::NaiveDateTime`], returning `None`
+/// if the timestamp is out of range.
+#[inline]
+pub fn timestamp_to_naive_datetime_opt(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<chrono::NaiveDateTime> {
+    match time_unit {
+        TimeUnit::Second => timestamp_s_to_datetime_opt(timestamp),
+        TimeUnit::Millisecond => timestamp_ms_to_datetime_opt(timestamp),
+        TimeUnit::Microsecond => timestamp_us_to_datetime_opt(timestamp),
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime_opt(timestamp),
+    }
+}
+
 /// Converts a timestamp in `time_unit` and `timezone` into [`chrono::DateTime`].
 #[inline]
 pub fn timestamp_to_datetime<T: chrono::TimeZone>(
@@ -477,50 +494,49 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
-fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
-    let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
-    let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
-    chrono::NaiveDate::from_ymd_opt(new_year, new_month as u32, 1)
-        .expect("invalid or out-of-range date")
+/// Adds `months` and then `days` to `datetime` following calendar semantics: the day of the
+/// month is clamped to the last day of the resulting month (e.g. `2021-01-31` plus one month
+/// is `2021-02-28`).
+fn add_months_days(datetime: NaiveDateTime, months: i32, days: i32) -> NaiveDateTime {
+    let datetime = if months >= 0 {
+        datetime.checked_add_months(chrono::Months::new(months as u32))
+    } else {
+        datetime.checked_sub_months(chrono::Months::new(months.unsigned_abs()))
+    }
+    .expect("invalid or out-of-range date");
+    datetime + Duration::days(days as i64)
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(
-            chrono::NaiveDate::from_ymd_opt(year, month, 1).expect("invalid or out-of-range date"),
-        )
-        .num_days()
+/// Converts a [`NaiveDateTime`] to a timestamp in `time_unit` units.
+#[inline]
+fn naive_datetime_to_timestamp(datetime: NaiveDateTime, time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Second => datetime.timestamp(),
+        TimeUnit::Millisecond => datetime.timestamp_millis(),
+        TimeUnit::Microsecond => datetime.timestamp_micros(),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt().unwrap(),
+    }
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+///
+/// The months and days of the interval are added following calendar semantics (see
+/// [`add_interval`]) and its nanoseconds are then added as elapsed time.
 #[inline]
 pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
-    };
-
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
-
-    // add; no leap hours are considered
-    let new_datetime_tz = datetime
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
-
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos_opt().unwrap() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos_opt().unwrap(),
-    }
+    let datetime = timestamp_to_naive_datetime(timestamp, time_unit);
+    let datetime = add_months_days(datetime, interval.months(), interval.days())
+        + Duration::nanoseconds(interval.ns());
+    naive_datetime_to_timestamp(datetime, time_unit)
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+///
+/// The months and days of the interval are added to the wall clock time in `timezone`, so that
+/// e.g. adding one day across a daylight saving time change keeps the time of the day, and the
+/// day of the month is clamped to the last day of the resulting month. Its nanoseconds are then
+/// added as elapsed time. A wall clock time that is ambiguous resolves to the earliest instant
+/// and one that does not exist is moved forward by the length of the gap.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
     timestamp: i64,
@@ -528,23 +544,25 @@ pub fn add_interval<T: chrono::TimeZone>(
     interval: months_days_ns,
     timezone: &T,
 ) -> i64 {
-    // convert seconds to a DateTime of a given offset.
     let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
 
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
-
-    // add; tz will take care of leap hours
-    let new_datetime_tz = datetime_tz
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    let local = add_months_days(
+        datetime_tz.naive_local(),
+        interval.months(),
+        interval.days(),
+    );
+    let datetime_tz = match timezone.from_local_datetime(&local) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            // in a gap: interpret it with the offset in force before the gap, a day earlier,
+            // which moves it past the gap
+            let before = local - Duration::days(1);
+            let offset = timezone.offset_from_utc_datetime(&before).fix();
+            timezone.from_utc_datetime(&(local - offset))
+        }
+    };
+    let datetime_tz = datetime_tz + Duration::nanoseconds(interval.ns());
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos_opt().unwrap() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos_opt().unwrap(),
-    }
+    naive_datetime_to_timestamp(datetime_tz.naive_utc(), time_unit)
 }
@@ -87,6 +87,73 @@ native_type!(f32, PrimitiveType::Float32);
 native_type!(f64, PrimitiveType::Float64);
 native_type!(i128, PrimitiveType::Int128);
 
+/// Arithmetic of [`NativeType`]s that never panics: integers wrap around at the boundary of
+/// their type, as their `wrapping_*` methods do, and floats follow IEEE 754.
+pub trait WrappingArithmetic: NativeType {
+    /// Wrapping addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping multiplication.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! wrapping_integer {
+    ($type:ty) => {
+        impl WrappingArithmetic for $type {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$type>::wrapping_add(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$type>::wrapping_sub(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$type>::wrapping_mul(self, rhs)
+            }
+        }
+    };
+}
+
+macro_rules! wrapping_float {
+    ($type:ty) => {
+        impl WrappingArithmetic for $type {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+        }
+    };
+}
+
+wrapping_integer!(u8);
+wrapping_integer!(u16);
+wrapping_integer!(u32);
+wrapping_integer!(u64);
+wrapping_integer!(i8);
+wrapping_integer!(i16);
+wrapping_integer!(i32);
+wrapping_integer!(i64);
+wrapping_integer!(i128);
+wrapping_float!(f32);
+wrapping_float!(f64);
+
 /// The in-memory representation of the DayMillisecond variant of arrow's "Interval" logical type.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
 #[allow(non_camel_case_types)]
@@ -476,6 +543,136 @@ impl f16 {
             f16((half_sign | half_exp | half_man) as u16)
         }
     }
+
+    /// Casts this `f16` to `f64`
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Casts an `f64` into `f16`, rounding to the nearest value (ties to even)
+    pub fn from_f64(value: f64) -> Self {
+        let x: u64 = value.to_bits();
+
+        // Extract IEEE754 components
+        let sign = ((x >> 48) & 0x8000u64) as u16;
+        let exp = ((x >> 52) & 0x07FFu64) as i64;
+        let man = x & 0x000F_FFFF_FFFF_FFFFu64;
+
+        // Check for all exponent bits being set, which is Infinity or NaN
+        if exp == 0x07FF {
+            let nan_bit = if man == 0 { 0 } else { 0x0200u16 };
+            return f16(sign | 0x7C00u16 | nan_bit | (man >> 42) as u16);
+        }
+
+        // Unbias the exponent, then bias for half precision
+        let half_exp = exp - 1023 + 15;
+
+        // Check for exponent overflow, return +infinity
+        if half_exp >= 0x1F {
+            return f16(sign | 0x7C00u16);
+        }
+
+        // Check for underflow, where the hidden leading bit is part of the mantissa
+        if half_exp <= 0 {
+            let shift = 43 - half_exp;
+            if shift > 53 {
+                return f16(sign);
+            }
+            let man = man | 0x0010_0000_0000_0000u64;
+            let mut half_man = (man >> shift) as u16;
+            let round_bit = 1u64 << (shift - 1);
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+            return f16(sign | half_man);
+        }
+
+        let half = sign | ((half_exp as u16) << 10) | (man >> 42) as u16;
+        let round_bit = 1u64 << 41;
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            // a carry into the exponent is the correct rounding, up to infinity
+            f16(half + 1)
+        } else {
+            f16(half)
+        }
+    }
+
+    /// Casts a slice of `f16` into `f32`, using the F16C instructions of the CPU when available.
+    #[cfg(any(
+        feature = "compute_aggregate",
+        feature = "compute_arithmetics",
+        feature = "compute_cast"
+    ))]
+    pub(crate) fn slice_to_f32(values: &[f16]) -> Vec<f32> {
+        #[cfg(target_arch = "x86_64")]
+        if f16c::is_available() {
+            // safety: the CPU supports the target features of the function
+            return unsafe { f16c::to_f32(values) };
+        }
+        values.iter().map(|x| x.to_f32()).collect()
+    }
+
+    /// Casts a slice of `f32` into `f16`, using the F16C instructions of the CPU when available.
+    #[cfg(any(feature = "compute_arithmetics", feature = "compute_cast"))]
+    pub(crate) fn slice_from_f32(values: &[f32]) -> Vec<f16> {
+        #[cfg(target_arch = "x86_64")]
+        if f16c::is_available() {
+            // safety: the CPU supports the target features of the function
+            return unsafe { f16c::from_f32(values) };
+        }
+        values.iter().copied().map(f16::from_f32).collect()
+    }
+}
+
+/// Conversions of 8 `f16` at a time with the F16C extension of x86_64.
+#[cfg(target_arch = "x86_64")]
+#[cfg(any(
+    feature = "compute_aggregate",
+    feature = "compute_arithmetics",
+    feature = "compute_cast"
+))]
+mod f16c {
+    use std::arch::x86_64::*;
+
+    use super::f16;
+
+    #[inline]
+    pub fn is_available() -> bool {
+        is_x86_feature_detected!("avx") && is_x86_feature_detected!("f16c")
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    pub unsafe fn to_f32(values: &[f16]) -> Vec<f32> {
+        let mut result = Vec::with_capacity(values.len());
+        let chunks = values.chunks_exact(8);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            let mut floats = [0f32; 8];
+            let halves = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+            _mm256_storeu_ps(floats.as_mut_ptr(), _mm256_cvtph_ps(halves));
+            result.extend_from_slice(&floats);
+        }
+        result.extend(remainder.iter().map(|x| x.to_f32()));
+        result
+    }
+
+    #[cfg(any(feature = "compute_arithmetics", feature = "compute_cast"))]
+    #[target_feature(enable = "avx,f16c")]
+    pub unsafe fn from_f32(values: &[f32]) -> Vec<f16> {
+        let mut result = Vec::with_capacity(values.len());
+        let chunks = values.chunks_exact(8);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            let mut halves = [f16(0); 8];
+            let floats = _mm256_loadu_ps(chunk.as_ptr());
+            let floats = _mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(floats);
+            _mm_storeu_si128(halves.as_mut_ptr() as *mut __m128i, floats);
+            result.extend_from_slice(&halves);
+        }
+        result.extend(remainder.iter().copied().map(f16::from_f32));
+        result
+    }
 }
 
 impl std::fmt::Debug for f16 {
@@ -490,6 +687,103 @@ impl std::fmt::Display for f16 {
     }
 }
 
+impl PartialOrd for f16 {
+    /// Compares two `f16` without casting them, following the semantics of `f32`.
+    fn partial_cmp(&self, other: &f16) -> Option<std::cmp::Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // the sign and magnitude of both, where `-0 == +0`
+        let key = |x: &f16| {
+            if x.0 & 0x7FFFu16 == 0 {
+                0i32
+            } else if x.0 & 0x8000u16 != 0 {
+                -((x.0 & 0x7FFFu16) as i32)
+            } else {
+                x.0 as i32
+            }
+        };
+        Some(key(self).cmp(&key(other)))
+    }
+}
+
+macro_rules! f16_binary_op {
+    ($trait:ident, $method:ident) => {
+        /// Computed in `f32` and rounded to the nearest `f16`.
+        impl std::ops::$trait for f16 {
+            type Output = f16;
+
+            #[inline]
+            fn $method(self, rhs: f16) -> f16 {
+                f16::from_f32(std::ops::$trait::$method(self.to_f32(), rhs.to_f32()))
+            }
+        }
+    };
+}
+
+f16_binary_op!(Add, add);
+f16_binary_op!(Sub, sub);
+f16_binary_op!(Mul, mul);
+f16_binary_op!(Div, div);
+f16_binary_op!(Rem, rem);
+
+impl Neg for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn neg(self) -> f16 {
+        f16(self.0 ^ 0x8000u16)
+    }
+}
+
+impl num_traits::Zero for f16 {
+    #[inline]
+    fn zero() -> Self {
+        f16(0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 & 0x7FFFu16 == 0
+    }
+}
+
+impl num_traits::One for f16 {
+    #[inline]
+    fn one() -> Self {
+        f16(0x3C00u16)
+    }
+}
+
+impl num_traits::ToPrimitive for f16 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        num_traits::ToPrimitive::to_i64(&f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        num_traits::ToPrimitive::to_u64(&f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f16::to_f64(*self))
+    }
+}
+
+impl num_traits::NumCast for f16 {
+    #[inline]
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(f16::from_f64)
+    }
+}
+
 impl NativeType for f16 {
     const PRIMITIVE: PrimitiveType = PrimitiveType::Float16;
     type Bytes = [u8; 2];
@@ -514,17 +808,140 @@ impl NativeType for f16 {
     }
 }
 
-/// Physical representation of a decimal
+/// Physical representation of a 256-bit decimal, a signed integer of two `i128`.
+///
+/// Its arithmetic follows the semantics of Rust's integers: the operators panic on overflow
+/// in debug builds and wrap around in release builds, while the `checked_*` variants return
+/// `None` on overflow (and on division by zero). It is wide enough to hold the sum or
+/// product of any two `i128`, and thus to accumulate intermediate results of decimals.
 #[derive(Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[allow(non_camel_case_types)]
 #[repr(C)]
 pub struct i256(pub ethnum::I256);
 
 impl i256 {
+    /// The value `0`.
+    pub const ZERO: Self = Self(ethnum::I256::ZERO);
+    /// The value `1`.
+    pub const ONE: Self = Self(ethnum::I256::ONE);
+    /// The smallest value, `-2^255`.
+    pub const MIN: Self = Self(ethnum::I256::MIN);
+    /// The largest value, `2^255 - 1`.
+    pub const MAX: Self = Self(ethnum::I256::MAX);
+
     /// Returns a new [`i256`] from two `i128`.
     pub fn from_words(hi: i128, lo: i128) -> Self {
         Self(ethnum::I256::from_words(hi, lo))
     }
+
+    /// Returns the high and low `i128` of this [`i256`], i.e. the inverse of
+    /// [`i256::from_words`].
+    #[inline]
+    pub fn into_words(self) -> (i128, i128) {
+        self.0.into_words()
+    }
+
+    /// Returns this [`i256`] as an `i128`, or `None` if it does not fit.
+    #[inline]
+    pub fn to_i128(self) -> Option<i128> {
+        i128::try_from(self.0).ok()
+    }
+
+    /// Returns `10^exponent`, or `None` if it overflows.
+    #[inline]
+    pub fn checked_pow10(exponent: u32) -> Option<Self> {
+        ethnum::I256::from(10i32).checked_pow(exponent).map(Self)
+    }
+
+    /// Returns `self + rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Returns `self - rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns `self * rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Returns `self / rhs` rounded toward zero, or `None` if `rhs` is zero or on overflow.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Returns `self % rhs`, or `None` if `rhs` is zero or on overflow.
+    #[inline]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(Self)
+    }
+
+    /// Returns `-self`, or `None` if `self` is [`i256::MIN`].
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Returns `|self|`, or `None` if `self` is [`i256::MIN`].
+    #[inline]
+    pub fn checked_abs(self) -> Option<Self> {
+        self.0.checked_abs().map(Self)
+    }
+
+    /// Returns whether this [`i256`] is smaller than zero.
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl From<i128> for i256 {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self(ethnum::I256::from(value))
+    }
+}
+
+impl TryFrom<i256> for i128 {
+    type Error = std::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(value: i256) -> Result<Self, Self::Error> {
+        i128::try_from(value.0)
+    }
+}
+
+macro_rules! i256_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl std::ops::$trait for i256 {
+            type Output = i256;
+
+            #[inline]
+            fn $method(self, rhs: i256) -> i256 {
+                Self(std::ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+    };
+}
+
+i256_binary_op!(Add, add);
+i256_binary_op!(Sub, sub);
+i256_binary_op!(Mul, mul);
+i256_binary_op!(Div, div);
+i256_binary_op!(Rem, rem);
+
+impl std::ops::AddAssign for i256 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i256) {
+        self.0 += rhs.0
+    }
 }
 
 impl Neg for i256 {
@@ -532,8 +949,7 @@ impl Neg for i256 {
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let (a, b) = self.0.into_words();
-        Self(ethnum::I256::from_words(-a, b))
+        Self(-self.0)
     }
 }
 
@@ -636,4 +1052,87 @@ mod test {
         assert_eq!(format!("{}", f16::from_f32(7.0)), "7".to_string());
         assert_eq!(format!("{:?}", f16::from_f32(7.0)), "7.0".to_string());
     }
+
+    #[test]
+    fn test_i256() {
+        let max = i256::from(i128::MAX);
+        let min = i256::from(i128::MIN);
+        assert_eq!((max + max).to_i128(), None);
+        assert_eq!((max + max - max).to_i128(), Some(i128::MAX));
+        assert_eq!((min * min / min).to_i128(), Some(i128::MIN));
+        assert_eq!(i128::try_from(min - i256::ONE).ok(), None);
+        assert_eq!(i256::from_words(1, -1).into_words(), (1, -1));
+
+        assert_eq!(-i256::from(5), i256::from(-5));
+        assert_eq!(-min, max + i256::ONE);
+        assert_eq!(i256::from(-7) % i256::from(2), i256::from(-1));
+
+        assert_eq!(i256::MAX.checked_add(i256::ONE), None);
+        assert_eq!(i256::MIN.checked_sub(i256::ONE), None);
+        assert_eq!(i256::MAX.checked_mul(i256::from(2)), None);
+        assert_eq!(i256::ONE.checked_div(i256::ZERO), None);
+        assert_eq!(i256::MIN.checked_div(-i256::ONE), None);
+        assert_eq!(i256::ONE.checked_rem(i256::ZERO), None);
+        assert_eq!(i256::MIN.checked_neg(), None);
+        assert_eq!(i256::MIN.checked_abs(), None);
+        assert_eq!(i256::from(-3).checked_abs(), Some(i256::from(3)));
+
+        assert_eq!(i256::checked_pow10(2), Some(i256::from(100)));
+        assert!(i256::checked_pow10(76).is_some());
+        assert_eq!(i256::checked_pow10(77), None);
+    }
+
+    #[test]
+    fn test_f16_from_f64() {
+        for value in [0.0, -0.0, 1.0, -7.1, 65504.0, 65520.0, 1e-8, 3.0e-8, 6.1e-5] {
+            assert_eq!(
+                f16::from_f64(value).to_bits(),
+                f16::from_f32(value as f32).to_bits()
+            );
+        }
+        // a tie in `f32` that is not a tie in `f64`
+        let value = 1.0 + 2.0f64.powi(-11) + 2.0f64.powi(-40);
+        assert_eq!(f16::from_f64(value).to_f64(), 1.0 + 2.0f64.powi(-10));
+        assert_eq!(f16::from_f32(value as f32).to_f64(), 1.0);
+
+        assert!(f16::from_f64(f64::NAN).is_nan());
+        assert_eq!(f16::from_f64(f64::INFINITY).to_f32(), f32::INFINITY);
+        assert_eq!(f16::from_f64(1e300).to_f32(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_f16_slice() {
+        let values = (0..=u16::MAX).map(f16).collect::<Vec<_>>();
+        let floats = f16::slice_to_f32(&values);
+        for (value, float) in values.iter().zip(floats.iter()) {
+            assert_eq!(value.to_f32().to_bits(), float.to_bits());
+        }
+        let floats = (0..1000)
+            .map(|x| x as f32 * 0.37 - 100.0)
+            .chain([f32::INFINITY, 1e-7, 65520.0, 1e10])
+            .collect::<Vec<_>>();
+        let values = f16::slice_from_f32(&floats);
+        for (value, float) in values.iter().zip(floats.iter()) {
+            assert_eq!(value.to_bits(), f16::from_f32(*float).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_f16_ord() {
+        let values = [-1.5f32, -0.0, 0.0, 1e-7, 0.5, 2.0, f32::INFINITY];
+        for lhs in values {
+            for rhs in values {
+                assert_eq!(
+                    f16::from_f32(lhs).partial_cmp(&f16::from_f32(rhs)),
+                    lhs.partial_cmp(&rhs)
+                );
+            }
+        }
+        assert_eq!(
+            f16::from_f32(f32::NAN).partial_cmp(&f16::from_f32(1.0)),
+            None
+        );
+        assert_eq!(f16::from_f32(1.5) + f16::from_f32(2.0), f16::from_f32(3.5));
+        assert_eq!(-f16::from_f32(1.5), f16::from_f32(-1.5));
+    }
 }
@@ -48,6 +48,33 @@ fn try_new_invalid() {
     );
 }
 
+#[test]
+fn from_packed() {
+    let array = BooleanArray::from_packed(
+        DataType::Boolean,
+        vec![0b0000_0101, 0b0000_0001],
+        9,
+        Some(Bitmap::from_u8_slice([0b1111_1110, 0b1], 9)),
+    );
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true)
+        ]
+    );
+
+    assert!(BooleanArray::try_from_packed(DataType::Boolean, vec![0], 9, None).is_err());
+    assert!(BooleanArray::try_from_packed(DataType::Int8, vec![0], 8, None).is_err());
+}
+
 #[test]
 fn with_validity() {
     let bitmap = Bitmap::from([true, false, true]);
@@ -143,3 +170,10 @@ fn into_iter() {
 
     assert!(array.into_iter().rev().eq(rev))
 }
+
+#[test]
+fn non_null_values_iter() {
+    let array = BooleanArray::from([Some(false), None, Some(true)]);
+    let result = array.non_null_values_iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, false), (2, true)]);
+}
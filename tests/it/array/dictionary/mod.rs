@@ -210,3 +210,65 @@ fn iter_values_typed_panic_2() {
     let iter = array.iter_typed::<Utf8Array<i32>>().unwrap();
     let _ = iter.collect::<Vec<_>>();
 }
+
+#[test]
+fn compact() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    let keys = PrimitiveArray::from([Some(3u8), None, Some(1), Some(3)]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+    let compacted = array.compact();
+    assert_eq!(
+        compacted.keys(),
+        &PrimitiveArray::from([Some(1u8), None, Some(0), Some(1)])
+    );
+    assert_eq!(
+        compacted.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["b", "d"]) as &dyn Array
+    );
+    assert_eq!(
+        compacted.iter().collect::<Vec<_>>(),
+        array.iter().collect::<Vec<_>>()
+    );
+
+    let compacted = array.sliced(2, 1).compact();
+    assert_eq!(compacted.keys(), &PrimitiveArray::from_vec(vec![0u8]));
+    assert_eq!(
+        compacted.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["b"]) as &dyn Array
+    );
+}
+
+#[test]
+fn compact_all_referenced() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let array =
+        DictionaryArray::try_from_keys(PrimitiveArray::from_vec(vec![1, 0]), values.boxed())
+            .unwrap();
+    assert_eq!(array.compact(), array);
+}
+
+#[test]
+fn compact_nulls() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let keys = PrimitiveArray::<i32>::from([None, None]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+    let compacted = array.compact();
+    assert_eq!(compacted.values().len(), 1);
+    assert_eq!(compacted.null_count(), 2);
+}
+
+#[test]
+fn compact_preserving_min_max() {
+    let values = Int32Array::from([Some(5), None, Some(1), Some(3), Some(9)]);
+    let keys = PrimitiveArray::from_vec(vec![3i32, 3]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+    let compacted = array.compact_preserving_min_max().unwrap();
+    assert_eq!(compacted.keys(), &PrimitiveArray::from_vec(vec![1i32, 1]));
+    assert_eq!(
+        compacted.values().as_ref(),
+        &Int32Array::from_slice([1, 3, 9]) as &dyn Array
+    );
+}
@@ -167,3 +167,21 @@ fn test_big_dict() {
     }
     assert_eq!(arr.values().len(), n);
 }
+
+#[test]
+fn shrink_to_fit() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    for i in 0..100 {
+        a.try_push(Some(format!("{}", i % 10)))?;
+    }
+    a.shrink_to_fit();
+
+    // values are still deduplicated after the map is rehashed
+    a.try_push(Some("3"))?;
+    a.try_push(Some("a"))?;
+    let array: DictionaryArray<i32> = a.into();
+    assert_eq!(array.values().len(), 11);
+    assert_eq!(array.keys().value(100), 3);
+    assert_eq!(array.keys().value(101), 10);
+    Ok(())
+}
@@ -88,3 +88,23 @@ fn test_primitive_slice() {
         test_equal(&lhs, &rhs, expected);
     }
 }
+
+#[test]
+fn test_primitive_nan() {
+    let cases = vec![
+        (vec![Some(f64::NAN), None], vec![Some(f64::NAN), None], true),
+        (vec![Some(f64::NAN), None], vec![Some(1.0), None], false),
+        (vec![Some(0.0), None], vec![Some(-0.0), None], true),
+        (vec![Some(-f64::NAN)], vec![Some(f64::NAN)], true),
+    ];
+
+    for (lhs, rhs, expected) in cases {
+        let lhs = Float64Array::from(&lhs);
+        let rhs = Float64Array::from(&rhs);
+        test_equal(&lhs, &rhs, expected);
+    }
+
+    let lhs = Float32Array::from_slice([f32::NAN]);
+    let rhs = Float32Array::from_slice([f32::NAN]);
+    test_equal(&lhs, &rhs, true);
+}
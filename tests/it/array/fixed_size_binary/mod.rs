@@ -94,3 +94,26 @@ fn to() {
     );
     let _ = a.to(extension);
 }
+
+#[test]
+fn into_mut() {
+    let array = FixedSizeBinaryArray::new(
+        DataType::FixedSizeBinary(2),
+        Buffer::from(vec![1, 2, 3, 4]),
+        Some(Bitmap::from([true, false])),
+    );
+    let mutable = array.into_mut().right().unwrap();
+    assert_eq!(mutable.values(), &vec![1, 2, 3, 4]);
+
+    let values = Buffer::from(vec![1, 2, 3, 4]);
+    let array = FixedSizeBinaryArray::new(DataType::FixedSizeBinary(2), values.clone(), None);
+    assert!(array.into_mut().is_left());
+
+    let validity = Bitmap::from([true, false]);
+    let array = FixedSizeBinaryArray::new(
+        DataType::FixedSizeBinary(2),
+        vec![1, 2, 3, 4].into(),
+        Some(validity.clone()),
+    );
+    assert!(array.into_mut().is_left());
+}
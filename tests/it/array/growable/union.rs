@@ -151,3 +151,61 @@ fn complex_dense() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn extend_validity() -> Result<()> {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, None, mode);
+        let types = vec![1].into();
+        let (fields, offsets) = if mode.is_sparse() {
+            let fields = vec![
+                Int32Array::from_slice([1]).boxed(),
+                Utf8Array::<i32>::from_slice(["a"]).boxed(),
+            ];
+            (fields, None)
+        } else {
+            let fields = vec![
+                Int32Array::new_empty(DataType::Int32).boxed(),
+                Utf8Array::<i32>::from_slice(["a"]).boxed(),
+            ];
+            (fields, Some(vec![0].into()))
+        };
+        let array = UnionArray::new(data_type, types, fields, offsets);
+
+        let mut a = GrowableUnion::new(vec![&array], 2);
+        a.extend_validity(1);
+        a.extend(0, 0, 1);
+        assert_eq!(a.len(), 2);
+
+        let result: UnionArray = a.into();
+        assert!(!result.value(0).is_valid());
+        assert!(result.value(1).is_valid());
+    }
+    Ok(())
+}
+
+#[test]
+fn extend_validity_with_ids() -> Result<()> {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let data_type = DataType::Union(fields, Some(vec![5, 7]), mode);
+        let array = UnionArray::new_empty(data_type);
+
+        let mut a = GrowableUnion::new(vec![&array], 2);
+        a.extend_validity(2);
+
+        let result: UnionArray = a.into();
+        assert_eq!(result.types().as_slice(), &[5, 5]);
+        assert_eq!(result.fields()[0].len(), 2);
+        assert!(!result.value(0).is_valid());
+        assert!(!result.value(1).is_valid());
+    }
+    Ok(())
+}
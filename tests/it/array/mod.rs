@@ -12,8 +12,11 @@ mod primitive;
 mod struct_;
 mod union;
 mod utf8;
+mod validate;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use arrow2::array::{
+    clone, new_empty_array, new_null_array, Array, AsArray, PrimitiveArray, Utf8Array,
+};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
@@ -135,6 +138,25 @@ fn test_with_validity() {
     assert_eq!(arr_ref, &expected);
 }
 
+#[test]
+fn as_array() {
+    let array = PrimitiveArray::from_slice([1i32, 2, 3]).boxed();
+    assert_eq!(array.as_primitive::<i32>().unwrap().value(2), 3);
+    assert!(array.as_primitive::<i64>().is_none());
+    assert!(array.as_utf8::<i32>().is_none());
+
+    let array: &dyn Array = &Utf8Array::<i64>::from_slice(["a"]);
+    assert_eq!(array.expect_utf8::<i64>().value(0), "a");
+    assert!(array.as_utf8::<i32>().is_none());
+}
+
+#[test]
+#[should_panic]
+fn expect_array_wrong_type() {
+    let array = PrimitiveArray::from_slice([1i32, 2, 3]).boxed();
+    array.expect_struct();
+}
+
 // check that we ca derive stuff
 #[derive(PartialEq, Clone, Debug)]
 struct A {
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use arrow2::array::ord::build_compare;
 use arrow2::array::*;
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, Field};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +115,63 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+        Some(vec![Some(1), Some(2)]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Equal, (cmp)(0, 4));
+    // shorter lists are smaller
+    assert_eq!(Ordering::Less, (cmp)(1, 0));
+    assert_eq!(Ordering::Less, (cmp)(3, 1));
+    // null items are smaller than any value
+    assert_eq!(Ordering::Less, (cmp)(2, 0));
+    assert_eq!(Ordering::Greater, (cmp)(2, 1));
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = Int32Array::from_slice([1, 2, 1, 3, 0, 5]);
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    let array = FixedSizeListArray::new(data_type, values.boxed(), None);
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    assert_eq!(Ordering::Greater, (cmp)(1, 2));
+    assert_eq!(Ordering::Equal, (cmp)(2, 2));
+    Ok(())
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let values = vec![
+        Int32Array::from([Some(1), Some(1), None, Some(1)]).boxed(),
+        Utf8Array::<i32>::from([Some("b"), Some("a"), Some("c"), Some("b")]).boxed(),
+    ];
+    let array = StructArray::new(DataType::Struct(fields), values, None);
+
+    let cmp = build_compare(&array, &array)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    assert_eq!(Ordering::Less, (cmp)(2, 1));
+    assert_eq!(Ordering::Equal, (cmp)(0, 3));
+    Ok(())
+}
@@ -136,3 +136,18 @@ fn into_iter() {
 
     assert!(array.into_iter().rev().eq(rev))
 }
+
+#[test]
+fn non_null_values_iter() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), None]);
+    let result = array.non_null_values_iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 1), (2, 3)]);
+
+    let array = array.sliced(1, 3);
+    let result = array.non_null_values_iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 3)]);
+
+    let array = Int32Array::from_slice([1, 2]);
+    let result = array.non_null_values_iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 1), (1, 2)]);
+}
@@ -25,3 +25,51 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+fn array() -> StructArray {
+    let boolean = BooleanArray::from_slice([false, false, true, true]).boxed();
+    let int = Int32Array::from_slice([42, 28, 19, 31]).boxed();
+    let utf8 = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed();
+
+    let fields = vec![
+        Field::new("b", DataType::Boolean, false),
+        Field::new("c", DataType::Int32, false),
+        Field::new("d", DataType::Utf8, false),
+    ];
+
+    StructArray::new(
+        DataType::Struct(fields),
+        vec![boolean, int, utf8],
+        Some(Bitmap::from([true, true, false, true])),
+    )
+}
+
+#[test]
+fn project() {
+    let array = array();
+
+    let projected = array.project(&[2, 0]).unwrap();
+    assert_eq!(
+        projected.fields(),
+        &[
+            Field::new("d", DataType::Utf8, false),
+            Field::new("b", DataType::Boolean, false),
+        ]
+    );
+    assert_eq!(projected.values()[0], array.values()[2]);
+    assert_eq!(projected.values()[1], array.values()[0]);
+    assert_eq!(projected.validity(), array.validity());
+
+    assert!(array.project(&[3]).is_err());
+    assert!(array.project(&[]).is_err());
+}
+
+#[test]
+fn reorder_by_names() {
+    let array = array();
+
+    let reordered = array.reorder_by_names(&["c", "b"]).unwrap();
+    assert_eq!(reordered, array.project(&[1, 0]).unwrap());
+
+    assert!(array.reorder_by_names(&["e"]).is_err());
+}
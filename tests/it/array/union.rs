@@ -371,3 +371,40 @@ fn types_must_be_in_bounds() -> Result<()> {
     assert!(UnionArray::try_new(data_type, types, fields.clone(), None).is_err());
     Ok(())
 }
+
+#[test]
+fn fields_by_type_id() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields.clone(), Some(vec![5, 7]), UnionMode::Dense);
+    let types = Buffer::from(vec![5, 5, 7]);
+    let offsets = Buffer::<i32>::from(vec![0, 1, 0]);
+    let children = vec![
+        Int32Array::from(&[Some(1), None]).boxed(),
+        Utf8Array::<i32>::from([Some("c")]).boxed(),
+    ];
+
+    let array = UnionArray::new(data_type, types, children.clone(), Some(offsets));
+
+    assert_eq!(array.field_index_by_type_id(7), Some(1));
+    assert_eq!(array.field_index_by_type_id(0), None);
+    let (field, child) = array.field_by_type_id(5).unwrap();
+    assert_eq!(field, &fields[0]);
+    assert_eq!(child, children[0].as_ref());
+
+    let type_ids = array
+        .fields_by_type_id()
+        .map(|(type_id, field, _)| (type_id, field.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(type_ids, vec![(5, "a"), (7, "b")]);
+
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let array = UnionArray::new_null(data_type, 2);
+    assert_eq!(array.field_index_by_type_id(1), Some(1));
+    assert_eq!(array.field_index_by_type_id(2), None);
+    assert_eq!(array.field_index_by_type_id(-1), None);
+
+    Ok(())
+}
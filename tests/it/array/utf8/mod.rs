@@ -127,6 +127,34 @@ fn not_utf8_individually() {
     assert!(Utf8Array::<i32>::try_new(DataType::Utf8, offsets, values, None).is_err());
 }
 
+#[test]
+fn try_from_binary() {
+    let binary = BinaryArray::<i32>::from([Some(b"hi".as_ref()), None, Some(b"there")]);
+    let values_ptr = binary.values().as_ptr();
+
+    let array = Utf8Array::<i32>::try_from_binary(binary).unwrap();
+    assert_eq!(array.data_type(), &DataType::Utf8);
+    assert_eq!(array.values().as_ptr(), values_ptr);
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some("hi"), None, Some("there")]
+    );
+
+    let binary = array.to_binary();
+    assert_eq!(binary.data_type(), &DataType::Binary);
+    assert_eq!(binary.values().as_ptr(), values_ptr);
+    assert_eq!(
+        binary.iter().collect::<Vec<_>>(),
+        vec![Some(b"hi".as_ref()), None, Some(b"there")]
+    );
+}
+
+#[test]
+fn try_from_binary_not_utf8() {
+    let binary = BinaryArray::<i64>::from_slice([[0, 159, 146, 150]]);
+    assert!(Utf8Array::<i64>::try_from_binary(binary).is_err());
+}
+
 #[test]
 fn wrong_data_type() {
     let offsets = vec![0, 4].try_into().unwrap();
@@ -233,3 +261,10 @@ fn test_apply_validity() {
     assert!(!array.is_valid(1));
     assert!(!array.is_valid(2));
 }
+
+#[test]
+fn non_null_values_iter() {
+    let array = Utf8Array::<i32>::from([None, Some("a"), None, Some("bc")]);
+    let result = array.non_null_values_iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, "a"), (3, "bc")]);
+}
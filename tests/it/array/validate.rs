@@ -0,0 +1,71 @@
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::offset::OffsetsBuffer;
+
+#[test]
+fn valid() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+    assert!(array.validate().is_ok());
+
+    let values = Int32Array::from_slice([1, 2, 3]).boxed();
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let array = StructArray::new(data_type, vec![values], None);
+    assert!(validate(&array).is_ok());
+
+    let array = array.sliced(1, 2).boxed();
+    assert!(array.validate().is_ok());
+}
+
+#[test]
+fn invalid_utf8() {
+    let offsets = OffsetsBuffer::<i32>::try_from(vec![0, 2]).unwrap();
+    let array = unsafe {
+        Utf8Array::<i32>::new_unchecked(DataType::Utf8, offsets, vec![0xff, 0xfe].into(), None)
+    };
+    assert!(array.validate().is_err());
+}
+
+#[test]
+fn invalid_offsets() {
+    let offsets = unsafe { OffsetsBuffer::<i32>::new_unchecked(vec![0, 2, 1].into()) };
+    let array = unsafe {
+        Utf8Array::<i32>::new_unchecked(DataType::Utf8, offsets, b"ab".to_vec().into(), None)
+    };
+    assert!(array.validate().is_err());
+
+    let offsets = unsafe { OffsetsBuffer::<i32>::new_unchecked(vec![0, 1, 0].into()) };
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let array = ListArray::<i32>::new(
+        data_type,
+        offsets,
+        Int32Array::from_slice([1]).boxed(),
+        None,
+    );
+    assert!(array.validate().is_err());
+}
+
+#[test]
+fn invalid_dictionary_keys() {
+    let keys = Int32Array::from_slice([0, 2]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let data_type = DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false,
+    );
+    assert!(DictionaryArray::try_new(data_type.clone(), keys.clone(), values.clone()).is_err());
+
+    let array = unsafe { DictionaryArray::try_new_unchecked(data_type, keys, values) }.unwrap();
+    assert!(array.validate().is_err());
+}
+
+#[test]
+fn invalid_nested_child() {
+    let offsets = OffsetsBuffer::<i32>::try_from(vec![0, 2]).unwrap();
+    let values = unsafe {
+        Utf8Array::<i32>::new_unchecked(DataType::Utf8, offsets, vec![0xff, 0xfe].into(), None)
+    };
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]);
+    let array = StructArray::new(data_type, vec![values.boxed()], None);
+    assert!(validate(&array).is_err());
+}
@@ -63,7 +63,44 @@ fn fast_paths() {
     assert_eq!(b, MutableBitmap::from_iter([true, false]));
 }
 
+#[test]
+fn assign_methods() {
+    let rhs = Bitmap::from([true, true, false, false]);
+
+    let mut b = MutableBitmap::from([true, false, true, false]);
+    b.and_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from([true, false, false, false]));
+
+    let mut b = MutableBitmap::from([true, false, true, false]);
+    b.or_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from([true, true, true, false]));
+
+    let mut b = MutableBitmap::from([true, false, true, false]);
+    b.xor_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from([false, true, true, false]));
+}
+
+#[test]
+#[should_panic]
+fn assign_methods_different_len() {
+    let mut b = MutableBitmap::from([true, false]);
+    b.and_assign(&Bitmap::from([true, true, true]));
+}
+
 proptest! {
+    /// Asserts that `and_assign` equals `&` of two bitmaps
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn and_assign(b in bitmap_strategy()) {
+        let rhs: Bitmap = b.iter().enumerate().map(|(i, x)| x ^ (i % 3 == 0)).collect();
+        let expected = &b & &rhs;
+
+        let mut b = b.make_mut();
+        b.and_assign(&rhs);
+
+        assert_eq!(Bitmap::from(b), expected);
+    }
+
     /// Asserts that !bitmap equals all bits flipped
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -12,6 +12,7 @@ proptest! {
         let not_bitmap: Bitmap = bitmap.iter().map(|x| !x).collect();
 
         assert_eq!(!&bitmap, not_bitmap);
+        assert_eq!((!&bitmap).unset_bits(), not_bitmap.unset_bits());
     }
 }
 
@@ -39,3 +40,39 @@ fn test_fast_paths() {
     assert_eq!(xor(&all_false, &all_false), all_false);
     assert_eq!(xor(&toggled, &toggled), all_false);
 }
+
+#[test]
+fn set_relationships() {
+    let lhs = Bitmap::from([true, false, false, true]);
+    let rhs = Bitmap::from([true, true, false, true]);
+    assert!(lhs.intersects_with(&rhs));
+    assert!(lhs.is_subset_of(&rhs));
+    assert!(!rhs.is_subset_of(&lhs));
+    assert_eq!(lhs.num_intersections_with(&rhs), 2);
+
+    let rhs = Bitmap::from([false, true, true, false]);
+    assert!(!lhs.intersects_with(&rhs));
+    assert_eq!(lhs.num_intersections_with(&rhs), 0);
+
+    // bits outside the slices are ignored
+    let lhs = Bitmap::from([true; 70]).sliced(3, 60);
+    let rhs = Bitmap::from([false; 70]);
+    assert!(!lhs.intersects_with(&rhs.clone().sliced(5, 60)));
+    assert!(!lhs.is_subset_of(&rhs.sliced(1, 60)));
+}
+
+proptest! {
+    /// Asserts that the set relationships are consistent with `&`
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn set_relationships_consistent(lhs in bitmap_strategy()) {
+        let rhs: Bitmap = lhs.iter().enumerate().map(|(i, x)| x && i % 4 != 0).collect();
+        let and = &lhs & &rhs;
+        let intersections = and.len() - and.unset_bits();
+
+        assert_eq!(lhs.num_intersections_with(&rhs), intersections);
+        assert_eq!(lhs.intersects_with(&rhs), intersections > 0);
+        assert!(rhs.is_subset_of(&lhs));
+        assert_eq!(lhs.is_subset_of(&rhs), and == lhs);
+    }
+}
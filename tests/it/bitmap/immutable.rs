@@ -84,3 +84,38 @@ fn from_arrow() {
     let back = NullBuffer::from(bitmap);
     assert_eq!(nulls, back);
 }
+
+#[test]
+fn runs() {
+    let bitmap = Bitmap::from([false, true, true, false, true, true, true, false, true]);
+    assert_eq!(
+        bitmap.runs().collect::<Vec<_>>(),
+        vec![(1, 2), (4, 3), (8, 1)]
+    );
+
+    let bitmap = bitmap.sliced(2, 6);
+    assert_eq!(bitmap.runs().collect::<Vec<_>>(), vec![(0, 1), (2, 3)]);
+
+    let bitmap = Bitmap::new_zeroed(10);
+    assert_eq!(bitmap.runs().count(), 0);
+}
+
+#[test]
+fn into_mut_sliced() {
+    let bitmap = Bitmap::from([true; 20]).sliced(0, 3);
+    let mut bitmap = bitmap.into_mut().unwrap_right();
+    bitmap.push(false);
+    assert_eq!(
+        Bitmap::from(bitmap),
+        Bitmap::from([true, true, true, false])
+    );
+
+    let bitmap = Bitmap::from([true; 20]).sliced(0, 3);
+    let _shared = bitmap.clone();
+    let mut bitmap = bitmap.make_mut();
+    bitmap.push(false);
+    assert_eq!(
+        Bitmap::from(bitmap),
+        Bitmap::from([true, true, true, false])
+    );
+}
@@ -123,3 +123,45 @@ fn subslicing_gives_correct_null_count() {
     let view3 = view2.sliced(0, 1);
     assert_eq!(view3.unset_bits(), 0);
 }
+
+#[test]
+fn bool_slice() {
+    let values = (0..133)
+        .map(|i| i % 3 == 0 || i % 7 == 0)
+        .collect::<Vec<_>>();
+    let bitmap = Bitmap::from_bool_slice(&values);
+    assert_eq!(
+        bitmap,
+        Bitmap::from_trusted_len_iter(values.iter().copied())
+    );
+    assert_eq!(bitmap.to_bool_vec(), values);
+
+    let bitmap = bitmap.sliced(3, 100);
+    assert_eq!(bitmap.to_bool_vec(), values[3..103]);
+
+    assert!(Bitmap::from_bool_slice(&[]).to_bool_vec().is_empty());
+}
+
+proptest! {
+    /// Asserts that `to_bool_vec` equals collecting the bitmap's iterator
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn to_bool_vec(bitmap in bitmap_strategy()) {
+        let values = bitmap.iter().collect::<Vec<_>>();
+        assert_eq!(bitmap.to_bool_vec(), values);
+        assert_eq!(Bitmap::from_bool_slice(&values), bitmap);
+    }
+}
+
+#[test]
+fn from_u64_chunks() {
+    let chunks = [0x8000_0000_0000_0001u64, u64::MAX];
+    let bitmap = Bitmap::from_u64_chunks(chunks, 100);
+    assert_eq!(bitmap.len(), 100);
+    assert_eq!(bitmap.unset_bits(), 62);
+
+    let expected = (0..100).map(|i| i == 0 || i >= 63).collect::<Bitmap>();
+    assert_eq!(bitmap, expected);
+
+    assert_eq!(Bitmap::from_u64_chunks(std::iter::empty(), 0).len(), 0);
+}
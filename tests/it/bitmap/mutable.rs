@@ -435,3 +435,38 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn extend_from_chunks() {
+    let mut bitmap = MutableBitmap::new();
+    bitmap.extend_from_chunks([u64::MAX, 0b101], 67);
+    assert_eq!(bitmap.len(), 67);
+    assert!(bitmap.iter().take(64).all(|x| x));
+    assert_eq!(
+        bitmap.iter().skip(64).collect::<Vec<_>>(),
+        vec![true, false, true]
+    );
+}
+
+#[test]
+fn extend_from_chunks_unaligned() {
+    let mut bitmap = MutableBitmap::from([true, false, true]);
+    bitmap.extend_from_chunks([0b1011, u64::MAX], 70);
+    assert_eq!(bitmap.len(), 73);
+
+    let mut expected = MutableBitmap::from([true, false, true, true, true, false, true]);
+    expected.extend_constant(60, false);
+    expected.extend_constant(6, true);
+    assert_eq!(bitmap, expected);
+
+    // bits beyond `length` are not exposed by subsequent pushes
+    bitmap.extend_constant(3, false);
+    assert_eq!(bitmap.unset_bits(), 2 + 60 + 3);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_chunks_too_few_bits() {
+    let mut bitmap = MutableBitmap::new();
+    bitmap.extend_from_chunks([0], 65);
+}
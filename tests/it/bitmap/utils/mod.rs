@@ -9,6 +9,7 @@ mod chunk_iter;
 mod fmt;
 mod iterator;
 mod slice_iterator;
+mod true_idx;
 mod zip_validity;
 
 #[test]
@@ -0,0 +1,40 @@
+use proptest::prelude::*;
+
+use arrow2::bitmap::utils::TrueIdxIter;
+use arrow2::bitmap::Bitmap;
+
+use crate::bitmap::bitmap_strategy;
+
+proptest! {
+    /// Asserts that the iterator returns exactly the indices of the set bits
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn check_invariants(bitmap in bitmap_strategy()) {
+        let iter = TrueIdxIter::new(bitmap.len(), Some(&bitmap));
+        assert_eq!(iter.size_hint().0, bitmap.len() - bitmap.unset_bits());
+
+        let result = iter.collect::<Vec<_>>();
+        let expected = bitmap
+            .iter()
+            .enumerate()
+            .filter_map(|(i, is_set)| is_set.then_some(i))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn no_validity() {
+    let iter = TrueIdxIter::new(3, None);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[test]
+fn sliced() {
+    let bitmap = Bitmap::from((0..200).map(|i| i % 3 == 0).collect::<Vec<_>>());
+    let bitmap = bitmap.sliced(5, 150);
+
+    let result = TrueIdxIter::new(bitmap.len(), Some(&bitmap)).collect::<Vec<_>>();
+    let expected = (0..150).filter(|i| (i + 5) % 3 == 0).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,106 @@
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow2::bitmap::Bitmap;
+use arrow2::buffer::{AlignedVec, Buffer, BufferAllocator, GlobalAllocator, ALIGNMENT};
+
+fn is_aligned<T>(values: &[T]) -> bool {
+    values.as_ptr().align_offset(ALIGNMENT) == 0
+}
+
+#[test]
+fn grows_aligned() {
+    let mut values = AlignedVec::<u8>::new();
+    assert!(values.is_empty());
+    for i in 0..1000 {
+        values.push(i as u8);
+        assert!(is_aligned(&values));
+    }
+    assert_eq!(values.len(), 1000);
+    assert!(values.capacity() >= 1000);
+    assert_eq!(values[999], (999 % 256) as u8);
+
+    values.resize(10, 0);
+    values.extend_from_slice(&[1, 2]);
+    values.extend([3, 4]);
+    assert_eq!(values.len(), 14);
+    assert_eq!(&values[10..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn into_buffer() {
+    let values = (0..100).collect::<AlignedVec<i64>>();
+    let ptr = values.as_ptr();
+    let buffer: Buffer<i64> = values.clone().into();
+    assert_eq!(buffer.as_slice(), values.as_ref());
+    assert!(is_aligned(buffer.as_slice()));
+
+    // not copied
+    let buffer: Buffer<i64> = values.into();
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+
+    // the allocation outlives clones and slices of the buffer
+    let sliced = buffer.clone().sliced(1, 2);
+    drop(buffer);
+    assert_eq!(sliced.as_slice(), &[1, 2]);
+
+    let buffer: Buffer<i64> = AlignedVec::new().into();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn into_bitmap() {
+    let bytes = AlignedVec::from([0b0000_0101u8, 0b1].as_ref());
+    let bitmap = Bitmap::try_from_aligned(bytes.clone(), 9).unwrap();
+    assert_eq!(bitmap.unset_bits(), 6);
+    assert!(bitmap.get_bit(0) && !bitmap.get_bit(1) && bitmap.get_bit(8));
+    assert!(is_aligned(bitmap.as_slice().0));
+
+    assert!(Bitmap::try_from_aligned(bytes, 17).is_err());
+}
+
+/// An allocator that counts its live regions.
+#[derive(Clone, Default)]
+struct Counting(Arc<AtomicUsize>);
+
+unsafe impl BufferAllocator for Counting {
+    fn allocate(&self, layout: Layout) -> *mut u8 {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        GlobalAllocator.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: *mut u8, layout: Layout) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+        GlobalAllocator.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn with_allocator() {
+    let allocator = Counting::default();
+    let live = allocator.0.clone();
+
+    let mut values = AlignedVec::<i32, _>::with_allocator(allocator.clone());
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+    for i in 0..100 {
+        values.push(i);
+        assert!(is_aligned(&values));
+    }
+    // reallocating keeps a single live region
+    assert_eq!(live.load(Ordering::SeqCst), 1);
+
+    let buffer: Buffer<i32> = values.into();
+    assert_eq!(buffer.as_slice(), (0..100).collect::<Vec<_>>());
+    let sliced = buffer.clone().sliced(1, 2);
+    drop(buffer);
+    assert_eq!(live.load(Ordering::SeqCst), 1);
+    drop(sliced);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+
+    let bytes = AlignedVec::with_capacity_and_allocator(1, allocator);
+    let bitmap = Bitmap::try_from_aligned(bytes, 0).unwrap();
+    assert!(bitmap.is_empty());
+    drop(bitmap);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
@@ -117,3 +117,125 @@ fn from_arrow_sliced() {
     let back = arrow_buffer::Buffer::from(sliced);
     assert_eq!(back.typed_data::<i32>(), &[2, 3]);
 }
+
+struct Region(Vec<u32>);
+
+impl AsRef<[u8]> for Region {
+    fn as_ref(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.0)
+    }
+}
+
+#[test]
+fn from_region() {
+    let region = std::sync::Arc::new(Region(vec![1, 2, 3]));
+    let buffer = Buffer::<u32>::try_from_region(region.clone()).unwrap();
+    assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+    // the buffer points to the region
+    assert_eq!(buffer.as_ptr(), region.0.as_ptr());
+    assert_eq!(std::sync::Arc::strong_count(&region), 2);
+
+    let mut sliced = buffer.clone().sliced(1, 2);
+    assert_eq!(sliced.as_slice(), &[2, 3]);
+    assert!(sliced.get_mut_slice().is_none());
+
+    drop(buffer);
+    drop(sliced);
+    assert_eq!(std::sync::Arc::strong_count(&region), 1);
+
+    let buffer = Buffer::<u16>::try_from_region(region).unwrap();
+    assert_eq!(buffer.len(), 6);
+}
+
+#[test]
+fn from_region_invalid() {
+    let region = std::sync::Arc::new(Region(vec![1, 2, 3]));
+    assert!(Buffer::<u64>::try_from_region(region).is_err());
+
+    let region = std::sync::Arc::new(Region(vec![]));
+    assert!(Buffer::<u64>::try_from_region(region).unwrap().is_empty());
+}
+
+// a region starting one byte into its allocation
+struct UnalignedRegion(Vec<u32>);
+
+impl AsRef<[u8]> for UnalignedRegion {
+    fn as_ref(&self) -> &[u8] {
+        &bytemuck::cast_slice(&self.0)[1..5]
+    }
+}
+
+#[test]
+fn from_region_unaligned() {
+    let region = std::sync::Arc::new(UnalignedRegion(vec![1, 2]));
+    assert!(Buffer::<u32>::try_from_region(region.clone()).is_err());
+    // bytes have no alignment requirement
+    assert_eq!(Buffer::<u8>::try_from_region(region).unwrap().len(), 4);
+}
+
+struct Owner {
+    data: Vec<i64>,
+    dropped: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.dropped
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn from_owner() {
+    let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let owner = Owner {
+        data: vec![1, 2, 3],
+        dropped: dropped.clone(),
+    };
+    let (ptr, len) = (owner.data.as_ptr(), owner.data.len());
+
+    let buffer = unsafe { Buffer::from_owner(owner, ptr, len) };
+    assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+    assert_eq!(buffer.as_ptr(), ptr);
+
+    let sliced = buffer.clone().sliced(2, 1);
+    drop(buffer);
+    assert!(!dropped.load(std::sync::atomic::Ordering::SeqCst));
+    assert_eq!(sliced.as_slice(), &[3]);
+
+    drop(sliced);
+    assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn from_owner_empty() {
+    let buffer = unsafe { Buffer::<u8>::from_owner((), std::ptr::null(), 0) };
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn into_mut_sliced() {
+    let buffer: Buffer<i32> = vec![1, 2, 3].into();
+    let buffer = buffer.sliced(1, 2);
+    let buffer = buffer.into_mut().unwrap_left();
+    assert_eq!(buffer.as_slice(), &[2, 3]);
+}
+
+#[test]
+fn make_mut() {
+    let buffer: Buffer<i32> = vec![1, 2, 3].into();
+    let ptr = buffer.as_ptr();
+    let vec = buffer.make_mut();
+    // uniquely owned: no clone
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let buffer: Buffer<i32> = vec.into();
+    let shared = buffer.clone();
+    let mut vec = buffer.make_mut();
+    vec[0] = 10;
+    assert_eq!(vec, vec![10, 2, 3]);
+    assert_eq!(shared.as_slice(), &[1, 2, 3]);
+
+    assert_eq!(shared.sliced(1, 1).make_mut(), vec![2]);
+}
@@ -1 +1,3 @@
+#[cfg(feature = "aligned_alloc")]
+mod aligned;
 mod immutable;
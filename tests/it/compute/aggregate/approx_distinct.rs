@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+
+fn assert_approx(estimate: u64, expected: u64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(error < 0.05, "{estimate} is not close to {expected}");
+}
+
+#[test]
+fn small() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+    assert_eq!(approx_distinct(&array).unwrap(), 2);
+
+    let array = Int32Array::from([None, None]);
+    assert_eq!(approx_distinct(&array).unwrap(), 0);
+}
+
+#[test]
+fn large() {
+    let array = Int64Array::from_vec((0..100_000).map(|x| x % 50_000).collect());
+    assert_approx(approx_distinct(&array).unwrap(), 50_000);
+}
+
+#[test]
+fn merge() {
+    let lhs = Int64Array::from_vec((0..30_000).collect());
+    let rhs = Int64Array::from_vec((20_000..50_000).collect());
+
+    let mut sketch = approx_distinct_sketch(&lhs, 14).unwrap();
+    sketch
+        .merge(&approx_distinct_sketch(&rhs, 14).unwrap())
+        .unwrap();
+    let mut expected = approx_distinct_sketch(&lhs, 14).unwrap();
+    expected.update(&rhs).unwrap();
+    assert_eq!(sketch, expected);
+    assert_approx(sketch.finalize(), 50_000);
+
+    let other = approx_distinct_sketch(&rhs, 12).unwrap();
+    assert!(sketch.merge(&other).is_err());
+}
+
+#[test]
+fn bytes() {
+    let array = BinaryArray::<i64>::from_slice([b"a", b"b", b"c"]);
+    let sketch = approx_distinct_sketch(&array, 4).unwrap();
+    let bytes = sketch.to_bytes();
+    assert_eq!(bytes.len(), 2 + 16);
+    assert_eq!(HyperLogLog::from_bytes(&bytes).unwrap(), sketch);
+
+    assert!(HyperLogLog::from_bytes(&bytes[..10]).is_err());
+    assert!(HyperLogLog::from_bytes(&[1]).is_err());
+    assert!(HyperLogLog::from_bytes(&[2, 4]).is_err());
+    let mut invalid = bytes;
+    invalid[2] = 62;
+    assert!(HyperLogLog::from_bytes(&invalid).is_err());
+}
+
+#[test]
+fn invalid() {
+    assert!(HyperLogLog::try_new(3).is_err());
+    assert!(HyperLogLog::try_new(19).is_err());
+    let array = Float32Array::from_slice([1.0]);
+    assert!(approx_distinct(&array).is_err());
+    let array = NullArray::new(DataType::Null, 1);
+    assert!(approx_distinct(&array).is_err());
+}
@@ -0,0 +1,112 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+#[test]
+fn bit_primitive() {
+    let a = UInt8Array::from_slice([0b1110, 0b0111, 0b0110]);
+    assert_eq!(bit_and_primitive(&a), Some(0b0110));
+    assert_eq!(bit_or_primitive(&a), Some(0b1111));
+    assert_eq!(bit_xor_primitive(&a), Some(0b1111));
+
+    let a = Int32Array::from([Some(-1), None, Some(6), None]);
+    assert_eq!(bit_and_primitive(&a), Some(6));
+    assert_eq!(bit_or_primitive(&a), Some(-1));
+    assert_eq!(bit_xor_primitive(&a), Some(!6));
+
+    let a = Int32Array::from([None, None]);
+    assert_eq!(bit_and_primitive(&a), None);
+    assert_eq!(bit_xor_primitive(&Int64Array::from_slice([])), None);
+}
+
+#[test]
+fn bit_dyn() {
+    let a = Int16Array::from([Some(3), None, Some(5)]);
+    let expected = PrimitiveScalar::new(DataType::Int16, Some(1i16));
+    assert_eq!(&expected as &dyn Scalar, bit_and(&a).unwrap().as_ref());
+    let expected = PrimitiveScalar::new(DataType::Int16, Some(7i16));
+    assert_eq!(&expected as &dyn Scalar, bit_or(&a).unwrap().as_ref());
+    let expected = PrimitiveScalar::new(DataType::Int16, Some(6i16));
+    assert_eq!(&expected as &dyn Scalar, bit_xor(&a).unwrap().as_ref());
+}
+
+#[test]
+fn consistency() {
+    use DataType::*;
+    let datatypes = [
+        Int8,
+        Int16,
+        Int32,
+        Int64,
+        UInt8,
+        UInt16,
+        UInt32,
+        UInt64,
+        Date32,
+        Float32,
+        Float64,
+        Decimal(5, 2),
+        Utf8,
+    ];
+    datatypes.into_iter().for_each(|data_type| {
+        let array = new_null_array(data_type.clone(), 2);
+        assert_eq!(
+            can_bit_aggregate(&data_type),
+            bit_and(array.as_ref()).is_ok()
+        );
+        assert_eq!(
+            can_bit_aggregate(&data_type),
+            bit_or(array.as_ref()).is_ok()
+        );
+        assert_eq!(
+            can_bit_aggregate(&data_type),
+            bit_xor(array.as_ref()).is_ok()
+        );
+    });
+}
+
+#[test]
+fn any_all() {
+    let a = BooleanArray::from_slice([true, false]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(false));
+
+    let a = BooleanArray::from([Some(true), None]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(true));
+
+    let a = BooleanArray::from([Some(false), None]);
+    assert_eq!(any(&a), Some(false));
+    assert_eq!(all(&a), Some(false));
+
+    let a = BooleanArray::from([None, None]);
+    assert_eq!(any(&a), None);
+    assert_eq!(all(&a), None);
+    assert_eq!(any(&BooleanArray::from_slice([])), None);
+}
+
+#[test]
+fn any_all_words() {
+    // a `false` and a `true` behind a null in the last word, which is not a full word
+    let mut values = vec![Some(true); 150];
+    values[140] = None;
+    let a = BooleanArray::from(values.clone());
+    assert_eq!(all(&a), Some(true));
+    assert_eq!(any(&a), Some(true));
+
+    // trailing (sliced-off) values are ignored
+    values[145] = Some(false);
+    let a = BooleanArray::from(values.clone());
+    assert_eq!(all(&a), Some(false));
+    assert_eq!(all(&a.clone().sliced(1, 140)), Some(true));
+    assert_eq!(all(&a.clone().sliced(3, 141)), Some(true));
+
+    let mut values = vec![Some(false); 150];
+    values[0] = None;
+    values[130] = Some(true);
+    let a = BooleanArray::from(values);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(any(&a.clone().sliced(0, 130)), Some(false));
+    assert_eq!(any(&a.clone().sliced(1, 129)), Some(false));
+}
@@ -1,6 +1,6 @@
 use arrow2::{
     array::*,
-    compute::aggregate::estimated_bytes_size,
+    compute::aggregate::{estimated_bytes_size, get_heap_size, get_heap_size_many},
     datatypes::{DataType, Field},
 };
 
@@ -30,3 +30,54 @@ fn fixed_size_list() {
     let a = FixedSizeListArray::new(data_type, values, None);
     assert_eq!(6 * std::mem::size_of::<f32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn heap_size_sliced() {
+    let a = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    let sliced = a.clone().sliced(1, 2);
+    assert_eq!(
+        2 * std::mem::size_of::<i32>(),
+        estimated_bytes_size(&sliced)
+    );
+    assert_eq!(5 * std::mem::size_of::<i32>(), get_heap_size(&sliced));
+}
+
+#[test]
+fn heap_size_validity() {
+    let a = Int32Array::from([Some(1), None, Some(3)]);
+    assert_eq!(3 * std::mem::size_of::<i32>() + 1, get_heap_size(&a));
+}
+
+#[test]
+fn heap_size_utf8() {
+    let a = Utf8Array::<i32>::from_slice(["aaa", "b"]);
+    assert_eq!(4 + 3 * std::mem::size_of::<i32>(), get_heap_size(&a));
+}
+
+#[test]
+fn heap_size_shared() {
+    let a = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    let b = a.clone().sliced(2, 3);
+    assert_eq!(
+        5 * std::mem::size_of::<i32>(),
+        get_heap_size_many(&[&a, &b])
+    );
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]);
+    let array = StructArray::new(data_type, vec![a.clone().boxed(), a.boxed()], None);
+    assert_eq!(5 * std::mem::size_of::<i32>(), get_heap_size(&array));
+}
+
+#[test]
+fn heap_size_dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]).boxed();
+    let keys = Int32Array::from_slice([0, 1, 1, 0]);
+    let a = DictionaryArray::try_from_keys(keys, values).unwrap();
+    assert_eq!(
+        4 * std::mem::size_of::<i32>() + 3 + 3 * std::mem::size_of::<i32>(),
+        get_heap_size(&a)
+    );
+}
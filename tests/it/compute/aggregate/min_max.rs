@@ -1,7 +1,9 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max, max_binary, max_boolean, max_f16, max_primitive, max_string, min, min_binary, min_boolean,
+    min_f16, min_primitive, min_string,
 };
+use arrow2::scalar::{BinaryScalar, PrimitiveScalar, Scalar};
+use arrow2::types::f16;
 use arrow2::{array::*, datatypes::DataType};
 
 #[test]
@@ -39,6 +41,22 @@ fn min_max_f32() {
     assert_eq!(Some(5.0), max_primitive(&a));
 }
 
+#[test]
+fn min_max_f16() {
+    let a = PrimitiveArray::from([
+        None,
+        Some(f16::from_f32(5.0)),
+        Some(f16::from_f32(-2.5)),
+        Some(f16::from_f32(0.5)),
+    ]);
+    assert_eq!(Some(f16::from_f32(-2.5)), min_f16(&a));
+    assert_eq!(Some(f16::from_f32(5.0)), max_f16(&a));
+
+    let result = max(&a).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Float16, Some(f16::from_f32(5.0)));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+}
+
 #[test]
 fn min_max_f64() {
     let a = Float64Array::from(&[None, None, Some(5.0), Some(2.0)]);
@@ -228,6 +246,35 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
 
+#[test]
+fn test_binary_min_max_common_prefix() {
+    // values that are only ordered past their first 8 bytes, or by their length
+    let a = BinaryArray::<i32>::from_slice([
+        b"abcdefghb".as_ref(),
+        b"abcdefgh",
+        b"abcdefghc",
+        b"abcdefgha",
+        b"abcdefgh\0",
+        b"abc",
+        b"abc\0",
+    ]);
+    assert_eq!(Some(b"abc".as_ref()), min_binary(&a));
+    assert_eq!(Some(b"abcdefghc".as_ref()), max_binary(&a));
+
+    let a = Utf8Array::<i64>::from([Some("zz"), None, Some("\u{ff}a"), Some("\u{ff}")]);
+    assert_eq!(Some("zz"), min_string(&a));
+    assert_eq!(Some("\u{ff}a"), max_string(&a));
+}
+
+#[test]
+fn test_large_binary_dyn() {
+    let a = BinaryArray::<i64>::from_slice([b"b", b"a", b"c"]);
+    let expected = BinaryScalar::<i64>::new(Some(b"a"));
+    assert_eq!(&expected as &dyn Scalar, min(&a).unwrap().as_ref());
+    let expected = BinaryScalar::<i64>::new(Some(b"c"));
+    assert_eq!(&expected as &dyn Scalar, max(&a).unwrap().as_ref());
+}
+
 #[test]
 fn test_max_not_lexi() {
     let values = [0, 10, 0, 0, 0, 0, 0, 0, 1, 0];
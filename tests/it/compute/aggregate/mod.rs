@@ -1,3 +1,8 @@
+#[cfg(feature = "compute_hash")]
+mod approx_distinct;
+mod bitwise;
 mod memory;
 mod min_max;
+mod quantile;
 mod sum;
+mod variance;
@@ -0,0 +1,117 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+#[test]
+fn median_() {
+    let a = Int32Array::from_slice([5, 1, 4, 2, 3]);
+    assert_eq!(median_primitive(&a), Some(3.0));
+
+    let a = Int32Array::from([Some(5), None, Some(1), Some(4), Some(2)]);
+    assert_eq!(median_primitive(&a), Some(3.0));
+
+    let a = Int32Array::from([None, None]);
+    assert_eq!(median_primitive(&a), None);
+}
+
+#[test]
+fn interpolation() {
+    let a = Float64Array::from_slice([4.0, 1.0, 3.0, 2.0]);
+    // the position of the quantile 0.5 is 1.5, between 2.0 and 3.0
+    let cases = [
+        (QuantileInterpolation::Linear, 2.5),
+        (QuantileInterpolation::Lower, 2.0),
+        (QuantileInterpolation::Higher, 3.0),
+        (QuantileInterpolation::Nearest, 3.0),
+        (QuantileInterpolation::Midpoint, 2.5),
+    ];
+    for (interpolation, expected) in cases {
+        assert_eq!(
+            quantile_primitive(&a, 0.5, interpolation).unwrap(),
+            Some(expected)
+        );
+    }
+    assert_eq!(
+        quantile_primitive(&a, 0.1, QuantileInterpolation::Linear).unwrap(),
+        Some(1.3)
+    );
+    assert_eq!(
+        quantile_primitive(&a, 0.1, QuantileInterpolation::Nearest).unwrap(),
+        Some(1.0)
+    );
+    assert!(quantile_primitive(&a, 1.5, QuantileInterpolation::Linear).is_err());
+    assert!(quantile_primitive(&a, f64::NAN, QuantileInterpolation::Linear).is_err());
+}
+
+#[test]
+fn multiple() {
+    let values = (0..1000).rev().map(|x| x as u16).collect::<Vec<_>>();
+    let a = UInt16Array::from_vec(values);
+    let result = quantiles_primitive(&a, &[1.0, 0.0, 0.25, 0.5, 0.999], Default::default());
+    assert_eq!(
+        result.unwrap(),
+        Some(vec![999.0, 0.0, 249.75, 499.5, 998.001])
+    );
+}
+
+#[test]
+fn dyn_() {
+    let a = Int64Array::from([Some(1), None, Some(3)]);
+    let expected = PrimitiveScalar::new(DataType::Float64, Some(2.0f64));
+    assert_eq!(&expected as &dyn Scalar, median(&a).unwrap().as_ref());
+
+    let result = quantiles(&a, &[0.0, 0.5], QuantileInterpolation::Linear).unwrap();
+    assert_eq!(Float64Array::from_slice([1.0, 2.0]), result.as_ref());
+
+    let a = Float32Array::from([None]);
+    let result = quantiles(&a, &[0.0, 0.5], QuantileInterpolation::Linear).unwrap();
+    assert_eq!(Float64Array::from([None, None]), result.as_ref());
+
+    assert!(median(&Utf8Array::<i32>::from_slice(["a"])).is_err());
+}
+
+#[test]
+fn dyn_discrete() {
+    // discrete quantiles are values of the array, of its type
+    let a = Int64Array::from([Some(1), None, Some(3)]).to(DataType::Date64);
+    let result = quantile(&a, 1.0, QuantileInterpolation::Lower).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Date64, Some(3i64));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+
+    let result = quantiles(&a, &[0.25, 0.75], QuantileInterpolation::Nearest).unwrap();
+    let expected = Int64Array::from_slice([1, 3]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+
+    let a = Int8Array::from([None]);
+    let result = quantiles(&a, &[0.5], QuantileInterpolation::Higher).unwrap();
+    assert_eq!(Int8Array::from([None]), result.as_ref());
+}
+
+#[test]
+fn precision() {
+    // above 2^53, distinct integers are not distinct `f64`s
+    let large = (1i64 << 53) + 1;
+    let a = Int64Array::from_slice([large + 2, large, large + 1]);
+    let result = quantile(&a, 0.5, QuantileInterpolation::Lower).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Int64, Some(large + 1));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+
+    let a = UInt64Array::from_slice([u64::MAX, 0, u64::MAX - 1]);
+    let result = quantiles(&a, &[0.5, 1.0], QuantileInterpolation::Higher).unwrap();
+    assert_eq!(
+        UInt64Array::from_slice([u64::MAX - 1, u64::MAX]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn nans() {
+    // NaNs, of either sign, are greater than all other values
+    let a = Float64Array::from_slice([f64::NAN, 2.0, -f64::NAN, 1.0]);
+    let result = quantiles_primitive(&a, &[0.0, 0.5, 1.0], QuantileInterpolation::Lower)
+        .unwrap()
+        .unwrap();
+    assert_eq!(result[..2], [1.0, 2.0]);
+    assert!(result[2].is_nan());
+}
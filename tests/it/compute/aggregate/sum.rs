@@ -1,8 +1,13 @@
+#![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
+
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{
+    sum, sum_decimal, sum_decimal_type, sum_f16, sum_primitive, DecimalSumOptions,
+};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::{f16, i256};
 
 #[test]
 fn test_primitive_array_sum() {
@@ -52,3 +57,78 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn decimal_type() {
+    let result = sum_decimal_type(&DataType::Decimal(10, 2), false).unwrap();
+    assert_eq!(result, DataType::Decimal(20, 2));
+    let result = sum_decimal_type(&DataType::Decimal(30, 2), false).unwrap();
+    assert_eq!(result, DataType::Decimal(38, 2));
+    let result = sum_decimal_type(&DataType::Decimal(30, 2), true).unwrap();
+    assert_eq!(result, DataType::Decimal256(40, 2));
+    assert!(sum_decimal_type(&DataType::Int32, false).is_err());
+}
+
+#[test]
+fn decimal() {
+    let a = PrimitiveArray::from([Some(1_25i128), None, Some(-0_50)]).to(DataType::Decimal(5, 2));
+    let result = sum_decimal(&a, DecimalSumOptions::default()).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Decimal(15, 2), Some(0_75i128));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+
+    let a = PrimitiveArray::<i128>::from([None, None]).to(DataType::Decimal(5, 2));
+    let result = sum_decimal(&a, DecimalSumOptions::default()).unwrap();
+    let expected = PrimitiveScalar::<i128>::new(DataType::Decimal(15, 2), None);
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+}
+
+#[test]
+fn decimal_overflow() {
+    let max = i128::MAX;
+    let a = PrimitiveArray::from_slice([max, max, 1, -max]).to(DataType::Decimal(38, 0));
+
+    // the intermediate sums overflow 128 bits
+    let result = sum_decimal(&a, DecimalSumOptions::default());
+    assert!(result.is_err());
+
+    let options = DecimalSumOptions {
+        null_on_overflow: true,
+        ..Default::default()
+    };
+    let result = sum_decimal(&a, options).unwrap();
+    assert!(!result.is_valid());
+
+    let options = DecimalSumOptions {
+        widen: true,
+        ..Default::default()
+    };
+    let result = sum_decimal(&a, options).unwrap();
+    let expected = PrimitiveScalar::new(
+        DataType::Decimal256(48, 0),
+        Some(i256(ethnum::I256::from(max) + 1)),
+    );
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+
+    // the intermediate sums overflow but the sum fits
+    let a = PrimitiveArray::from_slice([max, max, -max, -max, 5]).to(DataType::Decimal(38, 0));
+    let result = sum_decimal(&a, DecimalSumOptions::default()).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Decimal(38, 0), Some(5i128));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+}
+
+#[test]
+fn float16() {
+    let a = PrimitiveArray::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(2.25))]);
+    assert_eq!(sum_f16(&a), Some(f16::from_f32(3.75)));
+
+    let result = sum(&a).unwrap();
+    let expected = PrimitiveScalar::new(DataType::Float16, Some(f16::from_f32(3.75)));
+    assert_eq!(&expected as &dyn Scalar, result.as_ref());
+
+    // accumulated in `f32`, since `2048 + 1` is `2048` in `f16`
+    let a = PrimitiveArray::from_vec(vec![f16::from_f32(1.0); 2051]);
+    assert_eq!(sum_f16(&a), Some(f16::from_f32(2052.0)));
+
+    let a = PrimitiveArray::<f16>::from([None, None]);
+    assert_eq!(sum_f16(&a), None);
+}
@@ -0,0 +1,65 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+fn assert_close(actual: Option<f64>, expected: f64) {
+    let actual = actual.unwrap();
+    assert!(
+        (actual - expected).abs() <= expected.abs() * 1e-12,
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from_slice([2, 4, 4, 4, 5, 5, 7, 9]);
+    assert_close(var_pop_primitive(&a), 4.0);
+    assert_close(stddev_pop_primitive(&a), 2.0);
+    assert_close(var_primitive(&a), 32.0 / 7.0);
+    assert_close(stddev_primitive(&a), (32.0f64 / 7.0).sqrt());
+}
+
+#[test]
+fn with_nulls() {
+    let a = Float32Array::from([Some(1.0), None, Some(3.0), None]);
+    assert_close(var_primitive(&a), 2.0);
+    assert_close(var_pop_primitive(&a), 1.0);
+
+    let a = Float64Array::from([Some(1.0), None]);
+    assert_eq!(var_primitive(&a), None);
+    assert_eq!(var_pop_primitive(&a), Some(0.0));
+
+    let a = Float64Array::from([None, None]);
+    assert_eq!(var_pop_primitive(&a), None);
+    assert_eq!(stddev_primitive(&Float64Array::from_slice([])), None);
+}
+
+#[test]
+fn many_chunks_stable() {
+    // a large offset loses all precision in the naive `E[x^2] - E[x]^2` formula
+    let values = (0..10_000)
+        .map(|x| Some(1e9 + (x % 4) as f64))
+        .chain(std::iter::once(None))
+        .collect::<Vec<_>>();
+    let a = Float64Array::from(values);
+    assert_close(var_pop_primitive(&a), 1.25);
+    assert_close(var_pop_primitive(&a.sliced(1, 8)), 1.25);
+}
+
+#[test]
+fn dyn_() {
+    let a = UInt8Array::from_slice([1, 2, 3, 4]);
+    let expected = PrimitiveScalar::new(DataType::Float64, Some(1.25f64));
+    assert_eq!(&expected as &dyn Scalar, var_pop(&a).unwrap().as_ref());
+
+    let expected = PrimitiveScalar::<f64>::new(DataType::Float64, None);
+    let a = Int64Array::from([Some(1), None]);
+    assert_eq!(&expected as &dyn Scalar, stddev(&a).unwrap().as_ref());
+    assert!(stddev_pop(&a).is_ok());
+    assert!(var(&a).is_ok());
+
+    let a = PrimitiveArray::from_slice([1i128]).to(DataType::Decimal(5, 2));
+    assert!(var(&a).is_err());
+    assert!(!can_var(&DataType::Utf8));
+}
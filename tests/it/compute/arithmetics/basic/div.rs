@@ -1,6 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::basic::*;
-use arrow2::compute::arithmetics::{ArrayCheckedDiv, ArrayDiv};
+use arrow2::compute::arithmetics::{
+    ArrayCheckedDiv, ArrayDiv, ArrayOverflowingDiv, ArraySaturatingDiv, ArrayWrappingDiv,
+};
 
 #[test]
 #[should_panic]
@@ -99,3 +101,59 @@ fn test_div_scalar_checked() {
     let result = a.checked_div(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_div_wrapping() {
+    let a = Int8Array::from(&[Some(-128i8), None, Some(9i8)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(0i8), Some(2i8)]);
+    let result = wrapping_div(&a, &b);
+    let expected = Int8Array::from(&[Some(-128i8), None, Some(4i8)]);
+    assert_eq!(result, expected);
+
+    // Trait testing
+    let result = a.wrapping_div(&b);
+    assert_eq!(result, expected);
+
+    let result = a.wrapping_div(&-1i8);
+    let expected = Int8Array::from(&[Some(-128i8), None, Some(-9i8)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_div_saturating() {
+    let a = Int8Array::from(&[Some(-128i8), None, Some(9i8)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(0i8), Some(2i8)]);
+    let result = saturating_div(&a, &b);
+    let expected = Int8Array::from(&[Some(127i8), None, Some(4i8)]);
+    assert_eq!(result, expected);
+
+    // Trait testing
+    let result = a.saturating_div(&b);
+    assert_eq!(result, expected);
+
+    let result = a.saturating_div(&-1i8);
+    let expected = Int8Array::from(&[Some(127i8), None, Some(-9i8)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_div_overflowing() {
+    let a = Int8Array::from(&[Some(-128i8), None, Some(9i8)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(0i8), Some(2i8)]);
+    let (result, overflow) = overflowing_div(&a, &b);
+    let expected = Int8Array::from(&[Some(-128i8), None, Some(4i8)]);
+    assert_eq!(result, expected);
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+
+    // Trait testing
+    let (result, overflow) = a.overflowing_div(&-1i8);
+    let expected = Int8Array::from(&[Some(-128i8), None, Some(-9i8)]);
+    assert_eq!(result, expected);
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+}
@@ -3,4 +3,5 @@ mod div;
 mod mul;
 mod pow;
 mod rem;
+mod sign;
 mod sub;
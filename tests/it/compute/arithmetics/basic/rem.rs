@@ -1,6 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::basic::*;
-use arrow2::compute::arithmetics::{ArrayCheckedRem, ArrayRem};
+use arrow2::compute::arithmetics::{
+    ArrayCheckedRem, ArrayOverflowingRem, ArrayRem, ArrayWrappingRem,
+};
 
 #[test]
 #[should_panic]
@@ -99,3 +101,42 @@ fn test_rem_scalar_checked() {
     let result = a.checked_rem(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_rem_wrapping() {
+    let a = Int8Array::from(&[Some(-128i8), None, Some(9i8)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(0i8), Some(2i8)]);
+    let result = wrapping_rem(&a, &b);
+    let expected = Int8Array::from(&[Some(0i8), None, Some(1i8)]);
+    assert_eq!(result, expected);
+
+    // Trait testing
+    let result = a.wrapping_rem(&b);
+    assert_eq!(result, expected);
+
+    let result = a.wrapping_rem(&-1i8);
+    let expected = Int8Array::from(&[Some(0i8), None, Some(0i8)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rem_overflowing() {
+    let a = Int8Array::from(&[Some(-128i8), None, Some(9i8)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(0i8), Some(2i8)]);
+    let (result, overflow) = overflowing_rem(&a, &b);
+    let expected = Int8Array::from(&[Some(0i8), None, Some(1i8)]);
+    assert_eq!(result, expected);
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+
+    // Trait testing
+    let (result, overflow) = a.overflowing_rem(&-1i8);
+    let expected = Int8Array::from(&[Some(0i8), None, Some(0i8)]);
+    assert_eq!(result, expected);
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+}
@@ -0,0 +1,64 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_abs() {
+    let a = Int32Array::from(&[Some(-1), None, Some(2), Some(i32::MAX), Some(-i32::MAX)]);
+    let actual = abs(&a);
+    let expected = Int32Array::from(&[Some(1), None, Some(2), Some(i32::MAX), Some(i32::MAX)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[should_panic]
+fn test_abs_overflow() {
+    let a = Int64Array::from(&[Some(i64::MIN)]);
+    abs(&a);
+}
+
+#[test]
+fn test_checked_abs() {
+    let a = Int64Array::from(&[Some(-1), None, Some(i64::MIN), Some(i64::MAX)]);
+    let actual = checked_abs(&a);
+    let expected = Int64Array::from(&[Some(1), None, None, Some(i64::MAX)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_wrapping_abs() {
+    let a = Int16Array::from(&[Some(-1), None, Some(i16::MIN)]);
+    let actual = wrapping_abs(&a);
+    let expected = Int16Array::from(&[Some(1), None, Some(i16::MIN)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_signum() {
+    let a = Int8Array::from(&[Some(-7), None, Some(0), Some(i8::MAX), Some(i8::MIN)]);
+    let actual = signum(&a);
+    let expected = Int8Array::from(&[Some(-1), None, Some(0), Some(1), Some(-1)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_float() {
+    let a = Float32Array::from(&[Some(-1.5), None, Some(-0.0), Some(f32::INFINITY)]);
+    let expected = Float32Array::from(&[Some(1.5), None, Some(0.0), Some(f32::INFINITY)]);
+    assert_eq!(expected, abs(&a));
+    assert_eq!(expected, checked_abs(&a));
+    assert_eq!(expected, wrapping_abs(&a));
+
+    let expected = Float32Array::from(&[Some(-1.0), None, Some(0.0), Some(1.0)]);
+    assert_eq!(expected, signum(&a));
+
+    let a = Float64Array::from_slice([f64::NAN]);
+    assert!(signum(&a).value(0).is_nan());
+}
+
+#[test]
+fn test_checked_negate() {
+    let a = Int64Array::from(&[Some(-1), None, Some(i64::MIN)]);
+    let actual = checked_negate(&a);
+    let expected = Int64Array::from(&[Some(1), None, None]);
+    assert_eq!(expected, actual);
+}
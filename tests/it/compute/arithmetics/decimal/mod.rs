@@ -1,4 +1,6 @@
 mod add;
 mod div;
 mod mul;
+mod sign;
+mod sql;
 mod sub;
@@ -0,0 +1,55 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal::{abs, checked_abs, checked_negate, negate, signum};
+use arrow2::datatypes::DataType;
+use arrow2::error::Error;
+
+#[test]
+fn test_negate() {
+    let a = PrimitiveArray::from([Some(99999i128), None, Some(-1i128), Some(0i128)])
+        .to(DataType::Decimal(5, 2));
+    let result = negate(&a).unwrap();
+    let expected = PrimitiveArray::from([Some(-99999i128), None, Some(1i128), Some(0i128)])
+        .to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+    assert_eq!(checked_negate(&a), expected);
+}
+
+#[test]
+fn test_negate_overflow() {
+    let a = PrimitiveArray::from([Some(1i128), Some(i128::MIN)]).to(DataType::Decimal(38, 0));
+    assert!(matches!(negate(&a), Err(Error::Overflow)));
+    let expected = PrimitiveArray::from([Some(-1i128), None]).to(DataType::Decimal(38, 0));
+    assert_eq!(checked_negate(&a), expected);
+
+    // a value out of the precision does not fit after negation either
+    let a = PrimitiveArray::from([Some(100000i128)]).to(DataType::Decimal(5, 2));
+    assert!(negate(&a).is_err());
+    assert_eq!(checked_negate(&a).null_count(), 1);
+}
+
+#[test]
+fn test_abs() {
+    let a = PrimitiveArray::from([Some(-99999i128), None, Some(1i128)]).to(DataType::Decimal(5, 2));
+    let result = abs(&a).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(99999i128), None, Some(1i128)]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+    assert_eq!(checked_abs(&a), expected);
+}
+
+#[test]
+fn test_abs_overflow() {
+    let a =
+        PrimitiveArray::from([Some(-1i128), None, Some(i128::MIN)]).to(DataType::Decimal(38, 0));
+    assert!(matches!(abs(&a), Err(Error::Overflow)));
+    let expected = PrimitiveArray::from([Some(1i128), None, None]).to(DataType::Decimal(38, 0));
+    assert_eq!(checked_abs(&a), expected);
+}
+
+#[test]
+fn test_signum() {
+    let a = PrimitiveArray::from([Some(-5i128), None, Some(0i128), Some(99i128)])
+        .to(DataType::Decimal(2, 2));
+    let expected = Int8Array::from([Some(-1), None, Some(0), Some(1)]);
+    assert_eq!(signum(&a), expected);
+}
@@ -0,0 +1,111 @@
+#![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::decimal::{
+    sql_add, sql_div, sql_mul, sql_result_type, sql_sub, DecimalOperator, SqlDecimalOptions,
+};
+use arrow2::datatypes::DataType;
+use arrow2::types::i256;
+
+#[test]
+fn result_type() {
+    use DecimalOperator::*;
+    let cases = [
+        (Add, (5, 2), (4, 3), DataType::Decimal(7, 3)),
+        (Sub, (38, 0), (38, 10), DataType::Decimal(38, 6)),
+        (Mul, (10, 2), (5, 3), DataType::Decimal(16, 5)),
+        (Mul, (38, 10), (38, 10), DataType::Decimal(38, 6)),
+        (Div, (5, 2), (3, 0), DataType::Decimal(9, 6)),
+        (Div, (10, 4), (10, 2), DataType::Decimal(23, 15)),
+    ];
+    for (op, (lhs_p, lhs_s), (rhs_p, rhs_s), expected) in cases {
+        let lhs = DataType::Decimal(lhs_p, lhs_s);
+        let rhs = DataType::Decimal(rhs_p, rhs_s);
+        assert_eq!(sql_result_type(op, &lhs, &rhs, false).unwrap(), expected);
+    }
+
+    let lhs = DataType::Decimal(38, 10);
+    assert_eq!(
+        sql_result_type(Mul, &lhs, &lhs, true).unwrap(),
+        DataType::Decimal256(76, 19)
+    );
+    assert!(sql_result_type(Add, &DataType::Int32, &lhs, false).is_err());
+}
+
+#[test]
+fn add_sub_different_scales() {
+    let a = PrimitiveArray::from([Some(1_25i128), Some(-99_99), None]).to(DataType::Decimal(4, 2));
+    let b =
+        PrimitiveArray::from([Some(1_125i128), Some(-9_999), Some(1)]).to(DataType::Decimal(4, 3));
+
+    let result = sql_add(&a, &b, SqlDecimalOptions::default()).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(2_375i128), Some(-109_989), None]).to(DataType::Decimal(6, 3));
+    assert_eq!(expected, result.as_ref());
+
+    let result = sql_sub(&a, &b, SqlDecimalOptions::default()).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(0_125i128), Some(-89_991), None]).to(DataType::Decimal(6, 3));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn mul_rounds_reduced_scale() {
+    // 1.0000000005 * 1.0000000005 = 1.00000000100000000025, rounded to 6 digits
+    let a = PrimitiveArray::from([Some(1_0000000005i128), Some(-1_5000005000)])
+        .to(DataType::Decimal(38, 10));
+    let result = sql_mul(&a, &a, SqlDecimalOptions::default()).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(1_000000i128), Some(2_250002)]).to(DataType::Decimal(38, 6));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn mul_widen() {
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(max)]).to(DataType::Decimal(38, 0));
+    let options = SqlDecimalOptions {
+        widen: true,
+        ..Default::default()
+    };
+    let result = sql_mul(&a, &a, options).unwrap();
+
+    let expected = ethnum::I256::from(max) * ethnum::I256::from(max);
+    let expected = PrimitiveArray::from([Some(i256(expected))]).to(DataType::Decimal256(76, 0));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn overflow() {
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(max), Some(1)]).to(DataType::Decimal(38, 0));
+
+    assert!(sql_add(&a, &a, SqlDecimalOptions::default()).is_err());
+
+    let options = SqlDecimalOptions {
+        null_on_overflow: true,
+        ..Default::default()
+    };
+    let result = sql_add(&a, &a, options).unwrap();
+    let expected = PrimitiveArray::from([None, Some(2i128)]).to(DataType::Decimal(38, 0));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn div() {
+    let a = PrimitiveArray::from([Some(2_00i128), Some(-1_00), Some(1_00), None])
+        .to(DataType::Decimal(5, 2));
+    let b =
+        PrimitiveArray::from([Some(3i128), Some(8), Some(0), Some(0)]).to(DataType::Decimal(3, 0));
+
+    assert!(sql_div(&a, &b, SqlDecimalOptions::default()).is_err());
+
+    let options = SqlDecimalOptions {
+        null_on_overflow: true,
+        ..Default::default()
+    };
+    let result = sql_div(&a, &b, options).unwrap();
+    let expected = PrimitiveArray::from([Some(0_666667i128), Some(-0_125000), None, None])
+        .to(DataType::Decimal(9, 6));
+    assert_eq!(expected, result.as_ref());
+}
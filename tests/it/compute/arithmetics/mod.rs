@@ -7,6 +7,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::f16;
 
 #[test]
 fn test_add() {
@@ -39,6 +40,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -108,3 +110,134 @@ fn test_neg_dict() {
     .unwrap();
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+#[cfg(feature = "compute_cast")]
+fn test_arithmetic_coercion() {
+    let a = Int32Array::from(&[Some(1), None, Some(3)]);
+    let b = Int64Array::from_slice([10, 20, 30]);
+    let result = arithmetic(&a, Operator::Sub, &b).unwrap();
+    assert_eq!(
+        Int64Array::from(&[Some(-9), None, Some(-27)]),
+        result.as_ref()
+    );
+
+    let b = UInt32Array::from_slice([1, 2, 3]);
+    let result = arithmetic(&a, Operator::Mul, &b).unwrap();
+    assert_eq!(Int64Array::from(&[Some(1), None, Some(9)]), result.as_ref());
+
+    let b = Float32Array::from_slice([0.5, 0.5, 0.5]);
+    let result = arithmetic(&a, Operator::Add, &b).unwrap();
+    assert_eq!(
+        Float64Array::from(&[Some(1.5), None, Some(3.5)]),
+        result.as_ref()
+    );
+
+    let a = Int8Array::from_slice([1, 2, 3]);
+    let result = arithmetic(&a, Operator::Div, &b).unwrap();
+    assert_eq!(Float32Array::from_slice([2.0, 4.0, 6.0]), result.as_ref());
+}
+
+#[test]
+#[cfg(feature = "compute_cast")]
+fn test_arithmetic_decimal() {
+    let a = PrimitiveArray::from_slice([100i128, 250]).to(Decimal(5, 2));
+    let b = Int32Array::from_slice([1, 2]);
+    let result = arithmetic(&a, Operator::Add, &b).unwrap();
+    assert_eq!(result.data_type(), &Decimal(13, 2));
+    assert_eq!(
+        PrimitiveArray::from_slice([200i128, 450]).to(Decimal(13, 2)),
+        result.as_ref()
+    );
+
+    let b = Float64Array::from_slice([0.5, 0.5]);
+    let result = arithmetic(&a, Operator::Mul, &b).unwrap();
+    assert_eq!(Float64Array::from_slice([0.5, 1.25]), result.as_ref());
+
+    let a = UInt64Array::from_slice([u64::MAX]);
+    let b = Int8Array::from_slice([-1]);
+    assert_eq!(
+        coerce_types(a.data_type(), Operator::Add, b.data_type()).unwrap(),
+        (Decimal(20, 0), Decimal(20, 0))
+    );
+    let result = arithmetic(&a, Operator::Add, &b).unwrap();
+    assert_eq!(
+        PrimitiveArray::from_slice([u64::MAX as i128 - 1]).to(Decimal(21, 0)),
+        result.as_ref()
+    );
+}
+
+#[test]
+#[cfg(feature = "compute_cast")]
+fn test_arithmetic_temporal() {
+    let timestamp =
+        PrimitiveArray::from_slice([1_000i64, 2_000]).to(Timestamp(TimeUnit::Second, None));
+    let duration = PrimitiveArray::from_slice([1i64, 2]).to(Duration(TimeUnit::Second));
+    let expected =
+        PrimitiveArray::from_slice([1_001i64, 2_002]).to(Timestamp(TimeUnit::Second, None));
+
+    let result = arithmetic(&timestamp, Operator::Add, &duration).unwrap();
+    assert_eq!(expected, result.as_ref());
+    let result = arithmetic(&duration, Operator::Add, &timestamp).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    assert!(arithmetic(&duration, Operator::Sub, &timestamp).is_err());
+}
+
+#[test]
+#[cfg(feature = "compute_cast")]
+fn test_arithmetic_incompatible() {
+    let a = Int32Array::from_slice([1]);
+    let b = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(arithmetic(&a, Operator::Add, &b).is_err());
+
+    let b = Int32Array::from_slice([1, 2]);
+    assert!(arithmetic(&a, Operator::Add, &b).is_err());
+
+    let a = PrimitiveArray::from_slice([1i128]).to(Decimal(5, 2));
+    let b = PrimitiveArray::from_slice([1i128]).to(Decimal(5, 2));
+    assert!(arithmetic(&a, Operator::Rem, &b).is_err());
+}
+
+#[test]
+fn test_f16() {
+    let a = PrimitiveArray::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-3.0))]);
+    let b = PrimitiveArray::from([Some(f16::from_f32(2.0)), Some(f16::from_f32(1.0)), None]);
+    let expected = PrimitiveArray::from([Some(f16::from_f32(3.5)), None, None]);
+    assert_eq!(expected, add(&a, &b).as_ref());
+    let expected = PrimitiveArray::from([Some(f16::from_f32(0.75)), None, None]);
+    assert_eq!(expected, div(&a, &b).as_ref());
+
+    let scalar = PrimitiveScalar::new(Float16, Some(f16::from_f32(2.0)));
+    let expected =
+        PrimitiveArray::from([Some(f16::from_f32(3.0)), None, Some(f16::from_f32(-6.0))]);
+    assert_eq!(expected, mul_scalar(&a, &scalar).as_ref());
+
+    let expected =
+        PrimitiveArray::from([Some(f16::from_f32(-1.5)), None, Some(f16::from_f32(3.0))]);
+    assert_eq!(expected, neg(&a).as_ref());
+
+    // rounded to the nearest `f16`
+    let a = PrimitiveArray::from_slice([f16::from_f32(2048.0), f16::from_f32(60000.0)]);
+    let b = PrimitiveArray::from_slice([f16::from_f32(1.0), f16::from_f32(10000.0)]);
+    let expected =
+        PrimitiveArray::from_slice([f16::from_f32(2048.0), f16::from_f32(f32::INFINITY)]);
+    assert_eq!(expected, add(&a, &b).as_ref());
+}
+
+#[test]
+#[cfg(feature = "compute_cast")]
+fn test_arithmetic_f16() {
+    let a = PrimitiveArray::from([Some(f16::from_f32(1.5)), None]);
+    let b = Int8Array::from_slice([2, 3]);
+    let result = arithmetic(&a, Operator::Mul, &b).unwrap();
+    assert_eq!(Float32Array::from([Some(3.0), None]), result.as_ref());
+
+    let b = Float32Array::from_slice([0.25, 0.5]);
+    let result = arithmetic(&a, Operator::Add, &b).unwrap();
+    assert_eq!(Float32Array::from([Some(1.75), None]), result.as_ref());
+
+    let b = Int32Array::from_slice([2, 3]);
+    let result = arithmetic(&a, Operator::Sub, &b).unwrap();
+    assert_eq!(Float64Array::from([Some(-0.5), None]), result.as_ref());
+}
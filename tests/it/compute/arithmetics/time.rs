@@ -1,8 +1,9 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::compute::arithmetics::{add, add_scalar, can_sub, sub, sub_scalar};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
-use arrow2::types::months_days_ns;
+use arrow2::types::{days_ms, months_days_ns};
 
 #[test]
 fn test_adding_timestamp() {
@@ -383,3 +384,95 @@ fn test_add_interval_tz() {
     let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_subtract_timestamps_timezone() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let a = PrimitiveArray::from([Some(100_010i64), None]).to(data_type.clone());
+    let b = PrimitiveArray::from([Some(100_000i64), Some(1)]).to(data_type);
+
+    let expected =
+        PrimitiveArray::from([Some(10i64), None]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(subtract_timestamps(&a, &b).unwrap(), expected);
+    assert_eq!(expected, sub(&a, &b).as_ref());
+
+    let naive =
+        PrimitiveArray::from([Some(1i64), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(subtract_timestamps(&a, &naive).is_err());
+    assert!(!can_sub(a.data_type(), naive.data_type()));
+}
+
+#[test]
+fn test_add_interval_date32() {
+    // 2020-01-31
+    let date = PrimitiveArray::from([Some(18292i32), None]).to(DataType::Date32);
+
+    // the day is clamped to the end of the month
+    let interval =
+        PrimitiveArray::from_slice([1i32, 1]).to(DataType::Interval(IntervalUnit::YearMonth));
+    let expected = PrimitiveArray::from([Some(18321i32), None]).to(DataType::Date32);
+    assert_eq!(
+        expected,
+        add_interval_dyn(&date, &interval).unwrap().as_ref()
+    );
+    assert_eq!(expected, add(&date, &interval).as_ref());
+
+    // the time is truncated to the day
+    let interval = PrimitiveArray::from_slice([days_ms::new(1, -1); 2]);
+    let expected = PrimitiveArray::from([Some(18292i32), None]).to(DataType::Date32);
+    assert_eq!(expected, add(&date, &interval).as_ref());
+
+    // 2020-03-31 - 1 month
+    let date = PrimitiveArray::from([Some(18352i32)]).to(DataType::Date32);
+    let interval = PrimitiveArray::from_slice([months_days_ns::new(1, 0, 0)]);
+    let expected = PrimitiveArray::from([Some(18321i32)]).to(DataType::Date32);
+    assert_eq!(expected, sub(&date, &interval).as_ref());
+}
+
+#[test]
+fn test_add_interval_date64() {
+    let day = 86_400_000i64;
+    let date = PrimitiveArray::from([Some(18292 * day), None]).to(DataType::Date64);
+    let interval = PrimitiveScalar::new(DataType::Interval(IntervalUnit::YearMonth), Some(13i32));
+
+    let expected = PrimitiveArray::from([Some(18686 * day), None]).to(DataType::Date64);
+    assert_eq!(expected, add_scalar(&date, &interval).as_ref());
+
+    // 2021-02-28 - 13 months
+    let result = sub_scalar(add_scalar(&date, &interval).as_ref(), &interval);
+    let expected = PrimitiveArray::from([Some(18289 * day), None]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_sub_interval_overflow() {
+    let date = PrimitiveArray::from([Some(18292i32), None]).to(DataType::Date32);
+    // `i32::MIN` months cannot be negated
+    let interval = PrimitiveArray::from([Some(i32::MIN), None])
+        .to(DataType::Interval(IntervalUnit::YearMonth));
+    assert!(sub_interval_dyn(&date, &interval).is_err());
+
+    // null slots are not negated
+    let interval = PrimitiveArray::new(
+        DataType::Interval(IntervalUnit::YearMonth),
+        vec![i32::MIN, 1].into(),
+        Some([false, true].into()),
+    );
+    let result = sub_interval_dyn(&date, &interval).unwrap();
+    let expected = PrimitiveArray::<i32>::from([None, None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_add_interval_dst() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+    // 2020-03-28 12:00:00 WET and 2020-03-28 01:30:00 WET
+    let timestamp = PrimitiveArray::from_slice([1585396800i64, 1585359000]).to(data_type.clone());
+    let interval = PrimitiveArray::from_slice([months_days_ns::new(0, 1, 0); 2]);
+
+    // 2020-03-29 12:00:00 WEST, i.e. 23 hours later, and 2020-03-29 02:30:00 WEST,
+    // since 01:30:00 does not exist on that day
+    let expected = PrimitiveArray::from_slice([1585479600i64, 1585445400]).to(data_type);
+    assert_eq!(add_interval(&timestamp, &interval).unwrap(), expected);
+}
@@ -39,3 +39,51 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_shift_left() {
+    let a = Int8Array::from(&[Some(1i8), None, Some(1), Some(-1), Some(3)]);
+    let b = Int8Array::from(&[Some(3i8), Some(1), Some(7), Some(1), Some(9)]);
+    let result = shift_left(&a, &b);
+    // shifts are modulo the number of bits
+    let expected = Int8Array::from(&[Some(8i8), None, Some(-128), Some(-2), Some(6)]);
+
+    assert_eq!(result, expected);
+
+    let result = shift_left_scalar(&a, &2);
+    let expected = Int8Array::from(&[Some(4i8), None, Some(4), Some(-4), Some(12)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_right() {
+    let a = Int16Array::from(&[Some(-16i16), Some(16), None]);
+    let b = Int16Array::from(&[Some(2i16), Some(2), Some(2)]);
+    let result = shift_right(&a, &b);
+    let expected = Int16Array::from(&[Some(-4i16), Some(4), None]);
+    assert_eq!(result, expected);
+
+    let a = UInt8Array::from(&[Some(128u8), Some(255), None]);
+    let result = shift_right_scalar(&a, &7);
+    let expected = UInt8Array::from(&[Some(1u8), Some(1), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_wraps() {
+    // negative shifts and shifts of at least the number of bits are taken modulo it
+    let a = Int8Array::from_slice([1, 1, 1]);
+    let b = Int8Array::from_slice([-1, 8, -8]);
+    let expected = Int8Array::from_slice([-128, 1, 1]);
+    assert_eq!(shift_left(&a, &b), expected);
+    assert_eq!(shift_left_scalar(&a, &-1), Int8Array::from_slice([-128; 3]));
+
+    let a = Int16Array::from_slice([-16, 16, 16]);
+    let b = Int16Array::from_slice([-1, 16, 18]);
+    let expected = Int16Array::from_slice([-1, 16, 4]);
+    assert_eq!(shift_right(&a, &b), expected);
+
+    let a = UInt8Array::from_slice([128, 255]);
+    let expected = UInt8Array::from_slice([64, 127]);
+    assert_eq!(shift_right_scalar(&a, &9), expected);
+}
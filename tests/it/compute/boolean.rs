@@ -450,3 +450,21 @@ fn test_any_all() {
     assert!(!any(&array));
     assert!(all(&array));
 }
+
+#[cfg(feature = "compute_boolean_kleene")]
+#[test]
+fn array_and_or_kleene() {
+    let a = BooleanArray::from(&[Some(true), Some(false), None, None]);
+    let b = BooleanArray::from(&[None, None, Some(true), None]);
+
+    assert_eq!(
+        and_kleene(&a, &b),
+        BooleanArray::from(&[None, Some(false), None, None])
+    );
+    assert_eq!(
+        or_kleene(&a, &b),
+        BooleanArray::from(&[Some(true), None, Some(true), None])
+    );
+    // the null-propagating operators
+    assert_eq!(and(&a, &b), BooleanArray::from(&[None, None, None, None]));
+}
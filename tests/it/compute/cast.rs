@@ -1,8 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{can_cast_types, cast, CastOptions, RoundingMode};
 use arrow2::datatypes::DataType::LargeList;
 use arrow2::datatypes::*;
-use arrow2::types::{days_ms, months_days_ns, NativeType};
+use arrow2::types::{days_ms, f16, months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -91,6 +91,64 @@ fn f32_as_u8_overflow() {
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn f16_to_numeric() {
+    let array = PrimitiveArray::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(300.0))]);
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from(&[Some(1.5), None, Some(300.0)]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+    let expected = UInt8Array::from(&[Some(1), None, None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::Decimal(5, 2), CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[Some(150), None, Some(30000)]).to(DataType::Decimal(5, 2));
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn numeric_to_f16() {
+    let expected =
+        PrimitiveArray::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(2048.0))]);
+
+    let array = Float32Array::from(&[Some(1.5), None, Some(2049.0)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    assert_eq!(expected, b.as_ref());
+
+    let array = Float64Array::from(&[Some(1.5), None, Some(2048.5)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    assert_eq!(expected, b.as_ref());
+
+    let array = Int128Array::from(&[Some(150), None, Some(204800)]).to(DataType::Decimal(10, 2));
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    assert_eq!(expected, b.as_ref());
+
+    // integers that overflow `f16` are infinite
+    let array = Int32Array::from(&[Some(-1), None, Some(100_000)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(f16::from_f32(-1.0)),
+        None,
+        Some(f16::from_f32(f32::INFINITY)),
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    // 64-bit integers are rounded once, to the nearest even `f16` on ties
+    let array = Int64Array::from(&[Some(2049), Some(2051), Some(i64::MAX)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(f16::from_f32(2048.0)),
+        Some(f16::from_f32(2052.0)),
+        Some(f16::from_f32(f32::INFINITY)),
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = UInt64Array::from(&[Some(2049), Some(2051), Some(u64::MAX)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice([-5, 6, -7, 8, 100000000]);
@@ -295,14 +353,14 @@ fn utf8_to_i32() {
 
 #[test]
 fn int32_to_decimal() {
-    // 10 and -10 can be represented with precision 1 and scale 0
+    // 10 and -10 can't be represented with precision 1 and scale 0
     let array = Int32Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None]);
 
     let b = cast(&array, &DataType::Decimal(1, 0), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
-    let expected = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+    let expected =
+        Int128Array::from(&[Some(2), None, Some(-2), None, None]).to(DataType::Decimal(1, 0));
     assert_eq!(c, &expected)
 }
 
@@ -336,43 +394,90 @@ fn float32_to_decimal() {
 
 #[test]
 fn int32_to_decimal_scaled() {
-    // 10 and -10 can't be represented with precision 1 and scale 1
+    // 10 and -10 can't be represented with precision 2 and scale 1
     let array = Int32Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None]);
 
-    let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(2, 1));
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn utf8_to_decimal() {
+    let array = Utf8Array::<i32>::from([
+        Some("123.4500"),
+        Some("-1.005"),
+        Some("+0.004"),
+        Some("1.5e2"),
+        Some("12"),
+        Some("1000"), // can't be represented in (5,2)
+        Some("1.2.3"),
+        Some(""),
+        None,
+    ]);
+
+    let b = cast(&array, &DataType::Decimal(5, 2), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+
+    let expected = Int128Array::from(&[
+        Some(12345),
+        Some(-101),
+        Some(0),
+        Some(15000),
+        Some(1200),
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Decimal(5, 2));
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn utf8_to_decimal_strict() {
+    let options = CastOptions::default().with_strict(true);
+    let array = Utf8Array::<i64>::from([Some("9.99"), None]);
+    let b = cast(&array, &DataType::Decimal(3, 2), options).unwrap();
+    let expected = Int128Array::from(&[Some(999), None]).to(DataType::Decimal(3, 2));
+    assert_eq!(expected, b.as_ref());
+
+    // rounds up beyond the precision
+    let array = Utf8Array::<i64>::from([Some("9.995")]);
+    assert!(cast(&array, &DataType::Decimal(3, 2), options).is_err());
+    let b = cast(&array, &DataType::Decimal(3, 2), CastOptions::default()).unwrap();
+    assert_eq!(b.null_count(), 1);
+}
+
 #[test]
 fn decimal_to_decimal() {
     // increase scale and precision
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(3, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected = Int128Array::from(&[Some(20), Some(100), Some(-20), Some(-100), None])
-        .to(DataType::Decimal(2, 1));
+        .to(DataType::Decimal(3, 1));
     assert_eq!(c, &expected)
 }
 
 #[test]
 fn decimal_to_decimal_scaled() {
     // decrease precision
-    // 10 and -10 can't be represented with precision 1 and scale 1
+    // 10 and -10 can't be represented with precision 2 and scale 1
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(2, 1));
     assert_eq!(c, &expected)
 }
 
@@ -415,6 +520,133 @@ fn decimal_to_integer() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_integer_rounding() {
+    let array = Int128Array::from(&[Some(25), Some(-25), Some(35), Some(-24), Some(26)])
+        .to(DataType::Decimal(2, 1));
+
+    let cases = [
+        (RoundingMode::TowardZero, [2, -2, 3, -2, 2]),
+        (RoundingMode::HalfAwayFromZero, [3, -3, 4, -2, 3]),
+        (RoundingMode::HalfEven, [2, -2, 4, -2, 3]),
+        (RoundingMode::Floor, [2, -3, 3, -3, 2]),
+        (RoundingMode::Ceil, [3, -2, 4, -2, 3]),
+    ];
+    for (rounding, expected) in cases {
+        let options = CastOptions::default().with_rounding(rounding);
+        let b = cast(&array, &DataType::Int64, options).unwrap();
+        assert_eq!(Int64Array::from_slice(expected), b.as_ref(), "{rounding:?}");
+    }
+}
+
+#[test]
+fn decimal_to_integer_overflow() {
+    let array = Int128Array::from(&[Some(2560), Some(-2560), Some(10)]).to(DataType::Decimal(4, 1));
+
+    let b = cast(&array, &DataType::Int8, CastOptions::default()).unwrap();
+    assert_eq!(Int8Array::from(&[None, None, Some(1)]), b.as_ref());
+
+    let options = CastOptions::default().with_saturate(true);
+    let b = cast(&array, &DataType::Int8, options).unwrap();
+    assert_eq!(Int8Array::from_slice([127, -128, 1]), b.as_ref());
+
+    let options = CastOptions::default().with_strict(true);
+    assert!(cast(&array, &DataType::Int8, options).is_err());
+}
+
+#[test]
+fn float_to_decimal_rounding() {
+    let array = Float64Array::from(&[Some(1.255), Some(-0.125), Some(f64::NAN)]);
+    let options = CastOptions::default()
+        .with_rounding(RoundingMode::HalfEven)
+        .with_saturate(true);
+    let b = cast(&array, &DataType::Decimal(5, 2), options).unwrap();
+
+    // 1.255 is 1.25499999... in binary and NaN is never saturated
+    let expected = Int128Array::from(&[Some(125), Some(-12), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn decimal_to_decimal_rounding() {
+    let array = Int128Array::from(&[Some(125), Some(-135)]).to(DataType::Decimal(3, 2));
+    let options = CastOptions::default().with_rounding(RoundingMode::HalfEven);
+    let b = cast(&array, &DataType::Decimal(3, 1), options).unwrap();
+
+    let expected = Int128Array::from_slice([12, -14]).to(DataType::Decimal(3, 1));
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn decimal_to_decimal_bounds() {
+    let max_38 = 10_i128.pow(38) - 1;
+    let cases = [
+        (2, vec![99, -99, 100, -100], [99, -99]),
+        (
+            38,
+            vec![max_38, -max_38, i128::MAX, i128::MIN + 1],
+            [max_38, -max_38],
+        ),
+    ];
+    for (precision, values, [max, min]) in cases {
+        let array = Int128Array::from_slice(&values).to(DataType::Decimal(39, 0));
+        let to_type = DataType::Decimal(precision, 0);
+
+        let options = CastOptions::default().with_saturate(true);
+        let b = cast(&array, &to_type, options).unwrap();
+        let expected = Int128Array::from_slice([max, min, max, min]).to(to_type.clone());
+        assert_eq!(expected, b.as_ref(), "{precision}");
+
+        let options = CastOptions::default().with_strict(true);
+        let b = cast(&array.clone().sliced(0, 2), &to_type, options).unwrap();
+        let expected = Int128Array::from_slice([max, min]).to(to_type.clone());
+        assert_eq!(expected, b.as_ref(), "{precision}");
+        assert!(cast(&array.clone().sliced(2, 1), &to_type, options).is_err());
+        assert!(cast(&array.sliced(3, 1), &to_type, options).is_err());
+    }
+}
+
+#[test]
+fn i32_to_u8_overflow_options() {
+    let array = Int32Array::from(&[Some(300), Some(-1), Some(7), None]);
+
+    let b = cast(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+    assert_eq!(UInt8Array::from(&[None, None, Some(7), None]), b.as_ref());
+
+    let options = CastOptions::default().with_saturate(true);
+    let b = cast(&array, &DataType::UInt8, options).unwrap();
+    assert_eq!(
+        UInt8Array::from(&[Some(255), Some(0), Some(7), None]),
+        b.as_ref()
+    );
+
+    let options = CastOptions::default().with_strict(true);
+    assert!(cast(&array, &DataType::UInt8, options).is_err());
+}
+
+#[test]
+fn f64_to_i32_saturate() {
+    let array = Float64Array::from_slice([1e20, -1e20, f64::NAN, 1.5]);
+    let options = CastOptions::default().with_saturate(true);
+    let b = cast(&array, &DataType::Int32, options).unwrap();
+    let expected = Int32Array::from(&[Some(i32::MAX), Some(i32::MIN), None, Some(1)]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn utf8_to_i32_strict() {
+    let options = CastOptions::default().with_strict(true);
+    let array = Utf8Array::<i32>::from([Some("5"), None]);
+    let b = cast(&array, &DataType::Int32, options).unwrap();
+    assert_eq!(Int32Array::from(&[Some(5), None]), b.as_ref());
+
+    let array = Utf8Array::<i32>::from_slice(["5", "six"]);
+    assert!(cast(&array, &DataType::Int32, options).is_err());
+
+    let array = BinaryArray::<i64>::from_slice([b"six"]);
+    assert!(cast(&array, &DataType::Int32, options).is_err());
+}
+
 #[test]
 fn utf8_to_i32_partial() {
     let array = Utf8Array::<i32>::from_slice(["5", "6", "seven", "8aa", "9.1aa"]);
@@ -681,6 +913,53 @@ fn timestamp_to_timestamp() {
     );
 }
 
+#[test]
+fn naive_timestamp_to_timestamp_with_tz() {
+    // 1996-12-19T16:39:57 wall clock time at -02:00 is 18:39:57 UTC
+    let array =
+        Int64Array::from(&[Some(851013597), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("-02:00".to_string()));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(851020797000), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+
+    // and back
+    let to_type = DataType::Timestamp(TimeUnit::Second, None);
+    let result = cast(result.as_ref(), &to_type, CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn timestamp_with_tz_to_timestamp_with_tz() {
+    // the instants are preserved
+    let array = Int64Array::from_slice([851020797]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("-02:00".to_string()),
+    ));
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from_slice([851020797]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn naive_timestamp_to_timestamp_with_chrono_tz() {
+    let array = Int64Array::from_slice([
+        1615689000, // 2021-03-14T02:30:00 does not exist in New York
+        1636248600, // 2021-11-07T01:30:00 happens twice in New York
+        1625140800, // 2021-07-01T12:00:00
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, Some(1636263000), Some(1625155200)]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from([Some("one"), None, Some("three"), Some("one")]);
@@ -0,0 +1,115 @@
+use arrow2::array::*;
+use arrow2::compute::clamp::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::PrimitiveScalar;
+
+#[test]
+fn clamp_values() {
+    let array = Float64Array::from([Some(-1.5), None, Some(0.5), Some(2.0)]);
+    assert_eq!(
+        clamp(&array, 0.0, 1.0).unwrap(),
+        Float64Array::from([Some(0.0), None, Some(0.5), Some(1.0)])
+    );
+    assert!(clamp(&array, 1.0, 0.0).is_err());
+}
+
+#[test]
+fn clamp_dyn_scalars() {
+    let array = Int64Array::from_slice([-3, 5, 30]).to(DataType::Date64);
+    let min = PrimitiveScalar::<i64>::new(DataType::Date64, Some(0));
+    let max = PrimitiveScalar::<i64>::new(DataType::Date64, Some(10));
+    let result = clamp_dyn(&array, &min, &max).unwrap();
+    let expected = Int64Array::from_slice([0, 5, 10]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+
+    let max = PrimitiveScalar::<i64>::new(DataType::Date64, None);
+    assert!(clamp_dyn(&array, &min, &max).is_err());
+    let max = PrimitiveScalar::<i32>::from(Some(10));
+    assert!(clamp_dyn(&array, &min, &max).is_err());
+}
+
+#[test]
+fn greatest_least() {
+    let a = Int32Array::from([Some(1), None, None, Some(7)]);
+    let b = Int32Array::from([Some(3), Some(2), None, None]);
+    let c = Int32Array::from([Some(2), Some(5), None, Some(-1)]);
+
+    assert_eq!(
+        greatest(&[&a, &b, &c]).unwrap(),
+        Int32Array::from([Some(3), Some(5), None, Some(7)])
+    );
+    assert_eq!(
+        least(&[&a, &b, &c]).unwrap(),
+        Int32Array::from([Some(1), Some(2), None, Some(-1)])
+    );
+    assert_eq!(greatest(&[&a]).unwrap(), a);
+}
+
+#[test]
+fn greatest_no_nulls() {
+    let a = UInt8Array::from_slice([1, 9]);
+    let b = UInt8Array::from_slice([4, 2]);
+    assert_eq!(greatest(&[&a, &b]).unwrap(), UInt8Array::from_slice([4, 9]));
+    assert_eq!(least(&[&a, &b]).unwrap(), UInt8Array::from_slice([1, 2]));
+}
+
+#[test]
+fn greatest_least_errors() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(greatest::<i32>(&[]).is_err());
+    assert!(least(&[&a, &b]).is_err());
+
+    let b = Int64Array::from_slice([1, 2]);
+    assert!(greatest_dyn(&[&a, &b]).is_err());
+    let b = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert!(least_dyn(&[&b]).is_err());
+}
+
+#[test]
+fn greatest_dyn_values() {
+    let a = Float32Array::from([Some(1.0), None]);
+    let b = Float32Array::from([Some(-1.0), Some(3.0)]);
+    let result = greatest_dyn(&[&a, &b]).unwrap();
+    assert_eq!(Float32Array::from_slice([1.0, 3.0]), result.as_ref());
+}
+
+#[test]
+fn consistency() {
+    use arrow2::datatypes::DataType::*;
+    use arrow2::datatypes::{IntervalUnit, TimeUnit};
+
+    let datatypes = vec![
+        Null,
+        Boolean,
+        UInt8,
+        UInt16,
+        UInt32,
+        UInt64,
+        Int8,
+        Int16,
+        Int32,
+        Int64,
+        Float16,
+        Float32,
+        Float64,
+        Decimal(10, 2),
+        Timestamp(TimeUnit::Second, None),
+        Date32,
+        Date64,
+        Duration(TimeUnit::Millisecond),
+        Interval(IntervalUnit::DayTime),
+        Interval(IntervalUnit::MonthDayNano),
+        Utf8,
+        Binary,
+    ];
+
+    datatypes.into_iter().for_each(|data_type| {
+        let array = new_null_array(data_type.clone(), 2);
+        assert_eq!(
+            can_clamp(&data_type),
+            greatest_dyn(&[array.as_ref()]).is_ok()
+        );
+        assert_eq!(can_clamp(&data_type), least_dyn(&[array.as_ref()]).is_ok());
+    });
+}
@@ -2,7 +2,8 @@ use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::comparison::{self, boolean::*, primitive, utf8};
 use arrow2::datatypes::{DataType, DataType::*, IntegerType, IntervalUnit, TimeUnit};
-use arrow2::scalar::new_scalar;
+use arrow2::scalar::{new_scalar, PrimitiveScalar};
+use arrow2::types::{days_ms, f16};
 
 #[test]
 fn consistency() {
@@ -69,6 +70,14 @@ fn consistency() {
     });
 }
 
+#[test]
+#[should_panic]
+fn lt_interval_day_time() {
+    // intervals of days and milliseconds have no order
+    let array = DaysMsArray::from_slice([days_ms::new(1, 2), days_ms::new(2, 1)]);
+    comparison::lt(&array, &array);
+}
+
 macro_rules! cmp_bool {
     ($KERNEL:ident, $A_VEC:expr, $B_VEC:expr, $EXPECTED:expr) => {
         let a = BooleanArray::from_slice($A_VEC);
@@ -346,6 +355,37 @@ fn primitive_lt() {
     )
 }
 
+#[test]
+fn f16_lt() {
+    let a = PrimitiveArray::from([
+        Some(f16::from_f32(-1.0)),
+        Some(f16::from_f32(0.0)),
+        Some(f16::from_f32(2.5)),
+        Some(f16::from_f32(f32::NAN)),
+        None,
+    ]);
+    let b = PrimitiveArray::from([
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(-0.0)),
+        Some(f16::from_f32(2.0)),
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(1.0)),
+    ]);
+
+    let result = comparison::lt(&a, &b);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let result = comparison::lt_eq(&a, &b);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::new(DataType::Float16, Some(f16::from_f32(0.0)));
+    let result = comparison::gt_scalar(&a, &scalar);
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), Some(false), None]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn primitive_lt_eq() {
     let a = Int32Array::from([Some(0), Some(1), Some(3), Some(2), None]);
@@ -414,3 +454,344 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+#[test]
+fn total_order() {
+    use arrow2::compute::comparison::total;
+
+    let lhs = Float64Array::from(&[Some(f64::NAN), Some(f64::NAN), Some(-0.0), None, Some(1.0)]);
+    let rhs = Float64Array::from(&[Some(f64::NAN), Some(1.0), Some(0.0), Some(1.0), Some(1.0)]);
+
+    assert_eq!(
+        total::eq(&lhs, &rhs),
+        BooleanArray::from(&[Some(true), Some(false), Some(false), None, Some(true)])
+    );
+    assert_eq!(
+        total::neq(&lhs, &rhs),
+        BooleanArray::from(&[Some(false), Some(true), Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        total::lt(&lhs, &rhs),
+        BooleanArray::from(&[Some(false), Some(false), Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        total::lt_eq(&lhs, &rhs),
+        BooleanArray::from(&[Some(true), Some(false), Some(true), None, Some(true)])
+    );
+    assert_eq!(
+        total::gt(&lhs, &rhs),
+        BooleanArray::from(&[Some(false), Some(true), Some(false), None, Some(false)])
+    );
+    assert_eq!(
+        total::gt_eq(&lhs, &rhs),
+        BooleanArray::from(&[Some(true), Some(true), Some(false), None, Some(true)])
+    );
+
+    // equality agrees with the equality of arrays on `NaN`s, but not on signed zeros
+    let lhs = Float64Array::from_slice([0.0, -0.0, -f64::NAN, f64::NAN]);
+    let rhs = Float64Array::from_slice([-0.0, -0.0, f64::NAN, -f64::NAN]);
+    assert_eq!(
+        total::eq(&lhs, &rhs),
+        BooleanArray::from_slice([false, true, true, true])
+    );
+    for i in 0..lhs.len() {
+        assert!(lhs.clone().sliced(i, 1) == rhs.clone().sliced(i, 1));
+    }
+    assert_eq!(
+        total::lt_scalar(&lhs, 0.0),
+        BooleanArray::from_slice([false, true, false, false])
+    );
+
+    let lhs = Float32Array::from(&[Some(f32::NAN), Some(1.0), None]);
+    assert_eq!(
+        total::eq_scalar(&lhs, f32::NAN),
+        BooleanArray::from(&[Some(true), Some(false), None])
+    );
+    assert_eq!(
+        total::gt_scalar(&lhs, f32::INFINITY),
+        BooleanArray::from(&[Some(true), Some(false), None])
+    );
+}
+
+fn utf8_dictionary(keys: &[Option<i32>], values: &[Option<&str>]) -> DictionaryArray<i32> {
+    DictionaryArray::try_from_keys(
+        PrimitiveArray::from(keys),
+        Utf8Array::<i32>::from(values).boxed(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn dictionary_shared_values() {
+    let values = [Some("b"), Some("a"), None, Some("b")];
+    let lhs = utf8_dictionary(&[Some(0), Some(1), None, Some(2), Some(0)], &values);
+    let rhs = utf8_dictionary(&[Some(3), Some(0), Some(1), Some(1), Some(1)], &values);
+
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, Some(false)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, None, Some(false)]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, Some(true)]);
+    assert_eq!(comparison::gt_eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([true, false, false, false, false]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_different_values() {
+    let lhs = utf8_dictionary(
+        &[Some(0), Some(1), Some(2)],
+        &[Some("a"), Some("c"), Some("e")],
+    );
+    let rhs = utf8_dictionary(&[Some(1), Some(1), Some(0)], &[Some("f"), Some("c")]);
+
+    let expected = BooleanArray::from_slice([false, true, false]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+    assert_eq!(
+        comparison::lt_eq(&lhs, &rhs),
+        BooleanArray::from_slice([true, true, true])
+    );
+    assert_eq!(
+        comparison::gt(&lhs, &rhs),
+        BooleanArray::from_slice([false, false, false])
+    );
+
+    // consistent with comparing the unpacked values
+    let unpacked = |array: &DictionaryArray<i32>| {
+        arrow2::compute::take::take(array.values().as_ref(), array.keys()).unwrap()
+    };
+    assert_eq!(
+        comparison::lt(&lhs, &rhs),
+        comparison::lt(unpacked(&lhs).as_ref(), unpacked(&rhs).as_ref())
+    );
+}
+
+#[test]
+fn dictionary_scalar() {
+    let array = utf8_dictionary(&[Some(0), None, Some(1), Some(0)], &[Some("a"), Some("b")]);
+    let scalar = arrow2::scalar::Utf8Scalar::<i32>::new(Some("a"));
+    let result = comparison::gt_scalar(&array, &scalar);
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    // a scalar of the type of the dictionary
+    let scalar = new_scalar(&array, 0);
+    assert_eq!(comparison::gt_scalar(&array, scalar.as_ref()), expected);
+}
+
+#[test]
+fn dictionary_scalar_total_order() {
+    // NaN values are compared by the same total order as two dictionaries
+    let values = Float64Array::from_slice([f64::NAN, 1.0]).boxed();
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None]);
+    let array = DictionaryArray::try_from_keys(keys, values).unwrap();
+    let nan = new_scalar(&array, 0);
+
+    let rhs = DictionaryArray::try_from_keys(
+        PrimitiveArray::<i32>::from_slice([0, 0, 0]),
+        array.values().clone(),
+    )
+    .unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None]);
+    assert_eq!(comparison::eq(&array, &rhs), expected);
+    assert_eq!(comparison::eq_scalar(&array, nan.as_ref()), expected);
+    assert_eq!(
+        comparison::eq_scalar(
+            &array,
+            &arrow2::scalar::PrimitiveScalar::from(Some(f64::NAN))
+        ),
+        expected
+    );
+
+    let expected = BooleanArray::from([Some(false), Some(true), None]);
+    assert_eq!(comparison::lt(&array, &rhs), expected);
+    assert_eq!(comparison::lt_scalar(&array, nan.as_ref()), expected);
+
+    assert_eq!(
+        comparison::eq_scalar_and_validity(&array, nan.as_ref()),
+        BooleanArray::from_slice([true, false, false])
+    );
+    assert_eq!(
+        comparison::neq_scalar_and_validity(&array, nan.as_ref()),
+        BooleanArray::from_slice([false, true, true])
+    );
+}
+
+fn decimal(values: &[Option<i128>], scale: usize) -> PrimitiveArray<i128> {
+    PrimitiveArray::from(values).to(Decimal(38, scale))
+}
+
+#[test]
+fn decimal_rescaled() {
+    use comparison::decimal;
+    // 1.5, 2.00, null, -3.1
+    let lhs = decimal(&[Some(15), Some(20), None, Some(-31)], 1);
+    // 1.50, 1.99, 1.00, -3.10
+    let rhs = decimal(&[Some(150), Some(199), Some(100), Some(-310)], 2);
+
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(decimal::eq(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    assert_eq!(decimal::eq(&rhs, &lhs), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(decimal::gt(&lhs, &rhs), expected);
+    assert_eq!(decimal::lt(&rhs, &lhs), expected);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from_slice([false, true, true, false]);
+    assert_eq!(decimal::neq_and_validity(&lhs, &rhs), expected);
+}
+
+#[test]
+fn decimal_rescale_overflow() {
+    use comparison::decimal;
+    let lhs = decimal(&[Some(i128::MAX / 10), Some(-i128::MAX / 10), Some(0)], 0);
+    let rhs = decimal(&[Some(i128::MAX), Some(i128::MAX), Some(-1)], 2);
+
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(decimal::gt(&lhs, &rhs), expected);
+    assert_eq!(decimal::lt_eq(&rhs, &lhs), expected);
+}
+
+#[test]
+fn decimal_scalar() {
+    use arrow2::scalar::PrimitiveScalar;
+    use comparison::decimal;
+    let array = decimal(&[Some(100), None, Some(101), Some(99)], 2);
+    let scalar = PrimitiveScalar::new(Decimal(5, 0), Some(1i128));
+
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(false)]);
+    assert_eq!(decimal::eq_scalar(&array, &scalar), expected);
+    assert_eq!(comparison::eq_scalar(&array, &scalar), expected);
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(false)]);
+    assert_eq!(decimal::gt_eq_scalar(&array, &scalar), expected);
+    assert_eq!(comparison::gt_eq_scalar(&array, &scalar), expected);
+
+    let null = PrimitiveScalar::new(Decimal(5, 0), None::<i128>);
+    assert_eq!(decimal::lt_scalar(&array, &null).null_count(), 4);
+}
+
+#[test]
+fn decimal_between() {
+    use arrow2::scalar::PrimitiveScalar;
+    use comparison::decimal::between;
+    let array = decimal(&[Some(-5), Some(0), Some(25), None, Some(26)], 1);
+    let low = PrimitiveScalar::new(Decimal(5, 2), Some(-50i128));
+    let high = PrimitiveScalar::new(Decimal(5, 0), Some(2i128));
+
+    let result = between(&array, &low, &high);
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), None, Some(false)]);
+    assert_eq!(result, expected);
+
+    let null = PrimitiveScalar::new(Decimal(5, 0), None::<i128>);
+    assert_eq!(between(&array, &low, &null).null_count(), 5);
+}
+
+fn struct_array(
+    a: Vec<Option<i32>>,
+    b: Vec<Option<&str>>,
+    validity: Option<Bitmap>,
+) -> StructArray {
+    let fields = vec![
+        arrow2::datatypes::Field::new("a", Int32, true),
+        arrow2::datatypes::Field::new("b", Utf8, true),
+    ];
+    StructArray::new(
+        Struct(fields),
+        vec![
+            Int32Array::from(a).boxed(),
+            Utf8Array::<i32>::from(b).boxed(),
+        ],
+        validity,
+    )
+}
+
+#[test]
+fn nested_struct() {
+    let lhs = struct_array(
+        vec![Some(1), Some(2), None, Some(4), Some(5)],
+        vec![Some("a"), Some("b"), None, Some("d"), Some("e")],
+        Some(Bitmap::from([true, true, true, true, false])),
+    );
+    let rhs = struct_array(
+        vec![Some(1), Some(2), None, None, Some(5)],
+        vec![Some("a"), Some("c"), None, Some("d"), Some("e")],
+        None,
+    );
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true), Some(false), None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(false), Some(true), None]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([true, false, true, false, false]);
+    assert_eq!(comparison::eq_and_validity(&lhs, &rhs), expected);
+    assert!(comparison::can_eq(lhs.data_type()));
+    assert!(!comparison::can_lt(lhs.data_type()));
+}
+
+#[test]
+fn nested_struct_scalar() {
+    let array = struct_array(
+        vec![Some(1), Some(1), None, Some(1)],
+        vec![Some("a"), None, None, Some("a")],
+        Some(Bitmap::from([true, true, true, false])),
+    );
+    let scalar = new_scalar(&array, 0);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), None]);
+    assert_eq!(comparison::eq_scalar(&array, scalar.as_ref()), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(true), None]);
+    assert_eq!(comparison::neq_scalar(&array, scalar.as_ref()), expected);
+
+    // nested nulls are equal
+    let scalar = new_scalar(&array, 2);
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None]);
+    assert_eq!(comparison::eq_scalar(&array, scalar.as_ref()), expected);
+}
+
+#[test]
+fn nested_list() {
+    let list = |data: Vec<Option<Vec<Option<i32>>>>| {
+        let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data).unwrap();
+        ListArray::<i32>::from(array)
+    };
+    let lhs = list(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(0), Some(1), Some(2)]),
+    ]);
+    let rhs = list(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![]),
+        Some(vec![Some(1), Some(2)]),
+    ]);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true), None, Some(false)]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    // items at different offsets
+    let result = comparison::eq(&lhs.clone().sliced(4, 1), &rhs.clone().sliced(1, 1));
+    assert_eq!(result, BooleanArray::from_slice([false]));
+    let rhs_items = list(vec![Some(vec![Some(0), Some(0), Some(1), Some(2)])]);
+    let shifted = ListArray::<i32>::new(
+        rhs_items.data_type().clone(),
+        vec![0, 3].try_into().unwrap(),
+        rhs_items.values().sliced(1, 3),
+        None,
+    );
+    let result = comparison::eq(&lhs.clone().sliced(4, 1), &shifted);
+    assert_eq!(result, BooleanArray::from_slice([true]));
+
+    let scalar = new_scalar(&rhs, 0);
+    let result = comparison::eq_scalar(&rhs, scalar.as_ref());
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, false, false, false, false])
+    );
+}
@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::concatenate::{concatenate, concatenate_dictionaries};
+use arrow2::datatypes::{DataType, Field, IntegerType};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +116,169 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn fixed_size_list_arrays() -> Result<()> {
+    let data_type = FixedSizeListArray::default_datatype(arrow2::datatypes::DataType::Int32, 2);
+    let a = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        Some([true, false].into()),
+    );
+    let b = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from_slice([5, 6]).boxed(),
+        None,
+    );
+    let arr = concatenate(&[&a, &b])?;
+
+    let expected = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed(),
+        Some([true, false, true].into()),
+    );
+
+    assert_eq!(expected, arr.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn zero_copy() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    let result = concatenate(&[&array])?;
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+
+    // adjacent slices share the buffers of the array
+    let result = concatenate(&[&array.clone().sliced(1, 2), &array.clone().sliced(3, 1)])?;
+    let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(result, &array.clone().sliced(1, 3));
+    assert_eq!(result.values().as_ptr(), array.values()[1..].as_ptr());
+
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc"), Some("d")]);
+    let result = concatenate(&[&array.clone().sliced(0, 1), &array.clone().sliced(1, 3)])?;
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result, &array);
+    assert_eq!(result.offsets().as_ptr(), array.offsets().as_ptr());
+
+    let array = BooleanArray::from_slice([true, false, true]);
+    let result = concatenate(&[&array.clone().sliced(0, 1), &array.clone().sliced(1, 2)])?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    // slices that are not adjacent are copied
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let result = concatenate(&[&array.clone().sliced(1, 2), &array.clone().sliced(0, 1)])?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice([2, 3, 1]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn nested_capacities() -> Result<()> {
+    let mut list = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    list.try_extend([
+        Some(vec![Some("a"), None]),
+        None,
+        Some(vec![Some("bc")]),
+        Some(vec![]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    let fields = vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("b", DataType::Binary, true),
+    ];
+    let binary = BinaryArray::<i32>::from([Some(b"x".as_ref()), None, Some(b"yz"), None]);
+    let array = StructArray::new(
+        DataType::Struct(fields),
+        vec![list.boxed(), binary.boxed()],
+        None,
+    );
+
+    let result = concatenate(&[&array.clone().sliced(2, 2), &array.clone().sliced(0, 2)])?;
+    let expected = concatenate(&[&array.clone().sliced(2, 2), &array.clone().sliced(0, 1)])?;
+    assert_eq!(result.len(), 4);
+    assert_eq!(result.sliced(0, 3).as_ref(), expected.as_ref());
+    assert_eq!(
+        result.sliced(2, 2).as_ref(),
+        &array.sliced(0, 2) as &dyn Array
+    );
+    Ok(())
+}
+
+fn dictionary(keys: &[Option<u8>], values: &[&str]) -> DictionaryArray<u8> {
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false);
+    DictionaryArray::try_new(
+        data_type,
+        UInt8Array::from(keys),
+        Utf8Array::<i32>::from_slice(values).boxed(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn dictionary_arrays_concatenate() -> Result<()> {
+    let a = dictionary(&[Some(1), None, Some(0)], &["a", "b"]);
+    let b = dictionary(&[Some(0), Some(2), Some(1)], &["b", "c", "a"]);
+    let arr = concatenate(&[&a, &b])?;
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+
+    // the values are appended, not merged
+    let expected_keys = UInt8Array::from([Some(1), None, Some(0), Some(2), Some(4), Some(3)]);
+    assert_eq!(arr.keys(), &expected_keys);
+    let expected_values = Utf8Array::<i32>::from_slice(["a", "b", "b", "c", "a"]);
+    assert_eq!(arr.values().as_ref(), &expected_values as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays() -> Result<()> {
+    let a = dictionary(&[Some(1), None, Some(0)], &["a", "b"]);
+    let b = dictionary(&[Some(0), Some(2), Some(1)], &["b", "c", "a"]);
+    let arr = concatenate_dictionaries(&[&a, &b], false)?;
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+
+    let expected_keys = UInt8Array::from([Some(1), None, Some(0), Some(1), Some(0), Some(2)]);
+    assert_eq!(arr.keys(), &expected_keys);
+    let expected_values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    assert_eq!(arr.values().as_ref(), &expected_values as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays_shared_values() -> Result<()> {
+    let a = dictionary(&[Some(1), Some(1)], &["a", "b"]);
+    let b = dictionary(&[None, Some(0)], &["a", "b"]);
+    let arr = concatenate_dictionaries(&[&a, &b], false)?;
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<u8>>().unwrap();
+
+    let expected_keys = UInt8Array::from([Some(1), Some(1), None, Some(0)]);
+    assert_eq!(arr.keys(), &expected_keys);
+    assert_eq!(arr.values(), a.values());
+    Ok(())
+}
+
+#[test]
+fn dictionary_arrays_key_overflow() -> Result<()> {
+    let values = (0..200).map(|x| x.to_string()).collect::<Vec<_>>();
+    let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+    let keys = (0..200).map(Some).collect::<Vec<_>>();
+    let a = dictionary(&keys[..150], &values[..150]);
+    let b = dictionary(&keys[..100], &values[100..]);
+    // 200 distinct values fit in `u8`
+    assert_eq!(concatenate_dictionaries(&[&a, &b], false)?.len(), 250);
+
+    let values = (0..300).map(|x| x.to_string()).collect::<Vec<_>>();
+    let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+    let a = dictionary(&keys[..150], &values[..150]);
+    let b = dictionary(&keys[..150], &values[150..]);
+    assert!(concatenate_dictionaries(&[&a, &b], false).is_err());
+
+    let arr = concatenate_dictionaries(&[&a, &b], true)?;
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<u16>>().unwrap();
+    assert_eq!(arr.values().len(), 300);
+    assert_eq!(arr.key_value(299), 299);
+    Ok(())
+}
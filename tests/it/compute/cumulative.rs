@@ -0,0 +1,95 @@
+use arrow2::array::*;
+use arrow2::compute::cumulative::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn no_nulls() {
+    let array = Int32Array::from_slice([3, 1, 4, 1, 5]);
+    for nulls in [CumulativeNulls::Skip, CumulativeNulls::Propagate] {
+        assert_eq!(
+            cumsum(&array, nulls),
+            Int32Array::from_slice([3, 4, 8, 9, 14])
+        );
+        assert_eq!(
+            cumprod(&array, nulls),
+            Int32Array::from_slice([3, 3, 12, 12, 60])
+        );
+        assert_eq!(
+            cummin(&array, nulls),
+            Int32Array::from_slice([3, 1, 1, 1, 1])
+        );
+        assert_eq!(
+            cummax(&array, nulls),
+            Int32Array::from_slice([3, 3, 4, 4, 5])
+        );
+    }
+}
+
+#[test]
+fn skip_nulls() {
+    let array = Float64Array::from([None, Some(2.0), None, Some(-1.0), Some(3.0)]);
+    assert_eq!(
+        cumsum(&array, CumulativeNulls::Skip),
+        Float64Array::from([None, Some(2.0), None, Some(1.0), Some(4.0)])
+    );
+    assert_eq!(
+        cummin(&array, CumulativeNulls::Skip),
+        Float64Array::from([None, Some(2.0), None, Some(-1.0), Some(-1.0)])
+    );
+    assert_eq!(
+        cummax(&array, CumulativeNulls::Skip),
+        Float64Array::from([None, Some(2.0), None, Some(2.0), Some(3.0)])
+    );
+}
+
+#[test]
+fn propagate_nulls() {
+    let array = UInt8Array::from([Some(2), Some(3), None, Some(1)]);
+    assert_eq!(
+        cumprod(&array, CumulativeNulls::Propagate),
+        UInt8Array::from([Some(2), Some(6), None, None])
+    );
+
+    let array = UInt8Array::from([None, Some(3)]);
+    assert_eq!(
+        cumsum(&array, CumulativeNulls::Propagate),
+        UInt8Array::from([None, None])
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = Int64Array::from([Some(10), Some(1), None, Some(2)]).sliced(1, 3);
+    assert_eq!(
+        cumsum(&array, CumulativeNulls::Skip),
+        Int64Array::from([Some(1), None, Some(3)])
+    );
+}
+
+#[test]
+fn overflow_wraps() {
+    let array = Int8Array::from_slice([i8::MAX, 1, 1]);
+    assert_eq!(
+        cumsum(&array, CumulativeNulls::Skip),
+        Int8Array::from_slice([i8::MAX, i8::MIN, i8::MIN + 1])
+    );
+    let array = UInt8Array::from_slice([16, 16, 2]);
+    assert_eq!(
+        cumprod(&array, CumulativeNulls::Skip),
+        UInt8Array::from_slice([16, 0, 0])
+    );
+}
+
+#[test]
+fn dyn_() {
+    let array = Int16Array::from([Some(1), None, Some(2)]);
+    let result = cumsum_dyn(&array, CumulativeNulls::Skip).unwrap();
+    assert_eq!(Int16Array::from([Some(1), None, Some(3)]), result.as_ref());
+    let result = cummax_dyn(&array, CumulativeNulls::Propagate).unwrap();
+    assert_eq!(Int16Array::from([Some(1), None, None]), result.as_ref());
+
+    let array = Int32Array::from_slice([1]).to(DataType::Date32);
+    assert!(!can_cumulative(array.data_type()));
+    assert!(cumprod_dyn(&array, CumulativeNulls::Skip).is_err());
+    assert!(cummin_dyn(&Utf8Array::<i32>::from_slice(["a"]), CumulativeNulls::Skip).is_err());
+}
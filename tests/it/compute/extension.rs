@@ -0,0 +1,140 @@
+use arrow2::array::*;
+use arrow2::compute::comparison::{can_eq, can_lt, can_lt_scalar, eq, lt};
+use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::extension::*;
+use arrow2::compute::filter::filter;
+use arrow2::compute::sort::{can_sort, lexsort, sort, sort_to_indices, SortColumn, SortOptions};
+use arrow2::compute::take::take;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::error::Result;
+
+fn extension(storage: DataType) -> DataType {
+    DataType::Extension("ext".to_string(), Box::new(storage), None)
+}
+
+#[test]
+fn storage_roundtrip() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c")]).sliced(1, 2);
+    let wrapped = with_data_type(&array, extension(DataType::Utf8));
+    assert_eq!(wrapped.data_type(), &extension(DataType::Utf8));
+    assert_eq!(wrapped.len(), 2);
+    assert_eq!(
+        storage(wrapped.as_ref()).unwrap().as_ref(),
+        &array as &dyn Array
+    );
+    assert!(storage(&array).is_none());
+}
+
+#[test]
+fn sparse_union() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type.clone(),
+        vec![0, 1, 0].into(),
+        vec![
+            Int32Array::from([Some(1), None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from([None, Some("b"), None]).boxed(),
+        ],
+        None,
+    )
+    .sliced(1, 2);
+    let wrapped = with_data_type(&array, extension(data_type));
+    assert_eq!(
+        storage(wrapped.as_ref()).unwrap().as_ref(),
+        &array as &dyn Array
+    );
+}
+
+#[test]
+fn sort_extension() -> Result<()> {
+    let data_type = extension(DataType::Int32);
+    let array = Int32Array::from([Some(3), None, Some(1)]).to(data_type.clone());
+    assert!(can_sort(&data_type));
+
+    let options = SortOptions::default();
+    let result = sort(&array, &options, None)?;
+    let expected = Int32Array::from([None, Some(1), Some(3)]).to(data_type.clone());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let indices = sort_to_indices::<u32>(&array, &options, None)?;
+    assert_eq!(indices, UInt32Array::from_slice([1, 2, 0]));
+
+    let column = SortColumn {
+        values: &array,
+        options: None,
+    };
+    let result = lexsort::<u32>(&[column], None)?;
+    assert_eq!(result[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sort_extension_utf8() -> Result<()> {
+    let data_type = extension(DataType::Utf8);
+    let array = with_data_type(
+        &Utf8Array::<i32>::from_slice(["b", "c", "a"]),
+        data_type.clone(),
+    );
+    let result = sort(array.as_ref(), &SortOptions::default(), None)?;
+    assert_eq!(result.data_type(), &data_type);
+    assert_eq!(
+        storage(result.as_ref()).unwrap().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn compare_extension() {
+    let data_type = extension(DataType::Int32);
+    assert!(can_eq(&data_type));
+    assert!(can_lt(&data_type));
+    assert!(can_lt_scalar(&data_type));
+    assert!(!can_lt(&extension(DataType::Null)));
+
+    let lhs = Int32Array::from([Some(1), None, Some(3)]).to(data_type.clone());
+    let rhs = Int32Array::from([Some(1), Some(2), Some(2)]).to(data_type);
+    assert_eq!(
+        eq(&lhs, &rhs),
+        BooleanArray::from([Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        lt(&lhs, &rhs),
+        BooleanArray::from([Some(false), None, Some(false)])
+    );
+}
+
+#[test]
+fn selection_keeps_extension() -> Result<()> {
+    let data_type = extension(DataType::Utf8);
+    let array = with_data_type(
+        &Utf8Array::<i32>::from([Some("a"), None, Some("c")]),
+        data_type.clone(),
+    );
+
+    let result = take(array.as_ref(), &UInt32Array::from_slice([2, 0]))?;
+    assert_eq!(result.data_type(), &data_type);
+
+    let result = filter(
+        array.as_ref(),
+        &BooleanArray::from_slice([true, false, true]),
+    )?;
+    assert_eq!(result.data_type(), &data_type);
+
+    let result = concatenate(&[array.as_ref(), array.as_ref()])?;
+    assert_eq!(result.data_type(), &data_type);
+    assert_eq!(result.len(), 6);
+
+    // kernels that do not preserve the type are dispatched to the storage
+    let result = unary(array.as_ref(), |array| {
+        filter(array, &BooleanArray::from_slice([true, true, false]))
+    })?;
+    assert_eq!(result.data_type(), &data_type);
+    let result = on_storage(array.as_ref(), |array| array.data_type().clone());
+    assert_eq!(result, DataType::Utf8);
+    Ok(())
+}
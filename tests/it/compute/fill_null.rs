@@ -0,0 +1,162 @@
+use arrow2::array::*;
+use arrow2::compute::fill_null::*;
+use arrow2::datatypes::{DataType, IntervalUnit};
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn forward_backward() {
+    let array = Int32Array::from([None, Some(1), None, None, Some(4), None]);
+    assert_eq!(
+        fill_null_forward(&array),
+        Int32Array::from([None, Some(1), Some(1), Some(1), Some(4), Some(4)])
+    );
+    assert_eq!(
+        fill_null_backward(&array),
+        Int32Array::from([Some(1), Some(1), Some(4), Some(4), Some(4), None])
+    );
+
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    assert_eq!(fill_null_forward(&array).validity(), None);
+    assert_eq!(fill_null_backward(&array).validity(), None);
+}
+
+#[test]
+fn forward_backward_sliced() {
+    let array = Int32Array::from([Some(1), None, Some(3), None, None]).sliced(1, 4);
+    assert_eq!(
+        fill_null_forward(&array),
+        Int32Array::from([None, Some(3), Some(3), Some(3)])
+    );
+    assert_eq!(
+        fill_null_backward(&array),
+        Int32Array::from([Some(3), Some(3), None, None])
+    );
+}
+
+#[test]
+fn forward_backward_all_null() {
+    let array = Int32Array::new_null(DataType::Int32, 3);
+    assert_eq!(fill_null_forward(&array), array);
+    assert_eq!(fill_null_backward(&array), array);
+}
+
+#[test]
+fn forward_backward_utf8() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), None]);
+    let result = fill_null(&array, FillNullStrategy::Forward).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("a"), Some("c"), Some("c")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = fill_null(&array, FillNullStrategy::Backward).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("c"), Some("c"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn value() {
+    let array = Int32Array::from([None, Some(1), None]);
+    assert_eq!(
+        fill_null_primitive(&array, 5),
+        Int32Array::from_slice([5, 1, 5])
+    );
+
+    let value = PrimitiveScalar::<i32>::from(None);
+    let result = fill_null(&array, FillNullStrategy::Value(&value)).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    let array = BooleanArray::from([Some(true), None, Some(false), None]);
+    let value = arrow2::scalar::BooleanScalar::new(Some(true));
+    let result = fill_null(&array, FillNullStrategy::Value(&value)).unwrap();
+    let expected = BooleanArray::from_slice([true, true, false, true]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Utf8Array::<i64>::from([Some("a"), None]);
+    let value = Utf8Scalar::<i64>::new(Some("b"));
+    let result = fill_null(&array, FillNullStrategy::Value(&value)).unwrap();
+    let expected = Utf8Array::<i64>::from_slice(["a", "b"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn value_wrong_type() {
+    let array = Int32Array::from([None, Some(1)]);
+    let value = PrimitiveScalar::<i64>::from(Some(1));
+    assert!(fill_null(&array, FillNullStrategy::Value(&value)).is_err());
+}
+
+#[test]
+fn aggregates() {
+    let array = Int32Array::from([Some(4), None, Some(1), Some(2), None]);
+    let result = fill_null(&array, FillNullStrategy::Mean).unwrap();
+    let expected = Int32Array::from_slice([4, 2, 1, 2, 2]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = fill_null(&array, FillNullStrategy::Min).unwrap();
+    let expected = Int32Array::from_slice([4, 1, 1, 2, 1]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = fill_null(&array, FillNullStrategy::Max).unwrap();
+    let expected = Int32Array::from_slice([4, 4, 1, 2, 4]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Float64Array::new_null(DataType::Float64, 2);
+    let result = fill_null(&array, FillNullStrategy::Mean).unwrap();
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+
+    let array = Utf8Array::<i32>::from([Some("a"), None]);
+    assert!(fill_null(&array, FillNullStrategy::Max).is_err());
+    let array = DaysMsArray::new_null(DataType::Interval(IntervalUnit::DayTime), 2);
+    assert!(fill_null(&array, FillNullStrategy::Mean).is_err());
+}
+
+#[test]
+fn interpolate_linear() {
+    let values = Float64Array::from([None, Some(1.0), None, None, Some(4.0), None]);
+    assert_eq!(
+        interpolate(&values, InterpolationMethod::Linear),
+        Float64Array::from([None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None])
+    );
+
+    let values = Int32Array::from([Some(0), None, None, Some(5)]);
+    assert_eq!(
+        interpolate(&values, InterpolationMethod::Linear),
+        Int32Array::from_slice([0, 1, 3, 5])
+    );
+}
+
+#[test]
+fn interpolate_nearest() {
+    let values = Int32Array::from([Some(1), None, None, None, Some(5)]);
+    assert_eq!(
+        interpolate(&values, InterpolationMethod::Nearest),
+        Int32Array::from_slice([1, 1, 1, 5, 5])
+    );
+}
+
+#[test]
+fn interpolate_by_positions() {
+    let values = Float64Array::from([Some(0.0), None, None, Some(10.0)]);
+    let x = Int64Array::from_slice([0, 1, 8, 10]);
+    assert_eq!(
+        interpolate_by(&values, &x, InterpolationMethod::Linear).unwrap(),
+        Float64Array::from_slice([0.0, 1.0, 8.0, 10.0])
+    );
+    assert_eq!(
+        interpolate_by(&values, &x, InterpolationMethod::Nearest).unwrap(),
+        Float64Array::from_slice([0.0, 0.0, 10.0, 10.0])
+    );
+
+    let x = Int64Array::from([Some(0), None, Some(8), Some(10)]);
+    assert!(interpolate_by(&values, &x, InterpolationMethod::Linear).is_err());
+    let x = Int64Array::from_slice([0, 1]);
+    assert!(interpolate_by(&values, &x, InterpolationMethod::Linear).is_err());
+}
+
+#[test]
+fn interpolate_without_gaps() {
+    let values = Int32Array::new_null(DataType::Int32, 3);
+    assert_eq!(interpolate(&values, InterpolationMethod::Linear), values);
+
+    let values = Int32Array::from([Some(1), Some(2)]);
+    assert_eq!(interpolate(&values, InterpolationMethod::Linear), values);
+}
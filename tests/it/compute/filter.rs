@@ -1,5 +1,6 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
+use arrow2::chunk::Chunk;
 use arrow2::compute::filter::*;
 
 #[test]
@@ -100,6 +101,94 @@ fn primitive_array_with_null() {
     assert!(d.is_null(0));
 }
 
+#[test]
+fn primitive_runs_and_sparse() {
+    // alternate between chunks with long runs and chunks with isolated bits
+    let len = 64 * 6 + 7;
+    let mask = (0..len)
+        .map(|i| match (i / 64) % 2 {
+            0 => i % 16 < 10,
+            _ => i % 7 == 3,
+        })
+        .collect::<Vec<_>>();
+    let values = (0..len as i32)
+        .map(|i| if i % 5 == 0 { None } else { Some(i) })
+        .collect::<Vec<_>>();
+
+    let a = Int32Array::from(&values).sliced(3, len - 3);
+    let b = BooleanArray::from_slice(&mask).sliced(3, len - 3);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = values
+        .iter()
+        .zip(mask.iter())
+        .skip(3)
+        .filter_map(|(v, m)| m.then_some(*v))
+        .collect::<Int32Array>();
+    assert_eq!(expected, c.as_ref());
+}
+
+#[test]
+fn boolean_array() {
+    let len = 64 * 3 + 5;
+    let mask = (0..len)
+        .map(|i| i % 64 < 40 || i % 3 == 0)
+        .collect::<Vec<_>>();
+    let values = (0..len)
+        .map(|i| if i % 7 == 0 { None } else { Some(i % 3 == 1) })
+        .collect::<Vec<_>>();
+
+    let a = BooleanArray::from(values.clone()).sliced(1, len - 1);
+    let b = BooleanArray::from_slice(&mask).sliced(1, len - 1);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = values
+        .iter()
+        .zip(mask.iter())
+        .skip(1)
+        .filter_map(|(v, m)| m.then_some(*v))
+        .collect::<BooleanArray>();
+    assert_eq!(expected, c.as_ref());
+}
+
+#[test]
+fn chunk_with_null_filter() {
+    let columns = Chunk::new(vec![
+        Int32Array::from(&[Some(1), None, Some(3), Some(4)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]).boxed(),
+        BooleanArray::from([Some(true), Some(false), Some(true), None]).boxed(),
+    ]);
+    // the null in the filter is interpreted as `false`
+    let b = BooleanArray::from([Some(true), Some(true), None, Some(false)]);
+
+    let result = filter_chunk(&columns, &b).unwrap();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        Int32Array::from(&[Some(1), None]),
+        result.arrays()[0].as_ref()
+    );
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["a", "b"]),
+        result.arrays()[1].as_ref()
+    );
+    assert_eq!(
+        BooleanArray::from_slice([true, false]),
+        result.arrays()[2].as_ref()
+    );
+}
+
+#[test]
+fn build_filter_all_and_none() {
+    let a = Int32Array::from_slice([1, 2, 3]);
+
+    let all = build_filter(&BooleanArray::from_slice([true, true, true])).unwrap();
+    assert_eq!(a, all(&a).as_ref());
+
+    let none = build_filter(&BooleanArray::from([Some(false), None, Some(false)])).unwrap();
+    assert_eq!(Int32Array::from_slice([]), none(&a).as_ref());
+}
+
 #[test]
 fn string_array_with_null() {
     let a = Utf8Array::<i32>::from([Some("hello"), None, Some("world"), None]);
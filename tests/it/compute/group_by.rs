@@ -0,0 +1,107 @@
+use arrow2::array::*;
+use arrow2::compute::group_by::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn errors() {
+    let a = Int32Array::from([Some(1), Some(1), None, None]);
+    assert!(group_by(&[]).is_err());
+    assert!(group_by(&[&a, &Int32Array::from_slice([1])]).is_err());
+    assert!(group_by(&[&NullArray::new(DataType::Null, 4)]).is_err());
+}
+
+#[test]
+fn multiple_columns() {
+    let a = Int64Array::from([Some(1), Some(2), Some(1), None, Some(1), None]);
+    let b = BooleanArray::from([Some(true), Some(true), Some(false), None, Some(true), None]);
+    let groups = group_by(&[&a, &b]).unwrap();
+
+    assert_eq!(groups.group_ids, vec![0, 1, 2, 3, 0, 3]);
+    assert_eq!(groups.num_groups(), 4);
+    assert_eq!(
+        Int64Array::from([Some(1), Some(2), Some(1), None]),
+        groups.keys[0].as_ref()
+    );
+    assert_eq!(
+        BooleanArray::from([Some(true), Some(true), Some(false), None]),
+        groups.keys[1].as_ref()
+    );
+}
+
+#[test]
+fn dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None, Some(2), Some(1)]);
+    let a = DictionaryArray::try_from_keys(keys, values).unwrap();
+    assert!(can_group_by(a.data_type()));
+
+    let groups = group_by(&[&a]).unwrap();
+    assert_eq!(groups.group_ids, vec![0, 1, 2, 0, 1]);
+    assert_eq!(groups.num_groups(), 3);
+}
+
+#[test]
+fn floats() {
+    let a = Float64Array::from_slice([f64::NAN, 1.0, f64::NAN, 0.0, 1.0]);
+    let groups = group_by(&[&a]).unwrap();
+    assert_eq!(groups.group_ids, vec![0, 1, 0, 2, 1]);
+}
+
+#[test]
+fn many_groups() {
+    let a = UInt32Array::from_vec((0..10_000).map(|x| x % 3_000).collect());
+    let groups = group_by(&[&a]).unwrap();
+    assert_eq!(groups.num_groups(), 3_000);
+    let expected = (0..10_000).map(|x| x % 3_000).collect::<Vec<u32>>();
+    assert_eq!(groups.group_ids, expected);
+}
+
+#[test]
+fn kernels() {
+    let values = Int32Array::from([Some(1), None, Some(3), Some(-4), None]);
+    let group_ids = [0, 1, 0, 2, 2];
+
+    let result = count_by_group(&values, &group_ids, 4).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 0, 1, 0]));
+
+    let result = sum_by_group(&values, &group_ids, 4).unwrap();
+    assert_eq!(result, Int32Array::from([Some(4), None, Some(-4), None]));
+
+    let result = min_by_group(&values, &group_ids, 4).unwrap();
+    assert_eq!(result, Int32Array::from([Some(1), None, Some(-4), None]));
+
+    let result = max_by_group(&values, &group_ids, 4).unwrap();
+    assert_eq!(result, Int32Array::from([Some(3), None, Some(-4), None]));
+
+    let result = mean_by_group(&values, &group_ids, 4).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from([Some(2.0), None, Some(-4.0), None])
+    );
+}
+
+#[test]
+fn sum_wraps() {
+    let values = Int8Array::from_slice([i8::MAX, 1, i8::MIN, -1]);
+    let result = sum_by_group(&values, &[0, 0, 1, 1], 2).unwrap();
+    assert_eq!(result, Int8Array::from_slice([i8::MIN, i8::MAX]));
+
+    let values = Float64Array::from_slice([0.5, 1.5]);
+    let result = sum_by_group(&values, &[0, 0], 1).unwrap();
+    assert_eq!(result, Float64Array::from_slice([2.0]));
+}
+
+#[test]
+fn kernels_keep_type() {
+    let values = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    let result = max_by_group(&values, &[0, 0], 1).unwrap();
+    assert_eq!(result, Int32Array::from_slice([2]).to(DataType::Date32));
+}
+
+#[test]
+fn kernels_invalid_ids() {
+    let values = Int32Array::from_slice([1, 2]);
+    assert!(sum_by_group(&values, &[0], 1).is_err());
+    assert!(sum_by_group(&values, &[0, 1], 1).is_err());
+    assert!(count_by_group(&values, &[0, 1], 2).is_ok());
+}
@@ -1,7 +1,8 @@
-use arrow2::array::new_null_array;
+use arrow2::array::*;
 use arrow2::compute::hash::*;
+use arrow2::compute::take::take;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{IntegerType, TimeUnit};
 
 #[test]
 fn consistency() {
@@ -36,6 +37,9 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Dictionary(IntegerType::Int32, Box::new(Utf8), false),
+        Dictionary(IntegerType::UInt8, Box::new(Int64), false),
+        Dictionary(IntegerType::Int16, Box::new(Float32), false),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -47,3 +51,153 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn dictionary() {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("bb")]).boxed();
+    let keys = PrimitiveArray::<u16>::from([Some(2), Some(0), None, Some(1), Some(2)]);
+    let array = DictionaryArray::try_from_keys(keys.clone(), values.clone()).unwrap();
+
+    let expanded = take(values.as_ref(), &keys).unwrap();
+    let expected = hash(expanded.as_ref()).unwrap();
+    let result = hash(&array).unwrap();
+    assert_eq!(result, expected);
+    assert_eq!(
+        result.validity().unwrap(),
+        &[true, true, false, false, true].into()
+    );
+
+    for function in [
+        HashFunction::AHash,
+        HashFunction::XxHash64,
+        HashFunction::WyHash,
+    ] {
+        assert_eq!(
+            hash_with(&array, function, 3).unwrap(),
+            hash_with(expanded.as_ref(), function, 3).unwrap()
+        );
+    }
+}
+
+#[test]
+fn dictionary_of_unsupported_values() {
+    let values = Float64Array::from_slice([1.0, 2.0]).boxed();
+    let keys = PrimitiveArray::<i8>::from_slice([1, 0]);
+    let array = DictionaryArray::try_from_keys(keys, values).unwrap();
+    assert!(!can_hash(array.data_type()));
+    assert!(hash(&array).is_err());
+    assert!(hash_columns(&[&array], HashFunction::XxHash64, 0).is_ok());
+}
+
+#[test]
+fn hash_with_ahash_zero_seed_is_hash() {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    assert_eq!(
+        hash_with(&array, HashFunction::AHash, 0).unwrap(),
+        hash(&array).unwrap()
+    );
+    let array = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    assert_eq!(
+        hash_with(&array, HashFunction::AHash, 0).unwrap(),
+        hash(&array).unwrap()
+    );
+}
+
+#[test]
+fn hash_with_stable() {
+    // reference values of XXH64 and wyhash
+    let array = Utf8Array::<i32>::from_slice(["", "a"]);
+    let result = hash_with(&array, HashFunction::XxHash64, 0).unwrap();
+    assert_eq!(
+        result.values().as_slice(),
+        &[0xef46db3751d8e999, 0xd24ec4f1a98c6e5b]
+    );
+
+    let array = Int64Array::from_slice([1]);
+    // the little-endian bytes of 1i64
+    let bytes = Utf8Array::<i32>::from_slice(["\x01\0\0\0\0\0\0\0"]);
+    for function in [HashFunction::XxHash64, HashFunction::WyHash] {
+        assert_eq!(
+            hash_with(&array, function, 7).unwrap().values(),
+            hash_with(&bytes, function, 7).unwrap().values()
+        );
+        assert_ne!(
+            hash_with(&array, function, 7).unwrap(),
+            hash_with(&array, function, 8).unwrap()
+        );
+    }
+}
+
+#[test]
+fn hash_columns_combines() {
+    let a = Int32Array::from([Some(1), Some(1), Some(1), None]);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x"), Some("x")]);
+    for function in [
+        HashFunction::AHash,
+        HashFunction::XxHash64,
+        HashFunction::WyHash,
+    ] {
+        let result = hash_columns(&[&a, &b], function, 0).unwrap();
+        assert_eq!(result.validity(), None);
+        assert_eq!(result.value(0), result.value(2));
+        assert_ne!(result.value(0), result.value(1));
+
+        // a single column is hashed as by `hash_with`, nulls are hashed
+        let result = hash_columns(&[&a], function, 5).unwrap();
+        let expected = hash_with(&a, function, 5).unwrap();
+        assert_eq!(&result.values()[..3], &expected.values()[..3]);
+        assert_ne!(result.value(3), 5);
+    }
+}
+
+#[test]
+fn hash_columns_nulls() {
+    let a = Utf8Array::<i32>::from([None, Some("x"), None]);
+    let b = Utf8Array::<i32>::from([Some("x"), None, None]);
+    for function in [
+        HashFunction::AHash,
+        HashFunction::XxHash64,
+        HashFunction::WyHash,
+    ] {
+        let result = hash_columns(&[&a, &b], function, 0).unwrap();
+        assert_ne!(result.value(0), result.value(1));
+        assert_ne!(result.value(0), result.value(2));
+        assert_ne!(result.value(1), result.value(2));
+    }
+}
+
+#[test]
+fn hash_columns_floats_and_dictionaries() {
+    let a = Float64Array::from([Some(f64::NAN), Some(1.0), Some(f64::NAN), None]);
+    let values = Utf8Array::<i32>::from([Some("x"), None, Some("y")]).boxed();
+    let keys = PrimitiveArray::<u8>::from([Some(0), Some(2), Some(0), Some(1)]);
+    let b = DictionaryArray::try_from_keys(keys, values).unwrap();
+    let c = Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x"), None]);
+    assert!(can_hash_columns(a.data_type()));
+    assert!(can_hash_columns(b.data_type()));
+
+    let result = hash_columns(&[&a, &b], HashFunction::XxHash64, 0).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(1));
+
+    // a null value of a dictionary is hashed as a null key
+    let keys = PrimitiveArray::<u8>::from([Some(0), Some(2), Some(0), None]);
+    let b_null_key = DictionaryArray::try_from_keys(keys, b.values().clone()).unwrap();
+    let result_null_key = hash_columns(&[&a, &b_null_key], HashFunction::XxHash64, 0).unwrap();
+    assert_eq!(result, result_null_key);
+
+    // rows of the dictionary and of its expanded values are partitioned alike
+    let expanded = hash_columns(&[&a, &c], HashFunction::XxHash64, 0).unwrap();
+    assert_eq!(expanded.value(0), expanded.value(2));
+}
+
+#[test]
+fn hash_columns_errors() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(hash_columns(&[], HashFunction::XxHash64, 0).is_err());
+    assert!(hash_columns(&[&a, &b], HashFunction::XxHash64, 0).is_err());
+    let c = NullArray::new(Null, 2);
+    assert!(!can_hash_columns(c.data_type()));
+    assert!(hash_columns(&[&a, &c], HashFunction::WyHash, 0).is_err());
+}
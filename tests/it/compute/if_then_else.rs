@@ -1,6 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::if_then_else::if_then_else;
+use arrow2::compute::if_then_else::*;
+use arrow2::datatypes::{DataType, Field, UnionMode};
 use arrow2::error::Result;
+use arrow2::scalar::*;
 
 #[test]
 fn basics() -> Result<()> {
@@ -40,3 +42,245 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+fn list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_nulls_pred() -> Result<()> {
+    let lhs = list(vec![Some(vec![Some(1)]), Some(vec![Some(2), None]), None]);
+    let rhs = list(vec![None, Some(vec![]), Some(vec![Some(3)])]);
+    let predicate = BooleanArray::from(&[Some(true), None, Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = list(vec![Some(vec![Some(1)]), None, Some(vec![Some(3)])]);
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn struct_nulls_pred() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let lhs = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        ],
+        None,
+    );
+    let rhs = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from_slice([4, 5, 6]).boxed(),
+            Utf8Array::<i32>::from_slice(["d", "e", "f"]).boxed(),
+        ],
+        Some([true, true, false].into()),
+    );
+    let predicate = BooleanArray::from(&[Some(false), None, Some(true)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from([Some(4), None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from([Some("d"), None, Some("c")]).boxed(),
+        ],
+        Some([true, false, true].into()),
+    );
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let lhs = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        None,
+    );
+    let rhs = FixedSizeListArray::new(
+        data_type.clone(),
+        Int32Array::from_slice([5, 6, 7, 8]).boxed(),
+        None,
+    );
+    let predicate = BooleanArray::from(&[None, Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+
+    let expected = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from([None, None, Some(7), Some(8)]).boxed(),
+        Some([false, true].into()),
+    );
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let lhs = DictionaryArray::try_from_keys(
+        Int32Array::from_slice([0, 1, 0]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )?;
+    let rhs = DictionaryArray::try_from_keys(
+        Int32Array::from_slice([1, 0, 0]),
+        Utf8Array::<i32>::from_slice(["c", "d"]).boxed(),
+    )?;
+    let predicate = BooleanArray::from(&[Some(true), Some(false), None]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    let result = result
+        .keys()
+        .iter()
+        .map(|key| key.map(|key| values.value(*key as usize)))
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec![Some("a"), Some("c"), None]);
+    Ok(())
+}
+
+#[test]
+fn union_nulls_pred() -> Result<()> {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            None,
+            mode,
+        );
+        let union = |types: Vec<i8>, ints: Int32Array, strings: Utf8Array<i32>, offsets| {
+            UnionArray::new(
+                data_type.clone(),
+                types.into(),
+                vec![ints.boxed(), strings.boxed()],
+                offsets,
+            )
+        };
+        let (lhs, rhs) = if mode.is_sparse() {
+            (
+                union(
+                    vec![0, 1],
+                    Int32Array::from_slice([1, 2]),
+                    Utf8Array::from_slice(["a", "b"]),
+                    None,
+                ),
+                union(
+                    vec![1, 0],
+                    Int32Array::from_slice([3, 4]),
+                    Utf8Array::from_slice(["c", "d"]),
+                    None,
+                ),
+            )
+        } else {
+            (
+                union(
+                    vec![0, 1],
+                    Int32Array::from_slice([1]),
+                    Utf8Array::from_slice(["b"]),
+                    Some(vec![0, 0].into()),
+                ),
+                union(
+                    vec![1, 0],
+                    Int32Array::from_slice([4]),
+                    Utf8Array::from_slice(["c"]),
+                    Some(vec![0, 0].into()),
+                ),
+            )
+        };
+        let predicate = BooleanArray::from(&[None, Some(false)]);
+        let result = if_then_else(&predicate, &lhs, &rhs)?;
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(!result.value(0).is_valid());
+        assert_eq!(
+            result.value(1).as_ref(),
+            &arrow2::scalar::PrimitiveScalar::<i32>::from(Some(4)) as &dyn arrow2::scalar::Scalar
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn scalar_array() -> Result<()> {
+    let lhs = PrimitiveScalar::<i32>::from(Some(0));
+    let rhs = Int32Array::from([Some(4), None, Some(6), Some(7)]);
+    let predicate = BooleanArray::from([Some(true), Some(false), None, Some(false)]);
+    let result = if_then_else_scalar(&predicate, &lhs, &rhs)?;
+
+    let expected = Int32Array::from([Some(0), None, None, Some(7)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = if_then_else_array_scalar(&predicate, &rhs, &lhs)?;
+    let expected = Int32Array::from([Some(4), Some(0), None, Some(0)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_array_sliced() -> Result<()> {
+    let lhs = BooleanScalar::new(Some(true));
+    let rhs = BooleanArray::from([Some(false), Some(false), None, Some(true)]).sliced(1, 3);
+    let predicate = BooleanArray::from_slice([true, true, false, false]).sliced(1, 3);
+    let result = if_then_else_scalar(&predicate, &lhs, &rhs)?;
+
+    let expected = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalars() -> Result<()> {
+    let lhs = Utf8Scalar::<i32>::new(Some("yes"));
+    let rhs = Utf8Scalar::<i32>::new(None::<&str>);
+    let predicate = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    let result = if_then_else_scalars(&predicate, &lhs, &rhs)?;
+
+    let expected = Utf8Array::<i32>::from([Some("yes"), None, None, Some("yes")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_binary() -> Result<()> {
+    let lhs = BinaryScalar::<i64>::new(Some(b"a"));
+    let rhs = BinaryArray::<i64>::from([Some(b"bb".as_ref()), Some(b"cc"), None, Some(b"dd")]);
+    let predicate = BooleanArray::from_slice([false, true, false, false]);
+    let result = if_then_else_scalar(&predicate, &lhs, &rhs)?;
+
+    let expected = BinaryArray::<i64>::from([Some(b"bb".as_ref()), Some(b"a"), None, Some(b"dd")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn scalar_errors() {
+    let predicate = BooleanArray::from_slice([true, false]);
+    let lhs = PrimitiveScalar::<i64>::from(Some(0));
+    let rhs = Int32Array::from_slice([1, 2]);
+    assert!(if_then_else_scalar(&predicate, &lhs, &rhs).is_err());
+
+    let lhs = PrimitiveScalar::<i32>::from(Some(0));
+    let rhs = Int32Array::from_slice([1]);
+    assert!(if_then_else_scalar(&predicate, &lhs, &rhs).is_err());
+}
@@ -0,0 +1,81 @@
+use arrow2::array::*;
+use arrow2::compute::is_in::is_in;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let haystack = Int64Array::from_slice([1, 5, 9]);
+    let probe = Int64Array::from([Some(5), Some(2), None, Some(1)]);
+    let result = is_in(&haystack, &probe)?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+    Ok(())
+}
+
+#[test]
+fn null_in_haystack() -> Result<()> {
+    let haystack = Utf8Array::<i32>::from([Some("a"), None]);
+    let probe = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let result = is_in(&haystack, &probe)?;
+    assert_eq!(result, BooleanArray::from([Some(true), None, None]));
+    Ok(())
+}
+
+#[test]
+fn hashed() -> Result<()> {
+    // large enough to be hashed, with duplicates and a null
+    let haystack = Int32Array::from_iter((0..100).map(|x| (x != 50).then_some(x % 40 * 2)));
+    let probe = Int32Array::from([Some(0), Some(78), Some(79), Some(80), None]);
+    let result = is_in(&haystack, &probe)?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(true), None, None, None])
+    );
+
+    let haystack = Utf8Array::<i64>::from_iter_values((0..100).map(|x| x.to_string()));
+    let probe = Utf8Array::<i64>::from_slice(["10", "100", "99"]);
+    let result = is_in(&haystack, &probe)?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+    Ok(())
+}
+
+#[test]
+fn dictionary_probe() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let keys = PrimitiveArray::<u8>::from([Some(2), None, Some(0), Some(1)]);
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false);
+    let probe = DictionaryArray::try_new(data_type, keys, values.boxed())?;
+
+    let haystack = Utf8Array::<i32>::from_slice(["c", "b"]);
+    let result = is_in(&haystack, &probe)?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+    Ok(())
+}
+
+#[test]
+fn boolean_and_empty() -> Result<()> {
+    let probe = BooleanArray::from([Some(true), Some(false)]);
+    let result = is_in(&BooleanArray::from_slice([false]), &probe)?;
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+
+    let result = is_in(&BooleanArray::from_slice([]), &probe)?;
+    assert_eq!(result, BooleanArray::from_slice([false, false]));
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let haystack = Int32Array::from_slice([1]);
+    let probe = Int64Array::from_slice([1]);
+    assert!(is_in(&haystack, &probe).is_err());
+
+    let haystack = Utf8Array::<i32>::from_slice(["a"]);
+    let probe = Utf8Array::<i64>::from_slice(["a"]);
+    assert!(is_in(&haystack, &probe).is_err());
+}
@@ -41,6 +41,127 @@ fn utf8() {
     length_test_string::<i32>()
 }
 
+fn char_length_test_string<O: Offset>() {
+    vec![
+        (
+            vec![Some("hello"), Some(" "), None],
+            vec![Some(5usize), Some(1), None],
+            vec![Some(40usize), Some(8), None],
+        ),
+        (
+            vec![Some("💖"), None, Some("olá"), Some("")],
+            vec![Some(1), None, Some(3), Some(0)],
+            vec![Some(32), None, Some(32), Some(0)],
+        ),
+    ]
+    .into_iter()
+    .for_each(|(input, chars, bits)| {
+        let array = Utf8Array::<O>::from(input);
+
+        let data_type = if O::IS_LARGE {
+            DataType::Int64
+        } else {
+            DataType::Int32
+        };
+        let to_array = |expected: Vec<Option<usize>>| {
+            expected
+                .into_iter()
+                .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+                .collect::<PrimitiveArray<O>>()
+                .to(data_type.clone())
+        };
+
+        let result = char_length(&array).unwrap();
+        assert_eq!(to_array(chars), result.as_ref());
+        let result = bit_length(&array).unwrap();
+        assert_eq!(to_array(bits), result.as_ref());
+    })
+}
+
+#[test]
+fn char_length_large_utf8() {
+    char_length_test_string::<i64>()
+}
+
+#[test]
+fn char_length_utf8() {
+    char_length_test_string::<i32>()
+}
+
+#[test]
+fn char_length_sliced() {
+    // only the sliced values are ASCII
+    let array = Utf8Array::<i32>::from_slice(["olá", "ab", "c"]).sliced(1, 2);
+    let result = char_length(&array).unwrap();
+    assert_eq!(Int32Array::from_slice([2, 1]), result.as_ref());
+}
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i32>::from([Some(b"ab".as_ref()), None, Some(b"")]).sliced(1, 2);
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([None, Some(0)]), result.as_ref());
+
+    let array = BinaryArray::<i64>::from([Some(b"abc".as_ref()), None]);
+    let result = length(&array).unwrap();
+    assert_eq!(Int64Array::from([Some(3), None]), result.as_ref());
+
+    let array = FixedSizeBinaryArray::from([Some([1, 2]), None]);
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None]), result.as_ref());
+}
+
+#[test]
+fn list() {
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend([Some(vec![Some(1), None, Some(3)]), None, Some(vec![])])
+        .unwrap();
+    let array: ListArray<i64> = array.into();
+    let result = length(&array).unwrap();
+    assert_eq!(Int64Array::from([Some(3), None, Some(0)]), result.as_ref());
+
+    let result = length(&array.sliced(1, 2)).unwrap();
+    assert_eq!(Int64Array::from([None, Some(0)]), result.as_ref());
+
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let array = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        Some([false, true].into()),
+    );
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([None, Some(2)]), result.as_ref());
+}
+
+#[test]
+fn map() {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let entries = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", DataType::Struct(fields), false)),
+        false,
+    );
+    let array = MapArray::new(
+        data_type,
+        vec![0, 2, 2, 3].try_into().unwrap(),
+        entries.boxed(),
+        Some([true, false, true].into()),
+    );
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(1)]), result.as_ref());
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
@@ -76,14 +197,17 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        FixedSizeBinary(3),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new("item", Int32, true))),
+        FixedSizeList(Box::new(Field::new("item", Int32, true)), 2),
     ];
 
     datatypes.into_iter().for_each(|d1| {
         let array = new_null_array(d1.clone(), 10);
-        if can_length(&d1) {
-            assert!(length(array.as_ref()).is_ok());
-        } else {
-            assert!(length(array.as_ref()).is_err());
-        }
+        assert_eq!(can_length(&d1), length(array.as_ref()).is_ok());
+        let is_string = matches!(d1, Utf8 | LargeUtf8);
+        assert_eq!(is_string, char_length(array.as_ref()).is_ok());
+        assert_eq!(is_string, bit_length(array.as_ref()).is_ok());
     });
 }
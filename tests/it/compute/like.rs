@@ -65,6 +65,112 @@ fn test_like_utf8_scalar() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_like_utf8_patterns() -> Result<()> {
+    let array =
+        Utf8Array::<i32>::from_slice(["", "abc", "abcabc", "a\nbc", "aXbYc", "ác", "a%c", "a\\c"]);
+    let cases = [
+        ("", [true, false, false, false, false, false, false, false]),
+        ("%", [true; 8]),
+        ("%%", [true; 8]),
+        ("a%c", [false, true, true, true, true, false, true, true]),
+        ("a_c", [false, true, false, false, false, false, true, true]),
+        (
+            "_c",
+            [false, false, false, false, false, true, false, false],
+        ),
+        ("%b%c", [false, true, true, true, true, false, false, false]),
+        (
+            "a%b_c",
+            [false, false, false, false, true, false, false, false],
+        ),
+        (
+            "%abc_%",
+            [false, false, true, false, false, false, false, false],
+        ),
+        (
+            "a\\%c",
+            [false, false, false, false, false, false, true, false],
+        ),
+        (
+            "a\\c",
+            [false, false, false, false, false, false, false, true],
+        ),
+        // a non-wildcard is not escaped
+        ("a\\\\c", [false; 8]),
+        ("%bc", [false, true, true, true, false, false, false, false]),
+        (
+            "abc%",
+            [false, true, true, false, false, false, false, false],
+        ),
+        (
+            "%ca%",
+            [false, false, true, false, false, false, false, false],
+        ),
+    ];
+    for (pattern, expected) in cases {
+        let result = like_utf8_scalar(&array, pattern)?;
+        assert_eq!(result, BooleanArray::from_slice(expected), "{pattern}");
+
+        let patterns = Utf8Array::<i32>::from_slice(vec![pattern; array.len()]);
+        let result = like_utf8(&array, &patterns)?;
+        assert_eq!(result, BooleanArray::from_slice(expected), "{pattern}");
+
+        let result = nlike_utf8_scalar(&array, pattern)?;
+        assert_eq!(
+            result,
+            BooleanArray::from_slice(expected.map(|x| !x)),
+            "{pattern}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_nulls() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("Arrow"), None, Some("Arrow")]);
+    let patterns = Utf8Array::<i32>::from([Some("A%"), Some("A%"), None]);
+    let result = like_utf8(&array, &patterns)?;
+    assert_eq!(result, BooleanArray::from([Some(true), None, None]));
+
+    let result = nlike_utf8_scalar(&array, "A%")?;
+    assert_eq!(result, BooleanArray::from([Some(false), None, Some(false)]));
+
+    assert!(like_utf8(&array, &Utf8Array::<i32>::from_slice(["a"])).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(["Arrow", "ARROW", "arrow", "ÄRROW", "Boat"]);
+
+    let result = ilike_utf8_scalar(&array, "aRr%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, true, true, false, false])
+    );
+
+    let result = ilike_utf8_scalar(&array, "_rr_w")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, true, true, true, false])
+    );
+
+    let result = nilike_utf8_scalar(&array, "%ROW")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, false, false, true])
+    );
+
+    let patterns = Utf8Array::<i64>::from_slice(["ARROW", "a%", "%RR%", "ä%", "b_at"]);
+    let result = ilike_utf8(&array, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice([true; 5]));
+
+    let result = nilike_utf8(&array, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice([false; 5]));
+    Ok(())
+}
+
 #[test]
 fn test_nlike_binary_scalar() -> Result<()> {
     let array = BinaryArray::<i32>::from_slice(["Arrow", "Arrow", "Arrow", "BA"]);
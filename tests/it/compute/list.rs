@@ -0,0 +1,254 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::list::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+fn list<O: arrow2::offset::Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn explode_list() -> Result<()> {
+    let array = list::<i64>(vec![
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(4)]),
+    ]);
+    let (values, rows) = explode(&array)?;
+    let expected = Int32Array::from([Some(1), None, Some(4)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(rows, UInt64Array::from_slice([0, 0, 3]));
+    Ok(())
+}
+
+#[test]
+fn explode_sliced() -> Result<()> {
+    let array = list::<i32>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![Some(4)]),
+    ])
+    .sliced(1, 2);
+    let (values, rows) = explode(&array)?;
+    let expected = Int32Array::from_slice([2, 3, 4]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(rows, UInt64Array::from_slice([0, 0, 1]));
+    Ok(())
+}
+
+#[test]
+fn explode_fixed_size_list() -> Result<()> {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+    let validity = Bitmap::from([true, false, true]);
+    let array = FixedSizeListArray::new(data_type, values, Some(validity));
+
+    let (values, rows) = explode(&array)?;
+    let expected = Int32Array::from_slice([1, 2, 5, 6]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(rows, UInt64Array::from_slice([0, 0, 2, 2]));
+    Ok(())
+}
+
+#[test]
+fn explode_not_a_list() {
+    assert!(explode(&Int32Array::from_slice([1])).is_err());
+    assert!(flatten(&list::<i32>(vec![Some(vec![Some(1)])])).is_err());
+}
+
+#[test]
+fn flatten_list() -> Result<()> {
+    let inner = list::<i32>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4)]),
+        Some(vec![Some(5)]),
+    ]);
+    let data_type = DataType::LargeList(Box::new(Field::new(
+        "item",
+        inner.data_type().clone(),
+        true,
+    )));
+    let array = ListArray::<i64>::new(
+        data_type,
+        vec![0, 2, 4, 5].try_into().unwrap(),
+        inner.boxed(),
+        Some(Bitmap::from([true, true, false])),
+    );
+
+    let result = flatten(&array)?;
+    let mut expected = list::<i64>(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        Some(vec![Some(4)]),
+        Some(vec![]),
+    ]);
+    expected.set_validity(Some(Bitmap::from([true, true, false])));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn flatten_fixed_size_list() -> Result<()> {
+    let inner = list::<i32>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![]),
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![Some(4)]),
+    ]);
+    let data_type = DataType::FixedSizeList(
+        Box::new(Field::new("item", inner.data_type().clone(), true)),
+        2,
+    );
+    let array = FixedSizeListArray::new(data_type, inner.boxed(), None);
+
+    let result = flatten(&array)?;
+    let expected = list::<i32>(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+    ]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn contains_primitive() -> Result<()> {
+    let array = list::<i32>(vec![
+        Some(vec![Some(1), None, Some(3)]),
+        Some(vec![Some(4)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(5)]),
+    ]);
+    let needles = Int32Array::from([Some(3), Some(3), Some(1), Some(1), None]);
+    assert_eq!(
+        list_contains(&array, &needles)?,
+        BooleanArray::from([Some(true), Some(false), None, Some(false), None])
+    );
+    assert_eq!(
+        array_position(&array, &needles)?,
+        UInt64Array::from([Some(3), None, None, None, None])
+    );
+
+    let needle = arrow2::scalar::PrimitiveScalar::<i32>::from(Some(4));
+    assert_eq!(
+        list_contains_scalar(&array, &needle)?,
+        BooleanArray::from([Some(false), Some(true), None, Some(false), Some(false)])
+    );
+    let needle = arrow2::scalar::PrimitiveScalar::<i32>::from(None);
+    assert_eq!(
+        list_contains_scalar(&array, &needle)?,
+        BooleanArray::from([None, None, None, None, None])
+    );
+    Ok(())
+}
+
+#[test]
+fn contains_utf8() -> Result<()> {
+    let mut array = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![Some("a"), Some("b"), Some("b")]),
+        Some(vec![Some("c")]),
+    ])?;
+    let array: ListArray<i64> = array.into();
+
+    let needles = Utf8Array::<i32>::from_slice(["b", "b"]);
+    assert_eq!(
+        array_position(&array, &needles)?,
+        UInt64Array::from([Some(2), None])
+    );
+    let needle = arrow2::scalar::Utf8Scalar::<i32>::new(Some("c"));
+    assert_eq!(
+        list_contains_scalar(&array, &needle)?,
+        BooleanArray::from_slice([false, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn contains_dictionary() -> Result<()> {
+    let mut array =
+        MutableListArray::<i32, MutableDictionaryArray<u8, MutableUtf8Array<i32>>>::new();
+    array.try_extend(vec![
+        Some(vec![Some("a"), None, Some("b")]),
+        Some(vec![Some("a")]),
+        Some(vec![Some("c"), Some("a")]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+
+    let needle = arrow2::scalar::Utf8Scalar::<i32>::new(Some("a"));
+    assert_eq!(
+        array_position_scalar(&array, &needle)?,
+        UInt64Array::from([Some(1), Some(1), Some(2)])
+    );
+    let needles = Utf8Array::<i32>::from_slice(["b", "b", "c"]);
+    assert_eq!(
+        list_contains(&array, &needles)?,
+        BooleanArray::from_slice([true, false, true])
+    );
+    Ok(())
+}
+
+#[test]
+fn contains_errors() {
+    let array = list::<i32>(vec![Some(vec![Some(1)])]);
+    assert!(list_contains(&array, &Int32Array::from_slice([1, 2])).is_err());
+    assert!(list_contains(&array, &Int64Array::from_slice([1])).is_err());
+    assert!(list_contains(&Int32Array::from_slice([1]), &Int32Array::from_slice([1])).is_err());
+}
+
+#[test]
+fn concat() -> Result<()> {
+    let a = list::<i64>(vec![Some(vec![Some(1)]), Some(vec![]), None, Some(vec![])]);
+    let b = list::<i64>(vec![
+        Some(vec![Some(2), None]),
+        Some(vec![Some(3)]),
+        Some(vec![Some(4)]),
+        Some(vec![]),
+    ]);
+    let c = list::<i64>(vec![
+        Some(vec![Some(5)]),
+        Some(vec![]),
+        Some(vec![]),
+        Some(vec![]),
+    ]);
+
+    let result = list_concat(&[&a, &b, &c])?;
+    let expected = list::<i64>(vec![
+        Some(vec![Some(1), Some(2), None, Some(5)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = list_concat(&[&a.clone().sliced(1, 2), &b.sliced(1, 2)])?;
+    let expected = list::<i64>(vec![Some(vec![Some(3)]), None]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn concat_errors() {
+    let a = list::<i32>(vec![Some(vec![Some(1)])]);
+    let b = list::<i32>(vec![Some(vec![Some(1)]), None]);
+    assert!(list_concat::<i32>(&[]).is_err());
+    assert!(list_concat(&[&a, &b]).is_err());
+}
+
+#[test]
+fn append() -> Result<()> {
+    let array = list::<i32>(vec![Some(vec![Some(1)]), None, Some(vec![])]).sliced(1, 2);
+    let elements = Int32Array::from([Some(2), Some(3)]);
+    let result = list_append(&array, &elements)?;
+    let expected = list::<i32>(vec![None, Some(vec![Some(3)])]);
+    assert_eq!(result, expected);
+
+    assert!(list_append(&array, &Int64Array::from_slice([1, 2])).is_err());
+    assert!(list_append(&array, &Int32Array::from_slice([1])).is_err());
+    Ok(())
+}
@@ -0,0 +1,144 @@
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use arrow2::array::*;
+use arrow2::compute::cast::RoundingMode;
+use arrow2::compute::math::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn unary() {
+    let array = Float64Array::from([Some(4.0), None, Some(-1.0)]);
+    let result = sqrt(&array);
+    assert_eq!(result.value(0), 2.0);
+    assert!(!result.is_valid(1));
+    assert!(result.value(2).is_nan());
+
+    let array = Float32Array::from([Some(1.0f32), None]);
+    assert_eq!(ln(&array), Float32Array::from([Some(0.0f32), None]));
+    assert_eq!(
+        exp(&Float32Array::from_slice([0.0f32])),
+        Float32Array::from_slice([1.0f32])
+    );
+
+    let array = Float64Array::from_slice([0.0]);
+    assert_eq!(sin(&array), array);
+    assert_eq!(cos(&array), Float64Array::from_slice([1.0]));
+    assert_eq!(tan(&array), array);
+    assert_eq!(asin(&array), array);
+    assert_eq!(atan(&array), array);
+    assert_eq!(acos(&Float64Array::from_slice([1.0])), array);
+}
+
+#[test]
+fn unary_dyn_promotes_integers() {
+    let array = Int32Array::from([Some(100), None, Some(1)]);
+    let result = log10_dyn(&array).unwrap();
+    assert_eq!(
+        Float64Array::from([Some(2.0), None, Some(0.0)]),
+        result.as_ref()
+    );
+
+    let array = UInt8Array::from_slice([8]);
+    let result = log2_dyn(&array).unwrap();
+    assert_eq!(Float64Array::from_slice([3.0]), result.as_ref());
+
+    let array = Float32Array::from_slice([4.0f32]);
+    let result = sqrt_dyn(&array).unwrap();
+    assert_eq!(Float32Array::from_slice([2.0f32]), result.as_ref());
+
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(sqrt_dyn(&array).is_err());
+    assert!(!can_math(array.data_type()));
+    assert!(can_math(&DataType::UInt64));
+}
+
+#[test]
+fn pow_() {
+    let base = Float32Array::from([Some(2.0f32), None]);
+    assert_eq!(
+        pow_scalar(&base, 2.0),
+        Float32Array::from([Some(4.0f32), None])
+    );
+
+    let exponent = Float32Array::from_slice([3.0f32, 2.0]);
+    let result = pow_dyn(&base, &exponent).unwrap();
+    assert_eq!(Float32Array::from([Some(8.0f32), None]), result.as_ref());
+
+    let base = Int64Array::from_slice([2, 9]);
+    let exponent = Float32Array::from_slice([-1.0f32, 0.5]);
+    let result = pow_dyn(&base, &exponent).unwrap();
+    assert_eq!(Float64Array::from_slice([0.5, 3.0]), result.as_ref());
+
+    assert!(pow_dyn(&base, &Float64Array::from_slice([1.0])).is_err());
+}
+
+#[test]
+fn round_floats() {
+    let array = Float64Array::from([Some(2.5), Some(-2.5), Some(3.5), None]);
+    let result = round(&array, 0, RoundingMode::HalfEven);
+    assert_eq!(
+        result,
+        Float64Array::from([Some(2.0), Some(-2.0), Some(4.0), None])
+    );
+
+    let result = round(&array, 0, RoundingMode::HalfAwayFromZero);
+    assert_eq!(
+        result,
+        Float64Array::from([Some(3.0), Some(-3.0), Some(4.0), None])
+    );
+
+    let array = Float32Array::from_slice([1.55f32, -1.55, 1234.0]);
+    assert_eq!(
+        floor(&array, -1),
+        Float32Array::from_slice([0.0f32, -10.0, 1230.0])
+    );
+    assert_eq!(
+        ceil(&array, -2),
+        Float32Array::from_slice([100.0f32, -0.0, 1300.0])
+    );
+
+    // too many digits to scale: unchanged
+    let array = Float64Array::from_slice([1e300]);
+    assert_eq!(round(&array, 10, RoundingMode::HalfEven), array);
+
+    // digits beyond the range of the type: zero to the left, unchanged to the right
+    let array = Float64Array::from_slice([1e300, -2.5, f64::INFINITY]);
+    let result = round(&array, -400, RoundingMode::Ceil);
+    assert_eq!(result.values().as_slice(), &[0.0, -0.0, f64::INFINITY]);
+    assert!(result.value(1).is_sign_negative());
+    assert_eq!(round(&array, 400, RoundingMode::HalfEven), array);
+    let array = Float32Array::from_slice([-3.0e38f32]);
+    let result = floor(&array, -39);
+    assert_eq!(result.value(0), 0.0);
+    assert!(result.value(0).is_sign_negative());
+    assert!(round(
+        &Float64Array::from_slice([f64::NAN]),
+        -400,
+        RoundingMode::HalfEven
+    )
+    .value(0)
+    .is_nan());
+}
+
+#[test]
+fn round_decimals() {
+    let array =
+        PrimitiveArray::from([Some(12_345i128), Some(-12_355), None]).to(DataType::Decimal(5, 3));
+
+    let result = round_decimal(&array, 2, RoundingMode::HalfEven).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(12_340i128), Some(-12_360), None]).to(DataType::Decimal(5, 3));
+    assert_eq!(result, expected);
+
+    let result = round_dyn(&array, -1, RoundingMode::Floor).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(10_000i128), Some(-20_000), None]).to(DataType::Decimal(5, 3));
+    assert_eq!(expected, result.as_ref());
+
+    assert_eq!(round_decimal(&array, 3, RoundingMode::Ceil).unwrap(), array);
+
+    // 99.9 rounds to 100, which does not fit in a precision of 3
+    let array = PrimitiveArray::from_slice([99_9i128]).to(DataType::Decimal(3, 1));
+    assert!(round_decimal(&array, 0, RoundingMode::HalfAwayFromZero).is_err());
+    assert!(round_decimal(&array, -40, RoundingMode::HalfAwayFromZero).is_err());
+}
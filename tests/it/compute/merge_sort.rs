@@ -1,9 +1,10 @@
 use std::iter::once;
 
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::merge_sort::*;
 use arrow2::compute::sort::sort;
-use arrow2::error::Result;
+use arrow2::error::{Error, Result};
 
 #[test]
 fn merge_u32() -> Result<()> {
@@ -285,3 +286,147 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+type Stream = std::vec::IntoIter<Result<Chunk<Box<dyn Array>>>>;
+
+fn stream(chunks: Vec<Vec<Box<dyn Array>>>) -> Stream {
+    chunks
+        .into_iter()
+        .map(|arrays| Ok(Chunk::new(arrays)))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+fn merged_column(chunks: &[Chunk<Box<dyn Array>>], column: usize) -> Box<dyn Array> {
+    let arrays = chunks
+        .iter()
+        .map(|chunk| chunk.arrays()[column].as_ref())
+        .collect::<Vec<_>>();
+    arrow2::compute::concatenate::concatenate(&arrays).unwrap()
+}
+
+#[test]
+fn merge_chunks_sizes() -> Result<()> {
+    let a = stream(vec![
+        vec![Int32Array::from_slice([0, 2, 4]).boxed()],
+        vec![Int32Array::from_slice([]).boxed()],
+        vec![Int32Array::from_slice([6, 8]).boxed()],
+    ]);
+    let b = stream(vec![vec![Int32Array::from_slice([1, 3, 5, 7]).boxed()]]);
+    let c = stream(vec![]);
+
+    let merged = merge_chunks(vec![a, b, c], vec![(0, SortOptions::default())], 4)?
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(
+        merged.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        vec![4, 4, 1]
+    );
+    let expected = Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(expected, merged_column(&merged, 0).as_ref());
+    Ok(())
+}
+
+#[test]
+fn merge_chunks_multi_column() -> Result<()> {
+    // sorted by the first column ascending (nulls first), then by the second descending
+    let a = stream(vec![vec![
+        Int32Array::from([None, Some(1), Some(1), Some(2)]).boxed(),
+        Utf8Array::<i32>::from_slice(["z", "c", "a", "b"]).boxed(),
+    ]]);
+    let b = stream(vec![
+        vec![
+            Int32Array::from([None, Some(1)]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        vec![
+            Int32Array::from([Some(2)]).boxed(),
+            Utf8Array::<i32>::from_slice(["b"]).boxed(),
+        ],
+    ]);
+    let sort_columns = vec![
+        (0, SortOptions::default()),
+        (
+            1,
+            SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        ),
+    ];
+
+    let merged = merge_chunks(vec![a, b], sort_columns, 3)?.collect::<Result<Vec<_>>>()?;
+
+    let expected = Int32Array::from([None, None, Some(1), Some(1), Some(1), Some(2), Some(2)]);
+    assert_eq!(expected, merged_column(&merged, 0).as_ref());
+    // equal rows are taken from the first stream first
+    let expected = Utf8Array::<i32>::from_slice(["z", "a", "c", "b", "a", "b", "b"]);
+    assert_eq!(expected, merged_column(&merged, 1).as_ref());
+    Ok(())
+}
+
+#[test]
+fn merge_chunks_matches_sort() -> Result<()> {
+    let data = [vec![5, 1, 9, 3], vec![2, 2, 8], vec![7, 0, 4, 6, 1]];
+    let options = SortOptions::default();
+
+    let streams = data
+        .iter()
+        .map(|data| {
+            let sorted = sort(&Int32Array::from_slice(data), &options, None)?;
+            // split every sorted array in chunks of 2 rows
+            let chunks = (0..sorted.len())
+                .step_by(2)
+                .map(|start| vec![sorted.sliced(start, (sorted.len() - start).min(2))])
+                .collect();
+            Ok(stream(chunks))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let merged = merge_chunks(streams, vec![(0, options)], 5)?.collect::<Result<Vec<_>>>()?;
+
+    let mut expected = data.concat();
+    expected.sort_unstable();
+    let expected = Int32Array::from_slice(&expected);
+    assert_eq!(expected, merged_column(&merged, 0).as_ref());
+    Ok(())
+}
+
+#[test]
+fn merge_chunks_errors() -> Result<()> {
+    let chunk = || vec![Int32Array::from_slice([1]).boxed()];
+    assert!(merge_chunks(vec![stream(vec![chunk()])], vec![], 1).is_err());
+    assert!(merge_chunks(
+        vec![stream(vec![chunk()])],
+        vec![(0, SortOptions::default())],
+        0
+    )
+    .is_err());
+
+    let merged = merge_chunks(
+        vec![stream(vec![chunk()])],
+        vec![(1, SortOptions::default())],
+        1,
+    )?
+    .collect::<Vec<_>>();
+    assert_eq!(merged.len(), 1);
+    assert!(merged[0].is_err());
+
+    let other = stream(vec![vec![Int64Array::from_slice([1]).boxed()]]);
+    let mut merged = merge_chunks(
+        vec![stream(vec![chunk()]), other],
+        vec![(0, SortOptions::default())],
+        1,
+    )?;
+    assert!(merged.next().unwrap().is_err());
+    assert!(merged.next().is_none());
+
+    let failing = vec![Err(Error::OutOfSpec("stream".to_string()))].into_iter();
+    let merged = merge_chunks(
+        vec![stream(vec![chunk()]), failing],
+        vec![(0, SortOptions::default())],
+        1,
+    )?
+    .collect::<Vec<_>>();
+    assert!(merged.iter().any(|chunk| chunk.is_err()));
+    Ok(())
+}
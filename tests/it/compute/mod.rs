@@ -10,30 +10,59 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_clamp")]
+mod clamp;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_cumulative")]
+mod cumulative;
+#[cfg(all(
+    feature = "compute_comparison",
+    feature = "compute_concatenate",
+    feature = "compute_filter",
+    feature = "compute_sort"
+))]
+mod extension;
+#[cfg(feature = "compute_fill_null")]
+mod fill_null;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_group_by")]
+mod group_by;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_is_in")]
+mod is_in;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_math")]
+mod math;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nested")]
+mod nested;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_rle")]
+mod rle;
+#[cfg(feature = "compute_sample")]
+mod sample;
+#[cfg(feature = "compute_selection")]
+mod selection;
 #[cfg(feature = "compute_sort")]
 mod sort;
 #[cfg(feature = "compute_substring")]
@@ -42,6 +71,8 @@ mod substring;
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_unique")]
+mod unique;
 #[cfg(feature = "compute_utf8")]
 mod utf8;
 #[cfg(feature = "compute_window")]
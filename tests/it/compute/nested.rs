@@ -0,0 +1,149 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::nested::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::offset::OffsetsBuffer;
+
+fn list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn map(offsets: Vec<i32>, keys: &[&str], values: &[i32], validity: Option<Bitmap>) -> MapArray {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let entries = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Utf8Array::<i32>::from_slice(keys).boxed(),
+            Int32Array::from_slice(values).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", DataType::Struct(fields), false)),
+        false,
+    );
+    MapArray::new(
+        data_type,
+        OffsetsBuffer::try_from(offsets).unwrap(),
+        entries.boxed(),
+        validity,
+    )
+}
+
+fn wrap(name: &str, array: Box<dyn Array>, validity: Option<Bitmap>) -> StructArray {
+    let fields = vec![Field::new(name, array.data_type().clone(), true)];
+    StructArray::new(DataType::Struct(fields), vec![array], validity)
+}
+
+#[test]
+fn parse() -> Result<()> {
+    use PathSegment::*;
+    assert_eq!(
+        parse_path("a.b[2].c")?,
+        vec![
+            Name("a".to_string()),
+            Name("b".to_string()),
+            Index(2),
+            Name("c".to_string())
+        ]
+    );
+    assert_eq!(
+        parse_path("[-1][\"x.y\"]['z']")?,
+        vec![Index(-1), Name("x.y".to_string()), Name("z".to_string())]
+    );
+    for path in ["", "a.", "a..b", "a[", "a[b]", "a]", "a[1]b"] {
+        assert!(parse_path(path).is_err(), "{path}");
+    }
+    Ok(())
+}
+
+#[test]
+fn struct_validity() -> Result<()> {
+    let c = Int32Array::from([Some(1), None, Some(3)]);
+    let b = wrap("c", c.boxed(), Some(Bitmap::from([true, true, false])));
+    let a = wrap("b", b.boxed(), Some(Bitmap::from([false, true, true])));
+
+    let result = get_field(&a, "b.c")?;
+    let expected = Int32Array::from([None, None, None::<i32>]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field(&a.sliced(2, 1), "b")?;
+    assert_eq!(result.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn list_index() -> Result<()> {
+    let array = list(vec![
+        Some(vec![Some(1), None, Some(3)]),
+        Some(vec![Some(4)]),
+        None,
+        Some(vec![]),
+    ]);
+    let result = get_field_by(&array, &[PathSegment::Index(0)])?;
+    let expected = Int32Array::from([Some(1), Some(4), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field_by(&array, &[PathSegment::Index(-2)])?;
+    let expected = Int32Array::from([None, None, None::<i32>]);
+    assert_eq!(result.sliced(1, 3).as_ref(), &expected as &dyn Array);
+    assert_eq!(result.len(), 4);
+
+    let result = get_field_by(&array.sliced(1, 3), &[PathSegment::Index(0)])?;
+    let expected = Int32Array::from([Some(4), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn map_key() -> Result<()> {
+    let array = map(
+        vec![0, 2, 3, 3, 4],
+        &["a", "b", "b", "a"],
+        &[1, 2, 3, 4],
+        Some(Bitmap::from([true, true, true, false])),
+    );
+    let result = get_field(&array, "b")?;
+    let expected = Int32Array::from([Some(2), Some(3), None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = get_field(&array.sliced(1, 3), "['a']")?;
+    let expected = Int32Array::from([None, None, None::<i32>]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn nested_path() -> Result<()> {
+    let items = wrap("c", Int32Array::from_slice([1, 2, 3]).boxed(), None);
+    let offsets = OffsetsBuffer::try_from(vec![0, 2, 3]).unwrap();
+    let field = Field::new("item", items.data_type().clone(), true);
+    let b = ListArray::<i32>::new(
+        DataType::List(Box::new(field)),
+        offsets,
+        items.boxed(),
+        None,
+    );
+    let a = wrap("b", b.boxed(), None);
+    let array = wrap("a", a.boxed(), None);
+
+    let result = get_field(&array, "a.b[1].c")?;
+    let expected = Int32Array::from([Some(2), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let array = wrap("a", Int32Array::from_slice([1]).boxed(), None);
+    assert!(get_field(&array, "b").is_err());
+    assert!(get_field(&array, "[0]").is_err());
+    assert!(get_field(&array, "a.b").is_err());
+    assert!(get_field(&array, "a[0]").is_err());
+}
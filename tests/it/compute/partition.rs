@@ -1,4 +1,5 @@
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::partition::*;
 use arrow2::compute::sort::{SortColumn, SortOptions};
 use arrow2::datatypes::DataType;
@@ -189,3 +190,105 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn by_indices() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d"), Some("e")]);
+    let partitions = partition_by_indices(&array, &[2, 0, 2, 0, 1], 4)?;
+
+    assert_eq!(partitions.len(), 4);
+    assert_eq!(
+        Utf8Array::<i32>::from([None, Some("d")]),
+        partitions[0].as_ref()
+    );
+    assert_eq!(Utf8Array::<i32>::from_slice(["e"]), partitions[1].as_ref());
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["a", "c"]),
+        partitions[2].as_ref()
+    );
+    assert_eq!(partitions[3].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn by_indices_errors() {
+    let array = Int32Array::from_slice([1, 2]);
+    assert!(partition_by_indices(&array, &[0], 1).is_err());
+    assert!(partition_by_indices(&array, &[0, 1], 1).is_err());
+}
+
+#[test]
+fn chunk_by_indices() -> Result<()> {
+    let columns = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        BooleanArray::from_slice([true, false, true]).boxed(),
+    ]);
+    let partitions = partition_chunk_by_indices(&columns, &[1, 0, 1], 2)?;
+
+    assert_eq!(partitions[0].len(), 1);
+    assert_eq!(
+        Int32Array::from_slice([2]),
+        partitions[0].arrays()[0].as_ref()
+    );
+    assert_eq!(
+        BooleanArray::from_slice([true, true]),
+        partitions[1].arrays()[1].as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn hash() -> Result<()> {
+    let a = Int32Array::from([Some(1), Some(2), Some(1), None, Some(2)]);
+    let b = Utf8Array::<i32>::from_slice(["x", "y", "x", "z", "y"]);
+    let (partition_ids, counts) = hash_partition(&[&a, &b], 3)?;
+
+    assert_eq!(partition_ids.len(), 5);
+    assert!(partition_ids.iter().all(|id| *id < 3));
+    assert_eq!(partition_ids[0], partition_ids[2]);
+    assert_eq!(partition_ids[1], partition_ids[4]);
+    assert_eq!(counts.len(), 3);
+    for (partition, count) in counts.iter().enumerate() {
+        let expected = partition_ids
+            .iter()
+            .filter(|id| **id as usize == partition)
+            .count();
+        assert_eq!(*count, expected);
+    }
+
+    let (partition_ids, counts) = hash_partition(&[&a], 1)?;
+    assert_eq!(partition_ids, vec![0; 5]);
+    assert_eq!(counts, vec![5]);
+    Ok(())
+}
+
+#[test]
+fn hash_errors() {
+    let array = Int32Array::from_slice([1, 2]);
+    assert!(hash_partition(&[&array], 0).is_err());
+    assert!(hash_partition(&[], 2).is_err());
+    let other = Int32Array::from_slice([1]);
+    assert!(hash_partition(&[&array, &other], 2).is_err());
+}
+
+#[test]
+fn hash_chunk() -> Result<()> {
+    let keys = Int32Array::from_slice([1, 2, 3, 1, 2, 3, 1]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e", "f", "g"]);
+    let columns = Chunk::new(vec![keys.clone().boxed(), values.clone().boxed()]);
+    let partitions = hash_partition_chunk(&columns, &[0], 4)?;
+
+    let (partition_ids, counts) = hash_partition(&[&keys], 4)?;
+    let expected = partition_chunk_by_indices(&columns, &partition_ids, 4)?;
+    assert_eq!(partitions, expected);
+    assert_eq!(
+        partitions
+            .iter()
+            .map(|chunk| chunk.len())
+            .collect::<Vec<_>>(),
+        counts
+    );
+
+    assert!(hash_partition_chunk(&columns, &[2], 4).is_err());
+    Ok(())
+}
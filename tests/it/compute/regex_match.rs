@@ -56,3 +56,110 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn test_regexp_is_match() {
+    let values = Utf8Array::<i32>::from([Some("Arrow"), Some("arrow"), None, Some("parquet")]);
+    let patterns = Utf8Array::<i32>::from([Some("^a"), Some("^a"), Some("^a"), None]);
+
+    let result = regexp_is_match(&values, &patterns, None).unwrap();
+    let expected = BooleanArray::from([Some(false), Some(true), None, None]);
+    assert_eq!(result, expected);
+
+    let result = regexp_is_match(&values, &patterns, Some("i")).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(true), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_is_match_scalar() {
+    let values = Utf8Array::<i64>::from([Some("Arrow"), None, Some("parquet")]);
+
+    let result = regexp_is_match_scalar(&values, "^a", None).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), None, Some(false)]));
+
+    let result = regexp_is_match_scalar(&values, "^a", Some("i")).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+}
+
+#[test]
+fn test_regexp_errors() {
+    let values = Utf8Array::<i32>::from_slice(["a"]);
+    let patterns = Utf8Array::<i32>::from_slice(["(a", "a"]);
+
+    assert!(regexp_is_match(&values, &patterns, None).is_err());
+    assert!(regexp_is_match(&values, &patterns.clone().sliced(0, 1), None).is_err());
+    assert!(regexp_is_match_scalar(&values, "(a", None).is_err());
+    assert!(regexp_is_match_scalar(&values, "a", Some("z")).is_err());
+    assert!(regexp_extract_scalar(&values, "(a)", 2).is_err());
+    assert!(regexp_extract(&values, &patterns.sliced(1, 1), 1).is_err());
+    assert!(regexp_replace_scalar(&values, "(a", "b", 0).is_err());
+}
+
+#[test]
+fn test_regexp_extract() {
+    let values = Utf8Array::<i32>::from([
+        Some("key=value"),
+        Some("key=value"),
+        Some("no match"),
+        None,
+        Some("a=b"),
+    ]);
+    let patterns = Utf8Array::<i32>::from([
+        Some(r"(\w+)=(\w+)"),
+        Some(r"(\w+)=(x)?"),
+        Some(r"(\w+)="),
+        Some(r"(\w+)="),
+        None,
+    ]);
+
+    let result = regexp_extract(&values, &patterns, 1).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("key"), Some("key"), None, None, None]);
+    assert_eq!(result, expected);
+
+    let result = regexp_extract(&values, &patterns, 0).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("key=value"), Some("key="), None, None, None]);
+    assert_eq!(result, expected);
+
+    // the second group of the second pattern does not participate in the match
+    let patterns = patterns.sliced(0, 2);
+    let result = regexp_extract(&values.sliced(0, 2), &patterns, 2).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("value"), None]));
+}
+
+#[test]
+fn test_regexp_extract_scalar() {
+    let values = Utf8Array::<i64>::from([Some("id=12"), Some("id=3 id=4"), Some("id="), None]);
+
+    let result = regexp_extract_scalar(&values, r"id=(\d+)", 1).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("12"), Some("3"), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_replace() {
+    let values = Utf8Array::<i32>::from([Some("a-b-c"), Some("a.b.c"), None, Some("abc")]);
+    let patterns = Utf8Array::<i32>::from([Some("-"), Some(r"\."), Some("-"), None]);
+
+    let result = regexp_replace(&values, &patterns, "_", 0).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a_b_c"), Some("a_b_c"), None, None]);
+    assert_eq!(result, expected);
+
+    let result = regexp_replace(&values, &patterns, "_", 1).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a_b-c"), Some("a_b.c"), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_regexp_replace_scalar() {
+    let values = Utf8Array::<i64>::from([Some("john smith"), None, Some("jane")]);
+
+    let result = regexp_replace_scalar(&values, r"(\w+) (\w+)", "$2, $1", 0).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("smith, john"), None, Some("jane")]);
+    assert_eq!(result, expected);
+
+    // the cached regex is re-used
+    let result = regexp_replace_scalar(&values, r"(\w+) (\w+)", "$2", 0).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("smith"), None, Some("jane")]);
+    assert_eq!(result, expected);
+}
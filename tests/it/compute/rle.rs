@@ -0,0 +1,53 @@
+use arrow2::array::*;
+use arrow2::compute::rle::*;
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Int64Array::from_slice([3, 3, 3, 1, 2, 2]);
+    let (values, lengths) = runs(&array)?;
+    let expected = Int64Array::from_slice([3, 1, 2]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(lengths, UInt64Array::from_slice([3, 1, 2]));
+    Ok(())
+}
+
+#[test]
+fn nulls() -> Result<()> {
+    let array = Utf8Array::<i32>::from([None, None, Some("a"), Some("a"), None]);
+    let (values, lengths) = runs(&array)?;
+    let expected = Utf8Array::<i32>::from([None, Some("a"), None]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(lengths, UInt64Array::from_slice([2, 2, 1]));
+    Ok(())
+}
+
+#[test]
+fn floats() -> Result<()> {
+    let array = Float32Array::from_slice([f32::NAN, f32::NAN, 0.0, -0.0]);
+    let (_, lengths) = runs(&array)?;
+    assert_eq!(lengths, UInt64Array::from_slice([2, 1, 1]));
+    Ok(())
+}
+
+#[test]
+fn dedup() -> Result<()> {
+    let array = BooleanArray::from_slice([true, true, false, true]);
+    let result = dedup_consecutive(&array)?;
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = BinaryArray::<i64>::from_slice([b"a", b"a"]).sliced(1, 1);
+    let result = dedup_consecutive(&array)?;
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let array = Int32Array::from_slice([]);
+    let (values, lengths) = runs(&array)?;
+    assert_eq!(values.len(), 0);
+    assert_eq!(lengths.len(), 0);
+    Ok(())
+}
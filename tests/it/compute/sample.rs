@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::sample::*;
+use arrow2::error::Result;
+
+#[test]
+fn indices() -> Result<()> {
+    let indices = sample_indices(100, SampleSize::Count(10), 7)?;
+    assert_eq!(indices.len(), 10);
+    assert!(indices.values().windows(2).all(|w| w[0] < w[1]));
+    assert!(indices.values().iter().all(|x| *x < 100));
+    assert_eq!(indices, sample_indices(100, SampleSize::Count(10), 7)?);
+
+    assert_eq!(
+        sample_indices(100, SampleSize::Fraction(0.25), 0)?.len(),
+        25
+    );
+    assert_eq!(sample_indices(5, SampleSize::Count(10), 0)?.len(), 5);
+    assert!(sample_indices(5, SampleSize::Fraction(1.5), 0).is_err());
+    assert!(sample_indices(5, SampleSize::Fraction(f64::NAN), 0).is_err());
+
+    let mut permutation = shuffle_indices(50, 3).values().to_vec();
+    assert_eq!(permutation, shuffle_indices(50, 3).values().to_vec());
+    permutation.sort_unstable();
+    assert_eq!(permutation, (0..50).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn sample_keeps_order() -> Result<()> {
+    let array = Utf8Array::<i32>::from_iter_values((0..20).map(|x| format!("{x:02}")));
+    let result = sample(&array, SampleSize::Count(5), 11)?;
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result.len(), 5);
+    assert!(result
+        .values_iter()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .all(|w| w[0] < w[1]));
+    Ok(())
+}
+
+#[test]
+fn chunks_share_permutation() -> Result<()> {
+    let columns = Chunk::new(vec![
+        Int32Array::from_vec((0..30).collect()).boxed(),
+        Int64Array::from_vec((0..30).collect()).boxed(),
+    ]);
+    for result in [
+        shuffle_chunk(&columns, 5)?,
+        sample_chunk(&columns, SampleSize::Fraction(0.5), 5)?,
+    ] {
+        let a = result.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        let b = result.arrays()[1]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(a
+            .values_iter()
+            .zip(b.values_iter())
+            .all(|(a, b)| *a as i64 == *b));
+    }
+    Ok(())
+}
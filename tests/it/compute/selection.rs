@@ -0,0 +1,70 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::chunk::Chunk;
+use arrow2::compute::selection::*;
+
+#[test]
+fn from_filter() {
+    let filter = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    let selection = Selection::from_filter(&filter);
+
+    assert_eq!(
+        selection,
+        Selection::Mask(Bitmap::from([true, false, false, true]))
+    );
+    assert_eq!(selection.len(), 2);
+    assert_eq!(selection.to_indices(), UInt32Array::from_slice([0, 3]));
+}
+
+#[test]
+fn gather_mask_and_indices() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]);
+
+    let mask = Selection::from(Bitmap::from([false, true, true, false]));
+    let result = gather(&array, &mask).unwrap();
+    assert_eq!(Utf8Array::<i32>::from([None, Some("c")]), result.as_ref());
+
+    let indices = Selection::from(UInt32Array::from([Some(3), None, Some(3), Some(0)]));
+    let result = gather(&array, &indices).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("d"), None, Some("d"), Some("a")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn gather_wrong_mask_length() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let mask = Selection::from(Bitmap::from([true, false]));
+    assert!(gather(&array, &mask).is_err());
+}
+
+#[test]
+fn select() {
+    let array = Int32Array::from_slice([10, 11, 12, 13, 14]);
+    let first = Selection::from(Bitmap::from([true, false, true, true, true]));
+    let second = Selection::from(vec![3, 0]);
+
+    let composed = first.select(&second).unwrap();
+    assert_eq!(composed, Selection::from(vec![4, 0]));
+
+    let gathered = gather(gather(&array, &first).unwrap().as_ref(), &second).unwrap();
+    assert_eq!(gathered, gather(&array, &composed).unwrap());
+}
+
+#[test]
+fn chunk() {
+    let columns = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        BooleanArray::from_slice([true, false, true]).boxed(),
+    ]);
+
+    let result = gather_chunk(&columns, &Selection::from(vec![2, 2])).unwrap();
+    assert_eq!(Int32Array::from_slice([3, 3]), result.arrays()[0].as_ref());
+    assert_eq!(
+        BooleanArray::from_slice([true, true]),
+        result.arrays()[1].as_ref()
+    );
+
+    let mask = Selection::from(Bitmap::from([false, true, false]));
+    let result = gather_chunk(&columns, &mask).unwrap();
+    assert_eq!(Int32Array::from_slice([2]), result.arrays()[0].as_ref());
+}
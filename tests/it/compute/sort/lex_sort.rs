@@ -210,3 +210,34 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 */
+
+#[test]
+fn test_lex_sort_top_k() {
+    let c1 = (0..100).map(|i| Some(i % 3)).collect::<Int32Array>();
+    let c2 = (0..100)
+        .map(|i| (i % 11 != 0).then_some((i * 37) % 100))
+        .collect::<Int32Array>();
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+        },
+        SortColumn {
+            values: &c2,
+            options: None,
+        },
+    ];
+
+    let expected = lexsort::<i32>(&input, None).unwrap();
+    for limit in [0, 1, 5, 12] {
+        let sorted = lexsort::<i32>(&input, Some(limit)).unwrap();
+        let expected = expected
+            .iter()
+            .map(|x| x.sliced(0, limit))
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, expected);
+    }
+}
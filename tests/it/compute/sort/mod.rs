@@ -1,10 +1,11 @@
 mod lex_sort;
 mod row;
+mod search;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
 use arrow2::datatypes::*;
-use arrow2::types::NativeType;
+use arrow2::types::{f16, NativeType};
 
 fn to_indices_boolean_arrays(data: &[Option<bool>], options: SortOptions, expected_data: &[i32]) {
     let output = BooleanArray::from(data);
@@ -137,7 +138,6 @@ fn boolean() {
 }
 
 #[test]
-#[ignore] // improve equality for NaN values. These are right but the equality fails
 fn test_nans() {
     primitive_arrays::<f64>(
         &[None, Some(0.0), Some(2.0), Some(-1.0), Some(f64::NAN), None],
@@ -179,6 +179,41 @@ fn test_nans() {
     );
 }
 
+#[test]
+fn float16() {
+    let data = [-1.0, 2.0, f32::NAN, -0.0, 0.5]
+        .iter()
+        .map(|x| Some(f16::from_f32(*x)))
+        .chain(std::iter::once(None))
+        .collect::<Vec<_>>();
+    let expected = [
+        None,
+        Some(-1.0),
+        Some(-0.0),
+        Some(0.5),
+        Some(2.0),
+        Some(f32::NAN),
+    ]
+    .iter()
+    .map(|x| x.map(f16::from_f32))
+    .collect::<Vec<_>>();
+    let input = PrimitiveArray::<f16>::from(data);
+    let output = sort(&input, &SortOptions::default(), None).unwrap();
+    let output = output
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap();
+    // `NaN != NaN`, thus compare bits
+    let bits = |x: &Option<f16>| x.map(|x| x.to_bits());
+    assert_eq!(
+        output.iter().map(|x| bits(&x.copied())).collect::<Vec<_>>(),
+        expected.iter().map(bits).collect::<Vec<_>>()
+    );
+
+    let indices = sort_to_indices::<u32>(&input, &SortOptions::default(), None).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([5, 0, 3, 4, 1, 2]));
+}
+
 #[test]
 fn to_indices_strings() {
     to_indices_string_arrays(
@@ -565,6 +600,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -600,3 +636,113 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn limit_top_k() {
+    // a permutation of 0..101 with some nulls
+    let array = (0..101)
+        .map(|i| (i % 7 != 0).then_some((i * 37) % 101))
+        .collect::<Int32Array>();
+    let strings = array
+        .iter()
+        .map(|x| x.map(|x| format!("{x:03}")))
+        .collect::<Utf8Array<i32>>();
+
+    for array in [array.boxed(), strings.boxed()] {
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let expected = sort(array.as_ref(), &options, None).unwrap();
+            for limit in [0, 1, 5, 12, 15, 20, 101, 200] {
+                let result = sort(array.as_ref(), &options, Some(limit)).unwrap();
+                assert_eq!(result, expected.sliced(0, limit.min(101)));
+            }
+        }
+    }
+}
+
+#[test]
+fn primitive_dict() {
+    let values = Int32Array::from_slice([5, -1, 3, 0]);
+    let keys = Int32Array::from([Some(0), Some(2), None, Some(1), Some(3), Some(0)]);
+    let input = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([2, 3, 4, 1, 0, 5]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort(&input, &options, Some(3)).unwrap();
+    let output = output
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    // the values 5, 5, 3
+    assert_eq!(output.keys(), &Int32Array::from_slice([0, 0, 2]));
+
+    assert!(can_sort(input.data_type()));
+}
+
+#[test]
+fn list_of_strings() {
+    let data = vec![
+        Some(vec![Some("b")]),
+        None,
+        Some(vec![Some("a"), Some("c")]),
+        Some(vec![]),
+        Some(vec![Some("a")]),
+    ];
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 3, 4, 2, 0]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, Some(2)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([0, 2]));
+
+    assert!(can_sort(array.data_type()));
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Struct(fields);
+    let values = vec![
+        Int32Array::from([Some(2), Some(1), Some(1), None]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), Some("z")]).boxed(),
+    ];
+    let validity = Some([true, true, true, false].into());
+    let array = StructArray::new(data_type.clone(), values, validity);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([2, 1, 0, 3]));
+
+    assert!(can_sort(&data_type));
+}
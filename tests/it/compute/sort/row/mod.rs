@@ -2,13 +2,13 @@ use arrow2::{
     array::{
         Array, BinaryArray, BooleanArray, DictionaryArray, Float32Array, Int128Array, Int16Array,
         Int256Array, Int32Array, MutableDictionaryArray, MutablePrimitiveArray, MutableUtf8Array,
-        NullArray, TryExtend, TryPush, Utf8Array,
+        NullArray, StructArray, TryExtend, TryPush, Utf8Array,
     },
     compute::sort::{
         row::{RowConverter, SortField},
         SortOptions,
     },
-    datatypes::{DataType, IntegerType},
+    datatypes::{DataType, Field, IntegerType},
     types::i256,
 };
 
@@ -276,3 +276,122 @@ fn test_dictionary_nulls() {
     assert_eq!(rows.row(4), rows.row(5));
     assert!(rows.row(3) < rows.row(0));
 }
+
+#[test]
+fn test_round_trip() {
+    let cols = vec![
+        Int32Array::from([Some(1), None, Some(-5), Some(i32::MAX)]).boxed(),
+        Float32Array::from([Some(1.3), Some(-0.), None, Some(f32::NAN)]).boxed(),
+        BooleanArray::from([None, Some(false), Some(true), Some(true)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some(""), None, Some(&"b".repeat(70))]).boxed(),
+        BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(&[0, 255]), Some(&[])]).boxed(),
+        NullArray::new(DataType::Null, 4).boxed(),
+    ];
+
+    for (descending, nulls_first) in [(false, false), (false, true), (true, false), (true, true)] {
+        let options = SortOptions {
+            descending,
+            nulls_first,
+        };
+        let fields = cols
+            .iter()
+            .map(|x| SortField::new_with_options(x.data_type().clone(), options))
+            .collect();
+        let mut converter = RowConverter::new(fields);
+        let rows = converter.convert_columns(&cols).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back, cols);
+    }
+}
+
+#[test]
+fn test_round_trip_dictionary() {
+    let mut builder = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    builder
+        .try_extend([Some("b"), None, Some("a"), Some("b"), Some("c")])
+        .unwrap();
+    let a: DictionaryArray<i32> = builder.into();
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let mut converter = RowConverter::new(vec![SortField::new_with_options(
+        a.data_type().clone(),
+        options,
+    )]);
+    let rows = converter.convert_columns(&[a.clone().boxed()]).unwrap();
+    let back = converter.convert_rows(rows.iter().rev()).unwrap();
+
+    let expected = [Some("c"), Some("b"), Some("a"), None, Some("b")];
+    let back = back[0]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let back = back
+        .iter_typed::<Utf8Array<i32>>()
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(back, expected);
+}
+
+#[test]
+fn test_struct() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Struct(fields);
+    let values = vec![
+        Int32Array::from([Some(1), Some(1), None, Some(0), Some(1)]).boxed(),
+        Utf8Array::<i32>::from([Some("b"), Some("a"), Some("a"), Some("c"), Some("z")]).boxed(),
+    ];
+    let validity = [true, true, true, true, false].into();
+    let col = StructArray::new(data_type.clone(), values, Some(validity)).boxed();
+
+    let mut converter = RowConverter::new(vec![SortField::new(data_type)]);
+    let rows = converter.convert_columns(&[col.clone()]).unwrap();
+
+    // nulls first, then lexicographically by field
+    assert!(rows.row(4) < rows.row(2));
+    assert!(rows.row(2) < rows.row(3));
+    assert!(rows.row(3) < rows.row(1));
+    assert!(rows.row(1) < rows.row(0));
+
+    let back = converter.convert_rows(&rows).unwrap();
+    assert_eq!(back, vec![col]);
+}
+
+#[test]
+fn test_struct_nulls_equal() {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let values = vec![Int32Array::from([Some(1), Some(2)]).boxed()];
+    let col = StructArray::new(data_type.clone(), values, Some([false, false].into())).boxed();
+
+    let mut converter = RowConverter::new(vec![SortField::new(data_type)]);
+    let rows = converter.convert_columns(&[col]).unwrap();
+    assert_eq!(rows.row(0), rows.row(1));
+}
+
+#[test]
+fn test_rows_iter_rev() {
+    let col = Int32Array::from_slice([1, 2, 3]).boxed();
+    let mut converter = RowConverter::new(vec![SortField::new(DataType::Int32)]);
+    let rows = converter.convert_columns(&[col]).unwrap();
+
+    let reversed = rows.iter().rev().collect::<Vec<_>>();
+    assert_eq!(reversed, vec![rows.row(2), rows.row(1), rows.row(0)]);
+}
+
+#[test]
+fn test_convert_rows_other_converter() {
+    let col = Int32Array::from_slice([1, 2]).boxed();
+    let mut converter = RowConverter::new(vec![
+        SortField::new(DataType::Int32),
+        SortField::new(DataType::Int32),
+    ]);
+    let rows = converter.convert_columns(&[col.clone(), col]).unwrap();
+
+    let converter = RowConverter::new(vec![SortField::new(DataType::Int32)]);
+    assert!(converter.convert_rows(&rows).is_err());
+}
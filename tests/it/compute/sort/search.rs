@@ -0,0 +1,91 @@
+use arrow2::array::*;
+use arrow2::compute::sort::{search_sorted, SearchSide};
+use arrow2::error::Result;
+
+fn search(haystack: &dyn Array, needles: &dyn Array, side: SearchSide) -> Vec<u64> {
+    search_sorted(haystack, needles, side)
+        .unwrap()
+        .values()
+        .to_vec()
+}
+
+#[test]
+fn primitive() {
+    let haystack = Int32Array::from_slice([1, 3, 3, 3, 5, 8]);
+    // unsorted needles are bisected
+    let needles = Int32Array::from_slice([9, 3, 0, 5, 4]);
+    assert_eq!(
+        search(&haystack, &needles, SearchSide::Left),
+        [6, 1, 0, 4, 4]
+    );
+    assert_eq!(
+        search(&haystack, &needles, SearchSide::Right),
+        [6, 4, 0, 5, 4]
+    );
+
+    // sorted needles gallop
+    let needles = Int32Array::from_slice([0, 3, 3, 4, 5, 9]);
+    assert_eq!(
+        search(&haystack, &needles, SearchSide::Left),
+        [0, 1, 1, 4, 4, 6]
+    );
+    assert_eq!(
+        search(&haystack, &needles, SearchSide::Right),
+        [0, 4, 4, 4, 5, 6]
+    );
+}
+
+#[test]
+fn galloping_matches_bisection() {
+    let haystack = Int64Array::from_vec((0..1000).map(|x| x / 3).collect());
+    let needles = (-5..340).step_by(7).collect::<Vec<_>>();
+    let sorted = Int64Array::from_slice(&needles);
+    let reversed = Int64Array::from_vec(needles.into_iter().rev().collect());
+    for side in [SearchSide::Left, SearchSide::Right] {
+        let mut expected = search(&haystack, &reversed, side);
+        expected.reverse();
+        assert_eq!(search(&haystack, &sorted, side), expected);
+    }
+}
+
+#[test]
+fn nulls() {
+    let haystack = Int32Array::from([None, None, Some(1), Some(2)]);
+    let needles = Int32Array::from([Some(2), None, Some(0)]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Left), [3, 0, 2]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Right), [4, 2, 2]);
+}
+
+#[test]
+fn utf8() {
+    let haystack = Utf8Array::<i32>::from_slice(["a", "b", "b", "d"]);
+    let needles = Utf8Array::<i32>::from_slice(["b", "c", "e", ""]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Left), [1, 3, 4, 0]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Right), [3, 3, 4, 0]);
+}
+
+#[test]
+fn floats() {
+    let haystack = Float64Array::from_slice([-1.0, 0.0, 2.5, f64::NAN]);
+    let needles = Float64Array::from_slice([2.5, f64::NAN, 1.0]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Left), [2, 3, 2]);
+}
+
+#[test]
+fn empty() {
+    let haystack = Int32Array::from_slice([]);
+    let needles = Int32Array::from_slice([1, 2]);
+    assert_eq!(search(&haystack, &needles, SearchSide::Right), [0, 0]);
+    assert_eq!(
+        search(&needles, &haystack, SearchSide::Right),
+        Vec::<u64>::new()
+    );
+}
+
+#[test]
+fn different_types() {
+    let haystack = Int32Array::from_slice([1]);
+    let needles = Int64Array::from_slice([1]);
+    let result: Result<_> = search_sorted(&haystack, &needles, SearchSide::Left);
+    assert!(result.is_err());
+}
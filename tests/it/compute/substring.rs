@@ -342,3 +342,85 @@ fn consistency() {
         }
     });
 }
+
+fn multi_byte_utf8<O: Offset>() -> Result<()> {
+    let cases = vec![
+        (1, None, vec![Some("lá"), None, Some("ébc"), Some("")]),
+        (1, Some(1), vec![Some("l"), None, Some("é"), Some("")]),
+        (-1, None, vec![Some("á"), None, Some("c"), Some("")]),
+        (-2, Some(1), vec![Some("l"), None, Some("b"), Some("")]),
+        (-4, Some(2), vec![Some("ol"), None, Some("áé"), Some("")]),
+        (3, None, vec![Some(""), None, Some("c"), Some("")]),
+    ];
+
+    let array = Utf8Array::<O>::from([Some("olá"), None, Some("áébc"), Some("")]);
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(start, length, expected)| {
+            let result = substring(&array, start, &length)?;
+            let expected = Utf8Array::<O>::from(expected);
+            assert_eq!(expected, result.as_ref());
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+#[test]
+fn multi_byte_string() -> Result<()> {
+    multi_byte_utf8::<i32>()
+}
+
+#[test]
+fn multi_byte_large_string() -> Result<()> {
+    multi_byte_utf8::<i64>()
+}
+
+#[test]
+fn sql_utf8() -> Result<()> {
+    let cases = vec![
+        (1, None, vec![Some("olá"), None, Some("ab")]),
+        (2, None, vec![Some("lá"), None, Some("b")]),
+        (0, None, vec![Some("olá"), None, Some("ab")]),
+        (2, Some(1), vec![Some("l"), None, Some("b")]),
+        (0, Some(2), vec![Some("o"), None, Some("a")]),
+        (-1, Some(3), vec![Some("o"), None, Some("a")]),
+        (-5, Some(3), vec![Some(""), None, Some("")]),
+        (3, Some(10), vec![Some("á"), None, Some("")]),
+        (10, None, vec![Some(""), None, Some("")]),
+    ];
+
+    let array = Utf8Array::<i32>::from([Some("olá"), None, Some("ab")]);
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(start, length, expected)| {
+            let result = substring_sql(&array, start, length)?;
+            let expected = Utf8Array::<i32>::from(expected);
+            assert_eq!(expected, result.as_ref());
+
+            let sliced = Utf8Array::<i64>::from([Some("olá"), None, Some("ab")]).sliced(1, 2);
+            let result = substring_sql(&sliced, start, length)?;
+            let expected = Utf8Array::<i64>::from(expected.iter().skip(1).collect::<Vec<_>>());
+            assert_eq!(expected, result.as_ref());
+            Ok(())
+        })?;
+
+    assert!(substring_sql(&array, 1, Some(-1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn sql_binary() -> Result<()> {
+    let array = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"")]);
+
+    let result = substring_sql(&array, 2, Some(3))?;
+    let expected = BinaryArray::<i64>::from([Some(b"ell".as_ref()), None, Some(b"")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = substring_sql(&array, -2, Some(5))?;
+    let expected = BinaryArray::<i64>::from([Some(b"he".as_ref()), None, Some(b"")]);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(substring_sql(&array, 1, Some(-3)).is_err());
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use arrow2::compute::take::{can_take, take};
-use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::datatypes::{DataType, Field, IntervalUnit, UnionMode};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
 use arrow2::{bitmap::Bitmap, buffer::Buffer};
@@ -278,6 +278,41 @@ fn fixed_size_list_with_no_none() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn map_with_none() {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("val", DataType::Int32, true),
+    ];
+    let kv_type = DataType::Struct(fields);
+    let data_type = DataType::Map(Box::new(Field::new("kv", kv_type.clone(), false)), false);
+
+    let keys = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed();
+    let vals = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+    let kv = StructArray::new(kv_type.clone(), vec![keys, vals], None).boxed();
+    let array = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 1, 4].try_into().unwrap(),
+        kv,
+        Some([true, false, true].into()),
+    );
+
+    let indices = PrimitiveArray::from([Some(2i32), None, Some(0), Some(1)]);
+    let result = take(&array, &indices).unwrap();
+
+    let keys = Utf8Array::<i32>::from_slice(["b", "c", "d", "a"]).boxed();
+    let vals = Int32Array::from_slice([2, 3, 4, 1]).boxed();
+    let kv = StructArray::new(kv_type, vec![keys, vals], None).boxed();
+    let expected = MapArray::new(
+        data_type,
+        vec![0, 3, 3, 4, 4].try_into().unwrap(),
+        kv,
+        Some([true, false, true, false].into()),
+    );
+
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn test_nested() {
     let values = Buffer::from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
@@ -324,3 +359,94 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn sparse_union() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+    let types = vec![5, 7, 5, 7].into();
+    let fields = vec![
+        Int32Array::from([Some(1), None, Some(3), None]).boxed(),
+        Utf8Array::<i32>::from([None, Some("b"), None, Some("d")]).boxed(),
+    ];
+    let array = UnionArray::new(data_type.clone(), types, fields, None).sliced(1, 3);
+
+    let indices = Int32Array::from([Some(2), None, Some(1), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+
+    let types = vec![7, 5, 5, 7].into();
+    let fields = vec![
+        Int32Array::from([None, None, Some(3), None]).boxed(),
+        Utf8Array::<i32>::from([Some("d"), None, None, Some("b")]).boxed(),
+    ];
+    let expected = UnionArray::new(data_type, types, fields, None);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dense_union() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = vec![0, 1, 0, 1].into();
+    let fields = vec![
+        Int32Array::from_slice([1, 3]).boxed(),
+        Utf8Array::<i32>::from_slice(["b", "d"]).boxed(),
+    ];
+    let offsets = Some(vec![0, 0, 1, 1].into());
+    let array = UnionArray::new(data_type.clone(), types, fields, offsets);
+
+    let indices = Int32Array::from([Some(3), None, Some(2), Some(3)]);
+    let result = take(&array, &indices).unwrap();
+
+    let types = vec![1, 0, 0, 1].into();
+    let fields = vec![
+        Int32Array::from([None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from_slice(["d", "d"]).boxed(),
+    ];
+    let offsets = Some(vec![0, 0, 1, 1].into());
+    let expected = UnionArray::new(data_type.clone(), types, fields, offsets);
+    assert_eq!(expected, result.as_ref());
+    assert!(can_take(&data_type));
+}
+
+#[test]
+fn dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    let keys = Int32Array::from([Some(3), Some(1), None, Some(3), Some(0)]);
+    let array = DictionaryArray::try_from_keys(keys, values.clone().boxed()).unwrap();
+
+    let indices = Int32Array::from([Some(3), None, Some(0), Some(2)]);
+    let result = take(&array, &indices).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    // the values are reused
+    assert_eq!(values, result.values().as_ref());
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from([Some(3), None, Some(3), None])
+    );
+
+    let compacted = result.compact();
+    assert_eq!(&compacted, result);
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["d"]),
+        compacted.values().as_ref()
+    );
+    assert_eq!(
+        compacted.keys(),
+        &Int32Array::from([Some(0), None, Some(0), None])
+    );
+
+    // "c" is not referenced
+    let compacted = array.compact();
+    assert_eq!(&compacted, &array);
+    assert_eq!(compacted.values().len(), 3);
+}
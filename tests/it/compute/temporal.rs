@@ -191,6 +191,44 @@ temporal_tz_test!(temporal_tz_day, day);
 temporal_tz_test!(temporal_tz_weekday, weekday);
 temporal_tz_test!(temporal_tz_iso_week, iso_week);
 
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn temporal_tz_transitions() {
+    // Lisbon moved from WET to WEST at 2020-03-29T01:00:00Z
+    let array = Int64Array::from(&[
+        Some(1585440000),
+        Some(1585443599),
+        None,
+        Some(1585443600),
+        Some(1585447200),
+        Some(1585440000),
+    ])
+    .to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("Europe/Lisbon".to_string()),
+    ));
+    let expected = UInt32Array::from(&[Some(0), Some(0), None, Some(2), Some(3), Some(0)]);
+    assert_eq!(hour(&array).unwrap(), expected);
+
+    // St. John's moved from NST (-03:30) to NDT (-02:30) at 2021-03-14T05:30:00Z, in the
+    // middle of an hour in UTC
+    let array = Int64Array::from(&[
+        Some(1615698000),
+        Some(1615699799),
+        Some(1615699800),
+        Some(1615700700),
+        Some(1615699799),
+    ])
+    .to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("America/St_Johns".to_string()),
+    ));
+    let expected = UInt32Array::from(&[Some(1), Some(1), Some(3), Some(3), Some(1)]);
+    assert_eq!(hour(&array).unwrap(), expected);
+    let expected = UInt32Array::from(&[Some(30), Some(59), Some(0), Some(15), Some(59)]);
+    assert_eq!(minute(&array).unwrap(), expected);
+}
+
 fn test_data_tz() -> Vec<TestData> {
     vec![
         TestData {
@@ -323,6 +361,100 @@ fn consistency_iso_week() {
     consistency_check(can_iso_week, iso_week);
 }
 
+#[test]
+fn consistency_quarter() {
+    consistency_check(can_quarter, quarter);
+}
+
+#[test]
+fn consistency_day_of_year() {
+    consistency_check(can_day_of_year, day_of_year);
+}
+
+#[test]
+fn consistency_day_of_week() {
+    consistency_check(can_day_of_week, day_of_week);
+}
+
+#[test]
+fn consistency_millisecond() {
+    consistency_check(can_millisecond, millisecond);
+}
+
+#[test]
+fn consistency_microsecond() {
+    consistency_check(can_microsecond, microsecond);
+}
+
+#[test]
+fn extract_date_parts() {
+    // 2021-08-19T13:45:30.123 (a Thursday) and 1969-12-31T23:59:59.500 (a Wednesday)
+    let timestamps = Int64Array::from([Some(1_629_380_730_123), None, Some(-500)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let dates = Int32Array::from([Some(18_858), None, Some(-1)]).to(DataType::Date32);
+    let dates64 =
+        Int64Array::from([Some(1_629_331_200_000), None, Some(-86_400_000)]).to(DataType::Date64);
+
+    for array in [&timestamps as &dyn Array, &dates, &dates64] {
+        let expected = UInt32Array::from([Some(3), None, Some(4)]);
+        assert_eq!(quarter(array).unwrap(), expected);
+        let expected = UInt32Array::from([Some(231), None, Some(365)]);
+        assert_eq!(day_of_year(array).unwrap(), expected);
+        let expected = UInt32Array::from([Some(4), None, Some(3)]);
+        assert_eq!(day_of_week(array).unwrap(), expected);
+    }
+
+    // 1970-01-01T00:00:00Z is 1969-12-31T21:00:00-03:00
+    let array = Int64Array::from([Some(0)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("-03:00".to_string()),
+    ));
+    assert_eq!(quarter(&array).unwrap(), UInt32Array::from([Some(4)]));
+    assert_eq!(day_of_year(&array).unwrap(), UInt32Array::from([Some(365)]));
+    assert_eq!(day_of_week(&array).unwrap(), UInt32Array::from([Some(3)]));
+}
+
+#[test]
+fn extract_subseconds() {
+    let timestamps = Int64Array::from([Some(1_629_380_730_123_456), None, Some(-500)])
+        .to(DataType::Timestamp(TimeUnit::Microsecond, None));
+    assert_eq!(
+        millisecond(&timestamps).unwrap(),
+        UInt32Array::from([Some(123), None, Some(999)])
+    );
+    assert_eq!(
+        microsecond(&timestamps).unwrap(),
+        UInt32Array::from([Some(123_456), None, Some(999_500)])
+    );
+
+    // 12:34:56.789012345
+    let times = Int64Array::from([Some(45_296_789_012_345), None])
+        .to(DataType::Time64(TimeUnit::Nanosecond));
+    assert_eq!(
+        millisecond(&times).unwrap(),
+        UInt32Array::from([Some(789), None])
+    );
+    assert_eq!(
+        microsecond(&times).unwrap(),
+        UInt32Array::from([Some(789_012), None])
+    );
+
+    let times = Int32Array::from([Some(45_296_789)]).to(DataType::Time32(TimeUnit::Millisecond));
+    assert_eq!(millisecond(&times).unwrap(), UInt32Array::from([Some(789)]));
+
+    let dates = Int32Array::from([Some(18_858)]).to(DataType::Date32);
+    assert_eq!(millisecond(&dates).unwrap(), UInt32Array::from([Some(0)]));
+
+    let timestamps = Int64Array::from([Some(1_629_380_730_123)]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+05:30".to_string()),
+    ));
+    assert_eq!(
+        microsecond(&timestamps).unwrap(),
+        UInt32Array::from([Some(123_000)])
+    );
+}
+
 fn consistency_check<O: arrow2::types::NativeType>(
     can_extract: fn(&DataType) -> bool,
     extract: fn(&dyn Array) -> arrow2::error::Result<PrimitiveArray<O>>,
@@ -372,3 +504,375 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn strptime_chrono_format() {
+    let array = Utf8Array::<i32>::from([
+        Some("1970-01-01 00:00:01.500"),
+        None,
+        Some("1970-01-02"),
+        Some("1970-01-01 25:00:00.000"),
+    ]);
+    let format = StrptimeFormat::Chrono("%Y-%m-%d %H:%M:%S%.f");
+
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let result = strptime(&array, format, &data_type, StrptimeErrors::Null).unwrap();
+    // a string with a date but without the time of the format does not match it
+    let expected = Int64Array::from([Some(1_500), None, None, None]).to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    assert!(strptime(&array, format, &data_type, StrptimeErrors::Error).is_err());
+    let result = strptime(
+        &array.clone().sliced(0, 2),
+        format,
+        &data_type,
+        StrptimeErrors::Error,
+    )
+    .unwrap();
+    let expected = Int64Array::from([Some(1_500), None]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+
+    let date = StrptimeFormat::Chrono("%Y-%m-%d");
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let result = strptime(&array, date, &data_type, StrptimeErrors::Null).unwrap();
+    let expected = Int64Array::from([None, None, Some(86_400), None]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(strptime(
+        &array,
+        StrptimeFormat::Chrono("%Y-%m-%Q"),
+        &DataType::Date32,
+        StrptimeErrors::Null
+    )
+    .is_err());
+}
+
+#[test]
+fn strptime_auto() {
+    let array = Utf8Array::<i64>::from([
+        Some("1970-01-01T00:00:01Z"),
+        Some("1970-01-01T01:00:01+01:00"),
+        Some("1970-01-01 00:01:00.25"),
+        Some("1970-01-01T00:01"),
+        Some("1970-01-02"),
+        Some("00:00:02"),
+        Some("not a date"),
+    ]);
+    let auto = StrptimeFormat::Auto;
+
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let result = strptime(&array, auto, &data_type, StrptimeErrors::Null).unwrap();
+    let expected = Int64Array::from([
+        Some(1_000),
+        Some(3_601_000),
+        Some(60_250),
+        Some(60_000),
+        Some(86_400_000),
+        None,
+        None,
+    ])
+    .to(data_type);
+    assert_eq!(expected, result.as_ref());
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string()));
+    let result = strptime(&array, auto, &data_type, StrptimeErrors::Null).unwrap();
+    let expected = Int64Array::from([
+        Some(1),
+        Some(1),
+        Some(60 - 7_200),
+        Some(60 - 7_200),
+        Some(86_400 - 7_200),
+        None,
+        None,
+    ])
+    .to(data_type);
+    assert_eq!(expected, result.as_ref());
+
+    let result = strptime(&array, auto, &DataType::Date32, StrptimeErrors::Null).unwrap();
+    let expected = Int32Array::from([Some(0), Some(0), Some(0), Some(0), Some(1), None, None])
+        .to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let data_type = DataType::Time64(TimeUnit::Microsecond);
+    let result = strptime(&array, auto, &data_type, StrptimeErrors::Null).unwrap();
+    let expected = Int64Array::from([
+        Some(1_000_000),
+        Some(3_601_000_000),
+        Some(60_250_000),
+        Some(60_000_000),
+        None,
+        Some(2_000_000),
+        None,
+    ])
+    .to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn strptime_chrono_tz() {
+    let array = Utf8Array::<i32>::from([Some("2021-07-01 12:00:00"), Some("2021-07-01T12:00:00Z")]);
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+
+    let result = strptime(
+        &array,
+        StrptimeFormat::Auto,
+        &data_type,
+        StrptimeErrors::Null,
+    )
+    .unwrap();
+    let expected = Int64Array::from([Some(1_625_137_200), Some(1_625_140_800)]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn consistency_strptime() {
+    let array = Utf8Array::<i32>::from([None::<&str>]);
+    for data_type in [
+        DataType::Date32,
+        DataType::Date64,
+        DataType::Time32(TimeUnit::Second),
+        DataType::Time64(TimeUnit::Nanosecond),
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        DataType::Int64,
+    ] {
+        let result = strptime(
+            &array,
+            StrptimeFormat::Auto,
+            &data_type,
+            StrptimeErrors::Error,
+        );
+        assert_eq!(result.is_ok(), can_strptime(&data_type));
+    }
+}
+
+#[test]
+fn strftime_types() {
+    let cases = vec![
+        (
+            Int32Array::from([Some(1), None, Some(-1)])
+                .to(DataType::Date32)
+                .boxed(),
+            "%Y-%m-%d",
+            vec![Some("1970-01-02"), None, Some("1969-12-31")],
+        ),
+        (
+            Int64Array::from([Some(86_400_000 + 1_500)])
+                .to(DataType::Date64)
+                .boxed(),
+            "%F %T%.3f",
+            vec![Some("1970-01-02 00:00:01.500")],
+        ),
+        (
+            Int32Array::from([Some(3_661), None])
+                .to(DataType::Time32(TimeUnit::Second))
+                .boxed(),
+            "%H:%M:%S",
+            vec![Some("01:01:01"), None],
+        ),
+        (
+            Int32Array::from([Some(1_250)])
+                .to(DataType::Time32(TimeUnit::Millisecond))
+                .boxed(),
+            "%S%.3f",
+            vec![Some("01.250")],
+        ),
+        (
+            Int64Array::from([Some(1_000_001)])
+                .to(DataType::Time64(TimeUnit::Microsecond))
+                .boxed(),
+            "%S%.6f",
+            vec![Some("01.000001")],
+        ),
+        (
+            Int64Array::from([Some(1)])
+                .to(DataType::Time64(TimeUnit::Nanosecond))
+                .boxed(),
+            "%.9f",
+            vec![Some(".000000001")],
+        ),
+        (
+            Int64Array::from([Some(1_600_000_000), None])
+                .to(DataType::Timestamp(TimeUnit::Second, None))
+                .boxed(),
+            "%a %d %b %Y %H:%M",
+            vec![Some("Sun 13 Sep 2020 12:26"), None],
+        ),
+        (
+            Int64Array::from([Some(0)])
+                .to(DataType::Timestamp(
+                    TimeUnit::Millisecond,
+                    Some("-03:00".to_string()),
+                ))
+                .boxed(),
+            "%Y-%m-%dT%H:%M:%S%:z",
+            vec![Some("1969-12-31T21:00:00-03:00")],
+        ),
+    ];
+
+    for (array, format, expected) in cases {
+        let result = strftime(array.as_ref(), format).unwrap();
+        assert_eq!(
+            result,
+            Utf8Array::<i32>::from(expected),
+            "{:?}",
+            array.data_type()
+        );
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn strftime_chrono_tz() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+    let array = Int64Array::from([Some(0), Some(1_625_140_800)]).to(data_type);
+
+    let result = strftime(&array, "%Y-%m-%d %H:%M %Z").unwrap();
+    let expected =
+        Utf8Array::<i32>::from([Some("1970-01-01 01:00 CET"), Some("2021-07-01 13:00 WEST")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn strftime_errors() {
+    let time = Int64Array::from([Some(1)]).to(DataType::Time64(TimeUnit::Nanosecond));
+    assert!(strftime(&time, "%Y").is_err());
+    assert!(strftime(&time, "%Q").is_err());
+
+    let date = Int32Array::from([Some(i32::MAX)]).to(DataType::Date32);
+    assert!(strftime(&date, "%Y").is_err());
+
+    for data_type in [
+        DataType::Date32,
+        DataType::Time32(TimeUnit::Microsecond),
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        DataType::Duration(TimeUnit::Second),
+        DataType::Utf8,
+    ] {
+        let array = new_null_array(data_type.clone(), 2);
+        assert_eq!(
+            strftime(array.as_ref(), "%H").is_ok(),
+            can_strftime(&data_type)
+        );
+    }
+}
+
+#[test]
+fn date_trunc_timestamp() {
+    let granularities = [
+        (Granularity::Year, 1_609_459_200_000),
+        (Granularity::Quarter, 1_625_097_600_000),
+        (Granularity::Month, 1_627_776_000_000),
+        (Granularity::Week, 1_629_072_000_000),
+        (Granularity::Day, 1_629_331_200_000),
+        (Granularity::Hour, 1_629_378_000_000),
+        (Granularity::Minute, 1_629_380_700_000),
+        (Granularity::Second, 1_629_380_730_000),
+    ];
+    // 2021-08-19T13:45:30.123 and 1969-12-31T23:59:59.500
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let array = Int64Array::from([Some(1_629_380_730_123), None, Some(-500)]).to(data_type.clone());
+
+    for (granularity, expected) in granularities {
+        let result = date_trunc(&array, granularity).unwrap();
+        let before_epoch = match granularity {
+            Granularity::Year => -31_536_000_000,
+            Granularity::Quarter => -7_948_800_000,
+            Granularity::Month => -2_678_400_000,
+            Granularity::Week => -259_200_000,
+            Granularity::Day => -86_400_000,
+            Granularity::Hour => -3_600_000,
+            Granularity::Minute => -60_000,
+            Granularity::Second => -1_000,
+        };
+        let expected =
+            Int64Array::from([Some(expected), None, Some(before_epoch)]).to(data_type.clone());
+        assert_eq!(expected, result.as_ref(), "{granularity:?}");
+    }
+}
+
+#[test]
+fn date_trunc_dates() {
+    // 2021-08-19
+    let array = Int32Array::from([Some(18_858), None]).to(DataType::Date32);
+    let result = date_trunc(&array, Granularity::Week).unwrap();
+    assert_eq!(
+        Int32Array::from([Some(18_855), None]).to(DataType::Date32),
+        result.as_ref()
+    );
+    let result = date_trunc(&array, Granularity::Quarter).unwrap();
+    assert_eq!(
+        Int32Array::from([Some(18_809), None]).to(DataType::Date32),
+        result.as_ref()
+    );
+    let result = date_trunc(&array, Granularity::Day).unwrap();
+    assert_eq!(array, result.as_ref());
+    // dates have no time
+    assert!(date_trunc(&array, Granularity::Hour).is_err());
+
+    let array = Int64Array::from([Some(1_629_380_730_123)]).to(DataType::Date64);
+    assert!(date_trunc(&array, Granularity::Second).is_err());
+    let result = date_trunc(&array, Granularity::Day).unwrap();
+    assert_eq!(
+        Int64Array::from([Some(1_629_331_200_000)]).to(DataType::Date64),
+        result.as_ref()
+    );
+    let result = date_trunc(&array, Granularity::Month).unwrap();
+    assert_eq!(
+        Int64Array::from([Some(1_627_776_000_000)]).to(DataType::Date64),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_fixed_offset() {
+    // 2021-08-19T19:15:30.123+05:30
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+05:30".to_string()));
+    let array = Int64Array::from([Some(1_629_380_730_123)]).to(data_type.clone());
+
+    let result = date_trunc(&array, Granularity::Day).unwrap();
+    let expected = Int64Array::from([Some(1_629_311_400_000)]).to(data_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let result = date_trunc(&array, Granularity::Hour).unwrap();
+    let expected = Int64Array::from([Some(1_629_379_800_000)]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_chrono_tz() {
+    // a day with a transition in Europe/Lisbon (local midnight exists)
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+    let array = Int64Array::from([Some(1_616_932_800)]).to(data_type.clone());
+    let result = date_trunc(&array, Granularity::Day).unwrap();
+    assert_eq!(
+        Int64Array::from([Some(1_616_889_600)]).to(data_type),
+        result.as_ref()
+    );
+
+    // in America/Sao_Paulo on 2018-11-04, midnight was skipped to 01:00
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/Sao_Paulo".to_string()));
+    let array = Int64Array::from([Some(1_541_332_800)]).to(data_type.clone());
+    let result = date_trunc(&array, Granularity::Day).unwrap();
+    assert_eq!(
+        Int64Array::from([Some(1_541_300_400)]).to(data_type),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn consistency_date_trunc() {
+    for data_type in [
+        DataType::Date32,
+        DataType::Date64,
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+        DataType::Time64(TimeUnit::Nanosecond),
+        DataType::Int64,
+    ] {
+        let array = new_null_array(data_type.clone(), 2);
+        let result = date_trunc(array.as_ref(), Granularity::Day);
+        assert_eq!(result.is_ok(), can_date_trunc(&data_type));
+    }
+}
@@ -0,0 +1,111 @@
+use arrow2::array::*;
+use arrow2::compute::unique::*;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Int32Array::from([Some(3), None, Some(3), Some(1), None, Some(2)]);
+    let (values, inverse) = unique(&array, true)?;
+
+    let expected = Int32Array::from([Some(3), None, Some(1), Some(2)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(inverse.unwrap().values().as_slice(), &[0, 1, 0, 2, 1, 3]);
+    Ok(())
+}
+
+#[test]
+fn floats() -> Result<()> {
+    let array = Float64Array::from_slice([f64::NAN, 0.0, -0.0, f64::NAN]);
+    let (values, inverse) = unique(&array, true)?;
+
+    assert_eq!(values.len(), 3);
+    assert_eq!(inverse.unwrap().values().as_slice(), &[0, 1, 2, 0]);
+    Ok(())
+}
+
+#[test]
+fn utf8_sliced() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("x"), Some("a"), Some("b"), Some("a")]).sliced(1, 3);
+    let (values, inverse) = unique(&array, false)?;
+
+    let expected = Utf8Array::<i64>::from_slice(["a", "b"]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert!(inverse.is_none());
+    Ok(())
+}
+
+#[test]
+fn boolean_and_binary() -> Result<()> {
+    let array = BooleanArray::from([Some(true), Some(true), None, Some(false)]);
+    let (values, _) = unique(&array, false)?;
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+
+    let array = BinaryArray::<i32>::from_slice([b"a".as_ref(), b"", b"a"]);
+    let (values, inverse) = unique(&array, true)?;
+    let expected = BinaryArray::<i32>::from_slice([b"a".as_ref(), b""]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+    assert_eq!(inverse.unwrap().values().as_slice(), &[0, 1, 0]);
+    Ok(())
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    // the values "a" are duplicated in the dictionary
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]);
+    let keys = PrimitiveArray::<i32>::from([Some(2), Some(1), None, Some(0), Some(1)]);
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let array = DictionaryArray::try_new(data_type.clone(), keys, values.boxed())?;
+    let (result, inverse) = unique(&array, true)?;
+
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let expected = DictionaryArray::try_new(data_type, keys, values.boxed())?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert_eq!(inverse.unwrap().values().as_slice(), &[0, 1, 2, 0, 1]);
+    Ok(())
+}
+
+#[test]
+fn inverse_re_encodes() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+    let (values, inverse) = unique(&array, true)?;
+
+    let data_type = DataType::Dictionary(IntegerType::UInt32, Box::new(DataType::Utf8), false);
+    let dictionary = DictionaryArray::try_new(data_type, inverse.unwrap(), values)?;
+    let decoded = dictionary
+        .iter()
+        .map(|x| {
+            x.and_then(|x| {
+                x.as_any()
+                    .downcast_ref::<arrow2::scalar::Utf8Scalar<i32>>()
+                    .unwrap()
+                    .value()
+                    .map(|x| x.to_string())
+            })
+        })
+        .collect::<Vec<_>>();
+    let expected = array
+        .iter()
+        .map(|x| x.map(|x| x.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(decoded, expected);
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    assert!(!can_unique(&DataType::List(Box::new(
+        arrow2::datatypes::Field::new("item", DataType::Int32, true)
+    ))));
+    assert!(can_unique(&DataType::Dictionary(
+        IntegerType::UInt8,
+        Box::new(DataType::LargeUtf8),
+        false
+    )));
+    let array = ListArray::<i32>::new_empty(DataType::List(Box::new(
+        arrow2::datatypes::Field::new("item", DataType::Int32, true),
+    )));
+    assert!(unique(&array, false).is_err());
+}
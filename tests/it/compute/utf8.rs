@@ -1,4 +1,4 @@
-use arrow2::{array::*, compute::utf8::*, error::Result, offset::Offset};
+use arrow2::{array::*, compute::utf8::*, datatypes::DataType, error::Result, offset::Offset};
 
 fn with_nulls_utf8_lower<O: Offset>() -> Result<()> {
     let cases = vec![
@@ -369,3 +369,383 @@ fn consistency_upper() {
         }
     });
 }
+
+fn check<O: Offset>(result: Result<Box<dyn Array>>, expected: &[Option<&str>]) {
+    let result = result.unwrap();
+    let result = result.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    assert_eq!(result, &Utf8Array::<O>::from(expected));
+}
+
+fn generic_trim<O: Offset>() {
+    let array = Utf8Array::<O>::from([
+        Some("  hello \t"),
+        None,
+        Some(""),
+        Some("xyhelloyx"),
+        Some("\u{a0}olá\u{a0}"),
+    ]);
+
+    check::<O>(
+        trim(&array, None),
+        &[
+            Some("hello"),
+            None,
+            Some(""),
+            Some("xyhelloyx"),
+            Some("olá"),
+        ],
+    );
+    check::<O>(
+        ltrim(&array, None),
+        &[
+            Some("hello \t"),
+            None,
+            Some(""),
+            Some("xyhelloyx"),
+            Some("olá\u{a0}"),
+        ],
+    );
+    check::<O>(
+        rtrim(&array, None),
+        &[
+            Some("  hello"),
+            None,
+            Some(""),
+            Some("xyhelloyx"),
+            Some("\u{a0}olá"),
+        ],
+    );
+
+    let array = array.sliced(2, 3);
+    check::<O>(
+        trim(&array, Some("xy")),
+        &[Some(""), Some("hello"), Some("\u{a0}olá\u{a0}")],
+    );
+    check::<O>(
+        ltrim(&array, Some("yx")),
+        &[Some(""), Some("helloyx"), Some("\u{a0}olá\u{a0}")],
+    );
+    check::<O>(
+        rtrim(&array, Some("\u{a0}á")),
+        &[Some(""), Some("xyhelloyx"), Some("\u{a0}ol")],
+    );
+    check::<O>(
+        trim(&array, Some("")),
+        &[Some(""), Some("xyhelloyx"), Some("\u{a0}olá\u{a0}")],
+    );
+}
+
+#[test]
+fn trim_string() {
+    generic_trim::<i32>()
+}
+
+#[test]
+fn trim_large_string() {
+    generic_trim::<i64>()
+}
+
+fn generic_pad<O: Offset>() {
+    let array = Utf8Array::<O>::from([Some("ab"), None, Some(""), Some("abcde"), Some("olá")]);
+
+    check::<O>(
+        lpad(&array, 4, "xy"),
+        &[Some("xyab"), None, Some("xyxy"), Some("abcd"), Some("xolá")],
+    );
+    check::<O>(
+        rpad(&array, 4, "é"),
+        &[Some("abéé"), None, Some("éééé"), Some("abcd"), Some("oláé")],
+    );
+    check::<O>(
+        lpad(&array, 2, " "),
+        &[Some("ab"), None, Some("  "), Some("ab"), Some("ol")],
+    );
+    check::<O>(
+        rpad(&array, 6, ""),
+        &[Some("ab"), None, Some(""), Some("abcde"), Some("olá")],
+    );
+    check::<O>(
+        lpad(&array, 0, "x"),
+        &[Some(""), None, Some(""), Some(""), Some("")],
+    );
+}
+
+#[test]
+fn pad_string() {
+    generic_pad::<i32>()
+}
+
+#[test]
+fn pad_large_string() {
+    generic_pad::<i64>()
+}
+
+#[test]
+fn case_sliced_and_special() {
+    let array = Utf8Array::<i32>::from([Some("ABC"), Some("ΟΔΟΣ"), None, Some("straße")]);
+    let array = array.sliced(1, 3);
+    check::<i32>(lower(&array), &[Some("οδος"), None, Some("straße")]);
+    check::<i32>(upper(&array), &[Some("ΟΔΟΣ"), None, Some("STRASSE")]);
+}
+
+#[test]
+fn consistency_trim_pad() {
+    for data_type in [
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Binary,
+        DataType::Int32,
+    ] {
+        let array = new_null_array(data_type.clone(), 3);
+        assert_eq!(trim(array.as_ref(), None).is_ok(), can_trim(&data_type));
+        assert_eq!(ltrim(array.as_ref(), None).is_ok(), can_trim(&data_type));
+        assert_eq!(rtrim(array.as_ref(), None).is_ok(), can_trim(&data_type));
+        assert_eq!(lpad(array.as_ref(), 2, "x").is_ok(), can_pad(&data_type));
+        assert_eq!(rpad(array.as_ref(), 2, "x").is_ok(), can_pad(&data_type));
+    }
+}
+
+fn check_split<O: Offset>(result: Result<Box<dyn Array>>, expected: Vec<Option<Vec<&str>>>) {
+    let result = result.unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<O>>().unwrap();
+
+    let mut array = MutableListArray::<O, MutableUtf8Array<O>>::new();
+    array
+        .try_extend(
+            expected
+                .into_iter()
+                .map(|parts| parts.map(|parts| parts.into_iter().map(Some).collect::<Vec<_>>())),
+        )
+        .unwrap();
+    let expected: ListArray<O> = array.into();
+    assert_eq!(result, &expected);
+}
+
+fn generic_split<O: Offset>() {
+    let array = Utf8Array::<O>::from([Some("a, b, c"), None, Some(""), Some(", a, "), Some("olá")]);
+
+    check_split::<O>(
+        split(&array, ", "),
+        vec![
+            Some(vec!["a", "b", "c"]),
+            None,
+            Some(vec![""]),
+            Some(vec!["", "a", ""]),
+            Some(vec!["olá"]),
+        ],
+    );
+    check_split::<O>(
+        split_n(&array, ", ", 2),
+        vec![
+            Some(vec!["a", "b, c"]),
+            None,
+            Some(vec![""]),
+            Some(vec!["", "a, "]),
+            Some(vec!["olá"]),
+        ],
+    );
+    check_split::<O>(
+        split(&array.clone().sliced(3, 2), "l"),
+        vec![Some(vec![", a, "]), Some(vec!["o", "á"])],
+    );
+
+    check::<O>(
+        split_part(&array, ", ", 2),
+        &[Some("b"), None, Some(""), Some("a"), Some("")],
+    );
+    check::<O>(
+        split_part(&array, ", ", -1),
+        &[Some("c"), None, Some(""), Some(""), Some("olá")],
+    );
+    check::<O>(
+        split_part(&array, ", ", -3),
+        &[Some("a"), None, Some(""), Some(""), Some("")],
+    );
+
+    assert!(split(&array, "").is_err());
+    assert!(split_n(&array, ",", 0).is_err());
+    assert!(split_part(&array, ",", 0).is_err());
+    assert!(split_part(&array, "", 1).is_err());
+}
+
+#[test]
+fn split_string() {
+    generic_split::<i32>()
+}
+
+#[test]
+fn split_large_string() {
+    generic_split::<i64>()
+}
+
+#[test]
+fn consistency_split() {
+    for data_type in [
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Binary,
+        DataType::Int32,
+    ] {
+        let array = new_null_array(data_type.clone(), 3);
+        assert_eq!(split(array.as_ref(), ",").is_ok(), can_split(&data_type));
+        assert_eq!(
+            split_n(array.as_ref(), ",", 2).is_ok(),
+            can_split(&data_type)
+        );
+        assert_eq!(
+            split_part(array.as_ref(), ",", 1).is_ok(),
+            can_split(&data_type)
+        );
+    }
+}
+
+fn generic_concat<O: Offset>() {
+    let a = Utf8Array::<O>::from([Some("a"), Some("b"), None, Some("olá")]);
+    let b = Utf8Array::<O>::from([Some("1"), None, None, Some("")]);
+    let c = Utf8Array::<O>::from([Some("x"), Some("y"), Some("z"), Some("é")]);
+
+    check::<O>(
+        concat_elementwise(&[&a, &b, &c], ConcatNulls::Propagate),
+        &[Some("a1x"), None, None, Some("oláé")],
+    );
+    check::<O>(
+        concat_elementwise(&[&a, &b, &c], ConcatNulls::Skip),
+        &[Some("a1x"), Some("by"), Some("z"), Some("oláé")],
+    );
+    check::<O>(
+        concat_ws(&[&a, &b, &c], "--", ConcatNulls::Propagate),
+        &[Some("a--1--x"), None, None, Some("olá----é")],
+    );
+    check::<O>(
+        concat_ws(&[&a, &b, &c], "--", ConcatNulls::Skip),
+        &[Some("a--1--x"), Some("b--y"), Some("z"), Some("olá----é")],
+    );
+    check::<O>(
+        concat_ws(&[&a], "--", ConcatNulls::Skip),
+        &[Some("a"), Some("b"), Some(""), Some("olá")],
+    );
+
+    let a = a.sliced(1, 3);
+    let c = c.sliced(0, 3);
+    check::<O>(
+        concat_ws(&[&a, &c], " ", ConcatNulls::Propagate),
+        &[Some("b x"), None, Some("olá z")],
+    );
+}
+
+#[test]
+fn concat_string() {
+    generic_concat::<i32>()
+}
+
+#[test]
+fn concat_large_string() {
+    generic_concat::<i64>()
+}
+
+#[test]
+fn concat_binary() {
+    let a = BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(&[0, 255])]);
+    let b = BinaryArray::<i32>::from([Some(b"b".as_ref()), Some(b"c"), Some(&[1])]);
+
+    let result = concat_ws(&[&a, &b], ",", ConcatNulls::Skip).unwrap();
+    let expected =
+        BinaryArray::<i32>::from([Some(b"a,b".as_ref()), Some(b"c"), Some(&[0, 255, 44, 1])]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn concat_errors() {
+    let a = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let b = Utf8Array::<i64>::from_slice(["a", "b"]);
+
+    assert!(concat_elementwise(&[], ConcatNulls::Skip).is_err());
+    assert!(concat_elementwise(&[&a, &b], ConcatNulls::Skip).is_err());
+    assert!(concat_elementwise(&[&a, &a.clone().sliced(0, 1)], ConcatNulls::Skip).is_err());
+
+    for data_type in [DataType::Utf8, DataType::LargeBinary, DataType::Int32] {
+        let array = new_null_array(data_type.clone(), 3);
+        assert_eq!(
+            concat_elementwise(&[array.as_ref()], ConcatNulls::Propagate).is_ok(),
+            can_concat_elementwise(&data_type)
+        );
+    }
+}
+
+fn generic_replace<O: Offset>() {
+    let array = Utf8Array::<O>::from([Some("aXbXc"), None, Some(""), Some("XXX"), Some("olá")]);
+
+    check::<O>(
+        replace(&array, "X", "--"),
+        &[Some("a--b--c"), None, Some(""), Some("------"), Some("olá")],
+    );
+    check::<O>(
+        replace(&array, "XX", ""),
+        &[Some("aXbXc"), None, Some(""), Some("X"), Some("olá")],
+    );
+    check::<O>(
+        replace_n(&array, "X", "é", 1),
+        &[Some("aébXc"), None, Some(""), Some("éXX"), Some("olá")],
+    );
+    check::<O>(
+        replace_n(&array, "X", "é", 0),
+        &[Some("aXbXc"), None, Some(""), Some("XXX"), Some("olá")],
+    );
+    check::<O>(
+        replace(&array, "", "y"),
+        &[Some("aXbXc"), None, Some(""), Some("XXX"), Some("olá")],
+    );
+    check::<O>(
+        replace(&array.sliced(3, 2), "á", "a"),
+        &[Some("XXX"), Some("ola")],
+    );
+}
+
+#[test]
+fn replace_string() {
+    generic_replace::<i32>()
+}
+
+#[test]
+fn replace_large_string() {
+    generic_replace::<i64>()
+}
+
+#[test]
+fn replace_patterns() {
+    let array = Utf8Array::<i32>::from([Some("a-b-c"), Some("a.b.c"), None, Some("abc")]);
+    let from = Utf8Array::<i32>::from([Some("-"), Some("."), Some("-"), None]);
+
+    check::<i32>(
+        replace_array(&array, &from, "+"),
+        &[Some("a+b+c"), Some("a+b+c"), None, None],
+    );
+    check::<i32>(
+        replace_n_array(&array, &from, "", 1),
+        &[Some("ab-c"), Some("ab.c"), None, None],
+    );
+
+    let large = Utf8Array::<i64>::from_slice(["-", ".", "-", "-"]);
+    assert!(replace_array(&array, &large, "+").is_err());
+    assert!(replace_array(&array, &from.sliced(0, 2), "+").is_err());
+}
+
+#[test]
+fn consistency_replace() {
+    for data_type in [
+        DataType::Utf8,
+        DataType::LargeUtf8,
+        DataType::Binary,
+        DataType::Int32,
+    ] {
+        let array = new_null_array(data_type.clone(), 3);
+        assert_eq!(
+            replace(array.as_ref(), "a", "b").is_ok(),
+            can_replace(&data_type)
+        );
+        assert_eq!(
+            replace_array(array.as_ref(), array.as_ref(), "b").is_ok(),
+            can_replace(&data_type)
+        );
+    }
+}
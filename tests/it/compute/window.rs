@@ -1,11 +1,12 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::*;
 use arrow2::compute::window::*;
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{DictionaryScalar, ListScalar, PrimitiveScalar, StructScalar, Utf8Scalar};
 
 #[test]
 fn shift_pos() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]);
-    let result = shift(&array, 1).unwrap();
+    let result = shift(&array, 1, None).unwrap();
 
     let expected = Int32Array::from(&[None, Some(1), None]);
 
@@ -15,15 +16,251 @@ fn shift_pos() {
 #[test]
 fn shift_many() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    assert!(shift(&array, 10).is_err());
+    assert!(shift(&array, 10, None).is_err());
 }
 
 #[test]
 fn shift_max() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    let result = shift(&array, 3).unwrap();
+    let result = shift(&array, 3, None).unwrap();
 
     let expected = new_null_array(DataType::Date32, 3);
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn shift_fill() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
+    let fill = PrimitiveScalar::new(DataType::Date32, Some(7));
+    let result = shift(&array, 1, Some(&fill)).unwrap();
+    let expected = Int32Array::from(&[Some(7), Some(1), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = shift(&array, -3, Some(&fill)).unwrap();
+    let expected = Int32Array::from_slice([7, 7, 7]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = shift(&array, 0, Some(&fill)).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    // a null fill is a null
+    let fill = PrimitiveScalar::<i32>::new(DataType::Date32, None);
+    let result = shift(&array, 2, Some(&fill)).unwrap();
+    let expected = Int32Array::from(&[None, None, Some(1)]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let fill = PrimitiveScalar::from(Some(7i64));
+    assert!(shift(&array, 1, Some(&fill)).is_err());
+}
+
+#[test]
+fn shift_utf8() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]).sliced(1, 3);
+    let fill = Utf8Scalar::<i32>::new(Some("z"));
+    let result = shift(&array, -2, Some(&fill)).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("d"), Some("z"), Some("z")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_nested() {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([Some(vec![Some(1)]), None, Some(vec![Some(2), Some(3)])])
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+    let fill = ListScalar::<i32>::new(
+        list.data_type().clone(),
+        Some(Int32Array::from_slice([0, 0]).boxed()),
+    );
+    let result = shift(&list, 1, Some(&fill)).unwrap();
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected
+        .try_extend([Some(vec![Some(0), Some(0)]), Some(vec![Some(1)]), None])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(expected, result.as_ref());
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Struct(fields);
+    let array = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from_slice(["x", "y"]).boxed(),
+        ],
+        None,
+    );
+    let fill = StructScalar::new(
+        data_type.clone(),
+        Some(vec![
+            Box::new(PrimitiveScalar::<i32>::from(None)),
+            Box::new(Utf8Scalar::<i32>::new(Some("z"))),
+        ]),
+    );
+    let result = shift(&array, -1, Some(&fill)).unwrap();
+    let expected = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from([Some(2), None]).boxed(),
+            Utf8Array::<i32>::from_slice(["y", "z"]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_dictionary() {
+    let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b")]).unwrap();
+    let array: DictionaryArray<u8> = array.into();
+    let fill = DictionaryScalar::<u8>::new(
+        array.data_type().clone(),
+        Some(Box::new(Utf8Scalar::<i32>::new(Some("c")))),
+    );
+    let result = shift(&array, 1, Some(&fill)).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    let values = (0..result.len())
+        .map(|row| {
+            result.is_valid(row).then(|| {
+                let values = result.values().as_any();
+                let values = values.downcast_ref::<Utf8Array<i32>>().unwrap();
+                values.value(result.key_value(row)).to_string()
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![Some("c".to_string()), Some("a".to_string()), None]
+    );
+}
+
+#[test]
+fn rolling_size() {
+    let values = Int32Array::from([Some(3), Some(1), None, Some(4), Some(1), Some(5)]);
+    let cases = [
+        (
+            RollingAggregate::Sum,
+            Int32Array::from([Some(3), Some(4), Some(4), Some(5), Some(5), Some(10)]).boxed(),
+        ),
+        (
+            RollingAggregate::Min,
+            Int32Array::from([Some(3), Some(1), Some(1), Some(1), Some(1), Some(1)]).boxed(),
+        ),
+        (
+            RollingAggregate::Max,
+            Int32Array::from([Some(3), Some(3), Some(3), Some(4), Some(4), Some(5)]).boxed(),
+        ),
+        (
+            RollingAggregate::Count,
+            UInt64Array::from_slice([1, 2, 2, 2, 2, 3]).boxed(),
+        ),
+        (
+            RollingAggregate::Mean,
+            Float64Array::from_slice([3.0, 2.0, 2.0, 2.5, 2.5, 10.0 / 3.0]).boxed(),
+        ),
+    ];
+    for (aggregate, expected) in cases {
+        let result = rolling(&values, RollingWindow::Size(3), aggregate).unwrap();
+        assert_eq!(expected, result, "{aggregate:?}");
+    }
+}
+
+#[test]
+fn rolling_empty_windows() {
+    let values = Int32Array::from([None, Some(2), None, None]);
+    let result = rolling(&values, RollingWindow::Size(1), RollingAggregate::Sum).unwrap();
+    assert_eq!(
+        Int32Array::from([None, Some(2), None, None]),
+        result.as_ref()
+    );
+
+    let ranges = [0..0, 0..4, 2..4, 4..4];
+    let result = rolling(
+        &values,
+        RollingWindow::Ranges(&ranges),
+        RollingAggregate::Max,
+    )
+    .unwrap();
+    assert_eq!(
+        Int32Array::from([None, Some(2), None, None]),
+        result.as_ref()
+    );
+    let result = rolling(
+        &values,
+        RollingWindow::Ranges(&ranges),
+        RollingAggregate::Count,
+    )
+    .unwrap();
+    assert_eq!(UInt64Array::from_slice([0, 1, 0, 0]), result.as_ref());
+}
+
+#[test]
+fn rolling_min_max_monotonic() {
+    // a decreasing then increasing series exercises both ends of the deque
+    let values = (0..100).map(|x: i32| (x - 50).abs()).collect::<Vec<_>>();
+    let array = Int32Array::from_slice(&values).to(DataType::Date32);
+    let result = rolling(&array, RollingWindow::Size(7), RollingAggregate::Min).unwrap();
+    let expected = (0..100)
+        .map(|i: usize| *values[i.saturating_sub(6)..=i].iter().min().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Int32Array::from_vec(expected).to(DataType::Date32),
+        result.as_ref()
+    );
+
+    let result = rolling(&array, RollingWindow::Size(7), RollingAggregate::Max).unwrap();
+    let expected = (0..100)
+        .map(|i: usize| *values[i.saturating_sub(6)..=i].iter().max().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Int32Array::from_vec(expected).to(DataType::Date32),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn rolling_sum_wraps() {
+    // the running sum overflows, yet every window of two fits in `i8`
+    let values = Int8Array::from_slice([100, 27, 100, -100]);
+    let result = rolling(&values, RollingWindow::Size(2), RollingAggregate::Sum).unwrap();
+    assert_eq!(Int8Array::from_slice([100, 127, 127, 0]), result.as_ref());
+
+    let values = Int8Array::from_slice([i8::MAX, 1]);
+    let result = rolling(&values, RollingWindow::Size(2), RollingAggregate::Sum).unwrap();
+    assert_eq!(Int8Array::from_slice([i8::MAX, i8::MIN]), result.as_ref());
+}
+
+#[test]
+fn rolling_invalid() {
+    let values = Int32Array::from_slice([1, 2]);
+    assert!(rolling(&values, RollingWindow::Size(0), RollingAggregate::Sum).is_err());
+    let ranges = [0..1, 1..2, 2..2];
+    assert!(rolling(
+        &values,
+        RollingWindow::Ranges(&ranges),
+        RollingAggregate::Sum
+    )
+    .is_err());
+    let ranges = [0..1, 0..3];
+    assert!(rolling(
+        &values,
+        RollingWindow::Ranges(&ranges),
+        RollingAggregate::Sum
+    )
+    .is_err());
+    let ranges = [1..2, 0..2];
+    assert!(rolling(
+        &values,
+        RollingWindow::Ranges(&ranges),
+        RollingAggregate::Sum
+    )
+    .is_err());
+}
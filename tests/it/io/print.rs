@@ -3,7 +3,7 @@ use arrow2::{
     bitmap::Bitmap,
     buffer::Buffer,
     chunk::Chunk,
-    datatypes::{DataType, Field, TimeUnit, UnionMode},
+    datatypes::{DataType, Field, Schema, TimeUnit, UnionMode},
     error::Result,
     io::print::*,
 };
@@ -379,3 +379,39 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_options() -> Result<()> {
+    let a = Utf8Array::<i32>::from(vec![Some("hello world"), None, Some("c"), Some("d")]);
+    let b = Int32Array::from(vec![Some(1), None, Some(10), Some(100)]);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array, &b])?;
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Utf8, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+
+    let options = PrintOptions {
+        max_rows: Some(3),
+        max_column_width: Some(5),
+        null: "null",
+    };
+    let table = write_schema(&[batch.clone(), batch], &schema, &options);
+
+    let expected = vec![
+        "+-------+------+",
+        "| a     | b    |",
+        "+-------+------+",
+        "| hell… | 1    |",
+        "| null  | null |",
+        "| c     | 10   |",
+        "| ...   | ...  |",
+        "+-------+------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    Ok(())
+}
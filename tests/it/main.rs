@@ -8,6 +8,7 @@ mod arrow;
 mod bitmap;
 mod buffer;
 mod ffi;
+mod offsets;
 mod scalar;
 mod temporal_conversions;
 mod types;
@@ -0,0 +1,56 @@
+use arrow2::offset::*;
+
+#[test]
+fn try_from_invariants() {
+    assert!(Offsets::<i32>::try_from(vec![]).is_err());
+    assert!(Offsets::<i32>::try_from(vec![-1, 0]).is_err());
+    assert!(Offsets::<i32>::try_from(vec![0, 2, 1]).is_err());
+    assert!(OffsetsBuffer::<i64>::try_from(vec![0, 2, 1]).is_err());
+
+    let offsets = Offsets::<i32>::try_from(vec![0, 1, 1, 3]).unwrap();
+    assert_eq!(offsets.len_proxy(), 3);
+    assert_eq!(offsets.start_end(2), (1, 3));
+}
+
+#[test]
+fn push_overflow() {
+    let mut offsets = Offsets::<i32>::new();
+    offsets.try_push_usize(i32::MAX as usize).unwrap();
+    assert!(offsets.try_push(1).is_err());
+    assert!(offsets.try_push_usize(1).is_err());
+    assert_eq!(offsets.len_proxy(), 1);
+}
+
+#[test]
+fn from_lengths() {
+    let offsets = Offsets::<i32>::try_from_lengths([1, 0, 2].into_iter()).unwrap();
+    assert_eq!(offsets.as_slice(), &[0, 1, 1, 3]);
+
+    assert!(Offsets::<i32>::try_from_lengths([i32::MAX as usize, 1].into_iter()).is_err());
+
+    // a failed extension leaves the offsets untouched
+    let mut offsets = Offsets::<i32>::try_from_lengths([1].into_iter()).unwrap();
+    assert!(offsets
+        .try_extend_from_lengths([1, i32::MAX as usize].into_iter())
+        .is_err());
+    assert_eq!(offsets.as_slice(), &[0, 1]);
+}
+
+#[test]
+fn buffer_slice() {
+    let mut offsets = OffsetsBuffer::<i32>::try_from(vec![0, 1, 3, 6]).unwrap();
+    offsets.slice(1, 3);
+    assert_eq!(offsets.as_slice(), &[1, 3, 6]);
+    assert_eq!(offsets.range(), 5);
+    assert_eq!(offsets.lengths().collect::<Vec<_>>(), vec![2, 3]);
+}
+
+#[test]
+fn convert_offset_type() {
+    let offsets = Offsets::<i64>::try_from(vec![0, i32::MAX as i64 + 1]).unwrap();
+    assert!(Offsets::<i32>::try_from(offsets).is_err());
+
+    let offsets = OffsetsBuffer::<i32>::try_from(vec![0, 2]).unwrap();
+    let offsets: OffsetsBuffer<i64> = (&offsets).into();
+    assert_eq!(offsets.as_slice(), &[0, 2]);
+}
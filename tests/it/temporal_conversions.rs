@@ -264,6 +264,26 @@ fn add_interval_fixed_offset() {
     assert_eq!("1972-01-29 02:01:00 +01:00", format!("{r}"));
 }
 
+#[test]
+fn add_naive_interval_end_of_month() {
+    let timeunit = TimeUnit::Millisecond;
+    // Thu Jan 31 2019 10:00:00
+    let timestamp = 1548928800000;
+
+    let r =
+        temporal_conversions::add_naive_interval(timestamp, timeunit, months_days_ns::new(1, 0, 0));
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2019-02-28 10:00:00", format!("{r}"));
+
+    let r = temporal_conversions::add_naive_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(13, 1, -1_000_000),
+    );
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2020-03-01 09:59:59.999", format!("{r}"));
+}
+
 #[cfg(feature = "chrono-tz")]
 #[test]
 fn add_interval_timezone() {
@@ -292,3 +312,31 @@ fn add_interval_timezone() {
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{r}"));
 }
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn add_interval_gap() {
+    // on 2021-03-28, Europe/Berlin skipped from 02:00 CET to 03:00 CEST
+    let timeunit = TimeUnit::Second;
+    let timezone = temporal_conversions::parse_offset_tz("Europe/Berlin").unwrap();
+
+    // 2021-02-28 02:10:00 CET + 1 month, from before the gap
+    let r = temporal_conversions::add_interval(
+        1614474600,
+        timeunit,
+        months_days_ns::new(1, 0, 0),
+        &timezone,
+    );
+    let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
+    assert_eq!("2021-03-28 03:10:00 CEST", format!("{r}"));
+
+    // 2021-07-28 02:10:00 CEST - 4 months, from after the gap
+    let r = temporal_conversions::add_interval(
+        1627431000,
+        timeunit,
+        months_days_ns::new(-4, 0, 0),
+        &timezone,
+    );
+    let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
+    assert_eq!("2021-03-28 03:10:00 CEST", format!("{r}"));
+}